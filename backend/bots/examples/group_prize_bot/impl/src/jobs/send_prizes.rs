@@ -1,7 +1,6 @@
 use crate::{mutate_state, Prize, RuntimeState};
+use bot_sdk::Payout;
 use ic_ledger_types::Tokens;
-use icrc_ledger_types::icrc1::account::Account;
-use ledger_utils::icrc1;
 use rand::Rng;
 use std::{cmp, time::Duration};
 use tracing::{error, trace};
@@ -146,20 +145,17 @@ async fn transfer_prize_funds_to_group(
     amount: u128,
     now_nanos: TimestampNanos,
 ) -> Result<CompletedCryptoTransaction, String> {
-    // Assume ICRC-1 for now
-    let pending_transaction = types::icrc1::PendingCryptoTransaction {
-        ledger: ledger_canister_id,
+    let payout = Payout {
         fee: token.fee().unwrap(),
         token,
+        ledger_canister_id,
+        recipient: group,
         amount,
-        to: Account::from(group),
         memo: None,
-        created: now_nanos,
     };
 
-    match icrc1::process_transaction(pending_transaction, group).await {
+    match bot_sdk::pay_out(payout, now_nanos).await {
         Ok(completed_transaction) => mutate_state(|state| {
-            let completed_transaction = CompletedCryptoTransaction::from(completed_transaction);
             state.data.prizes_sent.push(Prize {
                 group,
                 transaction: completed_transaction.clone(),
@@ -197,6 +193,7 @@ async fn send_prize_message_to_group(
         forwarding: false,
         rules_accepted: None,
         correlation_id: 0,
+        forwarded_from: None,
     };
 
     match group_canister_c2c_client::send_message_v2(group, &c2c_args).await {
@@ -1,8 +1,8 @@
+use bot_sdk::BotData;
 use candid::{CandidType, Principal};
-use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
-use std::cell::RefCell;
 use std::collections::HashSet;
 use types::{
     BuildVersion, CanisterId, CompletedCryptoTransaction, Cryptocurrency, Cycles, Document, TimestampMillis, Timestamped,
@@ -12,15 +12,10 @@ use utils::env::Environment;
 mod guards;
 mod jobs;
 mod lifecycle;
-mod memory;
 mod queries;
 mod updates;
 
-thread_local! {
-    static WASM_VERSION: RefCell<Timestamped<BuildVersion>> = RefCell::default();
-}
-
-canister_state!(RuntimeState);
+bot_sdk::bot_canister!(RuntimeState, Data, group_prize_bot::post_upgrade::Args, crate::jobs::start);
 
 struct RuntimeState {
     pub env: Box<dyn Environment>,
@@ -106,6 +101,20 @@ impl Data {
     }
 }
 
+impl BotData for Data {
+    fn test_mode(&self) -> bool {
+        self.test_mode
+    }
+
+    fn rng_seed(&self) -> [u8; 32] {
+        self.rng_seed
+    }
+
+    fn set_rng_seed(&mut self, rng_seed: [u8; 32]) {
+        self.rng_seed = rng_seed;
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct PrizeData {
     pub token: Cryptocurrency,
@@ -137,3 +146,28 @@ pub struct Metrics {
     pub total_value_sent: u64,
     pub prizes_sent: u32,
 }
+
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge(
+                "group_prize_bot_started",
+                "Whether prizes are being sent",
+                u8::from(self.started),
+            )
+            .gauge("group_prize_bot_groups", "Groups which prizes are sent to", self.group_count)
+            .counter("group_prize_bot_prizes_sent", "Prizes sent", self.prizes_sent)
+            .counter(
+                "group_prize_bot_value_sent",
+                "Total value of the prizes sent",
+                self.total_value_sent,
+            );
+    }
+}
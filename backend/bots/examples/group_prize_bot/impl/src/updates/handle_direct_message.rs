@@ -1,23 +1,27 @@
 use crate::{mutate_state, RuntimeState};
+use bot_sdk::{text_message, BotMessageHandler};
 use canister_api_macros::update_msgpack;
 use canister_tracing_macros::trace;
 use group_prize_bot::handle_direct_message::*;
-use types::{BotMessage, MessageContentInitial, TextContent};
+use types::{MessageContentInitial, UserId};
 
 #[update_msgpack]
 #[trace]
-fn handle_direct_message(_args: Args) -> Response {
-    mutate_state(handle_message)
+fn handle_direct_message(args: Args) -> Response {
+    mutate_state(|state| {
+        let sender = state.env.caller().into();
+        bot_sdk::handle_direct_message(state, sender, args)
+    })
 }
 
-fn handle_message(state: &mut RuntimeState) -> Response {
-    let text = "Keep an eye out for prize messages in public groups - you've got to be quick to claim a prize!".to_string();
-    Success(SuccessResult {
-        bot_name: state.data.username.clone(),
-        bot_display_name: None,
-        messages: vec![BotMessage {
-            content: MessageContentInitial::Text(TextContent { text }),
-            message_id: None,
-        }],
-    })
+impl BotMessageHandler for RuntimeState {
+    fn bot_name(&self) -> String {
+        self.data.username.clone()
+    }
+
+    fn handle_message(&mut self, _sender: UserId, _message: Args) -> Vec<MessageContentInitial> {
+        vec![text_message(
+            "Keep an eye out for prize messages in public groups - you've got to be quick to claim a prize!",
+        )]
+    }
 }
@@ -3,9 +3,6 @@ use crate::{mutate_state, read_state, PrizeData};
 use canister_tracing_macros::trace;
 use group_prize_bot::initialize_bot::{Response::*, *};
 use ic_cdk_macros::update;
-use types::Cycles;
-
-const BOT_REGISTRATION_FEE: Cycles = 10_000_000_000_000; // 10T
 
 #[update(guard = "caller_is_admin")]
 #[trace]
@@ -22,7 +19,7 @@ async fn initialize_bot(args: Args) -> Response {
                 username: args.username.clone(),
                 display_name: None,
             };
-            user_index_canister_c2c_client::c2c_register_bot(user_index_canister_id, &register_bot_args, BOT_REGISTRATION_FEE)
+            bot_sdk::register_bot(user_index_canister_id, &register_bot_args)
                 .await
                 .map(|r| r.into())
         };
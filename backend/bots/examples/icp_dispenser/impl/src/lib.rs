@@ -1,25 +1,20 @@
 use crate::model::pending_actions::PendingActions;
 use crate::model::reward_codes::RewardCodes;
+use bot_sdk::BotData;
 use candid::{CandidType, Principal};
-use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use serde::{Deserialize, Serialize};
-use std::cell::RefCell;
 use std::collections::HashSet;
 use types::{BuildVersion, CanisterId, Cycles, Document, TimestampMillis, Timestamped};
 use utils::env::Environment;
 
 mod guards;
 mod lifecycle;
-mod memory;
 mod model;
 mod queries;
 mod updates;
 
-thread_local! {
-    static WASM_VERSION: RefCell<Timestamped<BuildVersion>> = RefCell::default();
-}
-
-canister_state!(RuntimeState);
+bot_sdk::bot_canister!(RuntimeState, Data, icp_dispenser_bot::post_upgrade::Args);
 
 struct RuntimeState {
     pub env: Box<dyn Environment>,
@@ -81,6 +76,20 @@ impl Data {
     }
 }
 
+impl BotData for Data {
+    fn test_mode(&self) -> bool {
+        self.test_mode
+    }
+
+    fn rng_seed(&self) -> [u8; 32] {
+        self.rng_seed
+    }
+
+    fn set_rng_seed(&mut self, rng_seed: [u8; 32]) {
+        self.rng_seed = rng_seed;
+    }
+}
+
 #[derive(CandidType, Serialize, Debug)]
 pub struct Metrics {
     pub now: TimestampMillis,
@@ -89,3 +98,15 @@ pub struct Metrics {
     pub wasm_version: BuildVersion,
     pub git_commit_id: String,
 }
+
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry.canister(
+            self.now,
+            self.memory_used,
+            self.cycles_balance,
+            self.wasm_version,
+            &self.git_commit_id,
+        );
+    }
+}
@@ -1,12 +1,10 @@
 use crate::model::pending_actions::PendingAction;
 use crate::{mutate_state, read_state, RuntimeState};
+use bot_sdk::Payout;
 use ic_cdk_macros::heartbeat;
 use ic_ledger_types::{TransferArgs, MAINNET_LEDGER_CANISTER_ID};
-use ledger_utils::default_ledger_account;
 use tracing::{error, info};
-use types::{
-    nns, BotMessage, CompletedCryptoTransaction, CryptoContent, CryptoTransaction, Cryptocurrency, TransactionHash, UserId,
-};
+use types::{BotMessage, Cryptocurrency, TransactionHash, UserId};
 
 #[heartbeat]
 fn heartbeat() {
@@ -15,7 +13,6 @@ fn heartbeat() {
 
 mod process_pending_actions {
     use super::*;
-    use types::MessageContentInitial;
 
     pub fn run() {
         if let Some(action) = mutate_state(get_next) {
@@ -34,33 +31,29 @@ mod process_pending_actions {
     }
 
     async fn process_icp_transfer(recipient: UserId, transfer_args: TransferArgs, transaction_hash: TransactionHash) {
-        let action = match icp_ledger_canister_c2c_client::transfer(MAINNET_LEDGER_CANISTER_ID, &transfer_args).await {
-            Ok(Ok(block_index)) => {
-                let this_canister_id = read_state(|state| state.env.canister_id());
+        let payout = Payout {
+            token: Cryptocurrency::InternetComputer,
+            ledger_canister_id: MAINNET_LEDGER_CANISTER_ID,
+            recipient: recipient.into(),
+            amount: transfer_args.amount.e8s() as u128,
+            fee: transfer_args.fee.e8s() as u128,
+            memo: None,
+        };
+        // Reuse the time the code was claimed so that retries are deduplicated by the ledger
+        let created_nanos = transfer_args.created_at_time.map_or(0, |t| t.timestamp_nanos);
+
+        let action = match bot_sdk::pay_out(payout, created_nanos).await {
+            Ok(transaction) => {
                 let message = BotMessage {
-                    content: MessageContentInitial::Crypto(CryptoContent {
-                        recipient,
-                        transfer: CryptoTransaction::Completed(CompletedCryptoTransaction::NNS(
-                            nns::CompletedCryptoTransaction {
-                                ledger: Cryptocurrency::InternetComputer.ledger_canister_id().unwrap(),
-                                token: Cryptocurrency::InternetComputer,
-                                amount: transfer_args.amount,
-                                fee: transfer_args.fee,
-                                from: nns::CryptoAccount::Account(default_ledger_account(this_canister_id)),
-                                to: nns::CryptoAccount::Account(transfer_args.to),
-                                memo: transfer_args.memo,
-                                created: transfer_args.created_at_time.map_or(0, |t| t.timestamp_nanos / 1_000_000),
-                                transaction_hash,
-                                block_index,
-                            },
-                        )),
-                        caption: None,
-                    }),
+                    content: bot_sdk::crypto_message(recipient, transaction, None),
                     message_id: None,
                 };
                 PendingAction::SendMessages(recipient, vec![message])
             }
-            _ => PendingAction::IcpTransfer(recipient, transfer_args, transaction_hash),
+            Err(failed_transaction) => {
+                error!(?failed_transaction, "Failed to transfer ICP, retrying");
+                PendingAction::IcpTransfer(recipient, transfer_args, transaction_hash)
+            }
         };
 
         mutate_state(|state| state.data.pending_actions.add(action, state.env.now()));
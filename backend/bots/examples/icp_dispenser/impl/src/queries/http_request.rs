@@ -1,8 +1,10 @@
 use crate::{read_state, RuntimeState};
-use http_request::{build_json_response, build_response, encode_logs, extract_route, get_document, Route};
+use http_request::{
+    build_metrics_response, build_response, encode_logs, extract_route, get_document, LogsQuery, MetricsFormat, Route,
+};
 use ic_cdk_macros::query;
 use ledger_utils::default_ledger_account;
-use types::{HttpRequest, HttpResponse, TimestampMillis};
+use types::{HttpRequest, HttpResponse};
 
 #[query]
 fn http_request(request: HttpRequest) -> HttpResponse {
@@ -10,16 +12,16 @@ fn http_request(request: HttpRequest) -> HttpResponse {
         get_document(requested_avatar_id, &state.data.avatar, "avatar")
     }
 
-    fn get_logs_impl(since: Option<TimestampMillis>) -> HttpResponse {
-        encode_logs(canister_logger::export_logs(), since.unwrap_or(0))
+    fn get_logs_impl(query: LogsQuery) -> HttpResponse {
+        encode_logs(canister_logger::export_logs(), query)
     }
 
-    fn get_traces_impl(since: Option<TimestampMillis>) -> HttpResponse {
-        encode_logs(canister_logger::export_traces(), since.unwrap_or(0))
+    fn get_traces_impl(query: LogsQuery) -> HttpResponse {
+        encode_logs(canister_logger::export_traces(), query)
     }
 
-    fn get_metrics_impl(state: &RuntimeState) -> HttpResponse {
-        build_json_response(&state.metrics())
+    fn get_metrics_impl(format: MetricsFormat, state: &RuntimeState) -> HttpResponse {
+        build_metrics_response(&state.metrics(), format)
     }
 
     fn get_ledger_account_impl(state: &RuntimeState) -> HttpResponse {
@@ -31,9 +33,9 @@ fn http_request(request: HttpRequest) -> HttpResponse {
 
     match extract_route(&request.url) {
         Route::Avatar(requested_avatar_id) => read_state(|state| get_avatar_impl(requested_avatar_id, state)),
-        Route::Logs(since) => get_logs_impl(since),
-        Route::Traces(since) => get_traces_impl(since),
-        Route::Metrics => read_state(get_metrics_impl),
+        Route::Logs(query) => get_logs_impl(query),
+        Route::Traces(query) => get_traces_impl(query),
+        Route::Metrics(format) => read_state(|state| get_metrics_impl(format, state)),
         Route::Other(path, _) if path == "ledger_account" => read_state(get_ledger_account_impl),
         _ => HttpResponse::not_found(),
     }
@@ -1,19 +1,32 @@
 use crate::model::pending_actions::PendingAction;
 use crate::model::reward_codes::ClaimRewardCodeResult;
 use crate::{mutate_state, RewardCodes, RuntimeState};
+use bot_sdk::{text_message, BotMessageHandler};
 use canister_api_macros::update_msgpack;
 use canister_tracing_macros::trace;
 use icp_dispenser_bot::handle_direct_message::*;
-use types::{BotMessage, MessageContent, MessageContentInitial, TextContent, UserId};
+use types::{MessageContent, MessageContentInitial, TextContent, UserId};
 
 #[update_msgpack]
 #[trace]
 fn handle_direct_message(args: Args) -> Response {
-    mutate_state(|state| handle_message(args, state))
+    mutate_state(|state| {
+        let sender = state.env.caller().into();
+        bot_sdk::handle_direct_message(state, sender, args)
+    })
 }
 
-fn handle_message(args: Args, state: &mut RuntimeState) -> Response {
-    let caller: UserId = state.env.caller().into();
+impl BotMessageHandler for RuntimeState {
+    fn bot_name(&self) -> String {
+        self.data.bot_name.clone()
+    }
+
+    fn handle_message(&mut self, sender: UserId, message: Args) -> Vec<MessageContentInitial> {
+        handle_message(sender, message, self)
+    }
+}
+
+fn handle_message(caller: UserId, args: Args, state: &mut RuntimeState) -> Vec<MessageContentInitial> {
     let now = state.env.now();
 
     let (text, action) = if let Some(code) = try_extract_code(args.content) {
@@ -38,18 +51,11 @@ fn handle_message(args: Args, state: &mut RuntimeState) -> Response {
         state.data.pending_actions.add(a, now);
     }
 
-    Success(SuccessResult {
-        bot_name: state.data.bot_name.clone(),
-        bot_display_name: None,
-        messages: vec![BotMessage {
-            content: MessageContentInitial::Text(TextContent { text: text.to_string() }),
-            message_id: None,
-        }],
-    })
+    vec![text_message(text)]
 }
 
 fn try_extract_code(content: MessageContent) -> Option<String> {
-    if let MessageContent::Text(TextContent { text }) = content {
+    if let MessageContent::Text(TextContent { text, .. }) = content {
         RewardCodes::validate(&text).then_some(text.to_ascii_uppercase())
     } else {
         None
@@ -3,9 +3,6 @@ use crate::{mutate_state, read_state};
 use canister_tracing_macros::trace;
 use ic_cdk_macros::update;
 use icp_dispenser_bot::register_bot::{Response::*, *};
-use types::Cycles;
-
-const BOT_REGISTRATION_FEE: Cycles = 10_000_000_000_000; // 10T
 
 #[update(guard = "caller_is_admin")]
 #[trace]
@@ -16,8 +13,7 @@ async fn register_bot(args: Args) -> Response {
     if already_registered {
         AlreadyRegistered
     } else {
-        let response =
-            user_index_canister_c2c_client::c2c_register_bot(user_index_canister_id, &args, BOT_REGISTRATION_FEE).await;
+        let response = bot_sdk::register_bot(user_index_canister_id, &args).await;
 
         match response {
             Ok(Success) => {
@@ -1,16 +1,12 @@
 use crate::model::pending_actions_queue::{Action, TransferCkbtc};
 use crate::{mutate_state, read_state, RuntimeState};
-use candid::Principal;
+use bot_sdk::Payout;
 use ic_cdk_timers::TimerId;
 use icrc_ledger_types::icrc1::account::Account;
-use icrc_ledger_types::icrc1::transfer::{TransferArg, TransferError};
 use std::cell::Cell;
 use std::time::Duration;
 use tracing::{error, trace};
-use types::{
-    icrc1, BotMessage, CanisterId, CompletedCryptoTransaction, CryptoContent, CryptoTransaction, Cryptocurrency,
-    MessageContentInitial,
-};
+use types::Cryptocurrency;
 
 const MAX_BATCH_SIZE: usize = 5;
 
@@ -54,23 +50,8 @@ async fn process_actions(actions: Vec<Action>) {
 async fn process_action(action: Action) {
     match action.clone() {
         Action::SendMessages(user_id, messages) => {
-            if user_canister_c2c_client::c2c_handle_bot_messages(
-                CanisterId::from(user_id),
-                &user_canister::c2c_handle_bot_messages::Args {
-                    bot_name: read_state(|state| state.data.username.clone()),
-                    bot_display_name: None,
-                    messages: messages
-                        .into_iter()
-                        .map(|m| BotMessage {
-                            content: m,
-                            message_id: None,
-                        })
-                        .collect(),
-                },
-            )
-            .await
-            .is_err()
-            {
+            let bot_name = read_state(|state| state.data.username.clone());
+            if bot_sdk::send_messages(user_id, bot_name, None, messages).await.is_err() {
                 mutate_state(|state| state.enqueue_pending_action(action));
             }
         }
@@ -79,64 +60,61 @@ async fn process_action(action: Action) {
             amount,
             send_oc_message,
         }) => {
-            let (this_canister_id, ledger_canister_id, now) =
-                read_state(|state| (state.env.canister_id(), state.data.ckbtc_ledger_canister_id, state.env.now()));
+            let (this_canister_id, ledger_canister_id, now_nanos) = read_state(|state| {
+                (
+                    state.env.canister_id(),
+                    state.data.ckbtc_ledger_canister_id,
+                    state.env.now_nanos(),
+                )
+            });
 
-            let from = Account::from(this_canister_id);
-            let now_nanos = now * 1_000_000;
-
-            let args = TransferArg {
-                from_subaccount: None,
-                to: Account::from(Principal::from(user_id)),
-                fee: Some(10.into()),
-                created_at_time: Some(now_nanos),
+            let fee = Cryptocurrency::CKBTC.fee().unwrap() as u64;
+            let payout = Payout {
+                token: Cryptocurrency::CKBTC,
+                ledger_canister_id,
+                recipient: user_id.into(),
+                amount: amount as u128,
+                fee: fee as u128,
                 memo: None,
-                amount: amount.into(),
             };
 
-            match icrc_ledger_canister_c2c_client::icrc1_transfer(ledger_canister_id, &args).await {
-                Ok(Ok(block_index)) => {
+            match bot_sdk::pay_out(payout, now_nanos).await {
+                Ok(transaction) => {
                     if send_oc_message {
                         mutate_state(|state| {
                             state.enqueue_pending_action(Action::SendMessages(
                                 user_id,
-                                vec![MessageContentInitial::Crypto(CryptoContent {
-                                    recipient: user_id,
-                                    transfer: CryptoTransaction::Completed(CompletedCryptoTransaction::ICRC1(
-                                        icrc1::CompletedCryptoTransaction {
-                                            ledger: Cryptocurrency::CKBTC.ledger_canister_id().unwrap(),
-                                            token: Cryptocurrency::CKBTC,
-                                            amount: amount as u128,
-                                            fee: 10,
-                                            from: icrc1::CryptoAccount::Account(from),
-                                            to: icrc1::CryptoAccount::Account(Account::from(Principal::from(user_id))),
-                                            memo: None,
-                                            created: now_nanos,
-                                            block_index: block_index.0.try_into().unwrap(),
-                                        },
-                                    )),
-                                    caption: None,
-                                })],
+                                vec![bot_sdk::crypto_message(user_id, transaction, None)],
                             ))
                         });
                     }
                 }
-                Ok(Err(TransferError::InsufficientFunds { balance })) => {
-                    error!(?args, ?balance, "Failed to transfer ckBTC, insufficient funds");
-                    mutate_state(|state| {
-                        state.enqueue_pending_action(Action::TransferCkbtc(TransferCkbtc {
-                            user_id,
-                            amount: balance.0.try_into().unwrap(),
-                            send_oc_message,
-                        }))
-                    })
-                }
-                Ok(error) => {
-                    error!(?args, ?error, "Failed to transfer ckBTC");
-                }
-                Err(error) => {
-                    error!(?args, ?error, "Failed to transfer ckBTC, retrying");
-                    mutate_state(|state| state.enqueue_pending_action(action))
+                Err(failed_transaction) => {
+                    // The failed transaction doesn't say why it failed, so check the balance to see if it was due to
+                    // insufficient funds, in which case pay out whatever is left
+                    let account = Account::from(this_canister_id);
+                    match icrc_ledger_canister_c2c_client::icrc1_balance_of(ledger_canister_id, &account)
+                        .await
+                        .map(|balance| u64::try_from(balance.0).unwrap())
+                    {
+                        Ok(balance) if balance < amount + fee => {
+                            error!(?failed_transaction, ?balance, "Failed to transfer ckBTC, insufficient funds");
+                            let remaining = balance.saturating_sub(fee);
+                            if remaining > 0 {
+                                mutate_state(|state| {
+                                    state.enqueue_pending_action(Action::TransferCkbtc(TransferCkbtc {
+                                        user_id,
+                                        amount: remaining,
+                                        send_oc_message,
+                                    }))
+                                })
+                            }
+                        }
+                        _ => {
+                            error!(?failed_transaction, "Failed to transfer ckBTC, retrying");
+                            mutate_state(|state| state.enqueue_pending_action(action))
+                        }
+                    }
                 }
             }
         }
@@ -1,9 +1,9 @@
 use crate::model::pending_actions_queue::{Action, PendingActionsQueue};
 use crate::model::user_map::UserMap;
+use bot_sdk::BotData;
 use candid::Principal;
-use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use serde::{Deserialize, Serialize};
-use std::cell::RefCell;
 use std::collections::HashSet;
 use types::{BuildVersion, CanisterId, Cycles, Document, TimestampMillis, Timestamped};
 use utils::env::Environment;
@@ -11,7 +11,6 @@ use utils::env::Environment;
 mod guards;
 mod jobs;
 mod lifecycle;
-mod memory;
 mod model;
 mod queries;
 mod updates;
@@ -19,11 +18,12 @@ mod updates;
 const MAX_ROLLS_PER_HOUR: usize = 5;
 const MAX_SATS_PER_ROLL: u64 = 10_000;
 
-thread_local! {
-    static WASM_VERSION: RefCell<Timestamped<BuildVersion>> = RefCell::default();
-}
-
-canister_state!(RuntimeState);
+bot_sdk::bot_canister!(
+    RuntimeState,
+    Data,
+    satoshi_dice_canister::post_upgrade::Args,
+    crate::jobs::start
+);
 
 struct RuntimeState {
     pub env: Box<dyn Environment>,
@@ -108,6 +108,20 @@ impl Data {
     }
 }
 
+impl BotData for Data {
+    fn test_mode(&self) -> bool {
+        self.test_mode
+    }
+
+    fn rng_seed(&self) -> [u8; 32] {
+        self.rng_seed
+    }
+
+    fn set_rng_seed(&mut self, rng_seed: [u8; 32]) {
+        self.rng_seed = rng_seed;
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct Metrics {
     pub now: TimestampMillis,
@@ -121,6 +135,25 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge("satoshi_dice_users", "Users who have played", self.users)
+            .gauge(
+                "satoshi_dice_initialized",
+                "Whether the bot has been initialized",
+                u8::from(self.initialized),
+            );
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CanisterIds {
     pub user_index: CanisterId,
@@ -1,5 +1,4 @@
-use crate::lifecycle::{init_env, init_state};
-use crate::Data;
+use crate::{init_bot_state, init_env, Data};
 use canister_tracing_macros::trace;
 use ic_cdk_macros::init;
 use satoshi_dice_canister::init::Args;
@@ -20,7 +19,7 @@ fn init(args: Args) {
         args.test_mode,
     );
 
-    init_state(env, data, args.wasm_version);
+    init_bot_state(env, data, args.wasm_version);
 
     info!(version = %args.wasm_version, "Initialization complete");
 }
@@ -45,5 +45,8 @@ fn welcome_messages() -> Vec<MessageContentInitial> {
 }
 
 fn to_text_content(str: &str) -> MessageContentInitial {
-    MessageContentInitial::Text(TextContent { text: str.to_string() })
+    MessageContentInitial::Text(TextContent {
+        text: str.to_string(),
+        link_previews: None,
+    })
 }
@@ -1,11 +1,12 @@
 use crate::model::pending_actions_queue::{Action, TransferCkbtc};
 use crate::model::user_map::DiceRoll;
 use crate::{mutate_state, RuntimeState, MAX_SATS_PER_ROLL};
+use bot_sdk::{text_message, BotMessageHandler};
 use canister_api_macros::update_msgpack;
 use canister_tracing_macros::trace;
 use rand::RngCore;
 use satoshi_dice_canister::handle_direct_message::*;
-use types::{BotMessage, CanisterId, Cryptocurrency, MessageContent, MessageContentInitial, TextContent, UserId};
+use types::{CanisterId, Cryptocurrency, MessageContent, MessageContentInitial, UserId};
 use utils::time::MINUTE_IN_MS;
 
 const MAX_TOTAL_WINNINGS: u64 = 50_000;
@@ -13,13 +14,25 @@ const MAX_TOTAL_WINNINGS: u64 = 50_000;
 #[update_msgpack]
 #[trace]
 fn handle_direct_message(args: Args) -> Response {
-    mutate_state(|state| handle_message(args, state))
+    mutate_state(|state| {
+        let sender = state.env.caller().into();
+        bot_sdk::handle_direct_message(state, sender, args)
+    })
 }
 
-fn handle_message(args: Args, state: &mut RuntimeState) -> Response {
+impl BotMessageHandler for RuntimeState {
+    fn bot_name(&self) -> String {
+        self.data.username.clone()
+    }
+
+    fn handle_message(&mut self, sender: UserId, message: Args) -> Vec<MessageContentInitial> {
+        handle_message(sender, message, self)
+    }
+}
+
+fn handle_message(user_id: UserId, args: Args, state: &mut RuntimeState) -> Vec<MessageContentInitial> {
     let mut messages = Vec::new();
     if let Some(sats) = extract_ckbtc_amount(&args.content, state.data.ckbtc_ledger_canister_id) {
-        let user_id: UserId = state.env.caller().into();
         let now = state.env.now();
         let fee = Cryptocurrency::CKBTC.fee().unwrap() as u64;
 
@@ -81,17 +94,7 @@ fn handle_message(args: Args, state: &mut RuntimeState) -> Response {
         );
     }
 
-    Success(SuccessResult {
-        bot_name: state.data.username.clone(),
-        bot_display_name: None,
-        messages: messages
-            .into_iter()
-            .map(|m| BotMessage {
-                content: MessageContentInitial::Text(TextContent { text: m }),
-                message_id: None,
-            })
-            .collect(),
-    })
+    messages.into_iter().map(text_message).collect()
 }
 
 fn extract_ckbtc_amount(content: &MessageContent, ckbtc_ledger_canister_id: CanisterId) -> Option<u64> {
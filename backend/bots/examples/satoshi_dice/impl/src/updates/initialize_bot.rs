@@ -3,9 +3,6 @@ use crate::{mutate_state, read_state};
 use canister_tracing_macros::trace;
 use ic_cdk_macros::update;
 use satoshi_dice_canister::initialize_bot::*;
-use types::Cycles;
-
-const BOT_REGISTRATION_FEE: Cycles = 10_000_000_000_000; // 10T
 
 #[update(guard = "caller_is_admin")]
 #[trace]
@@ -17,7 +14,7 @@ async fn initialize_bot(args: Args) -> Response {
         return AlreadyRegistered;
     }
 
-    let response = user_index_canister_c2c_client::c2c_register_bot(user_index_canister_id, &args, BOT_REGISTRATION_FEE).await;
+    let response = bot_sdk::register_bot(user_index_canister_id, &args).await;
 
     match response {
         Ok(Success) => {
@@ -1,5 +1,4 @@
-use crate::lifecycle::{init_env, init_state};
-use crate::Data;
+use crate::{init_bot_state, init_env, Data};
 use canister_tracing_macros::trace;
 use ic_cdk_macros::init;
 use sns1_airdrop::init::Args;
@@ -14,7 +13,7 @@ fn init(args: Args) {
 
     let data = Data::new(args.user_index_canister_id, args.admins.into_iter().collect(), args.test_mode);
 
-    init_state(env, data, args.wasm_version);
+    init_bot_state(env, data, args.wasm_version);
 
     info!(version = %args.wasm_version, "Initialization complete");
 }
@@ -1,37 +1,36 @@
 use crate::{mutate_state, RuntimeState};
+use bot_sdk::{text_message, BotMessageHandler};
 use candid::Principal;
 use canister_api_macros::update_msgpack;
 use canister_tracing_macros::trace;
 use sns1_airdrop::handle_direct_message::*;
 use std::collections::hash_map::Entry::{Occupied, Vacant};
-use types::{BotMessage, MessageContent, MessageContentInitial, TextContent, UserId};
+use types::{MessageContent, MessageContentInitial, TextContent, UserId};
 
 #[update_msgpack]
 #[trace]
 fn handle_direct_message(args: Args) -> Response {
-    mutate_state(|state| handle_message(args, state))
+    mutate_state(|state| {
+        let sender = state.env.caller().into();
+        bot_sdk::handle_direct_message(state, sender, args)
+    })
 }
 
-fn handle_message(args: Args, state: &mut RuntimeState) -> Response {
-    let text = build_text(args, state);
+impl BotMessageHandler for RuntimeState {
+    fn bot_name(&self) -> String {
+        self.data.bot_name.clone()
+    }
 
-    Success(SuccessResult {
-        bot_name: state.data.bot_name.clone(),
-        bot_display_name: None,
-        messages: vec![BotMessage {
-            content: MessageContentInitial::Text(TextContent { text }),
-            message_id: None,
-        }],
-    })
+    fn handle_message(&mut self, sender: UserId, message: Args) -> Vec<MessageContentInitial> {
+        vec![text_message(build_text(sender, message, self))]
+    }
 }
 
-fn build_text(args: Args, state: &mut RuntimeState) -> String {
+fn build_text(caller: UserId, args: Args, state: &mut RuntimeState) -> String {
     if state.data.completed {
         return "Registrations are no longer open".to_string();
     }
 
-    let caller: UserId = state.env.caller().into();
-
     match state.data.users.entry(caller) {
         Occupied(e) => {
             let current = *e.get();
@@ -76,7 +75,7 @@ For example '6hsbt-vqaaa-aaaaf-aaafq-cai'"
 }
 
 fn try_extract_principal(content: MessageContent) -> Result<Principal, ()> {
-    if let MessageContent::Text(TextContent { text }) = content {
+    if let MessageContent::Text(TextContent { text, .. }) = content {
         Principal::from_text(text).map_err(|_| ())
     } else {
         Err(())
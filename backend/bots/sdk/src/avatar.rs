@@ -0,0 +1,23 @@
+use types::{CanisterId, Document, FieldTooLongResult, TimestampMillis, Timestamped};
+use utils::document_validation::validate_avatar;
+
+// Validates and stores the bot's new avatar, then tells the UserIndex so that users see it
+pub fn set_avatar(
+    avatar: Option<Document>,
+    current: &mut Timestamped<Option<Document>>,
+    user_index_canister_id: CanisterId,
+    now: TimestampMillis,
+) -> Result<(), FieldTooLongResult> {
+    validate_avatar(avatar.as_ref())?;
+
+    let avatar_id = avatar.as_ref().map(|a| a.id);
+    *current = Timestamped::new(avatar, now);
+
+    ic_cdk::spawn(update_index_canister(user_index_canister_id, avatar_id));
+    Ok(())
+}
+
+async fn update_index_canister(user_index_canister_id: CanisterId, avatar_id: Option<u128>) {
+    let args = user_index_canister::c2c_set_avatar::Args { avatar_id };
+    let _ = user_index_canister_c2c_client::c2c_set_avatar(user_index_canister_id, &args).await;
+}
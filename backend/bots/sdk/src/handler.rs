@@ -0,0 +1,25 @@
+use crate::to_bot_messages;
+use bot_api::handle_direct_message::{Args, Response, SuccessResult};
+use types::{MessageContentInitial, UserId};
+
+// Implemented by each bot (typically on its `RuntimeState`) to decide how to reply to a direct message
+pub trait BotMessageHandler {
+    fn bot_name(&self) -> String;
+
+    fn bot_display_name(&self) -> Option<String> {
+        None
+    }
+
+    fn handle_message(&mut self, sender: UserId, message: Args) -> Vec<MessageContentInitial>;
+}
+
+// Called from the bot's `handle_direct_message` endpoint
+pub fn handle_direct_message<H: BotMessageHandler>(handler: &mut H, sender: UserId, args: Args) -> Response {
+    let messages = handler.handle_message(sender, args);
+
+    Response::Success(SuccessResult {
+        bot_name: handler.bot_name(),
+        bot_display_name: handler.bot_display_name(),
+        messages: to_bot_messages(messages),
+    })
+}
@@ -0,0 +1,18 @@
+// Everything a bot needs other than its own logic. A bot implements `BotMessageHandler` to decide how to
+// reply to the direct messages it receives and uses `bot_canister!` to generate its state and upgrade
+// lifecycle, then uses the helpers here to register itself, set its avatar, pay out tokens and send messages.
+mod avatar;
+mod handler;
+mod lifecycle;
+mod memory;
+mod messages;
+mod payouts;
+mod registration;
+
+pub use avatar::*;
+pub use handler::*;
+pub use lifecycle::*;
+pub use memory::*;
+pub use messages::*;
+pub use payouts::*;
+pub use registration::*;
@@ -0,0 +1,134 @@
+use crate::get_upgrades_memory;
+use canister_logger::LogEntry;
+use ic_stable_structures::Memory;
+use rand::Rng;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use stable_memory::{get_reader, get_writer};
+use std::time::Duration;
+use tracing::{info, trace};
+use utils::canister::get_random_seed;
+use utils::env::canister::CanisterEnv;
+use utils::env::Environment;
+
+// Implemented by each bot's `Data` so that `bot_canister!` can save and restore it across upgrades
+pub trait BotData: Serialize + DeserializeOwned {
+    fn test_mode(&self) -> bool;
+    fn rng_seed(&self) -> [u8; 32];
+    fn set_rng_seed(&mut self, rng_seed: [u8; 32]);
+}
+
+// Generates the bot's state macros (`init_state`, `read_state`, `mutate_state`, etc), its `WASM_VERSION`, and its
+// `pre_upgrade` and `post_upgrade` endpoints. The bot's `RuntimeState` must have `env` and `data` fields and a
+// `new(env, data)` constructor, and its `Data` must implement `BotData`. The bot's own `init` endpoint should
+// call `init_env` and `init_bot_state`. If given, `$on_start` is called with the new state after init and after
+// each upgrade, eg. to restart the bot's jobs.
+#[macro_export]
+macro_rules! bot_canister {
+    ($state:ident, $data:ty, $post_upgrade_args:ty) => {
+        $crate::bot_canister!($state, $data, $post_upgrade_args, |_| {});
+    };
+    ($state:ident, $data:ty, $post_upgrade_args:ty, $on_start:expr) => {
+        thread_local! {
+            static WASM_VERSION: std::cell::RefCell<types::Timestamped<types::BuildVersion>> =
+                std::cell::RefCell::default();
+        }
+
+        canister_state_macros::canister_state!($state);
+
+        fn init_env(rng_seed: [u8; 32]) -> Box<utils::env::canister::CanisterEnv> {
+            $crate::init_env(rng_seed, reseed_rng)
+        }
+
+        fn init_bot_state(env: Box<dyn utils::env::Environment>, data: $data, wasm_version: types::BuildVersion) {
+            let now = utils::env::Environment::now(env.as_ref());
+            let mut state = $state::new(env, data);
+
+            ($on_start)(&mut state);
+            init_state(state);
+            WASM_VERSION.set(types::Timestamped::new(wasm_version, now));
+        }
+
+        fn reseed_rng() {
+            $crate::reseed_rng(|seed| {
+                mutate_state(|state| {
+                    $crate::BotData::set_rng_seed(&mut state.data, seed);
+                    state.env = Box::new(utils::env::canister::CanisterEnv::new(seed));
+                })
+            });
+        }
+
+        #[ic_cdk_macros::pre_upgrade]
+        #[canister_tracing_macros::trace]
+        fn pre_upgrade() {
+            let mut state = take_state();
+            $crate::pre_upgrade(&mut state.data, state.env.as_mut());
+        }
+
+        #[ic_cdk_macros::post_upgrade]
+        #[canister_tracing_macros::trace]
+        fn post_upgrade(args: $post_upgrade_args) {
+            let data: $data = $crate::post_upgrade();
+
+            let env = init_env($crate::BotData::rng_seed(&data));
+            init_bot_state(env, data, args.wasm_version);
+
+            $crate::post_upgrade_complete(args.wasm_version);
+        }
+    };
+}
+
+// If the bot doesn't yet have a seed, a timer is set to call `reseed_rng` which fetches one
+pub fn init_env(rng_seed: [u8; 32], reseed_rng: fn()) -> Box<CanisterEnv> {
+    if rng_seed == [0; 32] {
+        ic_cdk_timers::set_timer(Duration::ZERO, reseed_rng);
+    }
+    Box::new(CanisterEnv::new(rng_seed))
+}
+
+pub fn reseed_rng(set_seed: fn([u8; 32])) {
+    ic_cdk::spawn(async move {
+        let seed = get_random_seed().await;
+        set_seed(seed);
+        trace!("Successfully reseeded rng");
+    });
+}
+
+pub fn pre_upgrade<D: BotData>(data: &mut D, env: &mut dyn Environment) {
+    info!("Pre-upgrade starting");
+
+    data.set_rng_seed(env.rng().gen());
+
+    let mut memory = get_upgrades_memory();
+    save_state(&*data, &mut memory);
+}
+
+pub fn post_upgrade<D: BotData>() -> D {
+    let memory = get_upgrades_memory();
+    restore_state(&memory, |data: &D| data.test_mode())
+}
+
+pub fn post_upgrade_complete(wasm_version: types::BuildVersion) {
+    info!(version = %wasm_version, "Post-upgrade complete");
+}
+
+// Writes the bot's data, logs and traces to stable memory
+pub fn save_state<D: Serialize, M: Memory>(data: D, memory: &mut M) {
+    let logs = canister_logger::export_logs();
+    let traces = canister_logger::export_traces();
+
+    let writer = get_writer(memory);
+
+    serializer::serialize((data, logs, traces), writer).unwrap();
+}
+
+// Reads back the data written by `save_state` and restores the logs and traces. `test_mode` determines how the
+// logger is initialized.
+pub fn restore_state<D: DeserializeOwned, M: Memory>(memory: &M, test_mode: impl FnOnce(&D) -> bool) -> D {
+    let reader = get_reader(memory);
+
+    let (data, logs, traces): (D, Vec<LogEntry>, Vec<LogEntry>) = serializer::deserialize(reader).unwrap();
+
+    canister_logger::init_with_logs(test_mode(&data), logs, traces);
+    data
+}
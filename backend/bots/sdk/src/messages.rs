@@ -0,0 +1,49 @@
+use ic_cdk::api::call::CallResult;
+use types::{
+    BotMessage, CompletedCryptoTransaction, CryptoContent, CryptoTransaction, MessageContentInitial, TextContent, UserId,
+};
+
+pub fn text_message(text: impl Into<String>) -> MessageContentInitial {
+    MessageContentInitial::Text(TextContent {
+        text: text.into(),
+        link_previews: None,
+    })
+}
+
+pub fn crypto_message(
+    recipient: UserId,
+    transaction: CompletedCryptoTransaction,
+    caption: Option<String>,
+) -> MessageContentInitial {
+    MessageContentInitial::Crypto(CryptoContent {
+        recipient,
+        transfer: CryptoTransaction::Completed(transaction),
+        caption,
+    })
+}
+
+pub fn to_bot_messages(messages: Vec<MessageContentInitial>) -> Vec<BotMessage> {
+    messages
+        .into_iter()
+        .map(|content| BotMessage {
+            content,
+            message_id: None,
+        })
+        .collect()
+}
+
+// Sends messages from the bot to a user outside of a reply, eg. once a payout has completed
+pub async fn send_messages(
+    recipient: UserId,
+    bot_name: String,
+    bot_display_name: Option<String>,
+    messages: Vec<MessageContentInitial>,
+) -> CallResult<user_canister::c2c_handle_bot_messages::Response> {
+    let args = user_canister::c2c_handle_bot_messages::Args {
+        bot_name,
+        bot_display_name,
+        messages: to_bot_messages(messages),
+    };
+
+    user_canister_c2c_client::c2c_handle_bot_messages(recipient.into(), &args).await
+}
@@ -0,0 +1,32 @@
+use candid::Principal;
+use types::{CanisterId, CompletedCryptoTransaction, Cryptocurrency, FailedCryptoTransaction, TimestampNanos, UserId};
+
+pub struct Payout {
+    pub token: Cryptocurrency,
+    pub ledger_canister_id: CanisterId,
+    // Either a user or a canister, eg. a group which is being sent a prize
+    pub recipient: Principal,
+    pub amount: u128,
+    pub fee: u128,
+    pub memo: Option<Vec<u8>>,
+}
+
+// Transfers tokens from the bot's default account to the recipient, returning the completed transaction so
+// that it can be sent to the recipient as a crypto message. Retries should pass the same `created_nanos` so
+// that the ledger deduplicates the transfer.
+pub async fn pay_out(
+    payout: Payout,
+    created_nanos: TimestampNanos,
+) -> Result<CompletedCryptoTransaction, FailedCryptoTransaction> {
+    let pending_transaction = ledger_utils::create_pending_transaction(
+        payout.token,
+        payout.ledger_canister_id,
+        payout.amount,
+        payout.fee,
+        UserId::from(payout.recipient),
+        payout.memo.as_deref(),
+        created_nanos,
+    );
+
+    ledger_utils::process_transaction(pending_transaction, ic_cdk::id()).await
+}
@@ -0,0 +1,13 @@
+use ic_cdk::api::call::CallResult;
+use types::{CanisterId, Cycles};
+use user_index_canister::c2c_register_bot;
+
+pub const BOT_REGISTRATION_FEE: Cycles = 10_000_000_000_000; // 10T
+
+// Registers this canister as a bot, paying the registration fee in cycles
+pub async fn register_bot(
+    user_index_canister_id: CanisterId,
+    args: &c2c_register_bot::Args,
+) -> CallResult<c2c_register_bot::Response> {
+    user_index_canister_c2c_client::c2c_register_bot(user_index_canister_id, args, BOT_REGISTRATION_FEE).await
+}
@@ -0,0 +1,166 @@
+use bot_api::handle_direct_message;
+use candid::{CandidType, Principal};
+use pocket_ic::{PocketIc, PocketIcBuilder, UserError, WasmResult};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::env;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use types::{CanisterId, Cycles, MessageContent, MessageId, TimestampMillis, UserId};
+
+pub static POCKET_IC_BIN: &str = "./pocket-ic";
+
+const INIT_CYCLES_BALANCE: Cycles = 1_000_000_000_000_000; // 1000T
+
+// Runs a single bot canister inside PocketIC so that its message handling can be tested end to end.
+// Calls the bot makes to other canisters (eg. the UserIndex or a ledger) will fail unless the test installs
+// those canisters into `env` itself.
+pub struct BotTestHarness {
+    pub env: PocketIc,
+    pub controller: Principal,
+    pub bot_canister_id: CanisterId,
+    next_message_id: u128,
+}
+
+impl BotTestHarness {
+    pub fn new<A: CandidType>(wasm: Vec<u8>, init_args: &A) -> BotTestHarness {
+        ensure_pocket_ic_bin_set();
+
+        let env = PocketIcBuilder::new().with_application_subnet().build();
+        let controller = Principal::from_slice(&[1]);
+        let bot_canister_id = env.create_canister_with_settings(Some(controller), None);
+        env.add_cycles(bot_canister_id, INIT_CYCLES_BALANCE);
+        env.install_canister(
+            bot_canister_id,
+            wasm,
+            candid::encode_one(init_args).unwrap(),
+            Some(controller),
+        );
+
+        BotTestHarness {
+            env,
+            controller,
+            bot_canister_id,
+            next_message_id: 1,
+        }
+    }
+
+    // Sends a direct message to the bot as if it had come from `sender`'s user canister
+    pub fn send_direct_message(&mut self, sender: UserId, content: MessageContent) -> handle_direct_message::SuccessResult {
+        let message_id = MessageId::from(self.next_message_id);
+        self.next_message_id += 1;
+
+        let args = handle_direct_message::Args {
+            message_id,
+            sender_message_index: 0.into(),
+            sender_name: sender.to_string(),
+            content,
+            replies_to: None,
+            forwarding: false,
+            correlation_id: 0,
+        };
+
+        let handle_direct_message::Response::Success(result) =
+            self.update_msgpack(sender.into(), "handle_direct_message_msgpack", &args);
+        result
+    }
+
+    pub fn update<P: CandidType, R: CandidType + DeserializeOwned>(
+        &mut self,
+        sender: Principal,
+        method_name: &str,
+        payload: &P,
+    ) -> R {
+        let response = self.env.update_call(
+            self.bot_canister_id,
+            sender,
+            method_name,
+            candid::encode_one(payload).unwrap(),
+        );
+        candid::decode_one(&unwrap_reply(response)).unwrap()
+    }
+
+    pub fn query<P: CandidType, R: CandidType + DeserializeOwned>(
+        &self,
+        sender: Principal,
+        method_name: &str,
+        payload: &P,
+    ) -> R {
+        let response = self.env.query_call(
+            self.bot_canister_id,
+            sender,
+            method_name,
+            candid::encode_one(payload).unwrap(),
+        );
+        candid::decode_one(&unwrap_reply(response)).unwrap()
+    }
+
+    pub fn update_msgpack<P: Serialize, R: DeserializeOwned>(
+        &mut self,
+        sender: Principal,
+        method_name: &str,
+        payload: &P,
+    ) -> R {
+        let response = self.env.update_call(
+            self.bot_canister_id,
+            sender,
+            method_name,
+            msgpack::serialize_then_unwrap(payload),
+        );
+        msgpack::deserialize_then_unwrap(&unwrap_reply(response))
+    }
+
+    pub fn upgrade<A: CandidType>(&mut self, wasm: Vec<u8>, upgrade_args: &A) {
+        self.env
+            .upgrade_canister(
+                self.bot_canister_id,
+                wasm,
+                candid::encode_one(upgrade_args).unwrap(),
+                Some(self.controller),
+            )
+            .unwrap();
+    }
+
+    pub fn tick_many(&mut self, count: usize) {
+        for _ in 0..count {
+            self.env.tick();
+        }
+    }
+
+    // Moves time forward then ticks so that any timers which are now due get run
+    pub fn advance_time(&mut self, duration: Duration) {
+        self.env.advance_time(duration);
+        self.env.tick();
+    }
+
+    pub fn now(&self) -> TimestampMillis {
+        self.env
+            .get_time()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+    }
+}
+
+pub fn read_wasm(path: impl AsRef<Path>) -> Vec<u8> {
+    let path = path.as_ref();
+    let mut file = File::open(path).unwrap_or_else(|_| panic!("Failed to open file: {}", path.display()));
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).expect("Failed to read file");
+    bytes
+}
+
+fn ensure_pocket_ic_bin_set() {
+    if env::var_os("POCKET_IC_BIN").is_none() {
+        env::set_var("POCKET_IC_BIN", POCKET_IC_BIN);
+    }
+}
+
+fn unwrap_reply(response: Result<WasmResult, UserError>) -> Vec<u8> {
+    match response.unwrap() {
+        WasmResult::Reply(bytes) => bytes,
+        WasmResult::Reject(error) => panic!("{error}"),
+    }
+}
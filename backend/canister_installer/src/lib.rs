@@ -267,6 +267,8 @@ async fn install_service_canisters_impl(
                 wasm: local_user_index_canister_wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         ),
         group_index_canister_client::upgrade_local_group_index_canister_wasm(
@@ -276,6 +278,8 @@ async fn install_service_canisters_impl(
                 wasm: local_group_index_canister_wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         ),
     )
@@ -290,6 +294,8 @@ async fn install_service_canisters_impl(
                 wasm: user_canister_wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         ),
         group_index_canister_client::upgrade_group_canister_wasm(
@@ -299,6 +305,8 @@ async fn install_service_canisters_impl(
                 wasm: group_canister_wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         ),
         group_index_canister_client::upgrade_community_canister_wasm(
@@ -308,6 +316,8 @@ async fn install_service_canisters_impl(
                 wasm: community_canister_wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         ),
         notifications_index_canister_client::upgrade_notifications_canister_wasm(
@@ -317,6 +327,8 @@ async fn install_service_canisters_impl(
                 wasm: notifications_canister_wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         ),
     )
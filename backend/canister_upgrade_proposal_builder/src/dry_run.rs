@@ -0,0 +1,363 @@
+use crate::{build, Config};
+use candid::{CandidType, Decode, Encode};
+use canister_agent_utils::build_ic_agent;
+use human_readable::to_human_readable_string;
+use ic_agent::identity::AnonymousIdentity;
+use ic_agent::Agent;
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use sha256::sha256;
+use sns_governance_canister::types::{proposal, Proposal, UpgradeSnsControlledCanister};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use types::{BuildVersion, CanisterId, Hash, HttpRequest, HttpResponse, UpgradeCanisterWasmArgs};
+
+// The id of the native SNS function which upgrades an SNS controlled canister
+const UPGRADE_SNS_CONTROLLED_CANISTER_FUNCTION_ID: u64 = 3;
+
+// Every canister's post_upgrade args consist of just the new wasm version
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+struct PostUpgradeArgs {
+    wasm_version: BuildVersion,
+}
+
+pub struct DryRunReport {
+    title: String,
+    url: String,
+    action: String,
+    target_canister_id: Option<CanisterId>,
+    wasm_hash: Hash,
+    wasm_length: usize,
+    version: BuildVersion,
+    upgrade_args: String,
+    deployed: Option<DeployedCanister>,
+    problems: Vec<String>,
+}
+
+struct DeployedCanister {
+    canister_id: CanisterId,
+    // The metric holding the version compared against. For custom functions this is the version of
+    // the wasm the index canister currently holds rather than the version of the index itself.
+    version_metric: &'static str,
+    // Only fetched when the proposal upgrades the deployed canister itself
+    module_hash: Option<Hash>,
+    wasm_version: Option<BuildVersion>,
+}
+
+impl DryRunReport {
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+// Builds the proposal as it would be submitted, then decodes it again so that what gets
+// printed is what the SNS would actually receive
+pub async fn dry_run(config: Config) -> Result<DryRunReport, Box<dyn Error>> {
+    let ic_url = config.ic_url.clone();
+    let function_id = config.function_id;
+    let compare_to = config.canister_id;
+
+    let proposal = if function_id == UPGRADE_SNS_CONTROLLED_CANISTER_FUNCTION_ID {
+        upgrade_sns_controlled_canister_proposal(config)?
+    } else {
+        Decode!(&build(config)?, Proposal)?
+    };
+    let mut report = describe(proposal)?;
+
+    if let Some(canister_id) = compare_to {
+        let agent = build_ic_agent(ic_url, Box::new(AnonymousIdentity)).await;
+        let deployed = if report.target_canister_id.is_some() {
+            DeployedCanister {
+                canister_id,
+                version_metric: "wasm_version",
+                module_hash: deployed_module_hash(&agent, canister_id).await,
+                wasm_version: deployed_version(&agent, canister_id, "wasm_version").await,
+            }
+        } else {
+            let version_metric = index_wasm_version_metric(function_id)
+                .ok_or_else(|| format!("Function id {function_id} does not upgrade a wasm held by an index canister"))?;
+
+            DeployedCanister {
+                canister_id,
+                version_metric,
+                module_hash: None,
+                wasm_version: deployed_version(&agent, canister_id, version_metric).await,
+            }
+        };
+        report.problems.extend(check(&report, &deployed));
+        report.deployed = Some(deployed);
+    }
+
+    Ok(report)
+}
+
+// UpgradeSnsControlledCanister proposals are made via quill by `submit_upgrade_proposal.sh`, so
+// this builds the equivalent proposal purely so that it can be described
+fn upgrade_sns_controlled_canister_proposal(config: Config) -> Result<Proposal, Box<dyn Error>> {
+    let canister_id = config
+        .canister_id
+        .ok_or("--canister-id must be set when upgrading an SNS controlled canister")?;
+
+    let canister_upgrade_arg = Encode!(&PostUpgradeArgs {
+        wasm_version: config.version
+    })?;
+
+    Ok(Proposal {
+        title: config.title,
+        summary: config.summary,
+        url: config.url,
+        action: Some(proposal::Action::UpgradeSnsControlledCanister(UpgradeSnsControlledCanister {
+            canister_id: Some(canister_id),
+            new_canister_wasm: fs::read(config.wasm_path)?,
+            canister_upgrade_arg: Some(canister_upgrade_arg),
+            mode: None,
+        })),
+    })
+}
+
+// The metric in which each index canister exposes the version of the wasm upgraded by each of its
+// custom functions
+fn index_wasm_version_metric(function_id: u64) -> Option<&'static str> {
+    match function_id {
+        1000 => Some("local_user_index_wasm_version"),
+        1001 => Some("user_wasm_version"),
+        2000 => Some("local_group_index_wasm_version"),
+        2001 => Some("group_wasm_version"),
+        2005 => Some("community_wasm_version"),
+        3000 => Some("notifications_canister_wasm_version"),
+        5003 => Some("bucket_canister_wasm"),
+        _ => None,
+    }
+}
+
+fn describe(proposal: Proposal) -> Result<DryRunReport, Box<dyn Error>> {
+    let (action, target_canister_id, wasm, version, upgrade_args) = match proposal.action {
+        Some(proposal::Action::UpgradeSnsControlledCanister(action)) => {
+            let arg = action
+                .canister_upgrade_arg
+                .ok_or("Proposal is missing the post_upgrade args")?;
+            let post_upgrade_args = Decode!(&arg, PostUpgradeArgs)?;
+            (
+                "UpgradeSnsControlledCanister".to_string(),
+                action.canister_id,
+                action.new_canister_wasm,
+                post_upgrade_args.wasm_version,
+                serde_json::to_string(&post_upgrade_args)?,
+            )
+        }
+        Some(proposal::Action::ExecuteGenericNervousSystemFunction(action)) => {
+            let args = Decode!(&action.payload, UpgradeCanisterWasmArgs)?;
+            let upgrade_args = to_human_readable_string(&args)?;
+            (
+                format!("ExecuteGenericNervousSystemFunction (function id {})", action.function_id),
+                None,
+                args.wasm.module,
+                args.wasm.version,
+                upgrade_args,
+            )
+        }
+        _ => return Err("Proposal is not a canister upgrade".into()),
+    };
+
+    Ok(DryRunReport {
+        title: proposal.title,
+        url: proposal.url,
+        action,
+        target_canister_id,
+        wasm_hash: sha256(&wasm),
+        wasm_length: wasm.len(),
+        version,
+        upgrade_args,
+        deployed: None,
+        problems: Vec::new(),
+    })
+}
+
+fn check(report: &DryRunReport, deployed: &DeployedCanister) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    // Custom functions upgrade the canisters of an index rather than the index itself, so the
+    // index's own module is irrelevant
+    if report.target_canister_id.is_some() {
+        match deployed.module_hash {
+            Some(hash) if hash == report.wasm_hash => problems.push("The wasm is already deployed".to_string()),
+            Some(_) => {}
+            None => problems.push("Unable to determine the deployed module hash".to_string()),
+        }
+    }
+
+    match deployed.wasm_version {
+        Some(version) if report.version <= version => problems.push(format!(
+            "Version {} is not higher than the current version {version}",
+            report.version
+        )),
+        Some(_) => {}
+        None => problems.push(format!(
+            "Unable to determine the current version from '{}'",
+            deployed.version_metric
+        )),
+    }
+
+    problems
+}
+
+async fn deployed_module_hash(agent: &Agent, canister_id: CanisterId) -> Option<Hash> {
+    let hash = agent.read_state_canister_info(canister_id, "module_hash").await.ok()?;
+    hash.try_into().ok()
+}
+
+// Every canister includes its wasm version in the metrics served from its `/metrics` endpoint, and
+// index canisters also include the versions of the wasms they hold
+async fn deployed_version(agent: &Agent, canister_id: CanisterId, metric: &str) -> Option<BuildVersion> {
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        url: "/metrics".to_string(),
+        headers: Vec::new(),
+        body: ByteBuf::new(),
+    };
+
+    let response = agent
+        .query(&canister_id, "http_request")
+        .with_arg(Encode!(&request).ok()?)
+        .call()
+        .await
+        .ok()?;
+
+    let response = Decode!(&response, HttpResponse).ok()?;
+    let mut metrics: serde_json::Value = serde_json::from_slice(&response.body).ok()?;
+    serde_json::from_value(metrics.get_mut(metric)?.take()).ok()
+}
+
+impl Display for DryRunReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Title: {}", self.title)?;
+        writeln!(f, "URL: {}", self.url)?;
+        writeln!(f, "Action: {}", self.action)?;
+        if let Some(canister_id) = self.target_canister_id {
+            writeln!(f, "Target canister: {canister_id}")?;
+        }
+        writeln!(f, "Wasm hash: {} ({} bytes)", hex::encode(self.wasm_hash), self.wasm_length)?;
+        writeln!(f, "Version: {}", self.version)?;
+        writeln!(f, "Upgrade args: {}", self.upgrade_args)?;
+
+        if let Some(deployed) = &self.deployed {
+            writeln!(f)?;
+            writeln!(f, "Compared to canister {}:", deployed.canister_id)?;
+            if self.target_canister_id.is_some() {
+                writeln!(
+                    f,
+                    "  Wasm hash: {} -> {}",
+                    deployed.module_hash.map_or("unknown".to_string(), hex::encode),
+                    hex::encode(self.wasm_hash)
+                )?;
+            }
+            writeln!(
+                f,
+                "  Version ({}): {} -> {}",
+                deployed.version_metric,
+                deployed.wasm_version.map_or("unknown".to_string(), |v| v.to_string()),
+                self.version
+            )?;
+        }
+
+        writeln!(f)?;
+        if self.problems.is_empty() {
+            write!(f, "No problems found")
+        } else {
+            writeln!(f, "Problems found:")?;
+            for problem in self.problems.iter() {
+                writeln!(f, "  - {problem}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_proposal;
+    use std::path::PathBuf;
+
+    #[test]
+    fn describe_upgrade_sns_controlled_canister() {
+        let wasm_path = write_wasm("describe_upgrade_sns_controlled_canister", &[1, 2, 3]);
+        let canister_id = CanisterId::from_slice(&[1]);
+        let config = Config {
+            function_id: 3,
+            canister_id: Some(canister_id),
+            ..test_config(wasm_path)
+        };
+
+        let report = describe(upgrade_sns_controlled_canister_proposal(config).unwrap()).unwrap();
+
+        assert_eq!(report.target_canister_id, Some(canister_id));
+        assert_eq!(report.wasm_hash, sha256(&[1, 2, 3]));
+        assert_eq!(report.version, BuildVersion::new(2, 0, 1000));
+        assert!(report.upgrade_args.contains("\"patch\":1000"));
+
+        let deployed = DeployedCanister {
+            canister_id,
+            version_metric: "wasm_version",
+            module_hash: Some(sha256(&[1, 2, 3])),
+            wasm_version: Some(BuildVersion::new(2, 0, 999)),
+        };
+        assert_eq!(check(&report, &deployed), vec!["The wasm is already deployed".to_string()]);
+    }
+
+    #[test]
+    fn function_id_3_still_builds_generic_proposal() {
+        let wasm_path = write_wasm("function_id_3_still_builds_generic_proposal", &[1, 2, 3]);
+        let config = Config {
+            function_id: 3,
+            canister_id: Some(CanisterId::from_slice(&[1])),
+            dry_run: false,
+            ..test_config(wasm_path)
+        };
+
+        let proposal = create_proposal(config).unwrap();
+
+        assert!(matches!(
+            proposal.action,
+            Some(proposal::Action::ExecuteGenericNervousSystemFunction(action)) if action.function_id == 3
+        ));
+    }
+
+    #[test]
+    fn version_must_exceed_index_wasm_version() {
+        let wasm_path = write_wasm("version_must_exceed_index_wasm_version", &[1, 2, 3]);
+        let report = describe(create_proposal(test_config(wasm_path)).unwrap()).unwrap();
+
+        // The index's own module hash isn't known, since it isn't what the proposal upgrades
+        let deployed = |version| DeployedCanister {
+            canister_id: CanisterId::from_slice(&[1]),
+            version_metric: index_wasm_version_metric(2001).unwrap(),
+            module_hash: None,
+            wasm_version: Some(version),
+        };
+
+        assert!(check(&report, &deployed(BuildVersion::new(2, 0, 999))).is_empty());
+        assert_eq!(check(&report, &deployed(BuildVersion::new(2, 0, 1000))).len(), 1);
+    }
+
+    fn test_config(wasm_path: PathBuf) -> Config {
+        Config {
+            title: "Upgrade".to_string(),
+            summary: "Summary".to_string(),
+            url: "https://oc.app".to_string(),
+            function_id: 2001,
+            wasm_path,
+            version: BuildVersion::new(2, 0, 1000),
+            canister_id: None,
+            dry_run: true,
+            ic_url: "https://ic0.app".to_string(),
+        }
+    }
+
+    fn write_wasm(name: &str, bytes: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{name}.wasm"));
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+}
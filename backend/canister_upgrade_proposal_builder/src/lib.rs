@@ -3,7 +3,11 @@ use clap::Parser;
 use sns_governance_canister::types::{proposal, ExecuteGenericNervousSystemFunction, Proposal};
 use std::error::Error;
 use std::fs;
-use types::{BuildVersion, CanisterWasm, UpgradeCanisterWasmArgs};
+use types::{BuildVersion, CanisterId, CanisterWasm, UpgradeCanisterWasmArgs};
+
+mod dry_run;
+
+pub use dry_run::{dry_run, DryRunReport};
 
 /// Builds the binary encoded candid representation of an ExecuteGenericNervousSystemFunction proposal
 /// for upgrading a canister WASM
@@ -32,6 +36,19 @@ pub struct Config {
     /// Version of the wasm module
     #[arg(long)]
     pub version: BuildVersion,
+
+    /// Only used by `--dry-run`. For function id 3 (UpgradeSnsControlledCanister) this is the canister being
+    /// upgraded, for custom functions it is the index canister whose current wasm version is compared against
+    #[arg(long)]
+    pub canister_id: Option<CanisterId>,
+
+    /// Decode and print the proposal instead of writing the encoded blob to stdout
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// URL of the IC used by `--dry-run` to look up the currently deployed canister
+    #[arg(long, default_value = "https://ic0.app")]
+    pub ic_url: String,
 }
 
 pub fn build(config: Config) -> Result<Vec<u8>, Box<dyn Error>> {
@@ -50,6 +67,8 @@ fn create_proposal(config: Config) -> Result<Proposal, Box<dyn Error>> {
         },
         filter: None,
         use_for_new_canisters: None,
+        rollout: None,
+        wasm_hash: None,
     };
 
     let payload = Encode!(&args)?;
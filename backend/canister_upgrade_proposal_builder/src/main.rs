@@ -1,14 +1,28 @@
-use canister_upgrade_proposal_builder::build;
 use canister_upgrade_proposal_builder::Config;
+use canister_upgrade_proposal_builder::{build, dry_run};
 use clap::Parser;
 use std::io;
 use std::io::Write;
 use std::process;
 
-fn main() {
+#[tokio::main]
+async fn main() {
     let config = Config::parse();
 
-    if let Err(e) = build(config).and_then(|blob| {
+    if config.dry_run {
+        match dry_run(config).await {
+            Ok(report) => {
+                println!("{report}");
+                if !report.is_ok() {
+                    process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("Application error: {e}");
+                process::exit(1);
+            }
+        }
+    } else if let Err(e) = build(config).and_then(|blob| {
         let mut stdout = io::stdout();
         Ok(stdout.write_all(&blob)?)
     }) {
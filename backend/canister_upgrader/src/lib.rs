@@ -5,7 +5,13 @@ use ic_utils::call::AsyncCall;
 use ic_utils::interfaces::management_canister::builders::InstallMode;
 use ic_utils::interfaces::management_canister::CanisterStatus;
 use ic_utils::interfaces::ManagementCanister;
-use types::{BuildVersion, CanisterId, CanisterWasm, UpgradeCanisterWasmArgs};
+use sha256::sha256;
+use std::error::Error;
+use std::future::Future;
+use types::{
+    BuildVersion, CanisterId, CanisterWasm, UpgradeCanisterWasmArgs, UploadWasmChunkArgs, UploadWasmChunkResponse,
+    WASM_CHUNK_SIZE,
+};
 
 pub async fn upgrade_group_index_canister(
     identity: Box<dyn Identity>,
@@ -205,15 +211,23 @@ pub async fn upgrade_local_group_index_canister(
 ) {
     let agent = build_ic_agent(url, identity).await;
     let canister_wasm = get_canister_wasm(CanisterName::LocalGroupIndex, version);
-    let args = UpgradeCanisterWasmArgs {
+    let mut args = UpgradeCanisterWasmArgs {
         wasm: CanisterWasm {
             version,
             module: canister_wasm.module,
         },
         filter: None,
         use_for_new_canisters: None,
+        rollout: None,
+        wasm_hash: None,
     };
 
+    upload_wasm_in_chunks_if_required(&mut args, |chunk| {
+        let agent = &agent;
+        async move { group_index_canister_client::upload_wasm_chunk(agent, &group_index_canister_id, &chunk).await }
+    })
+    .await;
+
     let response =
         group_index_canister_client::upgrade_local_group_index_canister_wasm(&agent, &group_index_canister_id, &args)
             .await
@@ -236,15 +250,23 @@ pub async fn upgrade_group_canister(
 ) {
     let agent = build_ic_agent(url, identity).await;
     let canister_wasm = get_canister_wasm(CanisterName::Group, version);
-    let args = UpgradeCanisterWasmArgs {
+    let mut args = UpgradeCanisterWasmArgs {
         wasm: CanisterWasm {
             version,
             module: canister_wasm.module,
         },
         filter: None,
         use_for_new_canisters: None,
+        rollout: None,
+        wasm_hash: None,
     };
 
+    upload_wasm_in_chunks_if_required(&mut args, |chunk| {
+        let agent = &agent;
+        async move { group_index_canister_client::upload_wasm_chunk(agent, &group_index_canister_id, &chunk).await }
+    })
+    .await;
+
     let response = group_index_canister_client::upgrade_group_canister_wasm(&agent, &group_index_canister_id, &args)
         .await
         .unwrap();
@@ -263,15 +285,23 @@ pub async fn upgrade_community_canister(
 ) {
     let agent = build_ic_agent(url, identity).await;
     let canister_wasm = get_canister_wasm(CanisterName::Community, version);
-    let args = UpgradeCanisterWasmArgs {
+    let mut args = UpgradeCanisterWasmArgs {
         wasm: CanisterWasm {
             version,
             module: canister_wasm.module,
         },
         filter: None,
         use_for_new_canisters: None,
+        rollout: None,
+        wasm_hash: None,
     };
 
+    upload_wasm_in_chunks_if_required(&mut args, |chunk| {
+        let agent = &agent;
+        async move { group_index_canister_client::upload_wasm_chunk(agent, &group_index_canister_id, &chunk).await }
+    })
+    .await;
+
     let response = group_index_canister_client::upgrade_community_canister_wasm(&agent, &group_index_canister_id, &args)
         .await
         .unwrap();
@@ -293,15 +323,23 @@ pub async fn upgrade_user_canister(
 ) {
     let agent = build_ic_agent(url, identity).await;
     let canister_wasm = get_canister_wasm(CanisterName::User, version);
-    let args = UpgradeCanisterWasmArgs {
+    let mut args = UpgradeCanisterWasmArgs {
         wasm: CanisterWasm {
             version,
             module: canister_wasm.module,
         },
         filter: None,
         use_for_new_canisters: None,
+        rollout: None,
+        wasm_hash: None,
     };
 
+    upload_wasm_in_chunks_if_required(&mut args, |chunk| {
+        let agent = &agent;
+        async move { user_index_canister_client::upload_wasm_chunk(agent, &user_index_canister_id, &chunk).await }
+    })
+    .await;
+
     let response = user_index_canister_client::upgrade_user_canister_wasm(&agent, &user_index_canister_id, &args)
         .await
         .unwrap();
@@ -320,15 +358,23 @@ pub async fn upgrade_local_user_index_canister(
 ) {
     let agent = build_ic_agent(url, identity).await;
     let canister_wasm = get_canister_wasm(CanisterName::LocalUserIndex, version);
-    let args = UpgradeCanisterWasmArgs {
+    let mut args = UpgradeCanisterWasmArgs {
         wasm: CanisterWasm {
             version,
             module: canister_wasm.module,
         },
         filter: None,
         use_for_new_canisters: None,
+        rollout: None,
+        wasm_hash: None,
     };
 
+    upload_wasm_in_chunks_if_required(&mut args, |chunk| {
+        let agent = &agent;
+        async move { user_index_canister_client::upload_wasm_chunk(agent, &user_index_canister_id, &chunk).await }
+    })
+    .await;
+
     let response = user_index_canister_client::upgrade_local_user_index_canister_wasm(&agent, &user_index_canister_id, &args)
         .await
         .unwrap();
@@ -350,15 +396,23 @@ pub async fn upgrade_notifications_canister(
 ) {
     let agent = build_ic_agent(url, identity).await;
     let canister_wasm = get_canister_wasm(CanisterName::Notifications, version);
-    let args = UpgradeCanisterWasmArgs {
+    let mut args = UpgradeCanisterWasmArgs {
         wasm: CanisterWasm {
             version,
             module: canister_wasm.module,
         },
         filter: None,
         use_for_new_canisters: None,
+        rollout: None,
+        wasm_hash: None,
     };
 
+    upload_wasm_in_chunks_if_required(&mut args, |chunk| {
+        let agent = &agent;
+        async move { notifications_index_canister_client::upload_wasm_chunk(agent, &notifications_index_canister_id, &chunk).await }
+    })
+    .await;
+
     let response = notifications_index_canister_client::upgrade_notifications_canister_wasm(
         &agent,
         &notifications_index_canister_id,
@@ -384,15 +438,23 @@ pub async fn upgrade_storage_bucket_canister(
 ) {
     let agent = build_ic_agent(url, identity).await;
     let canister_wasm = get_canister_wasm(CanisterName::StorageBucket, version);
-    let args = UpgradeCanisterWasmArgs {
+    let mut args = UpgradeCanisterWasmArgs {
         wasm: CanisterWasm {
             version,
             module: canister_wasm.module,
         },
         filter: None,
         use_for_new_canisters: None,
+        rollout: None,
+        wasm_hash: None,
     };
 
+    upload_wasm_in_chunks_if_required(&mut args, |chunk| {
+        let agent = &agent;
+        async move { storage_index_canister_client::upload_wasm_chunk(agent, &storage_index_canister_id, &chunk).await }
+    })
+    .await;
+
     let response = storage_index_canister_client::upgrade_bucket_canister_wasm(&agent, &storage_index_canister_id, &args)
         .await
         .unwrap();
@@ -468,3 +530,34 @@ async fn upgrade_wasm<A: CandidType + Send + Sync>(
         .expect("Failed to start canister");
     println!("Canister started");
 }
+
+// Wasms which are too large to be sent in a single message are uploaded in chunks first, after
+// which the upgrade args reference the wasm by its hash rather than including it
+async fn upload_wasm_in_chunks_if_required<F, R>(args: &mut UpgradeCanisterWasmArgs, upload_chunk: F)
+where
+    F: Fn(UploadWasmChunkArgs) -> R,
+    R: Future<Output = Result<UploadWasmChunkResponse, Box<dyn Error + Sync + Send>>>,
+{
+    if args.wasm.module.len() <= WASM_CHUNK_SIZE {
+        return;
+    }
+
+    let wasm_hash = sha256(&args.wasm.module);
+    for (index, chunk) in args.wasm.module.chunks(WASM_CHUNK_SIZE).enumerate() {
+        let response = upload_chunk(UploadWasmChunkArgs {
+            wasm_hash,
+            index: index as u32,
+            chunk: chunk.to_vec(),
+        })
+        .await
+        .unwrap();
+
+        if !matches!(response, UploadWasmChunkResponse::Success(_)) {
+            panic!("{response:?}");
+        }
+    }
+    println!("Wasm uploaded in chunks. Hash: {}", hex::encode(wasm_hash));
+
+    args.wasm.module = Vec::new();
+    args.wasm_hash = Some(wasm_hash);
+}
@@ -0,0 +1,25 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{AuditLogEntry, AuditLogFilter};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub filter: AuditLogFilter,
+    // Only entries with an index lower than this will be returned
+    pub before_index: Option<u32>,
+    pub max_results: u32,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    NotAuthorized,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    // Newest first
+    pub entries: Vec<AuditLogEntry>,
+    pub total_entries: u32,
+}
@@ -0,0 +1,18 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, ContentFilterReason};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub text: Option<String>,
+    pub mention_count: u32,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    ContentRejected(ContentFilterReason),
+    UserNotInCommunity,
+    ChannelNotFound,
+}
@@ -0,0 +1,18 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, ContentFilters};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // If None, the community-wide filters are returned
+    pub channel_id: Option<ChannelId>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ContentFilters),
+    UserNotInCommunity,
+    ChannelNotFound,
+    UserNotInChannel,
+    NotAuthorized,
+}
@@ -0,0 +1,25 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::AuditLogFilter;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub filter: AuditLogFilter,
+    // Only entries with an index lower than this will be exported
+    pub before_index: Option<u32>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    NotAuthorized,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub json: String,
+    // Set if there were more matching entries than fit in a single response, in which case only the newest entries
+    // are included. Pass this as `before_index` to export the next page.
+    pub next_before_index: Option<u32>,
+}
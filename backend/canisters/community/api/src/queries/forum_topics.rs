@@ -0,0 +1,29 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, ForumTopic, ForumTopicsSortOrder, TimestampMillis};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub tags: Vec<String>,
+    pub solved: Option<bool>,
+    pub sort: ForumTopicsSortOrder,
+    pub page_index: u32,
+    pub page_size: u8,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    ChannelNotFound,
+    UserNotInChannel,
+    NotForum,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub topics: Vec<ForumTopic>,
+    pub total: u32,
+    pub timestamp: TimestampMillis,
+}
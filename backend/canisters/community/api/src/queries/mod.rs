@@ -1,14 +1,26 @@
+pub mod audit_log;
+pub mod c2c_check_content_filters;
 pub mod c2c_summary;
+pub mod channel_invite_links;
 pub mod channel_summary;
 pub mod channel_summary_updates;
+pub mod content_filters;
 pub mod deleted_message;
+pub mod emoji_packs;
 pub mod events;
 pub mod events_by_index;
 pub mod events_window;
 pub mod explore_channels;
+pub mod export_audit_log;
+pub mod forum_topics;
+pub mod instruction_counts;
 pub mod invite_code;
+pub mod invite_links;
+pub mod join_requests;
 pub mod local_user_index;
+pub mod message_edit_history;
 pub mod messages_by_message_index;
+pub mod moderation_queue;
 pub mod search_channel;
 pub mod selected_channel_initial;
 pub mod selected_channel_updates;
@@ -0,0 +1,26 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::UserId;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_ids: Vec<UserId>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    NotAuthorized,
+    UserSuspended,
+    CommunityFrozen,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub approved: Vec<UserId>,
+    pub not_found: Vec<UserId>,
+    // Requests which could not be approved (eg. because the user failed the access gate or the community is full) are
+    // left pending
+    pub failed: Vec<UserId>,
+}
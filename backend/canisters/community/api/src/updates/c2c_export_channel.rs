@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use types::ChannelId;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub from: u64,
+    pub page_size: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ByteBuf),
+    // The channel is being detached into a group but the community hasn't yet recorded the group as
+    // its destination, so the caller should retry
+    DestinationNotSet,
+    ChannelNotFound,
+}
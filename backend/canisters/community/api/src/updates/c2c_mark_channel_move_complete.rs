@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, ChannelMovedTo, MessageIndex};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub moved_to: ChannelMovedTo,
+    pub latest_message_index: Option<MessageIndex>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    ChannelNotFound,
+}
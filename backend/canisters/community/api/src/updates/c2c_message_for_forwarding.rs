@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, MessageForForwarding, MessageIndex};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(MessageForForwarding),
+    UserNotInCommunity,
+    ChannelNotFound,
+    UserNotInChannel,
+    MessageNotFound,
+    NotForwardable,
+}
@@ -0,0 +1,22 @@
+use candid::{CandidType, Principal};
+use serde::{Deserialize, Serialize};
+use types::UserId;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub principal: Principal,
+    pub message: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AlreadyInCommunity,
+    AlreadyRequested,
+    CommunityIsPublic,
+    MessageTooLong(u32),
+    TooManyRequests(u32),
+    UserBlocked,
+    CommunityFrozen,
+}
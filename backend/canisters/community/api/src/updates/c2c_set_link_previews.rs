@@ -0,0 +1,2 @@
+pub type Args = types::SetLinkPreviewsArgs;
+pub type Response = types::SetLinkPreviewsResponse;
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, CommunityId, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub channel_id: ChannelId,
+    pub community_id: CommunityId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(u64),
+    UserNotInCommunity,
+    UserNotCommunityOwner,
+    UserNotChannelOwner,
+    UserSuspended,
+    ChannelNotFound,
+    ChannelAlreadyBeingMoved,
+    CommunityFrozen,
+}
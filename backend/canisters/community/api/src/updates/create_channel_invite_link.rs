@@ -0,0 +1,27 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, GroupInviteLink, GroupRole, TimestampMillis};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub name: String,
+    pub expires_at: Option<TimestampMillis>,
+    pub max_uses: Option<u32>,
+    pub role: Option<GroupRole>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(GroupInviteLink),
+    UserNotInCommunity,
+    ChannelNotFound,
+    UserNotInChannel,
+    NotAuthorized,
+    UserSuspended,
+    CommunityFrozen,
+    NameTooLong(u32),
+    ExpiryInPast,
+    InvalidMaxUses,
+    TooManyLinks(u32),
+}
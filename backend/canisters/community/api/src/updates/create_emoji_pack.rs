@@ -0,0 +1,28 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{EmojiPackAsset, EmojiPackId, EmojiPackKind};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub name: String,
+    pub kind: EmojiPackKind,
+    pub assets: Vec<EmojiPackAsset>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    NameInvalid,
+    InvalidShortcode(String),
+    DuplicateShortcode(String),
+    TooManyAssets(u32),
+    TooManyPacks(u32),
+    NotAuthorized,
+    CommunityFrozen,
+    UserSuspended,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub pack_id: EmojiPackId,
+}
@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{CommunityInviteLink, CommunityRole, TimestampMillis};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub name: String,
+    pub expires_at: Option<TimestampMillis>,
+    pub max_uses: Option<u32>,
+    pub role: Option<CommunityRole>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(CommunityInviteLink),
+    UserNotInCommunity,
+    NotAuthorized,
+    UserSuspended,
+    CommunityFrozen,
+    NameTooLong(u32),
+    ExpiryInPast,
+    InvalidMaxUses,
+    TooManyLinks(u32),
+}
@@ -0,0 +1,28 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, ChatId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    UserNotCommunityOwner,
+    UserNotChannelOwner,
+    UserSuspended,
+    CommunityFrozen,
+    ChannelNotFound,
+    ChannelAlreadyBeingMoved,
+    NameTaken,
+    InternalError(String),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub group_id: ChatId,
+    pub total_bytes: u64,
+}
@@ -0,0 +1,32 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, CommunityId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub community_id: CommunityId,
+    pub channel_id: ChannelId,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    UserNotInCommunity,
+    UserNotCommunityOwner,
+    UserSuspended,
+    CommunityFrozen,
+    SourceCommunityNotFound,
+    SourceCommunityFrozen,
+    UserNotInSourceCommunity,
+    UserNotSourceCommunityOwner,
+    UserNotChannelOwner,
+    ChannelNotFound,
+    ChannelAlreadyBeingMoved,
+    InternalError(String),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub channel_id: ChannelId,
+    pub total_bytes: u64,
+}
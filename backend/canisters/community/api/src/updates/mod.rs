@@ -1,8 +1,10 @@
 pub mod add_members_to_channel;
 pub mod add_reaction;
+pub mod approve_join_requests;
 pub mod block_user;
 pub mod c2c_create_proposals_channel;
 pub mod c2c_delete_community;
+pub mod c2c_export_channel;
 pub mod c2c_freeze_community;
 pub mod c2c_import_proposals_group;
 pub mod c2c_invite_users;
@@ -10,7 +12,12 @@ pub mod c2c_invite_users_to_channel;
 pub mod c2c_join_channel;
 pub mod c2c_join_community;
 pub mod c2c_leave_community;
+pub mod c2c_mark_channel_move_complete;
+pub mod c2c_message_for_forwarding;
+pub mod c2c_request_to_join;
+pub mod c2c_set_link_previews;
 pub mod c2c_set_user_suspended;
+pub mod c2c_start_channel_export;
 pub mod c2c_tip_message;
 pub mod c2c_unfreeze_community;
 pub mod c2c_update_proposals;
@@ -19,28 +26,40 @@ pub mod change_channel_role;
 pub mod change_role;
 pub mod claim_prize;
 pub mod create_channel;
+pub mod create_channel_invite_link;
+pub mod create_emoji_pack;
+pub mod create_invite_link;
 pub mod create_user_group;
 pub mod decline_invitation;
 pub mod delete_channel;
+pub mod delete_emoji_pack;
 pub mod delete_messages;
 pub mod delete_user_groups;
+pub mod detach_channel;
 pub mod disable_invite_code;
 pub mod edit_message;
 pub mod enable_invite_code;
+pub mod export_chat;
 pub mod follow_thread;
+pub mod import_channel;
 pub mod import_group;
 pub mod leave_channel;
+pub mod moderate_report;
 pub mod pin_message;
 pub mod register_poll_vote;
 pub mod register_proposal_vote;
 pub mod register_proposal_vote_v2;
+pub mod reject_join_requests;
 pub mod remove_member;
 pub mod remove_member_from_channel;
 pub mod remove_reaction;
 pub mod report_message;
 pub mod reset_invite_code;
+pub mod revoke_invite_link;
 pub mod send_message;
+pub mod set_content_filters;
 pub mod set_member_display_name;
+pub mod start_chat_history_import;
 pub mod toggle_mute_notifications;
 pub mod unblock_user;
 pub mod undelete_messages;
@@ -48,4 +67,7 @@ pub mod unfollow_thread;
 pub mod unpin_message;
 pub mod update_channel;
 pub mod update_community;
+pub mod update_emoji_pack;
+pub mod update_forum_topic;
 pub mod update_user_group;
+pub mod upload_chat_history_chunk;
@@ -0,0 +1,26 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, ModerationAction, ModerationReportId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub report_id: ModerationReportId,
+    pub action: ModerationAction,
+    pub notes: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    UserNotInCommunity,
+    ChannelNotFound,
+    UserNotInChannel,
+    NotAuthorized,
+    UserSuspended,
+    CommunityFrozen,
+    ReportNotFound,
+    AlreadyResolved,
+    InvalidDuration,
+    InternalError(String),
+}
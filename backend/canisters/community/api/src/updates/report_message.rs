@@ -8,6 +8,8 @@ pub struct Args {
     pub thread_root_message_index: Option<MessageIndex>,
     pub message_id: MessageId,
     pub delete: bool,
+    pub reason_code: Option<u32>,
+    pub notes: Option<String>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -1,8 +1,8 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use types::{
-    ChannelId, EventIndex, GroupReplyContext, InvalidPollReason, MessageContentInitial, MessageId, MessageIndex,
-    TimestampMillis, User, Version,
+    ChannelId, ContentFilterMatch, ContentFilterReason, EventIndex, ForwardedFrom, GroupReplyContext, InvalidPollReason,
+    MessageContentInitial, MessageId, MessageIndex, NewForumTopic, TimestampMillis, User, Version,
 };
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -18,6 +18,12 @@ pub struct Args {
     pub forwarding: bool,
     pub community_rules_accepted: Option<Version>,
     pub channel_rules_accepted: Option<Version>,
+    // Required when sending a top-level message to a forum channel
+    #[serde(default)]
+    pub forum_topic: Option<NewForumTopic>,
+    // Only accepted when the message is forwarded by the sender's user canister
+    #[serde(default)]
+    pub forwarded_from: Option<ForwardedFrom>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -36,6 +42,8 @@ pub enum Response {
     CommunityFrozen,
     RulesNotAccepted,
     CommunityRulesNotAccepted,
+    ContentRejected(ContentFilterReason),
+    RateLimited(TimestampMillis),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -44,4 +52,5 @@ pub struct SuccessResult {
     pub message_index: MessageIndex,
     pub timestamp: TimestampMillis,
     pub expires_at: Option<TimestampMillis>,
+    pub content_filter_match: Option<ContentFilterMatch>,
 }
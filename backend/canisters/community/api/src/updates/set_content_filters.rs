@@ -0,0 +1,22 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, ContentFilters};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // If None, the filters apply to every channel in the community
+    pub channel_id: Option<ChannelId>,
+    pub content_filters: ContentFilters,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    UserNotInCommunity,
+    ChannelNotFound,
+    UserNotInChannel,
+    NotAuthorized,
+    UserSuspended,
+    CommunityFrozen,
+    InvalidContentFilters(String),
+}
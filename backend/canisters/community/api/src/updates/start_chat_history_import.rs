@@ -0,0 +1,42 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{
+    ChannelId, ChatImportAuthorMapping, ChatImportMediaFile, ChatImportSource, FieldTooLongResult, FieldTooShortResult, UserId,
+};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub source: ChatImportSource,
+    pub channel_name: String,
+    pub channel_description: String,
+    pub is_public: bool,
+    // Authors not included here are imported as placeholder senders
+    pub author_mappings: Vec<ChatImportAuthorMapping>,
+    // Attachments not included here are replaced by their file names
+    pub media: Vec<ChatImportMediaFile>,
+    pub total_bytes: u64,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    NameTooShort(FieldTooShortResult),
+    NameTooLong(FieldTooLongResult),
+    NameReserved,
+    NameTaken,
+    DescriptionTooLong(FieldTooLongResult),
+    ExportTooLarge(u64),
+    // Only one export can be uploaded or imported at a time
+    ImportInProgress,
+    // Authors can only be mapped to members of the community
+    MappedUserNotInCommunity(UserId),
+    UserSuspended,
+    NotAuthorized,
+    CommunityFrozen,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    // The id of the channel which will be created once the export has been uploaded
+    pub channel_id: ChannelId,
+}
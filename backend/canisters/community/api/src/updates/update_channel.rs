@@ -2,7 +2,7 @@ use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use types::{
     AccessGate, ChannelId, Document, FieldTooLongResult, FieldTooShortResult, Milliseconds, OptionUpdate,
-    OptionalGroupPermissions, UpdatedRules, Version,
+    OptionalGroupPermissions, SlowMode, UpdatedRules, Version,
 };
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -16,6 +16,9 @@ pub struct Args {
     pub events_ttl: OptionUpdate<Milliseconds>,
     pub gate: OptionUpdate<AccessGate>,
     pub public: Option<bool>,
+    pub slow_mode: OptionUpdate<SlowMode>,
+    pub edit_history_enabled: Option<bool>,
+    pub hide_forwarded_from: Option<bool>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
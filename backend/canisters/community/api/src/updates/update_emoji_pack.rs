@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{EmojiPackAsset, EmojiPackId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub pack_id: EmojiPackId,
+    pub name: Option<String>,
+    pub assets_to_add: Vec<EmojiPackAsset>,
+    pub shortcodes_to_remove: Vec<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    PackNotFound,
+    NameInvalid,
+    InvalidShortcode(String),
+    DuplicateShortcode(String),
+    TooManyAssets(u32),
+    NotAuthorized,
+    CommunityFrozen,
+    UserSuspended,
+}
@@ -0,0 +1,27 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, MessageIndex};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub root_message_index: MessageIndex,
+    pub title: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub solved: Option<bool>,
+    pub answer: Option<MessageIndex>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    CommunityFrozen,
+    UserNotInCommunity,
+    UserSuspended,
+    ChannelNotFound,
+    UserNotInChannel,
+    NotForum,
+    TopicNotFound,
+    NotAuthorized,
+    InvalidRequest(String),
+}
@@ -0,0 +1,40 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use std::fmt::{Debug, Formatter};
+use types::ChannelId;
+
+#[derive(CandidType, Serialize, Deserialize)]
+pub struct Args {
+    pub channel_id: ChannelId,
+    pub offset: u64,
+    pub bytes: ByteBuf,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    ImportStarted(ImportStartedResult),
+    ImportNotFound,
+    InvalidOffset(u64),
+    TooManyBytes,
+    InvalidExport(String),
+    NameTaken,
+    NotAuthorized,
+    CommunityFrozen,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ImportStartedResult {
+    pub channel_id: ChannelId,
+}
+
+impl Debug for Args {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Args")
+            .field("channel_id", &self.channel_id)
+            .field("offset", &self.offset)
+            .field("byte_length", &self.bytes.len())
+            .finish()
+    }
+}
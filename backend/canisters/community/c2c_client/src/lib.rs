@@ -2,10 +2,12 @@ use canister_client::generate_c2c_call;
 use community_canister::*;
 
 // Queries
+generate_c2c_call!(c2c_check_content_filters);
 
 // Updates
 generate_c2c_call!(c2c_create_proposals_channel);
 generate_c2c_call!(c2c_delete_community);
+generate_c2c_call!(c2c_export_channel);
 generate_c2c_call!(c2c_freeze_community);
 generate_c2c_call!(c2c_import_proposals_group);
 generate_c2c_call!(c2c_invite_users);
@@ -13,7 +15,10 @@ generate_c2c_call!(c2c_invite_users_to_channel);
 generate_c2c_call!(c2c_join_channel);
 generate_c2c_call!(c2c_join_community);
 generate_c2c_call!(c2c_leave_community);
+generate_c2c_call!(c2c_message_for_forwarding);
+generate_c2c_call!(c2c_request_to_join);
 generate_c2c_call!(c2c_set_user_suspended);
+generate_c2c_call!(c2c_start_channel_export);
 generate_c2c_call!(c2c_tip_message);
 generate_c2c_call!(c2c_unfreeze_community);
 generate_c2c_call!(c2c_update_proposals);
@@ -0,0 +1,284 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::jobs::import_groups::{add_channel_members_to_community, PAGE_SIZE};
+use crate::model::audit_log::to_json;
+use crate::model::channels::Channel;
+use crate::model::groups_being_imported::{NextBatchResult, SourceChannel};
+use crate::model::invited_users::UserInvitation;
+use crate::timer_job_types::{FinalizeChannelImportJob, ProcessChannelImportMembersJob, TimerJob};
+use crate::{mutate_state, RuntimeState};
+use community_canister::c2c_export_channel::{Args, Response};
+use group_community_common::{ChannelExport, ExportedUserGroup};
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::{info, trace};
+use types::{AuditLogActionType, ChannelId, ChannelMovedTo, CommunityId, Empty};
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() && !state.data.channels_being_imported.is_empty() {
+        let timer_id = ic_cdk_timers::set_timer_interval(Duration::ZERO, run);
+        TIMER_ID.set(Some(timer_id));
+        trace!("'import_channels' job started");
+        true
+    } else {
+        false
+    }
+}
+
+fn run() {
+    match mutate_state(next_batch) {
+        NextBatchResult::Success(channels) => ic_cdk::spawn(import_channels(channels)),
+        NextBatchResult::Continue => {}
+        NextBatchResult::Exit => {
+            if let Some(timer_id) = TIMER_ID.take() {
+                ic_cdk_timers::clear_timer(timer_id);
+                trace!("'import_channels' job stopped");
+            }
+        }
+    }
+}
+
+fn next_batch(state: &mut RuntimeState) -> NextBatchResult<SourceChannel> {
+    let now = state.env.now();
+    state.data.channels_being_imported.next_batch(now)
+}
+
+async fn import_channels(channels: Vec<(SourceChannel, u64)>) {
+    futures::future::join_all(channels.into_iter().map(|(c, i)| import_channel(c, i))).await;
+}
+
+async fn import_channel(source: SourceChannel, from: u64) {
+    let (community_id, channel_id) = source;
+    info!(%community_id, %channel_id, from, "'import_channel' starting");
+    match community_canister_c2c_client::c2c_export_channel(
+        community_id.into(),
+        &Args {
+            channel_id,
+            from,
+            page_size: PAGE_SIZE,
+        },
+    )
+    .await
+    {
+        Ok(Response::Success(bytes)) => {
+            mutate_state(|state| {
+                if state.data.channels_being_imported.mark_batch_complete(&source, &bytes) {
+                    let now = state.env.now();
+
+                    state.data.timer_jobs.enqueue_job(
+                        TimerJob::FinalizeChannelImport(FinalizeChannelImportJob {
+                            source_community_id: community_id,
+                            source_channel_id: channel_id,
+                        }),
+                        now,
+                        now,
+                    );
+
+                    // We set a timer to trigger an upgrade in case deserializing the channel requires
+                    // more instructions than are allowed in a normal update call
+                    ic_cdk_timers::set_timer(Duration::from_secs(10), move || trigger_upgrade_to_finalize_import(source));
+
+                    info!(%community_id, %channel_id, "Channel data imported");
+                }
+            });
+        }
+        Ok(Response::ChannelNotFound) => {
+            // The source community is no longer moving the channel to us so the import is abandoned
+            mutate_state(|state| state.data.channels_being_imported.take(&source));
+        }
+        Ok(Response::DestinationNotSet) => {
+            mutate_state(|state| {
+                let now = state.env.now();
+                state
+                    .data
+                    .channels_being_imported
+                    .mark_batch_failed(&source, "Destination not set".to_string(), now);
+
+                start_job_if_required(state);
+            });
+        }
+        Err(error) => {
+            mutate_state(|state| {
+                let now = state.env.now();
+                state
+                    .data
+                    .channels_being_imported
+                    .mark_batch_failed(&source, format!("{error:?}"), now);
+
+                start_job_if_required(state);
+            });
+        }
+    }
+}
+
+pub(crate) fn finalize_channel_import(source: SourceChannel) {
+    let (source_community_id, source_channel_id) = source;
+    info!(%source_community_id, %source_channel_id, "'finalize_channel_import' starting");
+    let initial_instruction_count = ic_cdk::api::instruction_counter();
+
+    mutate_state(|state| {
+        if let Some(import) = state.data.channels_being_imported.take(&source) {
+            let now = state.env.now();
+            let channel_id = import.channel_id();
+            let export = ChannelExport::deserialize(import.bytes());
+
+            // Users invited to the channel who aren't community members become invited to the
+            // community, and we record their principals so that they are able to accept
+            for (principal, user_id) in export.principals {
+                if state.data.members.get_by_user_id(&user_id).is_none() {
+                    state.data.members.add_user_id(principal, user_id);
+                }
+            }
+            for user_id in export.chat.invited_users.users() {
+                if state.data.members.get_by_user_id(&user_id).is_none() {
+                    state.data.invited_users.add(
+                        user_id,
+                        UserInvitation {
+                            invited_by: import.imported_by(),
+                            timestamp: now,
+                        },
+                    );
+                }
+            }
+
+            state.data.audit_log.push(
+                import.imported_by(),
+                AuditLogActionType::ChannelMoved,
+                Some(channel_id),
+                Vec::new(),
+                to_json(&ChannelMovedTo::Community(source_community_id, source_channel_id)),
+                to_json(&export.chat.name.value),
+                now,
+            );
+
+            state.data.channels.add(Channel {
+                id: channel_id,
+                chat: export.chat,
+                date_imported: None, // This is only set once everything is complete
+                invite_links: export.invite_links,
+            });
+
+            state.data.timer_jobs.enqueue_job(
+                TimerJob::ProcessChannelImportMembers(ProcessChannelImportMembersJob {
+                    source_community_id,
+                    source_channel_id,
+                    channel_id,
+                    user_groups: export.user_groups,
+                    attempt: 0,
+                }),
+                now,
+                now,
+            );
+        }
+    });
+
+    let instruction_count = ic_cdk::api::instruction_counter() - initial_instruction_count;
+    info!(%source_community_id, %source_channel_id, instruction_count, "'finalize_channel_import' completed");
+}
+
+pub(crate) async fn process_channel_members(
+    source_community_id: CommunityId,
+    source_channel_id: ChannelId,
+    channel_id: ChannelId,
+    user_groups: Vec<ExportedUserGroup>,
+    attempt: u32,
+) {
+    info!(%source_community_id, %source_channel_id, attempt, "'process_channel_members' starting");
+
+    if add_channel_members_to_community(channel_id).await.is_none() && attempt < 30 {
+        mutate_state(|state| {
+            let now = state.env.now();
+            state.data.timer_jobs.enqueue_job(
+                TimerJob::ProcessChannelImportMembers(ProcessChannelImportMembersJob {
+                    source_community_id,
+                    source_channel_id,
+                    channel_id,
+                    user_groups,
+                    attempt: attempt + 1,
+                }),
+                now,
+                now,
+            );
+        });
+        return;
+    }
+
+    mutate_state(|state| {
+        import_user_groups(user_groups, state);
+        mark_move_complete(source_community_id, source_channel_id, channel_id, state);
+    });
+
+    info!(%source_community_id, %source_channel_id, attempt, "'process_channel_members' completed");
+}
+
+// User groups are merged with any existing user groups of the same name
+fn import_user_groups(user_groups: Vec<ExportedUserGroup>, state: &mut RuntimeState) {
+    let now = state.env.now();
+    for user_group in user_groups {
+        let name_upper = user_group.name.to_uppercase();
+        if let Some(existing_id) = state
+            .data
+            .members
+            .iter_user_groups()
+            .find(|g| g.name.value.to_uppercase() == name_upper)
+            .map(|g| g.id)
+        {
+            state
+                .data
+                .members
+                .update_user_group(existing_id, None, user_group.users, Vec::new(), now);
+        } else {
+            state
+                .data
+                .members
+                .create_user_group(user_group.name, user_group.users, state.env.rng(), now);
+        }
+    }
+}
+
+fn mark_move_complete(
+    source_community_id: CommunityId,
+    source_channel_id: ChannelId,
+    channel_id: ChannelId,
+    state: &mut RuntimeState,
+) {
+    let now = state.env.now();
+    let channel = match state.data.channels.get_mut(&channel_id) {
+        Some(c) => c,
+        None => {
+            // The channel was deleted before its members had been processed, so the source
+            // community is left to put the channel back once the move expires
+            info!(%source_community_id, %source_channel_id, "Imported channel not found");
+            return;
+        }
+    };
+    channel.date_imported = Some(now);
+
+    state.data.fire_and_forget_handler.send(
+        source_community_id.into(),
+        "c2c_mark_channel_move_complete_msgpack".to_string(),
+        msgpack::serialize_then_unwrap(community_canister::c2c_mark_channel_move_complete::Args {
+            channel_id: source_channel_id,
+            moved_to: ChannelMovedTo::Community(state.env.canister_id().into(), channel_id),
+            latest_message_index: channel.chat.events.main_events_list().latest_message_index(),
+        }),
+    );
+
+    handle_activity_notification(state);
+}
+
+fn trigger_upgrade_to_finalize_import(source: SourceChannel) {
+    mutate_state(|state| {
+        if state.data.channels_being_imported.contains(&source) {
+            state.data.fire_and_forget_handler.send(
+                state.data.local_group_index_canister_id,
+                "c2c_trigger_upgrade_msgpack".to_string(),
+                msgpack::serialize_then_unwrap(Empty {}),
+            );
+        }
+    });
+}
@@ -0,0 +1,158 @@
+use crate::model::chat_history_imports::ChatHistoryImport;
+use crate::{mutate_state, RuntimeState};
+use chat_events::{
+    ChatEvents, FileContentInternal, MessageContentInternal, PushMessageArgs, ReplyContextInternal, TextContentInternal,
+};
+use chat_import::ParsedMessage;
+use ic_cdk_timers::TimerId;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::{error, info, trace};
+use types::{ImportedMessageDetails, TimestampMillis};
+use utils::consts::OPENCHAT_BOT_USER_ID;
+
+const MAX_MESSAGES_PER_BATCH: usize = 100;
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() && state.data.chat_history_imports.has_imports_in_progress() {
+        let timer_id = ic_cdk_timers::set_timer_interval(Duration::ZERO, run);
+        TIMER_ID.set(Some(timer_id));
+        trace!("'import_chat_history' job started");
+        true
+    } else {
+        false
+    }
+}
+
+fn run() {
+    if !mutate_state(import_next_batch) {
+        if let Some(timer_id) = TIMER_ID.take() {
+            ic_cdk_timers::clear_timer(timer_id);
+            trace!("'import_chat_history' job stopped");
+        }
+    }
+}
+
+// Returns false once there are no imports remaining
+fn import_next_batch(state: &mut RuntimeState) -> bool {
+    let now = state.env.now();
+
+    if let Some((channel_id, import)) = state.data.chat_history_imports.next_import() {
+        if let Some(channel) = state.data.channels.get_mut(&channel_id) {
+            let finished = match import.reader.next_batch(&import.bytes, MAX_MESSAGES_PER_BATCH) {
+                Ok(messages) => {
+                    for message in messages {
+                        import_message(message, import, &mut channel.chat.events, state.env.rng(), now);
+                    }
+                    import.reader.is_finished()
+                }
+                Err(error) => {
+                    // The messages imported so far are kept, the rest of the export is skipped
+                    error!(%channel_id, %error, "Failed to parse chat history export");
+                    true
+                }
+            };
+
+            if finished {
+                channel.date_imported = Some(now);
+                state.data.chat_history_imports.remove_import(&channel_id);
+                info!(%channel_id, "Chat history import completed");
+            }
+        } else {
+            // The channel has been deleted so the import is abandoned
+            state.data.chat_history_imports.remove_import(&channel_id);
+        }
+        true
+    } else {
+        false
+    }
+}
+
+fn import_message(
+    message: ParsedMessage,
+    import: &mut ChatHistoryImport,
+    events: &mut ChatEvents,
+    rng: &mut StdRng,
+    now: TimestampMillis,
+) {
+    let sender = import
+        .author_mappings
+        .get(&message.author_id)
+        .copied()
+        .unwrap_or(OPENCHAT_BOT_USER_ID);
+
+    let mut replies_to = message
+        .replies_to
+        .as_ref()
+        .and_then(|id| import.imported.get(id))
+        .map(|event_index| ReplyContextInternal {
+            chat_if_other: None,
+            event_index: *event_index,
+        });
+
+    let mut text = message.text;
+    let mut files = Vec::new();
+    for attachment in message.attachments {
+        if let Some(file) = import.media.get(&attachment.path) {
+            files.push(FileContentInternal {
+                name: attachment.file_name,
+                caption: None,
+                mime_type: file.mime_type.clone(),
+                file_size: file.file_size,
+                blob_reference: Some(file.blob_reference.clone()),
+            });
+        } else {
+            // Attachments which weren't uploaded are replaced by their file names
+            if !text.is_empty() {
+                text.push('\n');
+            }
+            text.push_str(&format!("[{}]", attachment.file_name));
+        }
+    }
+
+    // Each file becomes a message of its own, with the text as the caption of the first
+    let contents: Vec<_> = if files.is_empty() {
+        if text.trim().is_empty() {
+            return;
+        }
+        vec![MessageContentInternal::Text(TextContentInternal {
+            text,
+            link_previews: Vec::new(),
+        })]
+    } else {
+        files[0].caption = (!text.is_empty()).then_some(text);
+        files.into_iter().map(MessageContentInternal::File).collect()
+    };
+
+    for (index, content) in contents.into_iter().enumerate() {
+        let event = events.push_imported_message(
+            PushMessageArgs {
+                sender,
+                thread_root_message_index: None,
+                message_id: rng.gen(),
+                content,
+                mentioned: Vec::new(),
+                replies_to: replies_to.take(),
+                forwarded: false,
+                forwarded_from: None,
+                correlation_id: 0,
+                now,
+            },
+            ImportedMessageDetails {
+                source: import.source,
+                sender_name: message.author_name.clone(),
+                timestamp: message.timestamp,
+            },
+        );
+
+        if index == 0 {
+            import.imported.insert(message.external_id.clone(), event.index);
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::updates::c2c_join_channel::join_channel_unchecked;
 use crate::{mutate_state, RuntimeState};
 use group_canister::c2c_export_group::{Args, Response};
 use group_chat_core::GroupChatCore;
+use group_community_common::InviteLinks;
 use ic_cdk_timers::TimerId;
 use std::cell::Cell;
 use std::collections::HashMap;
@@ -16,7 +17,7 @@ use tracing::{info, trace};
 use types::{ChannelId, ChannelLatestMessageIndex, ChatId, Empty, UserId};
 use utils::consts::OPENCHAT_BOT_USER_ID;
 
-const PAGE_SIZE: u32 = 19 * 102 * 1024; // Roughly 1.9MB (1.9 * 1024 * 1024)
+pub(crate) const PAGE_SIZE: u32 = 19 * 102 * 1024; // Roughly 1.9MB (1.9 * 1024 * 1024)
 
 thread_local! {
     static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
@@ -90,10 +91,11 @@ async fn import_group(group_id: ChatId, from: u64) {
                 if error.1.contains("violated contract") {
                     state.data.groups_being_imported.take(&group_id);
                 } else {
+                    let now = state.env.now();
                     state
                         .data
                         .groups_being_imported
-                        .mark_batch_failed(&group_id, format!("{error:?}"));
+                        .mark_batch_failed(&group_id, format!("{error:?}"), now);
 
                     start_job_if_required(state);
                 }
@@ -116,6 +118,7 @@ pub(crate) fn finalize_group_import(group_id: ChatId) {
                 id: channel_id,
                 chat,
                 date_imported: None, // This is only set once everything is complete
+                invite_links: InviteLinks::default(),
             });
 
             state.data.timer_jobs.enqueue_job(
@@ -134,14 +137,52 @@ pub(crate) fn finalize_group_import(group_id: ChatId) {
     info!(%group_id, instruction_count, "'finalize_group_import' completed");
 }
 
+pub(crate) async fn process_channel_members(group_id: ChatId, channel_id: ChannelId, attempt: u32) {
+    info!(%group_id, attempt, "'process_channel_members' starting");
+
+    let members_added = match add_channel_members_to_community(channel_id).await {
+        Some(members_added) => members_added,
+        None if attempt < 30 => {
+            mutate_state(|state| {
+                let now = state.env.now();
+                state.data.timer_jobs.enqueue_job(
+                    TimerJob::ProcessGroupImportChannelMembers(ProcessGroupImportChannelMembersJob {
+                        group_id,
+                        channel_id,
+                        attempt: attempt + 1,
+                    }),
+                    now,
+                    now,
+                );
+            });
+            return;
+        }
+        None => Vec::new(),
+    };
+
+    mutate_state(|state| {
+        state.data.events.push_event(
+            CommunityEventInternal::GroupImported(Box::new(GroupImportedInternal {
+                group_id,
+                channel_id,
+                members_added,
+            })),
+            state.env.now(),
+        );
+    });
+
+    ic_cdk_timers::set_timer(Duration::ZERO, move || mark_import_complete(group_id, channel_id));
+    info!(%group_id, attempt, "'process_channel_members' completed");
+}
+
 // 1. For channel members already in the community, add the new channel to their set of channels.
 // 2. If the channel is public, for community members not in the channel, add them to the channel.
 // 3. For channel members who are not yet community members, lookup their principals, then join them
 // to the community, then add them to the public channels, then add the new channel to their set of
 // channels.
-pub(crate) async fn process_channel_members(group_id: ChatId, channel_id: ChannelId, attempt: u32) {
-    info!(%group_id, attempt, "'process_channel_members' starting");
-
+// Returns the users who were added to the community, or `None` if their principals couldn't be
+// looked up, in which case the caller should retry.
+pub(crate) async fn add_channel_members_to_community(channel_id: ChannelId) -> Option<Vec<UserId>> {
     let (members_to_add_to_community, local_user_index_canister_id) = mutate_state(|state| {
         let channel = state.data.channels.get(&channel_id).unwrap();
         let mut to_add: HashMap<UserId, bool> = HashMap::new();
@@ -201,39 +242,15 @@ pub(crate) async fn process_channel_members(group_id: ChatId, channel_id: Channe
                     }
                 }
             });
-        } else if attempt < 30 {
-            mutate_state(|state| {
-                let now = state.env.now();
-                state.data.timer_jobs.enqueue_job(
-                    TimerJob::ProcessGroupImportChannelMembers(ProcessGroupImportChannelMembersJob {
-                        group_id,
-                        channel_id,
-                        attempt: attempt + 1,
-                    }),
-                    now,
-                    now,
-                );
-            });
-            return;
+        } else {
+            return None;
         }
     } else {
         // Add community members to the channel if it is public
         mutate_state(|state| add_community_members_to_channel_if_public(channel_id, state));
     }
 
-    mutate_state(|state| {
-        state.data.events.push_event(
-            CommunityEventInternal::GroupImported(Box::new(GroupImportedInternal {
-                group_id,
-                channel_id,
-                members_added,
-            })),
-            state.env.now(),
-        );
-    });
-
-    ic_cdk_timers::set_timer(Duration::ZERO, move || mark_import_complete(group_id, channel_id));
-    info!(%group_id, attempt, "'process_channel_members' completed");
+    Some(members_added)
 }
 
 fn add_community_members_to_channel_if_public(channel_id: ChannelId, state: &mut RuntimeState) {
@@ -1,9 +1,13 @@
 use crate::RuntimeState;
 
+pub mod import_channels;
+pub mod import_chat_history;
 pub mod import_groups;
 pub mod make_pending_payments;
 
 pub(crate) fn start(state: &RuntimeState) {
+    import_channels::start_job_if_required(state);
+    import_chat_history::start_job_if_required(state);
     import_groups::start_job_if_required(state);
     make_pending_payments::start_job_if_required(state);
 }
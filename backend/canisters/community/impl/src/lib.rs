@@ -1,6 +1,10 @@
 use crate::memory::{get_instruction_counts_data_memory, get_instruction_counts_index_memory};
+use crate::model::audit_log::AuditLog;
 use crate::model::channels::Channels;
-use crate::model::groups_being_imported::{GroupBeingImportedSummary, GroupsBeingImported};
+use crate::model::channels_being_moved::ChannelsBeingMoved;
+use crate::model::chat_history_imports::ChatHistoryImports;
+use crate::model::emoji_packs::EmojiPacks;
+use crate::model::groups_being_imported::{GroupBeingImportedSummary, GroupsBeingImported, SourceChannel};
 use crate::model::members::CommunityMembers;
 use crate::timer_job_types::{RemoveExpiredEventsJob, TimerJob};
 use activity_notification_state::ActivityNotificationState;
@@ -9,20 +13,28 @@ use canister_state_macros::canister_state;
 use canister_timer_jobs::TimerJobs;
 use chat_events::ChatMetricsInternal;
 use fire_and_forget_handler::FireAndForgetHandler;
-use group_chat_core::AccessRulesInternal;
-use group_community_common::{PaymentReceipts, PaymentRecipient, PendingPayment, PendingPaymentReason, PendingPaymentsQueue};
-use instruction_counts_log::{InstructionCountEntry, InstructionCountFunctionId, InstructionCountsLog};
+use group_chat_core::{AccessRulesInternal, CompiledContentFilters};
+use group_community_common::{
+    InviteLinks, JoinRequests, PaymentReceipts, PaymentRecipient, PendingPayment, PendingPaymentReason, PendingPaymentsQueue,
+};
+use http_request::{MetricsRegistry, RegisterMetrics};
+use instruction_counts_log::{
+    upgrade_instructions_histogram, InstructionCountEntry, InstructionCountFunctionId, InstructionCountSamples,
+    InstructionCountsLog, InstructionCountsSummary, DEFAULT_REGRESSION_THRESHOLD_PERCENT,
+};
+use local_user_index_canister::c2c_notify_join_request_outcome::{self, JoinRequestOutcome};
 use model::{events::CommunityEvents, invited_users::InvitedUsers, members::CommunityMemberInternal};
 use msgpack::serialize_then_unwrap;
-use notifications_canister::c2c_push_notification;
+use notifications_canister::{c2c_push_link_preview_request, c2c_push_notification};
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use std::cell::RefCell;
 use std::ops::Deref;
 use types::{
-    AccessGate, BuildVersion, CanisterId, ChannelId, ChatMetrics, CommunityCanisterCommunitySummary, CommunityMembership,
-    CommunityPermissions, CommunityRole, Cryptocurrency, Cycles, Document, Empty, FrozenGroupInfo, Milliseconds, Notification,
-    PaymentGate, Rules, TimestampMillis, Timestamped, UserId,
+    AccessGate, BuildVersion, CanisterId, ChannelId, Chat, ChatMetrics, CommunityCanisterCommunitySummary, CommunityMembership,
+    CommunityPermissions, CommunityRole, ContentFilterReason, Cryptocurrency, Cycles, Document, Empty, FrozenGroupInfo,
+    MessageContent, MessageId, MessageIndex, Milliseconds, Notification, PaymentGate, Rules, TimestampMillis, Timestamped,
+    UserId,
 };
 use utils::env::Environment;
 use utils::regular_jobs::RegularJobs;
@@ -71,6 +83,10 @@ impl RuntimeState {
         self.env.caller() == self.data.local_group_index_canister_id
     }
 
+    pub fn is_caller_notifications_canister(&self) -> bool {
+        self.env.caller() == self.data.notifications_canister_id
+    }
+
     pub fn is_caller_proposals_bot(&self) -> bool {
         self.env.caller() == self.data.proposals_bot_user_id.into()
     }
@@ -90,6 +106,32 @@ impl RuntimeState {
         }
     }
 
+    pub fn request_link_previews(
+        &self,
+        channel_id: ChannelId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        content: &MessageContent,
+    ) {
+        if let MessageContent::Text(text) = content {
+            let urls = link_previews::extract_urls(&text.text);
+            if !urls.is_empty() {
+                let args = c2c_push_link_preview_request::Args {
+                    authorizer: Some(self.data.local_group_index_canister_id),
+                    chat: Chat::Channel(self.env.canister_id().into(), channel_id),
+                    thread_root_message_index,
+                    message_id,
+                    urls,
+                };
+                ic_cdk::spawn(request_link_previews_inner(self.data.notifications_canister_id, args));
+            }
+        }
+
+        async fn request_link_previews_inner(canister_id: CanisterId, args: c2c_push_link_preview_request::Args) {
+            let _ = notifications_canister_c2c_client::c2c_push_link_preview_request(canister_id, &args).await;
+        }
+    }
+
     pub fn queue_access_gate_payments(&mut self, gate: PaymentGate) {
         // The amount available is the gate amount less the approval fee and the transfer_from fee
         let amount_available = gate.amount - 2 * gate.fee;
@@ -236,7 +278,14 @@ impl RuntimeState {
             invited: self.data.invited_users.len() as u32,
             frozen: self.data.is_frozen(),
             groups_being_imported: self.data.groups_being_imported.summaries(),
-            instruction_counts: self.data.instruction_counts_log.iter().collect(),
+            channels_being_imported: self.data.channels_being_imported.summaries(),
+            instruction_counts: self
+                .data
+                .instruction_counts_log
+                .iter()
+                .filter(|e| e.function_id().is_upgrade())
+                .collect(),
+            instruction_counts_summary: self.data.instruction_counts_summary(DEFAULT_REGRESSION_THRESHOLD_PERCENT),
             canister_ids: CanisterIds {
                 user_index: self.data.user_index_canister_id,
                 group_index: self.data.group_index_canister_id,
@@ -278,6 +327,10 @@ struct Data {
     invited_users: InvitedUsers,
     invite_code: Option<u64>,
     invite_code_enabled: bool,
+    #[serde(default)]
+    invite_links: InviteLinks<CommunityRole>,
+    #[serde(default)]
+    join_requests: JoinRequests,
     frozen: Timestamped<Option<FrozenGroupInfo>>,
     timer_jobs: TimerJobs<TimerJob>,
     fire_and_forget_handler: FireAndForgetHandler,
@@ -285,12 +338,26 @@ struct Data {
     groups_being_imported: GroupsBeingImported,
     #[serde(skip, default = "init_instruction_counts_log")]
     instruction_counts_log: InstructionCountsLog,
+    #[serde(default)]
+    instruction_count_samples: InstructionCountSamples,
     next_event_expiry: Option<TimestampMillis>,
     test_mode: bool,
     cached_chat_metrics: Timestamped<ChatMetrics>,
     rng_seed: [u8; 32],
     pub pending_payments_queue: PendingPaymentsQueue,
     pub total_payment_receipts: PaymentReceipts,
+    #[serde(default)]
+    content_filters: Timestamped<CompiledContentFilters>,
+    #[serde(default)]
+    audit_log: AuditLog,
+    #[serde(default)]
+    emoji_packs: EmojiPacks,
+    #[serde(default)]
+    chat_history_imports: ChatHistoryImports,
+    #[serde(default)]
+    channels_being_imported: GroupsBeingImported<SourceChannel>,
+    #[serde(default)]
+    channels_being_moved: ChannelsBeingMoved,
 }
 
 impl Data {
@@ -346,18 +413,27 @@ impl Data {
             invited_users: InvitedUsers::default(),
             invite_code: None,
             invite_code_enabled: false,
+            invite_links: InviteLinks::default(),
+            join_requests: JoinRequests::default(),
             frozen: Timestamped::default(),
             timer_jobs: TimerJobs::default(),
             fire_and_forget_handler: FireAndForgetHandler::default(),
             activity_notification_state: ActivityNotificationState::new(now, mark_active_duration),
             groups_being_imported: GroupsBeingImported::default(),
             instruction_counts_log: init_instruction_counts_log(),
+            instruction_count_samples: InstructionCountSamples::default(),
             next_event_expiry: None,
             test_mode,
             cached_chat_metrics: Timestamped::default(),
             rng_seed: [0; 32],
             pending_payments_queue: PendingPaymentsQueue::default(),
             total_payment_receipts: PaymentReceipts::default(),
+            content_filters: Timestamped::default(),
+            audit_log: AuditLog::default(),
+            emoji_packs: EmojiPacks::default(),
+            chat_history_imports: ChatHistoryImports::default(),
+            channels_being_imported: GroupsBeingImported::default(),
+            channels_being_moved: ChannelsBeingMoved::default(),
         }
     }
 
@@ -365,14 +441,14 @@ impl Data {
         self.frozen.is_some()
     }
 
-    pub fn is_accessible(&self, caller: Principal, invite_code: Option<u64>) -> bool {
+    pub fn is_accessible(&self, caller: Principal, invite_code: Option<u64>, now: TimestampMillis) -> bool {
         self.is_public
             || self.members.get(caller).is_some()
             || self
                 .members
                 .lookup_user_id(caller)
                 .map_or(false, |u| self.invited_users.get(&u).is_some())
-            || self.is_invite_code_valid(invite_code)
+            || self.is_invite_code_valid(invite_code, now)
     }
 
     pub fn build_chat_metrics(&mut self, now: TimestampMillis) {
@@ -394,13 +470,23 @@ impl Data {
                 .map_or(false, |accepted| accepted.value >= self.rules.text.version))
     }
 
-    pub fn record_instructions_count(&self, function_id: InstructionCountFunctionId, now: TimestampMillis) {
+    pub fn instruction_counts_summary(&self, regression_threshold_percent: u32) -> InstructionCountsSummary {
+        self.instruction_count_samples
+            .summarize(&self.instruction_counts_log, regression_threshold_percent)
+    }
+
+    pub fn record_instructions_count(&mut self, function_id: InstructionCountFunctionId, now: TimestampMillis) {
         let wasm_version = WASM_VERSION.with_borrow(|v| **v);
         let instructions_count = ic_cdk::api::instruction_counter();
 
-        let _ = self
-            .instruction_counts_log
-            .record(function_id, instructions_count, wasm_version, now);
+        if function_id.is_upgrade() {
+            let _ = self
+                .instruction_counts_log
+                .record(function_id, instructions_count, wasm_version, now);
+        } else {
+            self.instruction_count_samples
+                .record(function_id, instructions_count, wasm_version);
+        }
     }
 
     pub fn mark_community_updated_in_user_canister(&self, user_id: UserId) {
@@ -411,6 +497,51 @@ impl Data {
         );
     }
 
+    pub fn notify_message_deleted_by_content_filter(&self, user_id: UserId, channel_name: String, reason: ContentFilterReason) {
+        let args = user_canister::c2c_notify_message_deleted_by_content_filter::Args {
+            chat_name: channel_name,
+            community_name: Some(self.name.clone()),
+            reason,
+        };
+        self.fire_and_forget_handler.send(
+            user_id.into(),
+            "c2c_notify_message_deleted_by_content_filter_msgpack".to_string(),
+            serialize_then_unwrap(args),
+        );
+    }
+
+    pub fn notify_join_request_outcome(
+        &self,
+        user_id: UserId,
+        local_user_index_canister_id: CanisterId,
+        outcome: JoinRequestOutcome,
+    ) {
+        let args = c2c_notify_join_request_outcome::Args {
+            user_id,
+            name: self.name.clone(),
+            outcome,
+        };
+        self.fire_and_forget_handler.send(
+            local_user_index_canister_id,
+            "c2c_notify_join_request_outcome_msgpack".to_string(),
+            serialize_then_unwrap(args),
+        );
+    }
+
+    pub fn expire_join_requests(&mut self, now: TimestampMillis) {
+        for (user_id, request) in self.join_requests.remove_expired(now) {
+            self.notify_join_request_outcome(user_id, request.local_user_index_canister_id, JoinRequestOutcome::Expired);
+        }
+    }
+
+    // Puts back any channels whose moves have stalled, the destination's import is then abandoned
+    // the next time it requests a page
+    pub fn restore_expired_channel_moves(&mut self, now: TimestampMillis) {
+        for moved in self.channels_being_moved.take_expired(now) {
+            self.channels.add(moved.channel);
+        }
+    }
+
     pub fn details_last_updated(&self) -> TimestampMillis {
         [
             self.invited_users.last_updated(),
@@ -431,13 +562,12 @@ impl Data {
             .unwrap_or_default()
     }
 
-    fn is_invite_code_valid(&self, invite_code: Option<u64>) -> bool {
-        if self.invite_code_enabled {
-            if let Some(provided_code) = invite_code {
-                if let Some(stored_code) = self.invite_code {
-                    return provided_code == stored_code;
-                }
+    fn is_invite_code_valid(&self, invite_code: Option<u64>, now: TimestampMillis) -> bool {
+        if let Some(provided_code) = invite_code {
+            if self.invite_code_enabled && self.invite_code == Some(provided_code) {
+                return true;
             }
+            return self.invite_links.get_valid(provided_code, now).is_some();
         }
 
         false
@@ -464,10 +594,59 @@ pub struct Metrics {
     pub invited: u32,
     pub frozen: bool,
     pub groups_being_imported: Vec<GroupBeingImportedSummary>,
+    pub channels_being_imported: Vec<GroupBeingImportedSummary>,
     pub instruction_counts: Vec<InstructionCountEntry>,
+    pub instruction_counts_summary: InstructionCountsSummary,
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const MEMBERS: &str = "community_members";
+        const MEMBERS_HELP: &str = "Members of the community, by role";
+
+        let upgrade_instructions = upgrade_instructions_histogram(&self.instruction_counts);
+
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge("community_public", "Whether the community is public", u8::from(self.public))
+            .gauge("community_frozen", "Whether the community is frozen", u8::from(self.frozen))
+            .gauge(
+                "community_date_created_ms",
+                "When the community was created",
+                self.date_created,
+            )
+            .gauge_with_labels(MEMBERS, MEMBERS_HELP, &[("role", "all")], self.members)
+            .gauge_with_labels(MEMBERS, MEMBERS_HELP, &[("role", "admin")], self.admins)
+            .gauge_with_labels(MEMBERS, MEMBERS_HELP, &[("role", "owner")], self.owners)
+            .gauge("community_blocked_users", "Users blocked from the community", self.blocked)
+            .gauge("community_invited_users", "Users invited to the community", self.invited)
+            .gauge(
+                "community_groups_being_imported",
+                "Groups currently being imported into the community",
+                self.groups_being_imported.len(),
+            )
+            .gauge(
+                "community_channels_being_imported",
+                "Channels currently being moved into the community",
+                self.channels_being_imported.len(),
+            )
+            .histogram(
+                "community_upgrade_instructions",
+                "Instructions used by upgrades",
+                &upgrade_instructions,
+            );
+
+        self.instruction_counts_summary.register_metrics(registry);
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CanisterIds {
     pub user_index: CanisterId,
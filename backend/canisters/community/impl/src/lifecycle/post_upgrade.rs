@@ -1,7 +1,8 @@
+use crate::jobs::import_channels::finalize_channel_import;
 use crate::jobs::import_groups::finalize_group_import;
 use crate::lifecycle::{init_env, init_state};
 use crate::memory::get_upgrades_memory;
-use crate::{read_state, Data};
+use crate::{mutate_state, read_state, Data};
 use canister_logger::LogEntry;
 use canister_tracing_macros::trace;
 use community_canister::post_upgrade::Args;
@@ -29,9 +30,15 @@ fn post_upgrade(args: Args) {
         finalize_group_import(group_id);
     }
 
+    let completed_channel_imports = read_state(|state| state.data.channels_being_imported.completed_imports());
+
+    for source in completed_channel_imports {
+        finalize_channel_import(source);
+    }
+
     info!(version = %args.wasm_version, "Post-upgrade complete");
 
-    read_state(|state| {
+    mutate_state(|state| {
         let now = state.env.now();
         state
             .data
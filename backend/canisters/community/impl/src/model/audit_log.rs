@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use types::{AuditLogActionType, AuditLogEntry, AuditLogFilter, ChannelId, TimestampMillis, UserId};
+
+// Once the log is full the oldest entries are dropped to make room for new ones
+const MAX_ENTRIES: usize = 50_000;
+
+// Append-only log of administrative actions taken within the community or any of its channels
+#[derive(Serialize, Deserialize, Default)]
+pub struct AuditLog {
+    entries: VecDeque<AuditLogEntry>,
+}
+
+impl AuditLog {
+    #[allow(clippy::too_many_arguments)]
+    pub fn push(
+        &mut self,
+        actor: UserId,
+        action: AuditLogActionType,
+        channel_id: Option<ChannelId>,
+        targets: Vec<UserId>,
+        before: Option<String>,
+        after: Option<String>,
+        now: TimestampMillis,
+    ) {
+        if self.entries.len() >= MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(AuditLogEntry {
+            index: self.next_index(),
+            timestamp: now,
+            actor,
+            action,
+            channel_id,
+            targets,
+            before,
+            after,
+        });
+    }
+
+    // Returns the matching entries, newest first
+    pub fn search<'a>(
+        &'a self,
+        filter: &'a AuditLogFilter,
+        before_index: Option<u32>,
+    ) -> impl Iterator<Item = &'a AuditLogEntry> + 'a {
+        let end = before_index.map_or(self.entries.len(), |i| self.entries.partition_point(|e| e.index < i));
+
+        self.entries.range(..end).rev().filter(move |e| filter.matches(e))
+    }
+
+    // Entries keep their original indexes after older entries are dropped, so this is the total number of entries
+    // ever written rather than the number currently held
+    pub fn count(&self) -> u32 {
+        self.next_index()
+    }
+
+    fn next_index(&self) -> u32 {
+        self.entries.back().map_or(0, |e| e.index + 1)
+    }
+}
+
+pub fn to_json<T: Serialize + ?Sized>(value: &T) -> Option<String> {
+    serde_json::to_string(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn search_applies_filter_and_pagination() {
+        let mut log = AuditLog::default();
+        log.push(user(1), AuditLogActionType::RoleChanged, None, vec![user(2)], None, None, 1);
+        log.push(user(2), AuditLogActionType::MessagesDeleted, Some(1), vec![], None, None, 2);
+        log.push(user(1), AuditLogActionType::MessagesDeleted, Some(1), vec![], None, None, 3);
+        log.push(user(1), AuditLogActionType::UsersBlocked, None, vec![user(3)], None, None, 4);
+
+        let filter = AuditLogFilter {
+            actor: Some(user(1)),
+            ..Default::default()
+        };
+        let indexes: Vec<_> = log.search(&filter, None).map(|e| e.index).collect();
+        assert_eq!(indexes, vec![3, 2, 0]);
+
+        let indexes: Vec<_> = log.search(&filter, Some(3)).map(|e| e.index).collect();
+        assert_eq!(indexes, vec![2, 0]);
+
+        let filter = AuditLogFilter {
+            action_types: vec![AuditLogActionType::MessagesDeleted],
+            from: Some(3),
+            ..Default::default()
+        };
+        let indexes: Vec<_> = log.search(&filter, None).map(|e| e.index).collect();
+        assert_eq!(indexes, vec![2]);
+    }
+
+    #[test]
+    fn oldest_entries_are_dropped_once_full() {
+        let mut log = AuditLog::default();
+        for i in 0..(MAX_ENTRIES + 10) {
+            log.push(user(1), AuditLogActionType::RoleChanged, None, vec![], None, None, i as u64);
+        }
+
+        assert_eq!(log.entries.len(), MAX_ENTRIES);
+        assert_eq!(log.count(), (MAX_ENTRIES + 10) as u32);
+
+        let filter = AuditLogFilter::default();
+        let indexes: Vec<_> = log.search(&filter, Some(15)).map(|e| e.index).collect();
+        assert_eq!(indexes, (10..15).rev().collect::<Vec<_>>());
+    }
+
+    fn user(index: u64) -> UserId {
+        Principal::from_slice(&index.to_be_bytes()).into()
+    }
+}
@@ -1,5 +1,6 @@
 use chat_events::Reader;
 use group_chat_core::{CanLeaveResult, GroupChatCore, GroupMemberInternal, LeaveResult};
+use group_community_common::InviteLinks;
 use search::*;
 use serde::{Deserialize, Serialize};
 use std::cmp::{max, Reverse};
@@ -7,7 +8,7 @@ use std::collections::hash_map::Entry::Vacant;
 use std::collections::HashMap;
 use types::{
     ChannelId, ChannelMatch, CommunityCanisterChannelSummary, CommunityCanisterChannelSummaryUpdates, GroupMembership,
-    GroupMembershipUpdates, GroupPermissionRole, GroupPermissions, Rules, TimestampMillis, Timestamped, UserId,
+    GroupMembershipUpdates, GroupPermissionRole, GroupPermissions, GroupRole, Rules, TimestampMillis, Timestamped, UserId,
     MAX_THREADS_IN_SUMMARY,
 };
 
@@ -23,6 +24,8 @@ pub struct Channel {
     pub id: ChannelId,
     pub chat: GroupChatCore,
     pub date_imported: Option<TimestampMillis>,
+    #[serde(default)]
+    pub invite_links: InviteLinks<GroupRole>,
 }
 
 impl Channels {
@@ -190,6 +193,7 @@ impl Channel {
                 now,
             ),
             date_imported: None,
+            invite_links: InviteLinks::default(),
         }
     }
 
@@ -269,6 +273,7 @@ impl Channel {
             events_ttl: events_ttl.value,
             events_ttl_last_updated: events_ttl.timestamp,
             gate: chat.gate.value.clone(),
+            slow_mode: chat.slow_mode.value.clone(),
             membership,
         })
     }
@@ -350,6 +355,7 @@ impl Channel {
             events_ttl: updates.events_ttl,
             events_ttl_last_updated: updates.events_ttl_last_updated,
             gate: updates.gate,
+            slow_mode: updates.slow_mode,
             membership,
         })
     }
@@ -0,0 +1,101 @@
+use crate::model::channels::Channel;
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use std::cmp::min;
+use std::collections::HashMap;
+use types::{CanisterId, ChannelId, Milliseconds, TimestampMillis, UserId};
+use utils::time::DAY_IN_MS;
+
+const MAX_MOVE_DURATION: Milliseconds = DAY_IN_MS;
+
+// Channels which are being moved into another community or detached into a standalone group. Each
+// channel is removed from the community's set of channels for the duration of the move so that no
+// further changes can be made to it, and its serialized form is served in pages to the destination.
+#[derive(Serialize, Deserialize, Default)]
+pub struct ChannelsBeingMoved {
+    channels: HashMap<ChannelId, ChannelBeingMoved>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ChannelBeingMoved {
+    pub channel: Channel,
+    pub moved_by: UserId,
+    pub started: TimestampMillis,
+    // This is `None` until the group which a channel is being detached into has been created
+    pub destination: Option<CanisterId>,
+    #[serde(with = "serde_bytes")]
+    bytes: Vec<u8>,
+}
+
+pub enum PageResult {
+    Success(ByteBuf),
+    DestinationNotSet,
+    NotFound,
+}
+
+impl ChannelsBeingMoved {
+    pub fn add(
+        &mut self,
+        channel: Channel,
+        moved_by: UserId,
+        bytes: Vec<u8>,
+        destination: Option<CanisterId>,
+        now: TimestampMillis,
+    ) {
+        self.channels.insert(
+            channel.id,
+            ChannelBeingMoved {
+                channel,
+                moved_by,
+                started: now,
+                destination,
+                bytes,
+            },
+        );
+    }
+
+    pub fn contains(&self, channel_id: &ChannelId) -> bool {
+        self.channels.contains_key(channel_id)
+    }
+
+    pub fn set_destination(&mut self, channel_id: &ChannelId, destination: CanisterId) {
+        if let Some(channel) = self.channels.get_mut(channel_id) {
+            channel.destination = Some(destination);
+        }
+    }
+
+    pub fn destination(&self, channel_id: &ChannelId) -> Option<CanisterId> {
+        self.channels.get(channel_id).and_then(|c| c.destination)
+    }
+
+    // Only the destination canister is allowed to read the serialized channel
+    pub fn page(&self, channel_id: &ChannelId, caller: CanisterId, from: u64, page_size: u32) -> PageResult {
+        match self.channels.get(channel_id) {
+            Some(channel) if channel.destination == Some(caller) => {
+                let from = min(from as usize, channel.bytes.len());
+                let to = min(from + page_size as usize, channel.bytes.len());
+
+                PageResult::Success(ByteBuf::from(channel.bytes[from..to].to_vec()))
+            }
+            Some(channel) if channel.destination.is_none() => PageResult::DestinationNotSet,
+            _ => PageResult::NotFound,
+        }
+    }
+
+    // Moves which haven't completed within `MAX_MOVE_DURATION` are abandoned so that their
+    // channels can be put back
+    pub fn take_expired(&mut self, now: TimestampMillis) -> Vec<ChannelBeingMoved> {
+        let expired: Vec<_> = self
+            .channels
+            .iter()
+            .filter(|(_, c)| now.saturating_sub(c.started) > MAX_MOVE_DURATION)
+            .map(|(id, _)| *id)
+            .collect();
+
+        expired.iter().filter_map(|id| self.channels.remove(id)).collect()
+    }
+
+    pub fn take(&mut self, channel_id: &ChannelId) -> Option<ChannelBeingMoved> {
+        self.channels.remove(channel_id)
+    }
+}
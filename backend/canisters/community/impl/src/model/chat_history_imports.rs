@@ -0,0 +1,130 @@
+use chat_import::ExportReader;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use types::{ChannelId, ChatImportMediaFile, ChatImportSource, EventIndex, Milliseconds, TimestampMillis, UserId};
+use utils::time::DAY_IN_MS;
+
+// Uploads which haven't been completed within this time are abandoned
+const MAX_UPLOAD_AGE: Milliseconds = DAY_IN_MS;
+
+// Chat history imports are in one of two phases, first the export is uploaded in chunks, then once
+// the channel has been created, the messages are parsed and loaded into the channel in batches
+#[derive(Serialize, Deserialize, Default)]
+pub struct ChatHistoryImports {
+    uploads: HashMap<ChannelId, ChatHistoryUpload>,
+    in_progress: HashMap<ChannelId, ChatHistoryImport>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ChatHistoryUpload {
+    pub started_by: UserId,
+    pub started: TimestampMillis,
+    pub source: ChatImportSource,
+    pub channel_name: String,
+    pub channel_description: String,
+    pub is_public: bool,
+    pub author_mappings: HashMap<String, UserId>,
+    pub media: HashMap<String, ChatImportMediaFile>,
+    pub total_bytes: u64,
+    #[serde(with = "serde_bytes")]
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ChatHistoryImport {
+    pub source: ChatImportSource,
+    pub author_mappings: HashMap<String, UserId>,
+    pub media: HashMap<String, ChatImportMediaFile>,
+    #[serde(with = "serde_bytes")]
+    pub bytes: Vec<u8>,
+    pub reader: ExportReader,
+    // Maps the ids of the messages already imported to their event indexes, so that replies can be resolved
+    pub imported: HashMap<String, EventIndex>,
+}
+
+pub enum AppendChunkResult {
+    Success,
+    Complete,
+    NotFound,
+    NotAuthorized,
+    InvalidOffset(u64),
+    TooManyBytes,
+}
+
+impl ChatHistoryImports {
+    pub fn start_upload(&mut self, channel_id: ChannelId, upload: ChatHistoryUpload) {
+        self.uploads.insert(channel_id, upload);
+    }
+
+    pub fn append_chunk(&mut self, channel_id: ChannelId, user_id: UserId, offset: u64, bytes: &[u8]) -> AppendChunkResult {
+        let upload = match self.uploads.get_mut(&channel_id) {
+            Some(u) if u.started_by == user_id => u,
+            Some(_) => return AppendChunkResult::NotAuthorized,
+            None => return AppendChunkResult::NotFound,
+        };
+
+        let received = upload.bytes.len() as u64;
+        if offset != received {
+            AppendChunkResult::InvalidOffset(received)
+        } else if received + bytes.len() as u64 > upload.total_bytes {
+            AppendChunkResult::TooManyBytes
+        } else {
+            // Once complete, the upload is kept until the import has started, so an empty chunk at
+            // the final offset can be used to retry starting the import
+            upload.bytes.extend_from_slice(bytes);
+            if upload.bytes.len() as u64 == upload.total_bytes {
+                AppendChunkResult::Complete
+            } else {
+                AppendChunkResult::Success
+            }
+        }
+    }
+
+    pub fn upload(&self, channel_id: &ChannelId) -> Option<&ChatHistoryUpload> {
+        self.uploads.get(channel_id)
+    }
+
+    // Each upload holds its export in memory until its import completes, so only one is allowed at a time
+    pub fn is_upload_or_import_in_progress(&self) -> bool {
+        !self.uploads.is_empty() || !self.in_progress.is_empty()
+    }
+
+    pub fn remove_expired_uploads(&mut self, now: TimestampMillis) {
+        self.uploads.retain(|_, u| now.saturating_sub(u.started) < MAX_UPLOAD_AGE);
+    }
+
+    pub fn start_import(&mut self, channel_id: ChannelId, reader: ExportReader) {
+        if let Some(upload) = self.uploads.remove(&channel_id) {
+            self.in_progress.insert(
+                channel_id,
+                ChatHistoryImport {
+                    source: upload.source,
+                    author_mappings: upload.author_mappings,
+                    media: upload.media,
+                    bytes: upload.bytes,
+                    reader,
+                    imported: HashMap::new(),
+                },
+            );
+        }
+    }
+
+    // Channel names are reserved while their exports are being uploaded
+    pub fn is_name_reserved(&self, name: &str) -> bool {
+        let lowercase_name = name.to_lowercase();
+
+        self.uploads.values().any(|u| u.channel_name.to_lowercase() == lowercase_name)
+    }
+
+    pub fn next_import(&mut self) -> Option<(ChannelId, &mut ChatHistoryImport)> {
+        self.in_progress.iter_mut().next().map(|(c, i)| (*c, i))
+    }
+
+    pub fn remove_import(&mut self, channel_id: &ChannelId) -> Option<ChatHistoryImport> {
+        self.in_progress.remove(channel_id)
+    }
+
+    pub fn has_imports_in_progress(&self) -> bool {
+        !self.in_progress.is_empty()
+    }
+}
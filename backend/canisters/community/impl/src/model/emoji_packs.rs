@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use types::{
+    BlobReference, EmojiPack, EmojiPackAsset, EmojiPackId, EmojiPackKind, TimestampMillis, UserId, MAX_EMOJI_SHORTCODE_LENGTH,
+};
+
+const MAX_PACKS: usize = 50;
+const MAX_ASSETS_PER_PACK: usize = 200;
+const MAX_NAME_LENGTH: usize = 40;
+const MIN_SHORTCODE_LENGTH: usize = 2;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct EmojiPacks {
+    packs: BTreeMap<EmojiPackId, EmojiPack>,
+    next_pack_id: EmojiPackId,
+    last_updated: TimestampMillis,
+}
+
+#[derive(Debug)]
+pub enum EmojiPackError {
+    NameInvalid,
+    InvalidShortcode(String),
+    DuplicateShortcode(String),
+    TooManyAssets(u32),
+    TooManyPacks(u32),
+    PackNotFound,
+}
+
+impl EmojiPacks {
+    pub fn create(
+        &mut self,
+        name: String,
+        kind: EmojiPackKind,
+        assets: Vec<EmojiPackAsset>,
+        created_by: UserId,
+        now: TimestampMillis,
+    ) -> Result<EmojiPackId, EmojiPackError> {
+        if self.packs.len() >= MAX_PACKS {
+            return Err(EmojiPackError::TooManyPacks(MAX_PACKS as u32));
+        }
+        validate_name(&name)?;
+        validate_assets(&assets)?;
+
+        let pack_id = self.next_pack_id;
+        self.next_pack_id += 1;
+
+        self.packs.insert(
+            pack_id,
+            EmojiPack {
+                pack_id,
+                name,
+                kind,
+                assets,
+                created_by,
+                last_updated: now,
+            },
+        );
+        self.last_updated = now;
+        Ok(pack_id)
+    }
+
+    // Returns the blobs of any assets which were removed so that they can be deleted
+    pub fn update(
+        &mut self,
+        pack_id: EmojiPackId,
+        name: Option<String>,
+        assets_to_add: Vec<EmojiPackAsset>,
+        shortcodes_to_remove: Vec<String>,
+        now: TimestampMillis,
+    ) -> Result<Vec<BlobReference>, EmojiPackError> {
+        let pack = self.packs.get_mut(&pack_id).ok_or(EmojiPackError::PackNotFound)?;
+
+        if let Some(name) = name.as_ref() {
+            validate_name(name)?;
+        }
+
+        let mut assets: Vec<_> = pack
+            .assets
+            .iter()
+            .filter(|a| !shortcodes_to_remove.contains(&a.shortcode))
+            .cloned()
+            .collect();
+        assets.extend(assets_to_add);
+        validate_assets(&assets)?;
+
+        let removed = pack
+            .assets
+            .iter()
+            .filter(|a| shortcodes_to_remove.contains(&a.shortcode))
+            .map(|a| a.blob_reference.clone())
+            .collect();
+
+        if let Some(name) = name {
+            pack.name = name;
+        }
+        pack.assets = assets;
+        pack.last_updated = now;
+        self.last_updated = now;
+        Ok(removed)
+    }
+
+    pub fn delete(&mut self, pack_id: EmojiPackId, now: TimestampMillis) -> Option<EmojiPack> {
+        let pack = self.packs.remove(&pack_id)?;
+        self.last_updated = now;
+        Some(pack)
+    }
+
+    pub fn get(&self, pack_id: EmojiPackId) -> Option<&EmojiPack> {
+        self.packs.get(&pack_id)
+    }
+
+    pub fn contains(&self, pack_id: EmojiPackId, kind: EmojiPackKind, shortcode: &str) -> bool {
+        self.get_asset(pack_id, kind, shortcode).is_some()
+    }
+
+    pub fn get_asset(&self, pack_id: EmojiPackId, kind: EmojiPackKind, shortcode: &str) -> Option<&EmojiPackAsset> {
+        self.packs
+            .get(&pack_id)
+            .filter(|p| p.kind == kind)
+            .and_then(|p| p.assets.iter().find(|a| a.shortcode == shortcode))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &EmojiPack> {
+        self.packs.values()
+    }
+
+    pub fn last_updated(&self) -> TimestampMillis {
+        self.last_updated
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), EmojiPackError> {
+    let length = name.trim().chars().count();
+    if length == 0 || length > MAX_NAME_LENGTH {
+        Err(EmojiPackError::NameInvalid)
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_assets(assets: &[EmojiPackAsset]) -> Result<(), EmojiPackError> {
+    if assets.len() > MAX_ASSETS_PER_PACK {
+        return Err(EmojiPackError::TooManyAssets(MAX_ASSETS_PER_PACK as u32));
+    }
+
+    let mut shortcodes = HashSet::new();
+    for asset in assets {
+        if !is_valid_shortcode(&asset.shortcode) {
+            return Err(EmojiPackError::InvalidShortcode(asset.shortcode.clone()));
+        }
+        if !shortcodes.insert(asset.shortcode.as_str()) {
+            return Err(EmojiPackError::DuplicateShortcode(asset.shortcode.clone()));
+        }
+    }
+    Ok(())
+}
+
+fn is_valid_shortcode(shortcode: &str) -> bool {
+    (MIN_SHORTCODE_LENGTH..=MAX_EMOJI_SHORTCODE_LENGTH).contains(&shortcode.len())
+        && shortcode
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn update_replaces_assets_and_returns_removed_blobs() {
+        let mut packs = EmojiPacks::default();
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+
+        let pack_id = packs
+            .create(
+                "Party".to_string(),
+                EmojiPackKind::Emoji,
+                vec![asset("tada", 1), asset("cake", 2)],
+                user_id,
+                1,
+            )
+            .unwrap();
+
+        assert!(matches!(
+            packs.update(pack_id, None, vec![asset("cake", 3)], Vec::new(), 2),
+            Err(EmojiPackError::DuplicateShortcode(_))
+        ));
+
+        let removed = packs
+            .update(pack_id, None, vec![asset("balloon", 3)], vec!["cake".to_string()], 3)
+            .unwrap();
+
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].blob_id, 2);
+        assert!(packs.contains(pack_id, EmojiPackKind::Emoji, "balloon"));
+        assert!(!packs.contains(pack_id, EmojiPackKind::Emoji, "cake"));
+        assert!(!packs.contains(pack_id, EmojiPackKind::Sticker, "tada"));
+    }
+
+    fn asset(shortcode: &str, blob_id: u128) -> EmojiPackAsset {
+        EmojiPackAsset {
+            shortcode: shortcode.to_string(),
+            blob_reference: BlobReference {
+                canister_id: Principal::anonymous(),
+                blob_id,
+            },
+        }
+    }
+}
@@ -1,23 +1,40 @@
 use serde::{Deserialize, Serialize};
+use std::cmp::min;
 use std::collections::hash_map::Entry::Vacant;
 use std::collections::HashMap;
-use types::{ChannelId, ChatId, TimestampMillis, Timestamped, UserId};
+use std::hash::Hash;
+use types::{ChannelId, ChatId, CommunityId, Milliseconds, TimestampMillis, Timestamped, UserId};
+use utils::time::{MINUTE_IN_MS, SECOND_IN_MS};
 
-#[derive(Serialize, Deserialize, Default)]
-pub struct GroupsBeingImported {
-    groups: HashMap<ChatId, GroupBeingImported>,
+// Failed batches are retried after a delay which doubles after each consecutive failure
+const MAX_RETRY_DELAY: Milliseconds = 5 * MINUTE_IN_MS;
+
+// Channels being moved in from other communities are keyed by their original community and channel
+pub type SourceChannel = (CommunityId, ChannelId);
+
+// Tracks the serialized chats being pulled in pages from groups (keyed by `ChatId`) or from channels
+// of other communities (keyed by `SourceChannel`)
+#[derive(Serialize, Deserialize)]
+pub struct GroupsBeingImported<K: Eq + Hash = ChatId> {
+    groups: HashMap<K, GroupBeingImported>,
+}
+
+impl<K: Eq + Hash> Default for GroupsBeingImported<K> {
+    fn default() -> Self {
+        GroupsBeingImported { groups: HashMap::new() }
+    }
 }
 
-pub enum NextBatchResult {
-    Success(Vec<(ChatId, u64)>),
+pub enum NextBatchResult<K = ChatId> {
+    Success(Vec<(K, u64)>),
     Continue,
     Exit,
 }
 
-impl GroupsBeingImported {
+impl<K: Eq + Hash + Copy> GroupsBeingImported<K> {
     pub fn add(
         &mut self,
-        group_id: ChatId,
+        group_id: K,
         channel_id: ChannelId,
         imported_by: UserId,
         total_bytes: u64,
@@ -33,17 +50,17 @@ impl GroupsBeingImported {
         }
     }
 
-    pub fn contains(&self, group_id: &ChatId) -> bool {
+    pub fn contains(&self, group_id: &K) -> bool {
         self.groups.contains_key(group_id)
     }
 
-    pub fn next_batch(&mut self, now: TimestampMillis) -> NextBatchResult {
+    pub fn next_batch(&mut self, now: TimestampMillis) -> NextBatchResult<K> {
         if self.groups.is_empty() {
             NextBatchResult::Exit
         } else {
             let mut batch = Vec::new();
             for (chat_id, group) in self.groups.iter_mut().filter(|(_, g)| !g.is_complete()) {
-                if group.current_batch_started.is_none() {
+                if group.current_batch_started.is_none() && group.retry_after.map_or(true, |ts| now >= ts) {
                     group.current_batch_started = Some(now);
                     batch.push((*chat_id, group.bytes.len() as u64));
                 }
@@ -57,10 +74,12 @@ impl GroupsBeingImported {
     }
 
     // Returns true if the group bytes have all been imported, else false
-    pub fn mark_batch_complete(&mut self, group_id: &ChatId, bytes: &[u8]) -> bool {
+    pub fn mark_batch_complete(&mut self, group_id: &K, bytes: &[u8]) -> bool {
         if let Some(group) = self.groups.get_mut(group_id) {
             group.current_batch_started = None;
             group.error_message = None;
+            group.failed_batches = 0;
+            group.retry_after = None;
             group.bytes.extend_from_slice(bytes);
             group.is_complete()
         } else {
@@ -68,14 +87,17 @@ impl GroupsBeingImported {
         }
     }
 
-    pub fn mark_batch_failed(&mut self, group_id: &ChatId, error_message: String) {
+    pub fn mark_batch_failed(&mut self, group_id: &K, error_message: String, now: TimestampMillis) {
         if let Some(group) = self.groups.get_mut(group_id) {
+            let delay = min(SECOND_IN_MS << min(group.failed_batches, 16), MAX_RETRY_DELAY);
             group.current_batch_started = None;
             group.error_message = Some(error_message);
+            group.failed_batches += 1;
+            group.retry_after = Some(now + delay);
         }
     }
 
-    pub fn take(&mut self, group_id: &ChatId) -> Option<GroupBeingImported> {
+    pub fn take(&mut self, group_id: &K) -> Option<GroupBeingImported> {
         self.groups.remove(group_id)
     }
 
@@ -87,7 +109,7 @@ impl GroupsBeingImported {
         self.groups.values().map(|g| g.into()).collect()
     }
 
-    pub fn completed_imports(&self) -> Vec<ChatId> {
+    pub fn completed_imports(&self) -> Vec<K> {
         self.groups.iter().filter(|(_, g)| g.is_complete()).map(|(g, _)| *g).collect()
     }
 }
@@ -102,6 +124,10 @@ pub struct GroupBeingImported {
     #[serde(with = "serde_bytes")]
     bytes: Vec<u8>,
     error_message: Option<String>,
+    #[serde(default)]
+    failed_batches: u32,
+    #[serde(default)]
+    retry_after: Option<TimestampMillis>,
     is_default: Timestamped<bool>,
 }
 
@@ -121,6 +147,8 @@ impl GroupBeingImported {
             total_bytes,
             bytes: Vec::with_capacity(total_bytes as usize),
             error_message: None,
+            failed_batches: 0,
+            retry_after: None,
             is_default: Timestamped::new(is_default, now),
         }
     }
@@ -129,6 +157,10 @@ impl GroupBeingImported {
         self.channel_id
     }
 
+    pub fn imported_by(&self) -> UserId {
+        self.imported_by
+    }
+
     pub fn bytes(&self) -> &[u8] {
         &self.bytes
     }
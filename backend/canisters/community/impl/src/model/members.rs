@@ -294,6 +294,10 @@ impl CommunityMembers {
             .map(|(p, _)| *p)
     }
 
+    pub fn iter_principals(&self) -> impl Iterator<Item = (&Principal, &UserId)> {
+        self.principal_to_user_id_map.iter()
+    }
+
     pub fn get_mut(&mut self, user_id_or_principal: Principal) -> Option<&mut CommunityMemberInternal> {
         let user_id = user_id_or_principal.into();
 
@@ -1,4 +1,8 @@
+pub mod audit_log;
 pub mod channels;
+pub mod channels_being_moved;
+pub mod chat_history_imports;
+pub mod emoji_packs;
 pub mod events;
 pub mod groups_being_imported;
 pub mod invited_users;
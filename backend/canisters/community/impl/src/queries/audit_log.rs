@@ -0,0 +1,34 @@
+use crate::{read_state, RuntimeState};
+use community_canister::audit_log::{Response::*, *};
+use ic_cdk_macros::query;
+
+const MAX_RESULTS: u32 = 500;
+
+#[query]
+fn audit_log(args: Args) -> Response {
+    read_state(|state| audit_log_impl(args, state))
+}
+
+fn audit_log_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if !member.role.is_owner() && !member.role.is_admin() {
+            return NotAuthorized;
+        }
+
+        let entries = state
+            .data
+            .audit_log
+            .search(&args.filter, args.before_index)
+            .take(args.max_results.min(MAX_RESULTS) as usize)
+            .cloned()
+            .collect();
+
+        Success(SuccessResult {
+            entries,
+            total_entries: state.data.audit_log.count(),
+        })
+    } else {
+        UserNotInCommunity
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{read_state, RuntimeState};
+use community_canister::channel_invite_links::{Response::*, *};
+use ic_cdk_macros::query;
+
+#[query]
+fn channel_invite_links(args: Args) -> Response {
+    read_state(|state| channel_invite_links_impl(args, state))
+}
+
+fn channel_invite_links_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if let Some(channel) = state.data.channels.get(&args.channel_id) {
+            match channel.chat.members.get(&member.user_id) {
+                Some(m) if m.role.can_invite_users(&channel.chat.permissions) => Success(SuccessResult {
+                    links: channel.invite_links.list(),
+                }),
+                Some(_) => NotAuthorized,
+                None => UserNotInChannel,
+            }
+        } else {
+            ChannelNotFound
+        }
+    } else {
+        UserNotInCommunity
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{read_state, RuntimeState};
+use community_canister::content_filters::{Response::*, *};
+use group_chat_core::ContentFiltersResult;
+use ic_cdk_macros::query;
+
+#[query]
+fn content_filters(args: Args) -> Response {
+    read_state(|state| content_filters_impl(args, state))
+}
+
+fn content_filters_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if let Some(channel_id) = args.channel_id {
+            if let Some(channel) = state.data.channels.get(&channel_id) {
+                match channel.chat.content_filters(member.user_id) {
+                    ContentFiltersResult::Success(filters) => Success(filters),
+                    ContentFiltersResult::UserNotInGroup => UserNotInChannel,
+                    ContentFiltersResult::NotAuthorized => NotAuthorized,
+                }
+            } else {
+                ChannelNotFound
+            }
+        } else if member.role.can_update_details(&state.data.permissions) {
+            Success(state.data.content_filters.value.filters().clone())
+        } else {
+            NotAuthorized
+        }
+    } else {
+        UserNotInCommunity
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{read_state, RuntimeState};
+use community_canister::emoji_packs::{Response::*, *};
+use ic_cdk_macros::query;
+
+#[query]
+fn emoji_packs(_: Args) -> Response {
+    read_state(emoji_packs_impl)
+}
+
+fn emoji_packs_impl(state: &RuntimeState) -> Response {
+    if !state.data.is_accessible(state.env.caller(), None, state.env.now()) {
+        return PrivateCommunity;
+    }
+
+    Success(SuccessResult {
+        packs: state.data.emoji_packs.iter().cloned().collect(),
+        last_updated: state.data.emoji_packs.last_updated(),
+    })
+}
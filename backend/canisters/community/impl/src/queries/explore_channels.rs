@@ -24,7 +24,7 @@ fn explore_channels_impl(args: Args, state: &RuntimeState) -> Response {
 
     let caller = state.env.caller();
 
-    if !state.data.is_accessible(caller, args.invite_code) {
+    if !state.data.is_accessible(caller, args.invite_code, state.env.now()) {
         return PrivateCommunity;
     }
 
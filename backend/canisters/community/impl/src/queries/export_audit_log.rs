@@ -0,0 +1,43 @@
+use crate::{read_state, RuntimeState};
+use community_canister::export_audit_log::{Response::*, *};
+use ic_cdk_macros::query;
+
+// Keeps the response comfortably within the message size limit
+const MAX_JSON_BYTES: usize = 1_500_000;
+
+#[query]
+fn export_audit_log(args: Args) -> Response {
+    read_state(|state| export_audit_log_impl(args, state))
+}
+
+fn export_audit_log_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if !member.role.is_owner() && !member.role.is_admin() {
+            return NotAuthorized;
+        }
+
+        let mut entries = Vec::new();
+        let mut total_bytes = 0;
+        let mut next_before_index = None;
+        for entry in state.data.audit_log.search(&args.filter, args.before_index) {
+            let json = serde_json::to_string(entry).unwrap();
+            // Always include at least one entry so that the export can make progress
+            if !entries.is_empty() && total_bytes + json.len() + 1 > MAX_JSON_BYTES {
+                next_before_index = Some(entry.index + 1);
+                break;
+            }
+            total_bytes += json.len() + 1;
+            entries.push(json);
+        }
+        // Exported in chronological order
+        entries.reverse();
+
+        Success(SuccessResult {
+            json: format!("[{}]", entries.join(",")),
+            next_before_index,
+        })
+    } else {
+        UserNotInCommunity
+    }
+}
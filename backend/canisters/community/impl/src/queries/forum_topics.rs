@@ -0,0 +1,43 @@
+use crate::{read_state, RuntimeState};
+use community_canister::forum_topics::{Response::*, *};
+use group_chat_core::ForumTopicsResult;
+use ic_cdk_macros::query;
+
+const MAX_PAGE_SIZE: u8 = 50;
+
+#[query]
+fn forum_topics(args: Args) -> Response {
+    read_state(|state| forum_topics_impl(args, state))
+}
+
+fn forum_topics_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let user_id = state.data.members.get(caller).map(|m| m.user_id);
+
+    if user_id.is_none() && (!state.data.is_public || state.data.has_payment_gate()) {
+        return UserNotInCommunity;
+    }
+
+    if let Some(channel) = state.data.channels.get(&args.channel_id) {
+        let now = state.env.now();
+
+        match channel.chat.forum_topics(
+            user_id,
+            &args.tags,
+            args.solved,
+            args.sort,
+            args.page_index,
+            args.page_size.min(MAX_PAGE_SIZE),
+        ) {
+            ForumTopicsResult::Success(result) => Success(SuccessResult {
+                topics: result.topics,
+                total: result.total,
+                timestamp: now,
+            }),
+            ForumTopicsResult::UserNotInGroup => UserNotInChannel,
+            ForumTopicsResult::NotForum => NotForum,
+        }
+    } else {
+        ChannelNotFound
+    }
+}
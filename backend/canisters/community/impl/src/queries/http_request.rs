@@ -1,7 +1,7 @@
 use crate::{read_state, RuntimeState};
-use http_request::{build_json_response, encode_logs, extract_route, get_document, Route};
+use http_request::{build_metrics_response, encode_logs, extract_route, get_document, LogsQuery, MetricsFormat, Route};
 use ic_cdk_macros::query;
-use types::{ChannelId, HttpRequest, HttpResponse, TimestampMillis};
+use types::{ChannelId, HttpRequest, HttpResponse};
 
 #[query]
 fn http_request(request: HttpRequest) -> HttpResponse {
@@ -25,16 +25,16 @@ fn http_request(request: HttpRequest) -> HttpResponse {
         get_document(requested_banner_id, &state.data.banner, "banner")
     }
 
-    fn get_logs_impl(since: Option<TimestampMillis>) -> HttpResponse {
-        encode_logs(canister_logger::export_logs(), since.unwrap_or(0))
+    fn get_logs_impl(query: LogsQuery) -> HttpResponse {
+        encode_logs(canister_logger::export_logs(), query)
     }
 
-    fn get_traces_impl(since: Option<TimestampMillis>) -> HttpResponse {
-        encode_logs(canister_logger::export_traces(), since.unwrap_or(0))
+    fn get_traces_impl(query: LogsQuery) -> HttpResponse {
+        encode_logs(canister_logger::export_traces(), query)
     }
 
-    fn get_metrics_impl(state: &RuntimeState) -> HttpResponse {
-        build_json_response(&state.metrics())
+    fn get_metrics_impl(format: MetricsFormat, state: &RuntimeState) -> HttpResponse {
+        build_metrics_response(&state.metrics(), format)
     }
 
     match extract_route(&request.url) {
@@ -43,9 +43,9 @@ fn http_request(request: HttpRequest) -> HttpResponse {
             read_state(|state| get_channel_avatar_impl(channel_id, requested_avatar_id, state))
         }
         Route::Banner(requested_banner_id) => read_state(|state| get_banner_impl(requested_banner_id, state)),
-        Route::Logs(since) => get_logs_impl(since),
-        Route::Traces(since) => get_traces_impl(since),
-        Route::Metrics => read_state(get_metrics_impl),
+        Route::Logs(query) => get_logs_impl(query),
+        Route::Traces(query) => get_traces_impl(query),
+        Route::Metrics(format) => read_state(|state| get_metrics_impl(format, state)),
         _ => HttpResponse::not_found(),
     }
 }
@@ -0,0 +1,17 @@
+use crate::{read_state, RuntimeState};
+use community_canister::instruction_counts::{Response::*, *};
+use ic_cdk_macros::query;
+use instruction_counts_log::DEFAULT_REGRESSION_THRESHOLD_PERCENT;
+
+#[query]
+fn instruction_counts(args: Args) -> Response {
+    read_state(|state| instruction_counts_impl(args, state))
+}
+
+fn instruction_counts_impl(args: Args, state: &RuntimeState) -> Response {
+    let regression_threshold_percent = args
+        .regression_threshold_percent
+        .unwrap_or(DEFAULT_REGRESSION_THRESHOLD_PERCENT);
+
+    Success(state.data.instruction_counts_summary(regression_threshold_percent))
+}
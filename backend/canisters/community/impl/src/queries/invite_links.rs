@@ -0,0 +1,23 @@
+use crate::{read_state, RuntimeState};
+use community_canister::invite_links::{Response::*, *};
+use ic_cdk_macros::query;
+
+#[query]
+fn invite_links(_: Args) -> Response {
+    read_state(invite_links_impl)
+}
+
+fn invite_links_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if member.role.can_invite_users(&state.data.permissions) {
+            Success(SuccessResult {
+                links: state.data.invite_links.list(),
+            })
+        } else {
+            NotAuthorized
+        }
+    } else {
+        UserNotInCommunity
+    }
+}
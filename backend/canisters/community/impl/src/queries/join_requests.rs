@@ -0,0 +1,23 @@
+use crate::{read_state, RuntimeState};
+use community_canister::join_requests::{Response::*, *};
+use ic_cdk_macros::query;
+
+#[query]
+fn join_requests(_: Args) -> Response {
+    read_state(join_requests_impl)
+}
+
+fn join_requests_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if member.role.can_invite_users(&state.data.permissions) {
+            Success(SuccessResult {
+                requests: state.data.join_requests.list(),
+            })
+        } else {
+            NotAuthorized
+        }
+    } else {
+        UserNotInCommunity
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{read_state, RuntimeState};
+use community_canister::message_edit_history::{Response::*, *};
+use group_chat_core::MessageEditHistoryResult;
+use ic_cdk_macros::query;
+
+#[query]
+fn message_edit_history(args: Args) -> Response {
+    read_state(|state| message_edit_history_impl(args, state))
+}
+
+fn message_edit_history_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        let user_id = member.user_id;
+
+        if let Some(channel) = state.data.channels.get(&args.channel_id) {
+            match channel
+                .chat
+                .message_edit_history(user_id, args.thread_root_message_index, args.message_id, state.env.now())
+            {
+                MessageEditHistoryResult::Success(revisions) => Success(SuccessResult { revisions }),
+                MessageEditHistoryResult::UserNotInGroup => UserNotInChannel,
+                MessageEditHistoryResult::MessageNotFound => MessageNotFound,
+            }
+        } else {
+            ChannelNotFound
+        }
+    } else {
+        UserNotInCommunity
+    }
+}
@@ -1,17 +1,29 @@
 use crate::RuntimeState;
 use types::TimestampMillis;
 
+mod audit_log;
+mod c2c_check_content_filters;
+mod channel_invite_links;
 mod channel_summary;
 mod channel_summary_updates;
+mod content_filters;
 mod deleted_message;
+mod emoji_packs;
 mod events;
 mod events_by_index;
 mod events_window;
 mod explore_channels;
+mod export_audit_log;
+mod forum_topics;
 mod http_request;
+mod instruction_counts;
 mod invite_code;
+mod invite_links;
+mod join_requests;
 mod local_user_index;
+mod message_edit_history;
 mod messages_by_message_index;
+mod moderation_queue;
 mod search_channel;
 mod selected_channel_initial;
 mod selected_channel_updates;
@@ -0,0 +1,40 @@
+use crate::{read_state, RuntimeState};
+use community_canister::moderation_queue::{Response::*, *};
+use group_chat_core::ModerationQueueResult;
+use ic_cdk_macros::query;
+
+#[query]
+fn moderation_queue(args: Args) -> Response {
+    read_state(|state| moderation_queue_impl(args, state))
+}
+
+fn moderation_queue_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        let user_id = member.user_id;
+
+        if let Some(channel) = state.data.channels.get(&args.channel_id) {
+            // Community owners and admins can see the reports from every channel, even those they haven't joined
+            if member.role.is_owner() || member.role.is_admin() {
+                let result = channel.chat.moderation_queue_unchecked(args.since, args.include_resolved);
+                return Success(SuccessResult {
+                    reports: result.reports,
+                    decisions: result.decisions,
+                });
+            }
+
+            match channel.chat.moderation_queue(user_id, args.since, args.include_resolved) {
+                ModerationQueueResult::Success(result) => Success(SuccessResult {
+                    reports: result.reports,
+                    decisions: result.decisions,
+                }),
+                ModerationQueueResult::UserNotInGroup => UserNotInChannel,
+                ModerationQueueResult::NotAuthorized => NotAuthorized,
+            }
+        } else {
+            ChannelNotFound
+        }
+    } else {
+        UserNotInCommunity
+    }
+}
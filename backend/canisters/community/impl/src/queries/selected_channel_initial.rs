@@ -10,7 +10,7 @@ fn selected_channel_initial(args: Args) -> Response {
 fn selected_channel_initial_impl(args: Args, state: &RuntimeState) -> Response {
     let caller = state.env.caller();
 
-    if !state.data.is_accessible(caller, None) {
+    if !state.data.is_accessible(caller, None, state.env.now()) {
         return PrivateCommunity;
     }
 
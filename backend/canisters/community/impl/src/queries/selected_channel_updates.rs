@@ -20,7 +20,7 @@ fn selected_channel_updates_impl(args: Args, state: &RuntimeState) -> Response {
         }
 
         let caller = state.env.caller();
-        if !state.data.is_accessible(caller, None) {
+        if !state.data.is_accessible(caller, None, state.env.now()) {
             return PrivateCommunity;
         }
 
@@ -10,7 +10,7 @@ fn selected_initial(args: Args) -> Response {
 fn selected_initial_impl(args: Args, state: &RuntimeState) -> Response {
     let caller = state.env.caller();
 
-    if !state.data.is_accessible(caller, args.invite_code) {
+    if !state.data.is_accessible(caller, args.invite_code, state.env.now()) {
         return PrivateCommunity;
     }
 
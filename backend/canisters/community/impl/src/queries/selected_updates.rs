@@ -16,9 +16,9 @@ fn selected_updates_v2(args: Args) -> Response {
 
 fn selected_updates_impl(args: Args, state: &RuntimeState) -> Response {
     // Don't call `ic0.caller()` if the community is public or the invite_code is valid to maximise query caching
-    if !state.data.is_public || !state.data.is_invite_code_valid(args.invite_code) {
+    if !state.data.is_public || !state.data.is_invite_code_valid(args.invite_code, state.env.now()) {
         let caller = state.env.caller();
-        if !state.data.is_accessible(caller, None) {
+        if !state.data.is_accessible(caller, None, state.env.now()) {
             return PrivateCommunity;
         }
     }
@@ -17,7 +17,7 @@ fn c2c_summary(args: Args) -> Response {
 fn summary_impl(args: Args, state: &RuntimeState) -> Response {
     let caller = state.env.caller();
 
-    if !state.data.is_accessible(caller, args.invite_code) {
+    if !state.data.is_accessible(caller, args.invite_code, state.env.now()) {
         return PrivateCommunity;
     }
 
@@ -1,14 +1,25 @@
 use crate::Data;
 use utils::env::Environment;
 use utils::regular_jobs::{RegularJob, RegularJobs};
-use utils::time::MINUTE_IN_MS;
+use utils::time::{HOUR_IN_MS, MINUTE_IN_MS};
 
 pub(crate) fn build() -> RegularJobs<Data> {
     let check_cycles_balance = RegularJob::new("Check cycles balance", check_cycles_balance, 5 * MINUTE_IN_MS);
     let retry_deleting_files = RegularJob::new("Retry deleting files", retry_deleting_files, MINUTE_IN_MS);
+    let expire_join_requests = RegularJob::new("Expire join requests", expire_join_requests, HOUR_IN_MS);
+    let expire_chat_history_uploads = RegularJob::new("Expire chat history uploads", expire_chat_history_uploads, HOUR_IN_MS);
+    let restore_expired_channel_moves =
+        RegularJob::new("Restore expired channel moves", restore_expired_channel_moves, HOUR_IN_MS);
     let build_chat_metrics = RegularJob::new("Build chat metrics", build_chat_metrics, 30 * MINUTE_IN_MS);
 
-    RegularJobs::new(vec![check_cycles_balance, retry_deleting_files, build_chat_metrics])
+    RegularJobs::new(vec![
+        check_cycles_balance,
+        retry_deleting_files,
+        expire_join_requests,
+        expire_chat_history_uploads,
+        restore_expired_channel_moves,
+        build_chat_metrics,
+    ])
 }
 
 fn check_cycles_balance(_: &dyn Environment, data: &mut Data) {
@@ -19,6 +30,18 @@ fn retry_deleting_files(_: &dyn Environment, _: &mut Data) {
     storage_bucket_client::retry_failed();
 }
 
+fn expire_join_requests(env: &dyn Environment, data: &mut Data) {
+    data.expire_join_requests(env.now());
+}
+
+fn expire_chat_history_uploads(env: &dyn Environment, data: &mut Data) {
+    data.chat_history_imports.remove_expired_uploads(env.now());
+}
+
+fn restore_expired_channel_moves(env: &dyn Environment, data: &mut Data) {
+    data.restore_expired_channel_moves(env.now());
+}
+
 fn build_chat_metrics(env: &dyn Environment, data: &mut Data) {
     data.build_chat_metrics(env.now());
 }
@@ -1,12 +1,16 @@
 use crate::activity_notifications::handle_activity_notification;
+use crate::jobs::import_channels::{finalize_channel_import, process_channel_members as process_channel_import_members};
 use crate::jobs::import_groups::{finalize_group_import, mark_import_complete, process_channel_members};
 use crate::{mutate_state, read_state};
 use canister_timer_jobs::Job;
 use chat_events::MessageContentInternal;
+use group_community_common::ExportedUserGroup;
 use ledger_utils::process_transaction;
 use serde::{Deserialize, Serialize};
 use tracing::error;
-use types::{BlobReference, CanisterId, ChannelId, ChatId, MessageId, MessageIndex, PendingCryptoTransaction};
+use types::{
+    BlobReference, CanisterId, ChannelId, ChatId, CommunityId, MessageId, MessageIndex, PendingCryptoTransaction, UserId,
+};
 use utils::consts::MEMO_PRIZE_REFUND;
 use utils::time::MINUTE_IN_MS;
 
@@ -19,8 +23,11 @@ pub enum TimerJob {
     FinalizeGroupImport(FinalizeGroupImportJob),
     ProcessGroupImportChannelMembers(ProcessGroupImportChannelMembersJob),
     MarkGroupImportComplete(MarkGroupImportCompleteJob),
+    FinalizeChannelImport(FinalizeChannelImportJob),
+    ProcessChannelImportMembers(ProcessChannelImportMembersJob),
     RefundPrize(RefundPrizeJob),
     MakeTransfer(MakeTransferJob),
+    UnmuteChannelMember(UnmuteChannelMemberJob),
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -63,6 +70,21 @@ pub struct MarkGroupImportCompleteJob {
     pub channel_id: ChannelId,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FinalizeChannelImportJob {
+    pub source_community_id: CommunityId,
+    pub source_channel_id: ChannelId,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProcessChannelImportMembersJob {
+    pub source_community_id: CommunityId,
+    pub source_channel_id: ChannelId,
+    pub channel_id: ChannelId,
+    pub user_groups: Vec<ExportedUserGroup>,
+    pub attempt: u32,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct RefundPrizeJob {
     pub channel_id: ChannelId,
@@ -75,6 +97,12 @@ pub struct MakeTransferJob {
     pub pending_transaction: PendingCryptoTransaction,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UnmuteChannelMemberJob {
+    pub channel_id: ChannelId,
+    pub user_id: UserId,
+}
+
 impl Job for TimerJob {
     fn execute(self) {
         match self {
@@ -85,8 +113,11 @@ impl Job for TimerJob {
             TimerJob::FinalizeGroupImport(job) => job.execute(),
             TimerJob::ProcessGroupImportChannelMembers(job) => job.execute(),
             TimerJob::MarkGroupImportComplete(job) => job.execute(),
+            TimerJob::FinalizeChannelImport(job) => job.execute(),
+            TimerJob::ProcessChannelImportMembers(job) => job.execute(),
             TimerJob::RefundPrize(job) => job.execute(),
             TimerJob::MakeTransfer(job) => job.execute(),
+            TimerJob::UnmuteChannelMember(job) => job.execute(),
         }
     }
 }
@@ -96,12 +127,11 @@ impl Job for HardDeleteMessageContentJob {
         let mut follow_on_jobs = Vec::new();
         mutate_state(|state| {
             if let Some(channel) = state.data.channels.get_mut(&self.channel_id) {
-                if let Some((content, sender)) = channel
+                if let Some((content, sender, files_to_delete)) = channel
                     .chat
                     .events
                     .remove_deleted_message_content(self.thread_root_message_index, self.message_id)
                 {
-                    let files_to_delete = content.blob_references();
                     if !files_to_delete.is_empty() {
                         // If there was already a job queued up to delete these files, cancel it
                         state.data.timer_jobs.cancel_jobs(|job| {
@@ -174,6 +204,19 @@ impl Job for EndPollJob {
     }
 }
 
+impl Job for UnmuteChannelMemberJob {
+    fn execute(self) {
+        mutate_state(|state| {
+            let now = state.env.now();
+            if let Some(channel) = state.data.channels.get_mut(&self.channel_id) {
+                if channel.chat.unmute_member_if_expired(self.user_id, now) {
+                    handle_activity_notification(state);
+                }
+            }
+        });
+    }
+}
+
 impl Job for RemoveExpiredEventsJob {
     fn execute(self) {
         mutate_state(|state| state.run_event_expiry_job());
@@ -198,6 +241,24 @@ impl Job for MarkGroupImportCompleteJob {
     }
 }
 
+impl Job for FinalizeChannelImportJob {
+    fn execute(self) {
+        finalize_channel_import((self.source_community_id, self.source_channel_id));
+    }
+}
+
+impl Job for ProcessChannelImportMembersJob {
+    fn execute(self) {
+        ic_cdk::spawn(process_channel_import_members(
+            self.source_community_id,
+            self.source_channel_id,
+            self.channel_id,
+            self.user_groups,
+            self.attempt,
+        ));
+    }
+}
+
 impl Job for RefundPrizeJob {
     fn execute(self) {
         if let Some(pending_transaction) = read_state(|state| {
@@ -5,14 +5,21 @@ use canister_tracing_macros::trace;
 use chat_events::Reader;
 use community_canister::add_reaction::{Response::*, *};
 use group_chat_core::{AddRemoveReactionResult, GroupChatCore};
-use types::{ChannelReactionAddedNotification, EventIndex, EventWrapper, Message, Notification, UserId};
+use instruction_counts_log::InstructionCountFunctionId;
+use types::{ChannelReactionAddedNotification, EmojiPackKind, EventIndex, EventWrapper, Message, Notification, UserId};
 
 #[update_candid_and_msgpack]
 #[trace]
 fn add_reaction(args: Args) -> Response {
     run_regular_jobs();
 
-    mutate_state(|state| add_reaction_impl(args, state))
+    mutate_state(|state| {
+        let response = add_reaction_impl(args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::AddReaction, state.env.now());
+        response
+    })
 }
 
 fn add_reaction_impl(args: Args, state: &mut RuntimeState) -> Response {
@@ -26,6 +33,14 @@ fn add_reaction_impl(args: Args, state: &mut RuntimeState) -> Response {
             return UserSuspended;
         }
 
+        if let Some((pack_id, shortcode)) = args.reaction.custom_emoji() {
+            if !state.data.emoji_packs.contains(pack_id, EmojiPackKind::Emoji, shortcode) {
+                return InvalidReaction;
+            }
+        } else if args.reaction.is_custom_emoji() {
+            return InvalidReaction;
+        }
+
         let user_id = member.user_id;
 
         if let Some(channel) = state.data.channels.get_mut(&args.channel_id) {
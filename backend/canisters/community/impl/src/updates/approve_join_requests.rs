@@ -0,0 +1,145 @@
+use crate::updates::c2c_join_channel::join_channel_auto;
+use crate::updates::c2c_join_community::join_community_impl;
+use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
+use candid::Principal;
+use canister_tracing_macros::trace;
+use community_canister::approve_join_requests::{Response::*, *};
+use community_canister::c2c_join_community;
+use gated_groups::{check_if_passes_gate, CheckGateArgs, CheckIfPassesGateResult};
+use ic_cdk_macros::update;
+use local_user_index_canister::c2c_notify_join_request_outcome::JoinRequestOutcome;
+use types::{AccessGate, ChannelId, ChannelLatestMessageIndex, UserId};
+
+#[update]
+#[trace]
+async fn approve_join_requests(args: Args) -> Response {
+    run_regular_jobs();
+
+    let PrepareResult {
+        applicants,
+        not_found,
+        gate,
+    } = match read_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    let mut approved = Vec::new();
+    let mut failed = Vec::new();
+
+    for (user_id, principal) in applicants {
+        // Approved applicants must still pass the community's access gate
+        if let Some(gate) = gate.as_ref() {
+            let check_gate_args = read_state(|state| CheckGateArgs {
+                gate: gate.clone(),
+                user_index_canister: state.data.user_index_canister_id,
+                user_id,
+                this_canister: state.env.canister_id(),
+                now_nanos: state.env.now_nanos(),
+            });
+            if !matches!(check_if_passes_gate(check_gate_args).await, CheckIfPassesGateResult::Success) {
+                failed.push(user_id);
+                continue;
+            }
+        }
+
+        if let Some(public_channel_ids) = mutate_state(|state| join(user_id, principal, state)) {
+            for channel_id in public_channel_ids {
+                join_channel_auto(channel_id, principal);
+            }
+            mutate_state(|state| commit(user_id, state));
+            approved.push(user_id);
+        } else {
+            failed.push(user_id);
+        }
+    }
+
+    Success(SuccessResult {
+        approved,
+        not_found,
+        failed,
+    })
+}
+
+struct PrepareResult {
+    applicants: Vec<(UserId, Principal)>,
+    not_found: Vec<UserId>,
+    gate: Option<AccessGate>,
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response> {
+    if state.data.is_frozen() {
+        return Err(CommunityFrozen);
+    }
+
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if member.suspended.value {
+            return Err(UserSuspended);
+        }
+        if !member.role.can_invite_users(&state.data.permissions) {
+            return Err(NotAuthorized);
+        }
+
+        let mut applicants = Vec::new();
+        let mut not_found = Vec::new();
+        for user_id in args.user_ids.iter().copied() {
+            if let Some(request) = state.data.join_requests.get(&user_id) {
+                applicants.push((user_id, request.principal));
+            } else {
+                not_found.push(user_id);
+            }
+        }
+
+        Ok(PrepareResult {
+            applicants,
+            not_found,
+            gate: state.data.gate.value.clone(),
+        })
+    } else {
+        Err(UserNotInCommunity)
+    }
+}
+
+fn join(user_id: UserId, principal: Principal, state: &mut RuntimeState) -> Option<Vec<ChannelId>> {
+    // The request may have been dealt with by another admin while the gate was being checked
+    if state.data.join_requests.get(&user_id).is_none() || state.data.members.user_limit_reached().is_some() {
+        return None;
+    }
+
+    let args = c2c_join_community::Args {
+        user_id,
+        principal,
+        invite_code: None,
+        is_platform_moderator: false,
+        is_bot: false,
+    };
+
+    match join_community_impl(&args, state) {
+        Ok(public_channel_ids) => Some(public_channel_ids),
+        Err(c2c_join_community::Response::AlreadyInCommunity(_)) => Some(Vec::new()),
+        Err(_) => None,
+    }
+}
+
+fn commit(user_id: UserId, state: &mut RuntimeState) {
+    let request = state.data.join_requests.remove(&user_id);
+
+    if let (Some(request), Some(member)) = (request, state.data.members.get_by_user_id(&user_id)) {
+        let channels = state
+            .summary(Some(member))
+            .channels
+            .into_iter()
+            .map(|c| ChannelLatestMessageIndex {
+                channel_id: c.channel_id,
+                latest_message_index: c.latest_message.map(|m| m.event.message_index),
+            })
+            .collect();
+
+        state.data.notify_join_request_outcome(
+            user_id,
+            request.local_user_index_canister_id,
+            JoinRequestOutcome::CommunityJoined(channels),
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use crate::model::channels_being_moved::PageResult;
+use crate::{read_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use community_canister::c2c_export_channel::{Response::*, *};
+
+#[update_msgpack]
+fn c2c_export_channel(args: Args) -> Response {
+    run_regular_jobs();
+
+    read_state(|state| c2c_export_channel_impl(args, state))
+}
+
+fn c2c_export_channel_impl(args: Args, state: &RuntimeState) -> Response {
+    match state
+        .data
+        .channels_being_moved
+        .page(&args.channel_id, state.env.caller(), args.from, args.page_size)
+    {
+        PageResult::Success(page) => Success(page),
+        PageResult::DestinationNotSet => DestinationNotSet,
+        PageResult::NotFound => ChannelNotFound,
+    }
+}
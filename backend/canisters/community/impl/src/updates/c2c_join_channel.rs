@@ -19,7 +19,7 @@ async fn c2c_join_channel(args: Args) -> Response {
     run_regular_jobs();
 
     if read_state(|state| state.data.members.get_by_user_id(&args.user_id).is_some()) {
-        check_gate_then_join_channel(args.channel_id, args.principal).await
+        check_gate_then_join_channel(args.channel_id, args.principal, args.invite_code).await
     } else {
         match join_community(community_canister::c2c_join_community::Args {
             user_id: args.user_id,
@@ -31,7 +31,7 @@ async fn c2c_join_channel(args: Args) -> Response {
         .await
         {
             community_canister::c2c_join_community::Response::Success(_) => {
-                let response = check_gate_then_join_channel(args.channel_id, args.principal).await;
+                let response = check_gate_then_join_channel(args.channel_id, args.principal, args.invite_code).await;
                 if matches!(response, Success(_) | AlreadyInChannel(_)) {
                     let summary = read_state(|state| {
                         let member = state.data.members.get_by_user_id(&args.user_id);
@@ -43,7 +43,7 @@ async fn c2c_join_channel(args: Args) -> Response {
                 }
             }
             community_canister::c2c_join_community::Response::AlreadyInCommunity(_) => {
-                check_gate_then_join_channel(args.channel_id, args.principal).await
+                check_gate_then_join_channel(args.channel_id, args.principal, args.invite_code).await
             }
             community_canister::c2c_join_community::Response::GateCheckFailed(r) => GateCheckFailed(r),
             community_canister::c2c_join_community::Response::NotInvited => NotInvited,
@@ -56,16 +56,16 @@ async fn c2c_join_channel(args: Args) -> Response {
 }
 
 pub(crate) fn join_channel_auto(channel_id: ChannelId, user_principal: Principal) {
-    match read_state(|state| is_permitted_to_join(channel_id, user_principal, state)) {
+    match read_state(|state| is_permitted_to_join(channel_id, user_principal, None, state)) {
         Ok(None) => {}
         _ => return,
     };
 
-    mutate_state(|state| commit(channel_id, user_principal, state));
+    mutate_state(|state| commit(channel_id, user_principal, None, state));
 }
 
-async fn check_gate_then_join_channel(channel_id: ChannelId, user_principal: Principal) -> Response {
-    match read_state(|state| is_permitted_to_join(channel_id, user_principal, state)) {
+async fn check_gate_then_join_channel(channel_id: ChannelId, user_principal: Principal, invite_code: Option<u64>) -> Response {
+    match read_state(|state| is_permitted_to_join(channel_id, user_principal, invite_code, state)) {
         Ok(Some(check_gate_args)) => match check_if_passes_gate(check_gate_args).await {
             CheckIfPassesGateResult::Success => {}
             CheckIfPassesGateResult::Failed(reason) => return GateCheckFailed(reason),
@@ -75,12 +75,21 @@ async fn check_gate_then_join_channel(channel_id: ChannelId, user_principal: Pri
         Err(response) => return response,
     };
 
-    mutate_state(|state| commit(channel_id, user_principal, state))
+    mutate_state(|state| {
+        // Checked again since the state may have changed while the gate was being checked, eg. other users may have
+        // taken the invite link up to its max uses
+        if let Err(response) = is_permitted_to_join(channel_id, user_principal, invite_code, state) {
+            response
+        } else {
+            commit(channel_id, user_principal, invite_code, state)
+        }
+    })
 }
 
 fn is_permitted_to_join(
     channel_id: ChannelId,
     user_principal: Principal,
+    invite_code: Option<u64>,
     state: &RuntimeState,
 ) -> Result<Option<CheckGateArgs>, Response> {
     if state.data.is_frozen() {
@@ -99,7 +108,10 @@ fn is_permitted_to_join(
                         .summary(Some(channel_member.user_id), true, state.data.is_public, &state.data.members)
                         .unwrap(),
                 )))
-            } else if !channel.chat.is_public.value && channel.chat.invited_users.get(&member.user_id).is_none() {
+            } else if !channel.chat.is_public.value
+                && channel.chat.invited_users.get(&member.user_id).is_none()
+                && invite_code.map_or(true, |c| channel.invite_links.get_valid(c, state.env.now()).is_none())
+            {
                 Err(NotInvited)
             } else if let Some(limit) = channel.chat.members.user_limit_reached() {
                 Err(MemberLimitReached(limit))
@@ -122,12 +134,19 @@ fn is_permitted_to_join(
     }
 }
 
-fn commit(channel_id: ChannelId, user_principal: Principal, state: &mut RuntimeState) -> Response {
+fn commit(channel_id: ChannelId, user_principal: Principal, invite_code: Option<u64>, state: &mut RuntimeState) -> Response {
     if let Some(member) = state.data.members.get_mut(user_principal) {
         if let Some(channel) = state.data.channels.get_mut(&channel_id) {
             let now = state.env.now();
             match join_channel_unchecked(channel, member, state.data.is_public, now) {
                 AddResult::Success(_) => {
+                    if let Some(code) = invite_code {
+                        let chat = &mut channel.chat;
+                        channel.invite_links.apply(code, now, |created_by, role| {
+                            chat.change_role(created_by, member.user_id, role, false, false, now);
+                        });
+                    }
+
                     let summary = channel
                         .summary(Some(member.user_id), true, state.data.is_public, &state.data.members)
                         .unwrap();
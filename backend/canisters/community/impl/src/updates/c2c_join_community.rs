@@ -1,14 +1,15 @@
 use crate::activity_notifications::handle_activity_notification;
 use crate::guards::caller_is_user_index_or_local_user_index;
+use crate::model::audit_log::to_json;
 use crate::model::events::CommunityEventInternal;
-use crate::model::members::AddResult;
+use crate::model::members::{AddResult, ChangeRoleResult};
 use crate::updates::c2c_join_channel::join_channel_auto;
 use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
 use canister_api_macros::update_msgpack;
 use canister_tracing_macros::trace;
 use community_canister::c2c_join_community::{Response::*, *};
 use gated_groups::{check_if_passes_gate, CheckGateArgs, CheckIfPassesGateResult};
-use types::{AccessGate, ChannelId, MemberJoined, UsersUnblocked};
+use types::{AccessGate, AuditLogActionType, ChannelId, CommunityRoleChanged, MemberJoined, UsersUnblocked};
 
 #[update_msgpack(guard = "caller_is_user_index_or_local_user_index")]
 #[trace]
@@ -29,7 +30,9 @@ pub(crate) async fn join_community(args: Args) -> Response {
         Err(response) => return response,
     };
 
-    match mutate_state(|state| join_community_impl(&args, state)) {
+    // Checked again since the state may have changed while the gate was being checked, eg. other users may have taken
+    // the invite link up to its max uses
+    match mutate_state(|state| is_permitted_to_join(&args, state).and_then(|_| join_community_impl(&args, state))) {
         Ok(public_channel_ids) => {
             for c in public_channel_ids {
                 join_channel_auto(c, args.principal);
@@ -58,7 +61,7 @@ fn is_permitted_to_join(args: &Args, state: &RuntimeState) -> Result<Option<Chec
         Err(UserBlocked)
     } else if state.data.is_frozen() {
         Err(CommunityFrozen)
-    } else if !state.data.is_accessible(args.principal, args.invite_code) {
+    } else if !state.data.is_accessible(args.principal, args.invite_code, state.env.now()) {
         Err(NotInvited)
     } else if let Some(limit) = state.data.members.user_limit_reached() {
         Err(MemberLimitReached(limit))
@@ -94,6 +97,7 @@ pub(crate) fn join_community_impl(args: &Args, state: &mut RuntimeState) -> Resu
     match state.data.members.add(args.user_id, args.principal, args.is_bot, now) {
         AddResult::Success(_) => {
             let invitation = state.data.invited_users.remove(&args.user_id, now);
+            state.data.join_requests.remove(&args.user_id);
 
             state.data.events.push_event(
                 CommunityEventInternal::MemberJoined(Box::new(MemberJoined {
@@ -103,6 +107,35 @@ pub(crate) fn join_community_impl(args: &Args, state: &mut RuntimeState) -> Resu
                 now,
             );
 
+            if let Some(code) = args.invite_code {
+                let data = &mut state.data;
+                data.invite_links.apply(code, now, |created_by, role| {
+                    if let ChangeRoleResult::Success(r) =
+                        data.members
+                            .change_role(created_by, args.user_id, role, &data.permissions, false, false)
+                    {
+                        data.events.push_event(
+                            CommunityEventInternal::RoleChanged(Box::new(CommunityRoleChanged {
+                                user_ids: vec![args.user_id],
+                                old_role: r.prev_role,
+                                new_role: role,
+                                changed_by: created_by,
+                            })),
+                            now,
+                        );
+                        data.audit_log.push(
+                            created_by,
+                            AuditLogActionType::RoleChanged,
+                            None,
+                            vec![args.user_id],
+                            to_json(&r.prev_role),
+                            to_json(&role),
+                            now,
+                        );
+                    }
+                });
+            }
+
             // If there is a payment gate on this community then queue payments to owner(s) and treasury
             if let Some(AccessGate::Payment(gate)) = state.data.gate.value.as_ref() {
                 state.queue_access_gate_payments(gate.clone());
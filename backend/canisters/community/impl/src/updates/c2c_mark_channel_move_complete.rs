@@ -0,0 +1,59 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use community_canister::c2c_mark_channel_move_complete::{Response::*, *};
+use types::AuditLogActionType;
+
+// Called by the community or group which the channel has been moved into once it has finished
+// importing the channel
+#[update_msgpack]
+#[trace]
+fn c2c_mark_channel_move_complete(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_mark_channel_move_complete_impl(args, state))
+}
+
+fn c2c_mark_channel_move_complete_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let channel_id = args.channel_id;
+
+    if state.data.channels_being_moved.destination(&channel_id) != Some(caller) {
+        return ChannelNotFound;
+    }
+
+    let now = state.env.now();
+    let moved = state.data.channels_being_moved.take(&channel_id).unwrap();
+    let chat = moved.channel.chat;
+
+    state.data.audit_log.push(
+        moved.moved_by,
+        AuditLogActionType::ChannelMoved,
+        Some(channel_id),
+        Vec::new(),
+        to_json(&chat.name.value),
+        to_json(&args.moved_to),
+        now,
+    );
+
+    // Redirect each member's references to the channel to wherever it has been moved to
+    let notification = user_canister::c2c_notify_channel_moved::Args {
+        channel_id,
+        moved_to: args.moved_to,
+        latest_message_index: args.latest_message_index,
+    };
+    for user_id in chat.members.iter().map(|m| m.user_id) {
+        state.data.members.mark_member_left_channel(&user_id, channel_id, now);
+        state.data.fire_and_forget_handler.send(
+            user_id.into(),
+            "c2c_notify_channel_moved_msgpack".to_string(),
+            msgpack::serialize_then_unwrap(&notification),
+        );
+    }
+
+    handle_activity_notification(state);
+
+    Success
+}
@@ -0,0 +1,42 @@
+use crate::{read_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use community_canister::c2c_message_for_forwarding::{Response::*, *};
+use group_chat_core::MessageForForwardingResult;
+use types::Chat;
+
+// Called by user canisters when forwarding a message from one of this community's channels to other chats
+#[update_msgpack]
+#[trace]
+fn c2c_message_for_forwarding(args: Args) -> Response {
+    run_regular_jobs();
+
+    read_state(|state| c2c_message_for_forwarding_impl(args, state))
+}
+
+fn c2c_message_for_forwarding_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    let user_id = match state.data.members.get(caller) {
+        Some(m) => m.user_id,
+        None => return UserNotInCommunity,
+    };
+
+    if let Some(channel) = state.data.channels.get(&args.channel_id) {
+        let chat = Chat::Channel(state.env.canister_id().into(), args.channel_id);
+
+        match channel.chat.message_for_forwarding(
+            user_id,
+            chat,
+            !state.data.is_public,
+            args.thread_root_message_index,
+            args.message_index,
+        ) {
+            MessageForForwardingResult::Success(message) => Success(message),
+            MessageForForwardingResult::UserNotInGroup => UserNotInChannel,
+            MessageForForwardingResult::MessageNotFound => MessageNotFound,
+            MessageForForwardingResult::NotForwardable => NotForwardable,
+        }
+    } else {
+        ChannelNotFound
+    }
+}
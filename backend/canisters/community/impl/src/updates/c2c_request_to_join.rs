@@ -0,0 +1,42 @@
+use crate::guards::caller_is_local_user_index;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use community_canister::c2c_request_to_join::{Response::*, *};
+use group_community_common::AddJoinRequestError;
+
+#[update_msgpack(guard = "caller_is_local_user_index")]
+#[trace]
+fn c2c_request_to_join(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_request_to_join_impl(args, state))
+}
+
+fn c2c_request_to_join_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+    if state.data.is_public {
+        return CommunityIsPublic;
+    }
+    if state.data.members.get_by_user_id(&args.user_id).is_some() {
+        return AlreadyInCommunity;
+    }
+    if state.data.members.is_blocked(&args.user_id) {
+        return UserBlocked;
+    }
+
+    let caller = state.env.caller();
+    let now = state.env.now();
+    match state
+        .data
+        .join_requests
+        .add(args.user_id, args.principal, caller, args.message, now)
+    {
+        Ok(()) => Success,
+        Err(AddJoinRequestError::AlreadyRequested) => AlreadyRequested,
+        Err(AddJoinRequestError::MessageTooLong(max)) => MessageTooLong(max),
+        Err(AddJoinRequestError::TooManyRequests(max)) => TooManyRequests(max),
+    }
+}
@@ -0,0 +1,33 @@
+use crate::guards::caller_is_notifications_canister;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use community_canister::c2c_set_link_previews::{Response::*, *};
+use types::Chat;
+
+#[update_msgpack(guard = "caller_is_notifications_canister")]
+#[trace]
+fn c2c_set_link_previews(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_set_link_previews_impl(args, state))
+}
+
+fn c2c_set_link_previews_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let now = state.env.now();
+
+    if let Chat::Channel(_, channel_id) = args.chat {
+        if let Some(channel) = state.data.channels.get_mut(&channel_id) {
+            if channel.chat.events.set_link_previews(
+                args.thread_root_message_index,
+                args.message_id,
+                None,
+                args.link_previews,
+                now,
+            ) {
+                return Success;
+            }
+        }
+    }
+    MessageNotFound
+}
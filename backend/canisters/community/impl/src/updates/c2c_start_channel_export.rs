@@ -0,0 +1,110 @@
+use crate::guards::caller_is_group_index;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use community_canister::c2c_start_channel_export::{Response::*, *};
+use group_community_common::{ChannelExport, ExportedUserGroup};
+use std::collections::HashSet;
+use types::{CanisterId, ChannelId, UserId};
+
+#[update_msgpack(guard = "caller_is_group_index")]
+#[trace]
+fn c2c_start_channel_export(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_start_channel_export_impl(args, state))
+}
+
+fn c2c_start_channel_export_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    if let Some(member) = state.data.members.get_by_user_id(&args.user_id) {
+        if member.suspended.value {
+            return UserSuspended;
+        }
+        if !member.role.is_owner() {
+            return UserNotCommunityOwner;
+        }
+    } else {
+        return UserNotInCommunity;
+    }
+
+    if let Some(channel) = state.data.channels.get(&args.channel_id) {
+        if !channel
+            .chat
+            .members
+            .get(&args.user_id)
+            .map_or(false, |m| m.role.can_delete_group())
+        {
+            return UserNotChannelOwner;
+        }
+    } else if state.data.channels_being_moved.contains(&args.channel_id) {
+        return ChannelAlreadyBeingMoved;
+    } else {
+        return ChannelNotFound;
+    }
+
+    let total_bytes = start_channel_move(args.channel_id, args.user_id, Some(args.community_id.into()), state);
+
+    Success(total_bytes)
+}
+
+// Takes the channel out of the community then serializes it along with the user groups and
+// principals of its members, so that these can be remapped by the destination
+pub(crate) fn start_channel_move(
+    channel_id: ChannelId,
+    moved_by: UserId,
+    destination: Option<CanisterId>,
+    state: &mut RuntimeState,
+) -> u64 {
+    let now = state.env.now();
+    let channel = state.data.channels.delete(channel_id).expect("Channel should exist");
+
+    let user_groups = state
+        .data
+        .members
+        .iter_user_groups()
+        .filter_map(|g| {
+            let users: Vec<_> = g
+                .members
+                .value
+                .iter()
+                .filter(|u| channel.chat.members.contains(u))
+                .copied()
+                .collect();
+
+            (!users.is_empty()).then(|| ExportedUserGroup {
+                name: g.name.value.clone(),
+                users,
+            })
+        })
+        .collect();
+
+    let user_ids: HashSet<_> = channel
+        .chat
+        .members
+        .iter()
+        .map(|m| m.user_id)
+        .chain(channel.chat.invited_users.users())
+        .collect();
+
+    let principals = state
+        .data
+        .members
+        .iter_principals()
+        .filter(|(_, u)| user_ids.contains(u))
+        .map(|(p, u)| (*p, *u))
+        .collect();
+
+    let bytes = ChannelExport::serialize(&channel.chat, &channel.invite_links, user_groups, principals);
+    let total_bytes = bytes.len() as u64;
+
+    state
+        .data
+        .channels_being_moved
+        .add(channel, moved_by, bytes, destination, now);
+
+    total_bytes
+}
@@ -3,6 +3,7 @@ use canister_tracing_macros::trace;
 use community_canister::cancel_invites::{Response::*, *};
 use group_chat_core::CancelInvitesResult;
 use ic_cdk_macros::update;
+use types::AuditLogActionType;
 
 #[update]
 #[trace]
@@ -23,8 +24,19 @@ fn cancel_invites_impl(args: Args, state: &mut RuntimeState) -> Response {
         let now = state.env.now();
         if let Some(channel_id) = args.channel_id {
             if let Some(channel) = state.data.channels.get_mut(&channel_id) {
-                match channel.chat.cancel_invites(member.user_id, args.user_ids, now) {
-                    CancelInvitesResult::Success => Success,
+                match channel.chat.cancel_invites(member.user_id, args.user_ids.clone(), now) {
+                    CancelInvitesResult::Success => {
+                        state.data.audit_log.push(
+                            member.user_id,
+                            AuditLogActionType::InvitesCancelled,
+                            Some(channel_id),
+                            args.user_ids,
+                            None,
+                            None,
+                            now,
+                        );
+                        Success
+                    }
                     CancelInvitesResult::UserSuspended => UserSuspended,
                     CancelInvitesResult::NotAuthorized | CancelInvitesResult::UserNotInGroup => NotAuthorized,
                 }
@@ -32,13 +44,26 @@ fn cancel_invites_impl(args: Args, state: &mut RuntimeState) -> Response {
                 ChannelNotFound
             }
         } else if member.role.can_invite_users(&state.data.permissions) {
+            let mut cancelled = Vec::new();
             for user_id in args.user_ids {
                 if state.data.invited_users.remove(&user_id, now).is_some() {
                     for channel in state.data.channels.iter_mut() {
                         channel.chat.cancel_invite_unchecked(&user_id, now);
                     }
+                    cancelled.push(user_id);
                 }
             }
+            if !cancelled.is_empty() {
+                state.data.audit_log.push(
+                    member.user_id,
+                    AuditLogActionType::InvitesCancelled,
+                    None,
+                    cancelled,
+                    None,
+                    None,
+                    now,
+                );
+            }
             Success
         } else {
             NotAuthorized
@@ -1,8 +1,10 @@
+use crate::model::audit_log::to_json;
 use crate::{activity_notifications::handle_activity_notification, mutate_state, run_regular_jobs, RuntimeState};
 use canister_api_macros::update_candid_and_msgpack;
 use canister_tracing_macros::trace;
 use community_canister::change_channel_role::{Response::*, *};
 use group_chat_core::ChangeRoleResult;
+use types::{AuditLogActionType, GroupRole};
 
 #[update_candid_and_msgpack]
 #[trace]
@@ -30,7 +32,16 @@ fn change_channel_role_impl(args: Args, state: &mut RuntimeState) -> Response {
                 .chat
                 .change_role(member.user_id, args.user_id, args.new_role, false, false, now)
             {
-                ChangeRoleResult::Success(_) => {
+                ChangeRoleResult::Success(r) => {
+                    state.data.audit_log.push(
+                        member.user_id,
+                        AuditLogActionType::ChannelRoleChanged,
+                        Some(args.channel_id),
+                        vec![args.user_id],
+                        to_json(&GroupRole::from(r.prev_role)),
+                        to_json(&args.new_role),
+                        now,
+                    );
                     handle_activity_notification(state);
                     Success
                 }
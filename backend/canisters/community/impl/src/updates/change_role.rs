@@ -1,12 +1,12 @@
 use crate::{
     activity_notifications::handle_activity_notification,
-    model::{events::CommunityEventInternal, members::ChangeRoleResult},
+    model::{audit_log::to_json, events::CommunityEventInternal, members::ChangeRoleResult},
     mutate_state, read_state, run_regular_jobs, RuntimeState,
 };
 use canister_tracing_macros::trace;
 use community_canister::change_role::{Response::*, *};
 use ic_cdk_macros::update;
-use types::{CanisterId, CommunityRoleChanged, UserId};
+use types::{AuditLogActionType, CanisterId, CommunityRoleChanged, UserId};
 use user_index_canister_c2c_client::{lookup_user, LookupUserError};
 
 #[update]
@@ -109,6 +109,15 @@ fn change_role_impl(
         is_user_platform_moderator,
     ) {
         ChangeRoleResult::Success(r) => {
+            state.data.audit_log.push(
+                r.caller_id,
+                AuditLogActionType::RoleChanged,
+                None,
+                vec![args.user_id],
+                to_json(&r.prev_role),
+                to_json(&args.new_role),
+                now,
+            );
             let event = CommunityRoleChanged {
                 user_ids: vec![args.user_id],
                 old_role: r.prev_role,
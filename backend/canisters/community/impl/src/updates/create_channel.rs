@@ -8,10 +8,11 @@ use canister_api_macros::update_msgpack;
 use canister_tracing_macros::trace;
 use community_canister::c2c_join_community;
 use community_canister::create_channel::{Response::*, *};
-use group_chat_core::GroupChatCore;
+use group_chat_core::{sanitise_forum_subtype, GroupChatCore};
+use group_community_common::InviteLinks;
 use ic_cdk_macros::update;
 use rand::Rng;
-use types::ChannelId;
+use types::{ChannelId, GroupSubtype};
 use utils::document_validation::validate_avatar;
 use utils::text_validation::{
     validate_description, validate_group_name, validate_rules, NameValidationError, RulesValidationError,
@@ -67,7 +68,15 @@ fn create_channel_impl(args: Args, is_proposals_channel: bool, state: &mut Runti
             return UserSuspended;
         }
 
-        let subtype = is_proposals_channel.then_some(args.subtype).flatten();
+        // Users can only create forum channels, other subtypes are reserved for the proposals bot
+        let subtype = if is_proposals_channel {
+            args.subtype
+        } else {
+            match args.subtype {
+                Some(GroupSubtype::Forum(forum)) => Some(GroupSubtype::Forum(sanitise_forum_subtype(forum))),
+                _ => None,
+            }
+        };
 
         if !is_proposals_channel {
             let is_authorized = if args.is_public {
@@ -125,6 +134,7 @@ fn create_channel_impl(args: Args, is_proposals_channel: bool, state: &mut Runti
                 id: channel_id,
                 chat,
                 date_imported: None,
+                invite_links: InviteLinks::default(),
             };
 
             if args.is_public && channel.chat.gate.is_none() {
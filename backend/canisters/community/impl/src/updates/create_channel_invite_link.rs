@@ -0,0 +1,85 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::create_channel_invite_link::{Response::*, *};
+use group_community_common::{CreateInviteLinkError, NewInviteLink};
+use ic_cdk_macros::update;
+use rand::RngCore;
+use types::{AuditLogActionType, GroupRole};
+
+#[update]
+#[trace]
+fn create_channel_invite_link(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| create_channel_invite_link_impl(args, state))
+}
+
+fn create_channel_invite_link_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let user_id = match state.data.members.get(caller) {
+        Some(m) if m.suspended.value => return UserSuspended,
+        Some(m) => m.user_id,
+        None => return UserNotInCommunity,
+    };
+
+    if let Some(channel) = state.data.channels.get_mut(&args.channel_id) {
+        if let Some(channel_member) = channel.chat.members.get(&user_id) {
+            let permissions = &channel.chat.permissions;
+            if !channel_member.role.can_invite_users(permissions)
+                || args.role.map_or(false, |r| {
+                    r == GroupRole::Owner || !channel_member.role.can_change_roles(r.into(), permissions)
+                })
+            {
+                return NotAuthorized;
+            }
+
+            let now = state.env.now();
+
+            let code = loop {
+                let code = state.env.rng().next_u64();
+                if !channel.invite_links.contains(code) {
+                    break code;
+                }
+            };
+
+            let link = NewInviteLink {
+                code,
+                name: args.name,
+                created_by: user_id,
+                expires_at: args.expires_at,
+                max_uses: args.max_uses,
+                role: args.role,
+            };
+
+            match channel.invite_links.create(link, now) {
+                Ok(link) => {
+                    state.data.audit_log.push(
+                        user_id,
+                        AuditLogActionType::InviteLinkCreated,
+                        Some(args.channel_id),
+                        Vec::new(),
+                        None,
+                        to_json(&link),
+                        now,
+                    );
+                    handle_activity_notification(state);
+                    Success(link)
+                }
+                Err(CreateInviteLinkError::NameTooLong(max)) => NameTooLong(max),
+                Err(CreateInviteLinkError::ExpiryInPast) => ExpiryInPast,
+                Err(CreateInviteLinkError::InvalidMaxUses) => InvalidMaxUses,
+                Err(CreateInviteLinkError::TooManyLinks(max)) => TooManyLinks(max),
+            }
+        } else {
+            UserNotInChannel
+        }
+    } else {
+        ChannelNotFound
+    }
+}
@@ -0,0 +1,55 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
+use crate::model::emoji_packs::EmojiPackError;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::create_emoji_pack::{Response::*, *};
+use ic_cdk_macros::update;
+use types::AuditLogActionType;
+
+#[update]
+#[trace]
+fn create_emoji_pack(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| create_emoji_pack_impl(args, state))
+}
+
+fn create_emoji_pack_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    match state.data.members.get(caller) {
+        Some(m) if m.suspended.value => UserSuspended,
+        Some(m) if m.role.can_manage_emoji_packs(&state.data.permissions) => {
+            let now = state.env.now();
+            let user_id = m.user_id;
+
+            match state.data.emoji_packs.create(args.name, args.kind, args.assets, user_id, now) {
+                Ok(pack_id) => {
+                    let pack = state.data.emoji_packs.get(pack_id);
+                    state.data.audit_log.push(
+                        user_id,
+                        AuditLogActionType::EmojiPackCreated,
+                        None,
+                        Vec::new(),
+                        None,
+                        pack.and_then(to_json),
+                        now,
+                    );
+                    handle_activity_notification(state);
+                    Success(SuccessResult { pack_id })
+                }
+                Err(EmojiPackError::NameInvalid) => NameInvalid,
+                Err(EmojiPackError::InvalidShortcode(s)) => InvalidShortcode(s),
+                Err(EmojiPackError::DuplicateShortcode(s)) => DuplicateShortcode(s),
+                Err(EmojiPackError::TooManyAssets(max)) => TooManyAssets(max),
+                Err(EmojiPackError::TooManyPacks(max)) => TooManyPacks(max),
+                Err(EmojiPackError::PackNotFound) => unreachable!(),
+            }
+        }
+        _ => NotAuthorized,
+    }
+}
@@ -0,0 +1,80 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::create_invite_link::{Response::*, *};
+use group_community_common::{CreateInviteLinkError, NewInviteLink};
+use ic_cdk_macros::update;
+use rand::RngCore;
+use types::AuditLogActionType;
+
+#[update]
+#[trace]
+fn create_invite_link(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| create_invite_link_impl(args, state))
+}
+
+fn create_invite_link_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if member.suspended.value {
+            return UserSuspended;
+        }
+
+        let permissions = &state.data.permissions;
+        if !member.role.can_invite_users(permissions)
+            || args
+                .role
+                .map_or(false, |r| r.is_owner() || !member.role.can_change_roles(r, permissions))
+        {
+            return NotAuthorized;
+        }
+
+        let user_id = member.user_id;
+        let now = state.env.now();
+
+        let code = loop {
+            let code = state.env.rng().next_u64();
+            if !state.data.invite_links.contains(code) && state.data.invite_code != Some(code) {
+                break code;
+            }
+        };
+
+        let link = NewInviteLink {
+            code,
+            name: args.name,
+            created_by: user_id,
+            expires_at: args.expires_at,
+            max_uses: args.max_uses,
+            role: args.role,
+        };
+
+        match state.data.invite_links.create(link, now) {
+            Ok(link) => {
+                state.data.audit_log.push(
+                    user_id,
+                    AuditLogActionType::InviteLinkCreated,
+                    None,
+                    Vec::new(),
+                    None,
+                    to_json(&link),
+                    now,
+                );
+                handle_activity_notification(state);
+                Success(link)
+            }
+            Err(CreateInviteLinkError::NameTooLong(max)) => NameTooLong(max),
+            Err(CreateInviteLinkError::ExpiryInPast) => ExpiryInPast,
+            Err(CreateInviteLinkError::InvalidMaxUses) => InvalidMaxUses,
+            Err(CreateInviteLinkError::TooManyLinks(max)) => TooManyLinks(max),
+        }
+    } else {
+        UserNotInCommunity
+    }
+}
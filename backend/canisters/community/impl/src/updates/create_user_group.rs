@@ -1,8 +1,10 @@
 use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
 use crate::{mutate_state, run_regular_jobs, RuntimeState};
 use canister_tracing_macros::trace;
 use community_canister::create_user_group::{Response::*, *};
 use ic_cdk_macros::update;
+use types::{AuditLogActionType, UserGroupDetails};
 use utils::text_validation::{validate_user_group_name, UsernameValidationError};
 
 #[update]
@@ -36,7 +38,19 @@ fn create_user_group_impl(args: Args, state: &mut RuntimeState) -> Response {
             let now = state.env.now();
             let rng = state.env.rng();
 
+            let user_id = member.user_id;
+
             if let Some(user_group_id) = state.data.members.create_user_group(args.name, args.user_ids, rng, now) {
+                let details = state.data.members.get_user_group(user_group_id).map(UserGroupDetails::from);
+                state.data.audit_log.push(
+                    user_id,
+                    AuditLogActionType::UserGroupCreated,
+                    None,
+                    details.as_ref().map(|d| d.members.clone()).unwrap_or_default(),
+                    None,
+                    details.as_ref().and_then(to_json),
+                    now,
+                );
                 handle_activity_notification(state);
                 Success(SuccessResult { user_group_id })
             } else {
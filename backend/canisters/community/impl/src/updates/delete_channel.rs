@@ -1,11 +1,12 @@
 use crate::{
-    activity_notifications::handle_activity_notification, model::events::CommunityEventInternal, mutate_state,
-    run_regular_jobs, RuntimeState,
+    activity_notifications::handle_activity_notification,
+    model::{audit_log::to_json, events::CommunityEventInternal},
+    mutate_state, run_regular_jobs, RuntimeState,
 };
 use canister_api_macros::update_candid_and_msgpack;
 use canister_tracing_macros::trace;
 use community_canister::delete_channel::{Response::*, *};
-use types::{ChannelDeleted, ChannelId};
+use types::{AuditLogActionType, ChannelDeleted, ChannelId};
 
 #[update_candid_and_msgpack]
 #[trace]
@@ -34,6 +35,16 @@ fn delete_channel_impl(channel_id: ChannelId, state: &mut RuntimeState) -> Respo
                     let now = state.env.now();
                     let channel = state.data.channels.delete(channel_id).expect("Channel should exist");
 
+                    state.data.audit_log.push(
+                        user_id,
+                        AuditLogActionType::ChannelDeleted,
+                        Some(channel_id),
+                        Vec::new(),
+                        to_json(&channel.chat.name.value),
+                        None,
+                        now,
+                    );
+
                     state.data.events.push_event(
                         CommunityEventInternal::ChannelDeleted(Box::new(ChannelDeleted {
                             channel_id,
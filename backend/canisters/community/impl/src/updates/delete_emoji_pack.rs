@@ -0,0 +1,52 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::delete_emoji_pack::{Response::*, *};
+use ic_cdk_macros::update;
+use types::AuditLogActionType;
+
+#[update]
+#[trace]
+fn delete_emoji_pack(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| delete_emoji_pack_impl(args, state))
+}
+
+fn delete_emoji_pack_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    match state.data.members.get(caller) {
+        Some(m) if m.suspended.value => UserSuspended,
+        Some(m) if m.role.can_manage_emoji_packs(&state.data.permissions) => {
+            let now = state.env.now();
+            let user_id = m.user_id;
+
+            if let Some(pack) = state.data.emoji_packs.delete(args.pack_id, now) {
+                state.data.audit_log.push(
+                    user_id,
+                    AuditLogActionType::EmojiPackDeleted,
+                    None,
+                    Vec::new(),
+                    to_json(&pack),
+                    None,
+                    now,
+                );
+
+                let files_to_delete: Vec<_> = pack.assets.into_iter().map(|a| a.blob_reference).collect();
+                if !files_to_delete.is_empty() {
+                    ic_cdk::spawn(storage_bucket_client::delete_files(files_to_delete));
+                }
+                handle_activity_notification(state);
+                Success
+            } else {
+                PackNotFound
+            }
+        }
+        _ => NotAuthorized,
+    }
+}
@@ -1,4 +1,5 @@
 use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
 use crate::timer_job_types::HardDeleteMessageContentJob;
 use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState, TimerJob};
 use candid::Principal;
@@ -7,7 +8,9 @@ use canister_tracing_macros::trace;
 use chat_events::DeleteMessageResult;
 use community_canister::delete_messages::{Response::*, *};
 use group_chat_core::DeleteMessagesResult;
-use types::{CanisterId, UserId};
+use instruction_counts_log::InstructionCountFunctionId;
+use itertools::Itertools;
+use types::{AuditLogActionType, CanisterId, UserId};
 use user_index_canister_c2c_client::lookup_user;
 use utils::consts::OPENCHAT_BOT_USER_ID;
 use utils::time::MINUTE_IN_MS;
@@ -34,7 +37,13 @@ async fn delete_messages(args: Args) -> Response {
         }
     }
 
-    mutate_state(|state| delete_messages_impl(user_id, args, state))
+    mutate_state(|state| {
+        let response = delete_messages_impl(user_id, args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::DeleteMessages, state.env.now());
+        response
+    })
 }
 
 struct PrepareResult {
@@ -80,14 +89,34 @@ fn delete_messages_impl(user_id: UserId, args: Args, state: &mut RuntimeState) -
             now,
         ) {
             DeleteMessagesResult::Success(results) => {
-                let remove_deleted_message_content_at = now + (5 * MINUTE_IN_MS);
-                for message_id in results.into_iter().filter_map(|(message_id, result)| {
+                let mut deleted_by_sender = Vec::new();
+                let mut deleted_by_moderator = Vec::new();
+                for (message_id, result) in results {
                     if let DeleteMessageResult::Success(sender) = result {
-                        (sender == user_id).then_some(message_id)
-                    } else {
-                        None
+                        if sender == user_id {
+                            deleted_by_sender.push(message_id);
+                        } else {
+                            deleted_by_moderator.push((message_id, sender));
+                        }
                     }
-                }) {
+                }
+
+                if !deleted_by_moderator.is_empty() {
+                    let message_ids: Vec<_> = deleted_by_moderator.iter().map(|(m, _)| *m).collect();
+                    let senders: Vec<_> = deleted_by_moderator.iter().map(|(_, s)| *s).unique().collect();
+                    state.data.audit_log.push(
+                        user_id,
+                        AuditLogActionType::MessagesDeleted,
+                        Some(args.channel_id),
+                        senders,
+                        None,
+                        to_json(&message_ids),
+                        now,
+                    );
+                }
+
+                let remove_deleted_message_content_at = now + (5 * MINUTE_IN_MS);
+                for message_id in deleted_by_sender {
                     // After 5 minutes hard delete those messages where the deleter was the message sender
                     state.data.timer_jobs.enqueue_job(
                         TimerJob::HardDeleteMessageContent(HardDeleteMessageContentJob {
@@ -1,8 +1,10 @@
 use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
 use crate::{mutate_state, run_regular_jobs, RuntimeState};
 use canister_tracing_macros::trace;
 use community_canister::delete_user_groups::{Response::*, *};
 use ic_cdk_macros::update;
+use types::{AuditLogActionType, UserGroupDetails};
 
 #[update]
 #[trace]
@@ -22,14 +24,25 @@ fn delete_user_groups_impl(args: Args, state: &mut RuntimeState) -> Response {
         Some(m) if m.suspended.value => UserSuspended,
         Some(m) if m.role.can_manage_user_groups(&state.data.permissions) => {
             let now = state.env.now();
+            let user_id = m.user_id;
 
-            let mut updated = false;
+            let mut deleted = Vec::new();
             for user_group_id in args.user_group_ids {
+                let details = state.data.members.get_user_group(user_group_id).map(UserGroupDetails::from);
                 if state.data.members.delete_user_group(user_group_id, now) {
-                    updated = true;
+                    deleted.extend(details);
                 }
             }
-            if updated {
+            if !deleted.is_empty() {
+                state.data.audit_log.push(
+                    user_id,
+                    AuditLogActionType::UserGroupsDeleted,
+                    None,
+                    Vec::new(),
+                    to_json(&deleted),
+                    None,
+                    now,
+                );
                 handle_activity_notification(state);
             }
             Success
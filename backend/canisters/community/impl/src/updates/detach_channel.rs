@@ -0,0 +1,112 @@
+use crate::updates::c2c_start_channel_export::start_channel_move;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::detach_channel::{Response::*, *};
+use group_index_canister::c2c_detach_channel_into_group::{Args as C2cArgs, Response as C2cResponse};
+use ic_cdk_macros::update;
+use types::{CanisterId, ChannelId};
+
+#[update]
+#[trace]
+async fn detach_channel(args: Args) -> Response {
+    run_regular_jobs();
+
+    let PrepareResult {
+        group_index_canister_id,
+        c2c_args,
+    } = match mutate_state(|state| prepare(args.channel_id, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    let total_bytes = c2c_args.total_bytes;
+
+    let response =
+        match group_index_canister_c2c_client::c2c_detach_channel_into_group(group_index_canister_id, &c2c_args).await {
+            Ok(C2cResponse::Success(group_id)) => {
+                mutate_state(|state| {
+                    state
+                        .data
+                        .channels_being_moved
+                        .set_destination(&args.channel_id, group_id.into())
+                });
+                return Success(SuccessResult { group_id, total_bytes });
+            }
+            Ok(C2cResponse::NameTaken) => NameTaken,
+            Ok(C2cResponse::CyclesBalanceTooLow) => InternalError("Cycles balance too low".to_string()),
+            Ok(C2cResponse::InternalError(error)) => InternalError(error),
+            Err(error) => InternalError(format!("{error:?}")),
+        };
+
+    // The group wasn't created so put the channel back
+    mutate_state(|state| {
+        if let Some(moved) = state.data.channels_being_moved.take(&args.channel_id) {
+            state.data.channels.add(moved.channel);
+        }
+    });
+
+    response
+}
+
+struct PrepareResult {
+    group_index_canister_id: CanisterId,
+    c2c_args: C2cArgs,
+}
+
+fn prepare(channel_id: ChannelId, state: &mut RuntimeState) -> Result<PrepareResult, Response> {
+    let caller = state.env.caller();
+
+    if state.data.is_frozen() {
+        return Err(CommunityFrozen);
+    }
+
+    let user_id = if let Some(member) = state.data.members.get(caller) {
+        if member.suspended.value {
+            return Err(UserSuspended);
+        }
+        if !member.role.is_owner() {
+            return Err(UserNotCommunityOwner);
+        }
+        member.user_id
+    } else {
+        return Err(UserNotInCommunity);
+    };
+
+    let chat = if let Some(channel) = state.data.channels.get(&channel_id) {
+        &channel.chat
+    } else if state.data.channels_being_moved.contains(&channel_id) {
+        return Err(ChannelAlreadyBeingMoved);
+    } else {
+        return Err(ChannelNotFound);
+    };
+
+    if !chat.members.get(&user_id).map_or(false, |m| m.role.can_delete_group()) {
+        return Err(UserNotChannelOwner);
+    }
+
+    // The group is created with the channel's details, its history and members are then pulled
+    // in by the new group canister
+    let mut c2c_args = C2cArgs {
+        user_id,
+        user_principal: caller,
+        channel_id,
+        total_bytes: 0,
+        is_public: chat.is_public.value,
+        name: chat.name.value.clone(),
+        description: chat.description.value.clone(),
+        rules: chat.rules.value.clone().into(),
+        subtype: chat.subtype.value.clone(),
+        avatar: chat.avatar.value.clone(),
+        history_visible_to_new_joiners: chat.history_visible_to_new_joiners,
+        permissions_v2: Some(chat.permissions.value.clone()),
+        events_ttl: chat.events.get_events_time_to_live().value,
+        gate: chat.gate.value.clone(),
+    };
+
+    c2c_args.total_bytes = start_channel_move(channel_id, user_id, None, state);
+
+    Ok(PrepareResult {
+        group_index_canister_id: state.data.group_index_canister_id,
+        c2c_args,
+    })
+}
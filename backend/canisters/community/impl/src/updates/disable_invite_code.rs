@@ -1,11 +1,12 @@
 use crate::{
-    activity_notifications::handle_activity_notification, model::events::CommunityEventInternal, mutate_state,
-    run_regular_jobs, RuntimeState,
+    activity_notifications::handle_activity_notification,
+    model::{audit_log::to_json, events::CommunityEventInternal},
+    mutate_state, run_regular_jobs, RuntimeState,
 };
 use canister_tracing_macros::trace;
 use community_canister::disable_invite_code::{Response::*, *};
 use ic_cdk_macros::update;
-use types::{GroupInviteCodeChange, GroupInviteCodeChanged};
+use types::{AuditLogActionType, GroupInviteCodeChange, GroupInviteCodeChanged};
 
 #[update]
 #[trace]
@@ -30,6 +31,15 @@ fn disable_invite_code_impl(state: &mut RuntimeState) -> Response {
             state.data.invite_code_enabled = false;
 
             let now = state.env.now();
+            state.data.audit_log.push(
+                member.user_id,
+                AuditLogActionType::InviteCodeChanged,
+                None,
+                Vec::new(),
+                None,
+                to_json(&GroupInviteCodeChange::Disabled),
+                now,
+            );
             state.data.events.push_event(
                 CommunityEventInternal::InviteCodeChanged(Box::new(GroupInviteCodeChanged {
                     change: GroupInviteCodeChange::Disabled,
@@ -3,13 +3,20 @@ use canister_tracing_macros::trace;
 use chat_events::{EditMessageArgs, EditMessageResult};
 use community_canister::edit_message::{Response::*, *};
 use ic_cdk_macros::update;
+use instruction_counts_log::InstructionCountFunctionId;
 
 #[update]
 #[trace]
 fn edit_message(args: Args) -> Response {
     run_regular_jobs();
 
-    mutate_state(|state| edit_message_impl(args, state))
+    mutate_state(|state| {
+        let response = edit_message_impl(args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::EditMessage, state.env.now());
+        response
+    })
 }
 
 fn edit_message_impl(args: Args, state: &mut RuntimeState) -> Response {
@@ -1,4 +1,5 @@
 use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
 use crate::model::events::CommunityEventInternal;
 use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
 use candid::Principal;
@@ -8,7 +9,7 @@ use community_canister::reset_invite_code;
 use ic_cdk_macros::update;
 use rand::rngs::StdRng;
 use rand::{RngCore, SeedableRng};
-use types::{GroupInviteCodeChange, GroupInviteCodeChanged};
+use types::{AuditLogActionType, GroupInviteCodeChange, GroupInviteCodeChanged};
 use utils::canister;
 
 #[update]
@@ -68,6 +69,15 @@ fn record_event(caller: Principal, change: GroupInviteCodeChange, state: &mut Ru
     let now = state.env.now();
 
     if let Some(participant) = state.data.members.get(caller) {
+        state.data.audit_log.push(
+            participant.user_id,
+            AuditLogActionType::InviteCodeChanged,
+            None,
+            Vec::new(),
+            None,
+            to_json(&change),
+            now,
+        );
         state.data.events.push_event(
             CommunityEventInternal::InviteCodeChanged(Box::new(GroupInviteCodeChanged {
                 change,
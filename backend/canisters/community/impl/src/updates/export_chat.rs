@@ -0,0 +1,76 @@
+use crate::{read_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use chat_export::{build_export, ChatExport, ExportedChat, ExportedChatKind};
+use community_canister::export_chat::{Response::*, *};
+use ic_cdk_macros::update;
+use types::CanisterId;
+use user_index_canister::users_v2::UserGroup;
+
+const MAX_MESSAGES_PER_PAGE: usize = 500;
+
+#[update]
+#[trace]
+async fn export_chat(args: Args) -> Response {
+    run_regular_jobs();
+
+    let (mut export, user_index_canister_id) = match read_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    let users_args = user_index_canister::users_v2::Args {
+        user_groups: vec![UserGroup {
+            users: export.user_ids().into_iter().collect(),
+            updated_since: 0,
+        }],
+    };
+    // If the usernames can't be resolved the export still succeeds, with users identified by their ids
+    if let Ok(user_index_canister::users_v2::Response::Success(result)) =
+        user_index_canister_c2c_client::users_v2(user_index_canister_id, &users_args).await
+    {
+        for user in result.users {
+            export.set_user_details(user.user_id, user.username, user.display_name);
+        }
+    }
+
+    Success(SuccessResult {
+        data: chat_export::render(&export, args.format),
+        next_message_index: export.next_message_index,
+        next_thread_message_index: export.next_thread_message_index,
+    })
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<(ChatExport, CanisterId), Response> {
+    let caller = state.env.caller();
+    let user_id = state.data.members.get(caller).map(|m| m.user_id);
+
+    if user_id.is_none() && (!state.data.is_public || state.data.has_payment_gate()) {
+        return Err(UserNotInCommunity);
+    }
+
+    if let Some(channel) = state.data.channels.get(&args.channel_id) {
+        if let Some(min_visible_event_index) = channel.chat.min_visible_event_index(user_id) {
+            let chat = ExportedChat {
+                kind: ExportedChatKind::Channel,
+                id: format!("{}/{}", state.env.canister_id(), args.channel_id),
+                name: format!("{} / {}", state.data.name, channel.chat.name.value),
+            };
+
+            let export = build_export(
+                &channel.chat.events,
+                chat,
+                min_visible_event_index,
+                args.from_message_index,
+                args.from_thread_message_index,
+                (args.max_messages as usize).min(MAX_MESSAGES_PER_PAGE),
+                state.env.now(),
+            );
+
+            Ok((export, state.data.user_index_canister_id))
+        } else {
+            Err(UserNotInChannel)
+        }
+    } else {
+        Err(ChannelNotFound)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::model::groups_being_imported::SourceChannel;
+use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::import_channel::{Response::*, *};
+use group_index_canister::c2c_start_importing_channel_into_community::Response as C2cResponse;
+use ic_cdk_macros::update;
+use rand::Rng;
+use types::{CanisterId, ChannelId, UserId};
+
+#[update]
+#[trace]
+async fn import_channel(args: Args) -> Response {
+    run_regular_jobs();
+
+    let PrepareResult {
+        group_index_canister_id,
+        user_id,
+    } = match read_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    match group_index_canister_c2c_client::c2c_start_importing_channel_into_community(
+        group_index_canister_id,
+        &group_index_canister::c2c_start_importing_channel_into_community::Args {
+            user_id,
+            community_id: args.community_id,
+            channel_id: args.channel_id,
+        },
+    )
+    .await
+    {
+        Ok(C2cResponse::Success(total_bytes)) => mutate_state(|state| {
+            let channel_id = state.env.rng().gen();
+            commit_channel_to_import(user_id, (args.community_id, args.channel_id), channel_id, total_bytes, state)
+        }),
+        Ok(C2cResponse::CommunityNotFound) => SourceCommunityNotFound,
+        Ok(C2cResponse::UserNotInCommunity) => UserNotInSourceCommunity,
+        Ok(C2cResponse::UserNotCommunityOwner) => UserNotSourceCommunityOwner,
+        Ok(C2cResponse::UserNotChannelOwner) => UserNotChannelOwner,
+        Ok(C2cResponse::UserSuspended) => UserSuspended,
+        Ok(C2cResponse::ChannelNotFound) => ChannelNotFound,
+        Ok(C2cResponse::ChannelAlreadyBeingMoved) => ChannelAlreadyBeingMoved,
+        Ok(C2cResponse::CommunityFrozen) => SourceCommunityFrozen,
+        Ok(C2cResponse::InternalError(error)) => InternalError(error),
+        Err(error) => InternalError(format!("{error:?}")),
+    }
+}
+
+struct PrepareResult {
+    group_index_canister_id: CanisterId,
+    user_id: UserId,
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response> {
+    if state.data.is_frozen() {
+        return Err(CommunityFrozen);
+    }
+
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if member.suspended.value {
+            Err(UserSuspended)
+        } else if !member.role.is_owner() {
+            Err(UserNotCommunityOwner)
+        } else if state
+            .data
+            .channels_being_imported
+            .contains(&(args.community_id, args.channel_id))
+        {
+            Err(ChannelAlreadyBeingMoved)
+        } else {
+            Ok(PrepareResult {
+                group_index_canister_id: state.data.group_index_canister_id,
+                user_id: member.user_id,
+            })
+        }
+    } else {
+        Err(UserNotInCommunity)
+    }
+}
+
+fn commit_channel_to_import(
+    user_id: UserId,
+    source: SourceChannel,
+    channel_id: ChannelId,
+    total_bytes: u64,
+    state: &mut RuntimeState,
+) -> Response {
+    let now = state.env.now();
+
+    if state
+        .data
+        .channels_being_imported
+        .add(source, channel_id, user_id, total_bytes, now, false)
+    {
+        crate::jobs::import_channels::start_job_if_required(state);
+
+        Success(SuccessResult { channel_id, total_bytes })
+    } else {
+        ChannelAlreadyBeingMoved
+    }
+}
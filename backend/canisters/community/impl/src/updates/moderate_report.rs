@@ -0,0 +1,111 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::timer_job_types::{TimerJob, UnmuteChannelMemberJob};
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::moderate_report::{Response::*, *};
+use group_chat_core::{CanModerateReportResult, RemoveMemberResult};
+use ic_cdk_macros::update;
+use serde_json::json;
+use types::{AuditLogActionType, ModerationAction};
+
+#[update]
+#[trace]
+fn moderate_report(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| moderate_report_impl(args, state))
+}
+
+fn moderate_report_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let user_id = match state.data.members.get(caller) {
+        Some(m) if m.suspended.value => return UserSuspended,
+        Some(m) => m.user_id,
+        None => return UserNotInCommunity,
+    };
+
+    let channel = match state.data.channels.get_mut(&args.channel_id) {
+        Some(c) => c,
+        None => return ChannelNotFound,
+    };
+
+    let sender = match channel.chat.can_moderate_report(user_id, args.report_id) {
+        CanModerateReportResult::Yes(sender) => sender,
+        result => return map_error(result),
+    };
+
+    let now = state.env.now();
+
+    if matches!(args.action, ModerationAction::RemoveSender | ModerationAction::BlockSender) {
+        let block = matches!(args.action, ModerationAction::BlockSender);
+
+        // Offenders are only removed from the channel, channel admins can't remove members from the community
+        match channel.chat.remove_member(user_id, sender, block, now) {
+            RemoveMemberResult::Success => {
+                if let Some(member) = state.data.members.get_by_user_id_mut(&sender) {
+                    member.leave(channel.id, now);
+                }
+            }
+            // The sender may have already left the channel
+            RemoveMemberResult::TargetUserNotInGroup => {}
+            RemoveMemberResult::UserSuspended => return UserSuspended,
+            RemoveMemberResult::UserNotInGroup => return UserNotInChannel,
+            RemoveMemberResult::NotAuthorized | RemoveMemberResult::CannotRemoveSelf => return NotAuthorized,
+        }
+    }
+
+    let suspend_sender = matches!(args.action, ModerationAction::SuspendSender(_));
+
+    match channel
+        .chat
+        .moderate_report(user_id, args.report_id, args.action, args.notes, now)
+    {
+        CanModerateReportResult::Yes(sender) => {
+            if suspend_sender {
+                if let Some(muted_until) = channel.chat.members.muted_until(&sender, now) {
+                    let channel_id = args.channel_id;
+                    // Replace any job left over from a previous suspension of the same member
+                    state.data.timer_jobs.cancel_jobs(|j| {
+                        matches!(j, TimerJob::UnmuteChannelMember(job) if job.channel_id == channel_id && job.user_id == sender)
+                    });
+                    state.data.timer_jobs.enqueue_job(
+                        TimerJob::UnmuteChannelMember(UnmuteChannelMemberJob {
+                            channel_id,
+                            user_id: sender,
+                        }),
+                        muted_until,
+                        now,
+                    );
+                }
+            }
+            state.data.audit_log.push(
+                user_id,
+                AuditLogActionType::ReportModerated,
+                Some(args.channel_id),
+                vec![sender],
+                None,
+                Some(json!({ "report_id": args.report_id, "action": args.action }).to_string()),
+                now,
+            );
+            handle_activity_notification(state);
+            Success
+        }
+        result => map_error(result),
+    }
+}
+
+fn map_error(result: CanModerateReportResult) -> Response {
+    match result {
+        CanModerateReportResult::Yes(_) => Success,
+        CanModerateReportResult::UserSuspended => UserSuspended,
+        CanModerateReportResult::UserNotInGroup => UserNotInChannel,
+        CanModerateReportResult::NotAuthorized => NotAuthorized,
+        CanModerateReportResult::ReportNotFound => ReportNotFound,
+        CanModerateReportResult::AlreadyResolved => AlreadyResolved,
+        CanModerateReportResult::InvalidDuration => InvalidDuration,
+    }
+}
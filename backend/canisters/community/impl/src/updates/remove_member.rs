@@ -8,7 +8,7 @@ use fire_and_forget_handler::FireAndForgetHandler;
 use ic_cdk_macros::update;
 use local_user_index_canister_c2c_client::{lookup_user, LookupUserError};
 use msgpack::serialize_then_unwrap;
-use types::{CanisterId, MembersRemoved, UserId, UsersBlocked};
+use types::{AuditLogActionType, CanisterId, MembersRemoved, UserId, UsersBlocked};
 use user_canister::c2c_remove_from_community;
 
 #[update]
@@ -130,6 +130,16 @@ fn commit(user_id: UserId, block: bool, removed_by: UserId, state: &mut RuntimeS
     };
     state.data.events.push_event(event, now);
 
+    state.data.audit_log.push(
+        removed_by,
+        if block { AuditLogActionType::UsersBlocked } else { AuditLogActionType::MembersRemoved },
+        None,
+        vec![user_id],
+        None,
+        None,
+        now,
+    );
+
     handle_activity_notification(state);
 
     // Fire-and-forget call to notify the user canister
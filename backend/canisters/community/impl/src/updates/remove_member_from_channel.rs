@@ -3,6 +3,7 @@ use canister_tracing_macros::trace;
 use community_canister::remove_member_from_channel::{Response::*, *};
 use group_chat_core::RemoveMemberResult;
 use ic_cdk_macros::update;
+use types::AuditLogActionType;
 
 #[update]
 #[trace]
@@ -34,6 +35,15 @@ fn remove_member_from_channel_impl(args: Args, state: &mut RuntimeState) -> Resp
         match channel.chat.remove_member(user_id, args.user_id, false, now) {
             RemoveMemberResult::Success => {
                 target_member.leave(channel.id, now);
+                state.data.audit_log.push(
+                    user_id,
+                    AuditLogActionType::ChannelMemberRemoved,
+                    Some(args.channel_id),
+                    vec![args.user_id],
+                    None,
+                    None,
+                    now,
+                );
                 handle_activity_notification(state);
                 Success
             }
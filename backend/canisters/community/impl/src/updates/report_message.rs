@@ -3,9 +3,10 @@ use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
 use canister_tracing_macros::trace;
 use chat_events::Reader;
 use community_canister::report_message::{Response::*, *};
+use group_chat_core::ReportMessageResult;
 use group_index_canister::c2c_report_message;
 use ic_cdk_macros::update;
-use types::{CanisterId, MultiUserChat, UserId};
+use types::{CanisterId, ModerationAction, ModerationReportId, MultiUserChat, UserId};
 
 #[update]
 #[trace]
@@ -19,8 +20,15 @@ async fn report_message(args: Args) -> Response {
 
     match group_index_canister_c2c_client::c2c_report_message(group_index_canister, &c2c_args).await {
         Ok(result) => {
+            // Only queue the report once the platform moderators have accepted it
+            let report_id = if matches!(result, c2c_report_message::Response::InternalError(_)) {
+                None
+            } else {
+                mutate_state(|state| add_to_moderation_queue(&args, c2c_args.reporter, state))
+            };
+
             if args.delete {
-                mutate_state(|state| delete_message(&args, c2c_args.reporter, state));
+                mutate_state(|state| delete_message(&args, c2c_args.reporter, report_id, state));
             }
 
             match result {
@@ -89,16 +97,42 @@ fn build_c2c_args(args: &Args, state: &RuntimeState) -> Result<(c2c_report_messa
     }
 }
 
-fn delete_message(args: &Args, reporter: UserId, state: &mut RuntimeState) {
+fn add_to_moderation_queue(args: &Args, reporter: UserId, state: &mut RuntimeState) -> Option<ModerationReportId> {
+    let now = state.env.now();
+    let channel = state.data.channels.get_mut(&args.channel_id)?;
+
+    if let ReportMessageResult::Success(report_id) = channel.chat.report_message(
+        reporter,
+        args.thread_root_message_index,
+        args.message_id,
+        args.reason_code.unwrap_or_default(),
+        args.notes.clone(),
+        now,
+    ) {
+        Some(report_id)
+    } else {
+        None
+    }
+}
+
+fn delete_message(args: &Args, reporter: UserId, report_id: Option<ModerationReportId>, state: &mut RuntimeState) {
     if let Some(channel) = state.data.channels.get_mut(&args.channel_id) {
-        if let group_chat_core::DeleteMessagesResult::Success(results) = channel.chat.delete_messages(
-            reporter,
-            args.thread_root_message_index,
-            vec![args.message_id],
-            false,
-            state.env.now(),
-        ) {
+        let now = state.env.now();
+
+        if let group_chat_core::DeleteMessagesResult::Success(results) =
+            channel
+                .chat
+                .delete_messages(reporter, args.thread_root_message_index, vec![args.message_id], false, now)
+        {
             if matches!(results[0].1, chat_events::DeleteMessageResult::Success(_)) {
+                // The reporter has already dealt with the message so there is nothing left for the moderators to do
+                if let Some(report_id) = report_id {
+                    channel
+                        .chat
+                        .moderation_queue
+                        .resolve(report_id, ModerationAction::DeleteMessage, reporter, None, now);
+                }
+
                 handle_activity_notification(state);
             }
         }
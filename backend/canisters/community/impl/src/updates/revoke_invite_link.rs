@@ -0,0 +1,61 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::revoke_invite_link::{Response::*, *};
+use ic_cdk_macros::update;
+use types::AuditLogActionType;
+
+#[update]
+#[trace]
+fn revoke_invite_link(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| revoke_invite_link_impl(args, state))
+}
+
+fn revoke_invite_link_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let member = match state.data.members.get(caller) {
+        Some(m) if m.suspended.value => return UserSuspended,
+        Some(m) => m,
+        None => return UserNotInCommunity,
+    };
+    let user_id = member.user_id;
+
+    let revoked = if let Some(channel_id) = args.channel_id {
+        if let Some(channel) = state.data.channels.get_mut(&channel_id) {
+            match channel.chat.members.get(&user_id) {
+                Some(m) if m.role.can_invite_users(&channel.chat.permissions) => channel.invite_links.revoke(args.code),
+                Some(_) => return NotAuthorized,
+                None => return UserNotInChannel,
+            }
+        } else {
+            return ChannelNotFound;
+        }
+    } else if member.role.can_invite_users(&state.data.permissions) {
+        state.data.invite_links.revoke(args.code)
+    } else {
+        return NotAuthorized;
+    };
+
+    if revoked {
+        let now = state.env.now();
+        state.data.audit_log.push(
+            user_id,
+            AuditLogActionType::InviteLinkRevoked,
+            args.channel_id,
+            Vec::new(),
+            Some(args.code.to_string()),
+            None,
+            now,
+        );
+        handle_activity_notification(state);
+        Success
+    } else {
+        LinkNotFound
+    }
+}
@@ -8,13 +8,14 @@ use canister_timer_jobs::TimerJobs;
 use canister_tracing_macros::trace;
 use community_canister::send_message::{Response::*, *};
 use group_chat_core::SendMessageResult;
+use instruction_counts_log::InstructionCountFunctionId;
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex_lite::Regex;
 use std::str::FromStr;
 use types::{
-    ChannelId, ChannelMessageNotification, EventWrapper, Message, MessageContent, MessageContentInitial, MessageIndex,
-    Notification, TimestampMillis, UserId,
+    ChannelId, ChannelMessageNotification, ContentFilterAction, ContentFilterMatch, EmojiPackKind, EventWrapper, Message,
+    MessageContent, MessageContentInitial, MessageIndex, Notification, TimestampMillis, UserId,
 };
 
 #[update_candid_and_msgpack]
@@ -22,10 +23,16 @@ use types::{
 fn send_message(args: Args) -> Response {
     run_regular_jobs();
 
-    mutate_state(|state| send_message_impl(args, state))
+    mutate_state(|state| {
+        let response = send_message_impl(args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::SendMessage, state.env.now());
+        response
+    })
 }
 
-fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
+fn send_message_impl(mut args: Args, state: &mut RuntimeState) -> Response {
     if state.data.is_frozen() {
         return CommunityFrozen;
     }
@@ -51,6 +58,19 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
         return CommunityRulesNotAccepted;
     }
 
+    if let MessageContentInitial::Sticker(sticker) = &mut args.content {
+        // The image is stored in the message so that it doesn't change if the shortcode is later reassigned
+        if let Some(asset) = state
+            .data
+            .emoji_packs
+            .get_asset(sticker.pack_id, EmojiPackKind::Sticker, &sticker.shortcode)
+        {
+            sticker.blob_reference = Some(asset.blob_reference.clone());
+        } else {
+            return InvalidRequest("Sticker not found".to_string());
+        }
+    }
+
     if let Some(channel) = state.data.channels.get_mut(&args.channel_id) {
         let user_id = member.user_id;
 
@@ -63,6 +83,18 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
             .unique()
             .collect();
 
+        let forum_topic = match channel
+            .chat
+            .validate_forum_topic(args.thread_root_message_index, args.forum_topic)
+        {
+            Ok(topic) => topic,
+            Err(error) => return InvalidRequest(error),
+        };
+
+        // Provenance is only trusted when it is set by the sender's user canister, which looks the
+        // message up in the chat it was forwarded from
+        let forwarded_from = args.forwarded_from.filter(|_| caller == user_id.into());
+
         match channel.chat.send_message(
             user_id,
             args.thread_root_message_index,
@@ -71,8 +103,10 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
             args.replies_to,
             mentioned,
             args.forwarding,
+            forwarded_from,
             args.channel_rules_accepted,
             state.data.proposals_bot_user_id,
+            Some(&state.data.content_filters.value),
             now,
         ) {
             SendMessageResult::Success(result) => {
@@ -80,6 +114,10 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
                 let message_index = result.message_event.event.message_index;
                 let expires_at = result.message_event.expires_at;
 
+                if let Some(topic) = forum_topic {
+                    channel.chat.add_forum_topic(message_index, topic, now);
+                }
+
                 let mut is_next_event_to_expire = false;
                 if let Some(expiry) = expires_at {
                     is_next_event_to_expire = state.data.next_event_expiry.map_or(true, |ex| expiry < ex);
@@ -104,6 +142,7 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
                     .filter(|u| state.data.members.get_by_user_id(u).map_or(false, |m| !m.suspended.value))
                     .collect();
 
+                let channel_name = channel.chat.name.value.clone();
                 let content = &result.message_event.event.content;
                 let notification = Notification::ChannelMessage(ChannelMessageNotification {
                     community_id: state.env.canister_id().into(),
@@ -112,7 +151,7 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
                     message_index: result.message_event.event.message_index,
                     event_index: result.message_event.index,
                     community_name: state.data.name.clone(),
-                    channel_name: channel.chat.name.value.clone(),
+                    channel_name: channel_name.clone(),
                     sender: user_id,
                     sender_name: args.sender_name,
                     sender_display_name: member.display_name().value.clone().or(args.sender_display_name),
@@ -130,6 +169,17 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
                     crypto_transfer: content.notification_crypto_transfer_details(&args.mentioned),
                 });
                 state.push_notification(users_to_notify, notification);
+                state.request_link_previews(args.channel_id, args.thread_root_message_index, args.message_id, content);
+
+                if let Some(ContentFilterMatch {
+                    action: ContentFilterAction::DeleteAndWarn,
+                    reason,
+                }) = result.content_filter_match
+                {
+                    state
+                        .data
+                        .notify_message_deleted_by_content_filter(user_id, channel_name, reason);
+                }
 
                 handle_activity_notification(state);
 
@@ -138,6 +188,7 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
                     message_index,
                     timestamp: now,
                     expires_at,
+                    content_filter_match: result.content_filter_match,
                 })
             }
             SendMessageResult::ThreadMessageNotFound => ThreadMessageNotFound,
@@ -149,6 +200,9 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
             SendMessageResult::UserSuspended => UserSuspended,
             SendMessageResult::RulesNotAccepted => RulesNotAccepted,
             SendMessageResult::InvalidRequest(error) => InvalidRequest(error),
+            SendMessageResult::ContentRejected(reason) => ContentRejected(reason),
+            SendMessageResult::RateLimited(next_message_allowed) => RateLimited(next_message_allowed),
+            SendMessageResult::UserMuted(_) => NotAuthorized,
         }
     } else {
         ChannelNotFound
@@ -0,0 +1,77 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::set_content_filters::{Response::*, *};
+use group_chat_core::{validate_content_filters, SetContentFiltersResult};
+use ic_cdk_macros::update;
+use types::{AuditLogActionType, Timestamped};
+
+#[update]
+#[trace]
+fn set_content_filters(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| set_content_filters_impl(args, state))
+}
+
+fn set_content_filters_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let now = state.env.now();
+    let after = to_json(&args.content_filters);
+
+    let member = match state.data.members.get(caller) {
+        Some(m) => m,
+        None => return UserNotInCommunity,
+    };
+
+    if member.suspended.value {
+        return UserSuspended;
+    }
+
+    let user_id = member.user_id;
+    let before;
+
+    if let Some(channel_id) = args.channel_id {
+        if let Some(channel) = state.data.channels.get_mut(&channel_id) {
+            before = to_json(&channel.chat.content_filters.value);
+
+            match channel.chat.set_content_filters(user_id, args.content_filters, now) {
+                SetContentFiltersResult::Success => {}
+                SetContentFiltersResult::UserSuspended => return UserSuspended,
+                SetContentFiltersResult::UserNotInGroup => return UserNotInChannel,
+                SetContentFiltersResult::NotAuthorized => return NotAuthorized,
+                SetContentFiltersResult::InvalidContentFilters(error) => return InvalidContentFilters(error),
+            }
+        } else {
+            return ChannelNotFound;
+        }
+    } else {
+        if !member.role.can_update_details(&state.data.permissions) {
+            return NotAuthorized;
+        }
+        if let Err(error) = validate_content_filters(&args.content_filters) {
+            return InvalidContentFilters(error);
+        }
+
+        before = to_json(&state.data.content_filters.value);
+        state.data.content_filters = Timestamped::new(args.content_filters.into(), now);
+    }
+
+    state.data.audit_log.push(
+        user_id,
+        AuditLogActionType::ContentFiltersChanged,
+        args.channel_id,
+        Vec::new(),
+        before,
+        after,
+        now,
+    );
+
+    handle_activity_notification(state);
+    Success
+}
@@ -0,0 +1,81 @@
+use crate::model::chat_history_imports::ChatHistoryUpload;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::start_chat_history_import::{Response::*, *};
+use ic_cdk_macros::update;
+use rand::Rng;
+use types::ChannelId;
+use utils::text_validation::{validate_description, validate_group_name, NameValidationError};
+
+const MAX_EXPORT_BYTES: u64 = 32 * 1024 * 1024; // 32MB
+
+#[update]
+#[trace]
+fn start_chat_history_import(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| start_chat_history_import_impl(args, state))
+}
+
+fn start_chat_history_import_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let user_id = match state.data.members.get(caller) {
+        Some(m) if m.suspended.value => return UserSuspended,
+        Some(m) if m.role.is_owner() && !m.is_bot => m.user_id,
+        _ => return NotAuthorized,
+    };
+
+    if let Err(error) = validate_group_name(&args.channel_name, args.is_public, None) {
+        return match error {
+            NameValidationError::TooShort(s) => NameTooShort(s),
+            NameValidationError::TooLong(l) => NameTooLong(l),
+            NameValidationError::Reserved => NameReserved,
+        };
+    }
+    if let Err(error) = validate_description(&args.channel_description) {
+        return DescriptionTooLong(error);
+    }
+    if state.data.channels.is_name_taken(&args.channel_name)
+        || state.data.chat_history_imports.is_name_reserved(&args.channel_name)
+    {
+        return NameTaken;
+    }
+    if args.total_bytes > MAX_EXPORT_BYTES {
+        return ExportTooLarge(MAX_EXPORT_BYTES);
+    }
+    if state.data.chat_history_imports.is_upload_or_import_in_progress() {
+        return ImportInProgress;
+    }
+    if let Some(mapping) = args
+        .author_mappings
+        .iter()
+        .find(|m| state.data.members.get_by_user_id(&m.user_id).is_none())
+    {
+        return MappedUserNotInCommunity(mapping.user_id);
+    }
+
+    let channel_id: ChannelId = state.env.rng().gen();
+    let now = state.env.now();
+
+    state.data.chat_history_imports.start_upload(
+        channel_id,
+        ChatHistoryUpload {
+            started_by: user_id,
+            started: now,
+            source: args.source,
+            channel_name: args.channel_name,
+            channel_description: args.channel_description,
+            is_public: args.is_public,
+            author_mappings: args.author_mappings.into_iter().map(|m| (m.author_id, m.user_id)).collect(),
+            media: args.media.into_iter().map(|m| (m.path.clone(), m)).collect(),
+            total_bytes: args.total_bytes,
+            bytes: Vec::new(),
+        },
+    );
+
+    Success(SuccessResult { channel_id })
+}
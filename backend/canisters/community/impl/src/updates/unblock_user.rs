@@ -5,7 +5,7 @@ use crate::{mutate_state, run_regular_jobs, RuntimeState};
 use canister_tracing_macros::trace;
 use community_canister::unblock_user::*;
 use ic_cdk_macros::update;
-use types::UsersUnblocked;
+use types::{AuditLogActionType, UsersUnblocked};
 
 #[update]
 #[trace]
@@ -47,6 +47,16 @@ fn unblock_user_impl(args: Args, state: &mut RuntimeState) -> Response {
                 .events
                 .push_event(CommunityEventInternal::UsersUnblocked(Box::new(event)), now);
 
+            state.data.audit_log.push(
+                unblocked_by,
+                AuditLogActionType::UsersUnblocked,
+                None,
+                vec![args.user_id],
+                None,
+                None,
+                now,
+            );
+
             handle_activity_notification(state);
 
             Success
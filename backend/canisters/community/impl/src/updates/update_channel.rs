@@ -1,9 +1,12 @@
+use crate::model::audit_log::to_json;
 use crate::updates::c2c_join_channel::join_channel_unchecked;
 use crate::{activity_notifications::handle_activity_notification, mutate_state, run_regular_jobs, RuntimeState};
 use canister_tracing_macros::trace;
 use community_canister::update_channel::{Response::*, *};
-use group_chat_core::UpdateResult;
+use group_chat_core::{GroupChatCore, UpdateResult};
 use ic_cdk_macros::update;
+use serde::Serialize;
+use types::{AccessGate, AuditLogActionType, Document, SlowMode};
 
 #[update]
 #[trace]
@@ -31,6 +34,10 @@ fn update_channel_impl(mut args: Args, state: &mut RuntimeState) -> Response {
 
         if let Some(member) = state.data.members.get(caller) {
             let now = state.env.now();
+            let user_id = member.user_id;
+            let details_before = to_json(&ChannelDetailsSnapshot::new(&channel.chat));
+            let permissions_before = to_json(&channel.chat.permissions.value);
+
             match channel.chat.update(
                 member.user_id,
                 args.name,
@@ -41,9 +48,37 @@ fn update_channel_impl(mut args: Args, state: &mut RuntimeState) -> Response {
                 args.gate,
                 args.public,
                 args.events_ttl,
+                args.slow_mode,
+                args.edit_history_enabled,
+                args.hide_forwarded_from,
                 now,
             ) {
                 UpdateResult::Success(result) => {
+                    let details_after = to_json(&ChannelDetailsSnapshot::new(&channel.chat));
+                    if details_after != details_before {
+                        state.data.audit_log.push(
+                            user_id,
+                            AuditLogActionType::ChannelDetailsChanged,
+                            Some(args.channel_id),
+                            Vec::new(),
+                            details_before,
+                            details_after,
+                            now,
+                        );
+                    }
+                    let permissions_after = to_json(&channel.chat.permissions.value);
+                    if permissions_after != permissions_before {
+                        state.data.audit_log.push(
+                            user_id,
+                            AuditLogActionType::ChannelPermissionsChanged,
+                            Some(args.channel_id),
+                            Vec::new(),
+                            permissions_before,
+                            permissions_after,
+                            now,
+                        );
+                    }
+
                     if result.newly_public && channel.chat.gate.is_none() {
                         for m in state.data.members.iter_mut() {
                             join_channel_unchecked(channel, m, true, now);
@@ -74,6 +109,33 @@ fn update_channel_impl(mut args: Args, state: &mut RuntimeState) -> Response {
     }
 }
 
+#[derive(Serialize)]
+struct ChannelDetailsSnapshot<'a> {
+    name: &'a str,
+    description: &'a str,
+    rules: &'a str,
+    rules_enabled: bool,
+    avatar_id: Option<u128>,
+    gate: &'a Option<AccessGate>,
+    public: bool,
+    slow_mode: &'a Option<SlowMode>,
+}
+
+impl<'a> ChannelDetailsSnapshot<'a> {
+    fn new(chat: &'a GroupChatCore) -> ChannelDetailsSnapshot<'a> {
+        ChannelDetailsSnapshot {
+            name: &chat.name.value,
+            description: &chat.description.value,
+            rules: &chat.rules.value.text.value,
+            rules_enabled: chat.rules.value.enabled,
+            avatar_id: Document::id(&chat.avatar.value),
+            gate: &chat.gate.value,
+            public: chat.is_public.value,
+            slow_mode: &chat.slow_mode.value,
+        }
+    }
+}
+
 fn clean_args(args: &mut Args) {
     args.name = args.name.as_ref().map(|name| name.trim().to_string());
     args.description = args.description.as_ref().map(|desc| desc.trim().to_string());
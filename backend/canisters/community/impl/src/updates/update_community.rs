@@ -1,15 +1,17 @@
 use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
 use crate::model::events::CommunityEventInternal;
 use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
 use canister_tracing_macros::trace;
 use community_canister::update_community::{Response::*, *};
 use group_index_canister::{c2c_make_community_private, c2c_update_community};
 use ic_cdk_macros::update;
+use serde::Serialize;
 use tracing::error;
 use types::{
-    AccessGate, AvatarChanged, BannerChanged, CanisterId, CommunityId, CommunityPermissions, CommunityPermissionsChanged,
-    Document, GroupDescriptionChanged, GroupGateUpdated, GroupNameChanged, GroupRulesChanged, GroupVisibilityChanged,
-    OptionalCommunityPermissions, PrimaryLanguageChanged, Timestamped, UserId,
+    AccessGate, AuditLogActionType, AvatarChanged, BannerChanged, CanisterId, CommunityId, CommunityPermissions,
+    CommunityPermissionsChanged, Document, GroupDescriptionChanged, GroupGateUpdated, GroupNameChanged, GroupRulesChanged,
+    GroupVisibilityChanged, OptionalCommunityPermissions, PrimaryLanguageChanged, Timestamped, UserId,
 };
 use utils::document_validation::{validate_avatar, validate_banner};
 use utils::text_validation::{
@@ -189,6 +191,7 @@ fn commit(my_user_id: UserId, args: Args, state: &mut RuntimeState) -> SuccessRe
     let mut result = SuccessResult { rules_version: None };
 
     let now = state.env.now();
+    let details_before = CommunityDetailsSnapshot::new(state);
     let events = &mut state.data.events;
 
     if let Some(name) = args.name {
@@ -284,6 +287,16 @@ fn commit(my_user_id: UserId, args: Args, state: &mut RuntimeState) -> SuccessRe
         let new_permissions = merge_permissions(permissions, &old_permissions);
         state.data.permissions = new_permissions.clone();
 
+        state.data.audit_log.push(
+            my_user_id,
+            AuditLogActionType::CommunityPermissionsChanged,
+            None,
+            Vec::new(),
+            to_json(&old_permissions),
+            to_json(&new_permissions),
+            now,
+        );
+
         state.data.events.push_event(
             CommunityEventInternal::PermissionsChanged(Box::new(CommunityPermissionsChanged {
                 old_permissions,
@@ -342,10 +355,52 @@ fn commit(my_user_id: UserId, args: Args, state: &mut RuntimeState) -> SuccessRe
         }
     }
 
+    let details_after = CommunityDetailsSnapshot::new(state);
+    if details_after != details_before {
+        state.data.audit_log.push(
+            my_user_id,
+            AuditLogActionType::CommunityDetailsChanged,
+            None,
+            Vec::new(),
+            to_json(&details_before),
+            to_json(&details_after),
+            now,
+        );
+    }
+
     handle_activity_notification(state);
     result
 }
 
+#[derive(Serialize, PartialEq)]
+struct CommunityDetailsSnapshot {
+    name: String,
+    description: String,
+    rules: String,
+    rules_enabled: bool,
+    avatar_id: Option<u128>,
+    banner_id: Option<u128>,
+    gate: Option<AccessGate>,
+    public: bool,
+    primary_language: String,
+}
+
+impl CommunityDetailsSnapshot {
+    fn new(state: &RuntimeState) -> CommunityDetailsSnapshot {
+        CommunityDetailsSnapshot {
+            name: state.data.name.clone(),
+            description: state.data.description.clone(),
+            rules: state.data.rules.text.value.clone(),
+            rules_enabled: state.data.rules.enabled,
+            avatar_id: Document::id(&state.data.avatar),
+            banner_id: Document::id(&state.data.banner),
+            gate: state.data.gate.value.clone(),
+            public: state.data.is_public,
+            primary_language: state.data.primary_language.clone(),
+        }
+    }
+}
+
 fn merge_permissions(new: OptionalCommunityPermissions, old: &CommunityPermissions) -> CommunityPermissions {
     CommunityPermissions {
         change_roles: new.change_roles.unwrap_or(old.change_roles),
@@ -355,5 +410,6 @@ fn merge_permissions(new: OptionalCommunityPermissions, old: &CommunityPermissio
         create_public_channel: new.create_public_channel.unwrap_or(old.create_public_channel),
         create_private_channel: new.create_private_channel.unwrap_or(old.create_private_channel),
         manage_user_groups: new.manage_user_groups.unwrap_or(old.manage_user_groups),
+        manage_emoji_packs: new.manage_emoji_packs.unwrap_or(old.manage_emoji_packs),
     }
 }
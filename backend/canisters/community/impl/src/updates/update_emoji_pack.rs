@@ -0,0 +1,63 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
+use crate::model::emoji_packs::EmojiPackError;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::update_emoji_pack::{Response::*, *};
+use ic_cdk_macros::update;
+use types::AuditLogActionType;
+
+#[update]
+#[trace]
+fn update_emoji_pack(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| update_emoji_pack_impl(args, state))
+}
+
+fn update_emoji_pack_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    match state.data.members.get(caller) {
+        Some(m) if m.suspended.value => UserSuspended,
+        Some(m) if m.role.can_manage_emoji_packs(&state.data.permissions) => {
+            let now = state.env.now();
+            let user_id = m.user_id;
+            let before = state.data.emoji_packs.get(args.pack_id).and_then(to_json);
+
+            match state
+                .data
+                .emoji_packs
+                .update(args.pack_id, args.name, args.assets_to_add, args.shortcodes_to_remove, now)
+            {
+                Ok(files_to_delete) => {
+                    let after = state.data.emoji_packs.get(args.pack_id).and_then(to_json);
+                    state.data.audit_log.push(
+                        user_id,
+                        AuditLogActionType::EmojiPackUpdated,
+                        None,
+                        Vec::new(),
+                        before,
+                        after,
+                        now,
+                    );
+                    if !files_to_delete.is_empty() {
+                        ic_cdk::spawn(storage_bucket_client::delete_files(files_to_delete));
+                    }
+                    handle_activity_notification(state);
+                    Success
+                }
+                Err(EmojiPackError::PackNotFound) => PackNotFound,
+                Err(EmojiPackError::NameInvalid) => NameInvalid,
+                Err(EmojiPackError::InvalidShortcode(s)) => InvalidShortcode(s),
+                Err(EmojiPackError::DuplicateShortcode(s)) => DuplicateShortcode(s),
+                Err(EmojiPackError::TooManyAssets(max)) => TooManyAssets(max),
+                Err(EmojiPackError::TooManyPacks(_)) => unreachable!(),
+            }
+        }
+        _ => NotAuthorized,
+    }
+}
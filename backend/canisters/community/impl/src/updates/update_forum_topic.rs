@@ -0,0 +1,57 @@
+use crate::{activity_notifications::handle_activity_notification, mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use community_canister::update_forum_topic::{Response::*, *};
+use group_chat_core::UpdateForumTopicResult;
+use ic_cdk_macros::update;
+
+#[update]
+#[trace]
+fn update_forum_topic(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| update_forum_topic_impl(args, state))
+}
+
+fn update_forum_topic_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    if let Some(member) = state.data.members.get(caller) {
+        if member.suspended.value {
+            return UserSuspended;
+        }
+
+        let user_id = member.user_id;
+
+        if let Some(channel) = state.data.channels.get_mut(&args.channel_id) {
+            let now = state.env.now();
+
+            match channel.chat.update_forum_topic(
+                user_id,
+                args.root_message_index,
+                args.title,
+                args.tags,
+                args.solved,
+                args.answer,
+                now,
+            ) {
+                UpdateForumTopicResult::Success => {
+                    handle_activity_notification(state);
+                    Success
+                }
+                UpdateForumTopicResult::UserNotInGroup => UserNotInChannel,
+                UpdateForumTopicResult::UserSuspended => UserSuspended,
+                UpdateForumTopicResult::NotForum => NotForum,
+                UpdateForumTopicResult::TopicNotFound => TopicNotFound,
+                UpdateForumTopicResult::NotAuthorized => NotAuthorized,
+                UpdateForumTopicResult::InvalidRequest(error) => InvalidRequest(error),
+            }
+        } else {
+            ChannelNotFound
+        }
+    } else {
+        UserNotInCommunity
+    }
+}
@@ -1,8 +1,10 @@
 use crate::activity_notifications::handle_activity_notification;
+use crate::model::audit_log::to_json;
 use crate::{mutate_state, run_regular_jobs, RuntimeState};
 use canister_tracing_macros::trace;
 use community_canister::update_user_group::{Response::*, *};
 use ic_cdk_macros::update;
+use types::{AuditLogActionType, UserGroupDetails};
 use utils::text_validation::{validate_user_group_name, UsernameValidationError};
 
 #[update]
@@ -34,12 +36,33 @@ fn update_user_group_impl(args: Args, state: &mut RuntimeState) -> Response {
             }
         } else {
             let now = state.env.now();
+            let user_id = member.user_id;
+            let before = state
+                .data
+                .members
+                .get_user_group(args.user_group_id)
+                .map(UserGroupDetails::from);
+            let targets: Vec<_> = args.users_to_add.iter().chain(args.users_to_remove.iter()).copied().collect();
 
             if state
                 .data
                 .members
                 .update_user_group(args.user_group_id, args.name, args.users_to_add, args.users_to_remove, now)
             {
+                let after = state
+                    .data
+                    .members
+                    .get_user_group(args.user_group_id)
+                    .map(UserGroupDetails::from);
+                state.data.audit_log.push(
+                    user_id,
+                    AuditLogActionType::UserGroupUpdated,
+                    None,
+                    targets,
+                    before.as_ref().and_then(to_json),
+                    after.as_ref().and_then(to_json),
+                    now,
+                );
                 handle_activity_notification(state);
                 Success
             } else {
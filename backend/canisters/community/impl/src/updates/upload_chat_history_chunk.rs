@@ -0,0 +1,113 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::model::channels::Channel;
+use crate::model::chat_history_imports::AppendChunkResult;
+use crate::updates::c2c_join_channel::join_channel_unchecked;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use chat_import::ExportReader;
+use community_canister::upload_chat_history_chunk::{Response::*, *};
+use group_chat_core::GroupChatCore;
+use group_community_common::InviteLinks;
+use ic_cdk_macros::update;
+use types::{ChannelId, GroupPermissions, Rules};
+
+#[update]
+#[trace]
+fn upload_chat_history_chunk(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| upload_chat_history_chunk_impl(args, state))
+}
+
+fn upload_chat_history_chunk_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return CommunityFrozen;
+    }
+
+    let caller = state.env.caller();
+    let user_id = match state.data.members.get(caller) {
+        Some(m) => m.user_id,
+        None => return NotAuthorized,
+    };
+
+    match state
+        .data
+        .chat_history_imports
+        .append_chunk(args.channel_id, user_id, args.offset, &args.bytes)
+    {
+        AppendChunkResult::Success => Success,
+        AppendChunkResult::Complete => start_import(args.channel_id, state),
+        AppendChunkResult::NotFound => ImportNotFound,
+        AppendChunkResult::NotAuthorized => NotAuthorized,
+        AppendChunkResult::InvalidOffset(expected) => InvalidOffset(expected),
+        AppendChunkResult::TooManyBytes => TooManyBytes,
+    }
+}
+
+// The upload is only removed once the channel has been created, so if this fails the import can be
+// retried by uploading an empty chunk at the final offset
+fn start_import(channel_id: ChannelId, state: &mut RuntimeState) -> Response {
+    let upload = match state.data.chat_history_imports.upload(&channel_id) {
+        Some(u) => u,
+        None => return ImportNotFound,
+    };
+
+    // Only the start of the export is read here, the messages are parsed in batches by the
+    // 'import_chat_history' job
+    let reader = match ExportReader::new(upload.source, &upload.bytes) {
+        Ok(reader) => reader,
+        Err(error) => return InvalidExport(error),
+    };
+
+    if state.data.channels.is_name_taken(&upload.channel_name) {
+        return NameTaken;
+    }
+
+    let now = state.env.now();
+    let member = match state.data.members.get_mut(upload.started_by.into()) {
+        Some(m) => m,
+        None => return NotAuthorized,
+    };
+
+    let is_public = upload.is_public;
+    let chat = GroupChatCore::new(
+        member.user_id,
+        is_public,
+        upload.channel_name.clone(),
+        upload.channel_description.clone(),
+        Rules::default(),
+        None,
+        None,
+        true,
+        GroupPermissions::default(),
+        None,
+        None,
+        // Bots can't start imports, see `start_chat_history_import`
+        false,
+        now,
+    );
+
+    member.channels.insert(channel_id);
+
+    let mut channel = Channel {
+        id: channel_id,
+        chat,
+        date_imported: None, // This is set once all the messages have been imported
+        invite_links: InviteLinks::default(),
+    };
+
+    if is_public {
+        for m in state.data.members.iter_mut() {
+            join_channel_unchecked(&mut channel, m, true, now);
+        }
+    }
+
+    state.data.channels.add(channel);
+
+    state.data.chat_history_imports.start_import(channel_id, reader);
+
+    crate::jobs::import_chat_history::start_job_if_required(state);
+    handle_activity_notification(state);
+
+    ImportStarted(ImportStartedResult { channel_id })
+}
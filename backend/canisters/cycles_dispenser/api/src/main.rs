@@ -2,6 +2,8 @@ use candid_gen::generate_candid_method;
 
 #[allow(deprecated)]
 fn main() {
+    generate_candid_method!(cycles_dispenser, cycles_forecast, query);
+
     generate_candid_method!(cycles_dispenser, add_canister, update);
     generate_candid_method!(cycles_dispenser, c2c_request_cycles, update);
     generate_candid_method!(cycles_dispenser, update_config, update);
@@ -0,0 +1,45 @@
+use candid::CandidType;
+use ic_ledger_types::Tokens;
+use serde::{Deserialize, Serialize};
+use types::{CanisterId, Cycles, Empty, TimestampMillis};
+
+pub type Args = Empty;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub timestamp: TimestampMillis,
+    pub dispenser: DispenserForecast,
+    pub canisters: Vec<CanisterForecast>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct DispenserForecast {
+    pub cycles_balance: Cycles,
+    pub min_cycles_balance: Cycles,
+    // The sum of the burn rates of all canisters whose burn rate could be estimated
+    pub cycles_dispensed_per_day: Cycles,
+    // Days until the cycles balance falls to `min_cycles_balance` if no more ICP is burned
+    pub runway_days: Option<f64>,
+    pub icp_balance: Option<Tokens>,
+    pub icp_balance_updated: Option<TimestampMillis>,
+    // Taken from the most recent time ICP was burned into cycles
+    pub cycles_per_icp: Option<Cycles>,
+    // Days of runway once the remaining ICP has also been burned into cycles
+    pub runway_days_including_icp: Option<f64>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct CanisterForecast {
+    pub canister_id: CanisterId,
+    pub burn_rate_per_day: Option<Cycles>,
+    pub estimated_cycles_balance: Option<Cycles>,
+    pub runway_days: Option<f64>,
+    pub next_top_up_due: Option<TimestampMillis>,
+    pub latest_top_up: Option<TimestampMillis>,
+    pub proactive_top_ups: u32,
+}
@@ -1 +1 @@
-
+pub mod cycles_forecast;
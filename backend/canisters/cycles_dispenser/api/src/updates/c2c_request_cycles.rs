@@ -5,6 +5,9 @@ use types::{Cycles, Milliseconds};
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct Args {
     pub amount: Option<Cycles>,
+    // The requesting canister's balance, used to forecast when it will next need topping up
+    #[serde(default)]
+    pub cycles_balance: Option<Cycles>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
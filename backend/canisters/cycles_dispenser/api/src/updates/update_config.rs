@@ -10,6 +10,7 @@ pub struct Args {
     pub min_interval: Option<Milliseconds>,
     pub min_cycles_balance: Option<Cycles>,
     pub icp_burn_amount: Option<Tokens>,
+    pub proactive_top_up_lead_time: Option<Milliseconds>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -6,7 +6,7 @@ use ic_ledger_types::{AccountIdentifier, BlockIndex, Memo, Subaccount, Timestamp
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use tracing::{error, info};
-use types::{CanisterId, Cycles, TimestampMillis};
+use types::{CanisterId, Cycles, TimestampMillis, Timestamped};
 use utils::canister_timers::run_now_then_interval;
 
 const INTERVAL: Duration = Duration::from_secs(300);
@@ -42,6 +42,8 @@ struct NotifyTopUpDetails {
     this_canister_id: CanisterId,
     cmc: CanisterId,
     block_index: BlockIndex,
+    // Not known when retrying a notification which previously failed
+    icp_burned: Option<Tokens>,
 }
 
 fn get_next_action(state: &mut State) -> Action {
@@ -50,6 +52,7 @@ fn get_next_action(state: &mut State) -> Action {
             this_canister_id: state.env.canister_id(),
             cmc: state.data.cycles_minting_canister,
             block_index,
+            icp_burned: None,
         })
     } else {
         let cycles_balance = state.env.cycles_balance();
@@ -94,6 +97,7 @@ async fn burn_icp(burn_details: BurnIcpDetails) {
                 this_canister_id: burn_details.this_canister_id,
                 cmc: burn_details.cmc,
                 block_index,
+                icp_burned: Some(burn_details.amount),
             })
             .await;
         }
@@ -122,6 +126,10 @@ async fn notify_cmc(notify_details: NotifyTopUpDetails) {
     match response {
         Ok(Ok(cycles)) => {
             info!(cycles, "Canister topped up with cycles");
+            if let Some(icp) = notify_details.icp_burned.filter(|t| t.e8s() > 0) {
+                let cycles_per_icp = cycles * Tokens::SUBDIVIDABLE_BY as Cycles / icp.e8s() as Cycles;
+                mutate_state(|state| state.data.cycles_per_icp = Some(Timestamped::new(cycles_per_icp, state.env.now())));
+            }
         }
         err => {
             error!(?err, "Failed to notify the CMC");
@@ -0,0 +1,94 @@
+use crate::{mutate_state, read_state, State};
+use ic_ledger_types::{AccountBalanceArgs, Tokens};
+use ledger_utils::default_ledger_account;
+use std::time::Duration;
+use tracing::{error, info};
+use types::{CanisterId, Cycles, Timestamped};
+use utils::canister::deposit_cycles;
+use utils::canister_timers::run_now_then_interval;
+
+const INTERVAL: Duration = Duration::from_secs(60 * 60); // 1 hour
+
+pub fn start_job() {
+    run_now_then_interval(INTERVAL, run);
+}
+
+fn run() {
+    ic_cdk::spawn(refresh_icp_balance());
+
+    let (to_top_up, amount) = mutate_state(prepare);
+    for canister_id in to_top_up {
+        ic_cdk::spawn(top_up_canister(canister_id, amount));
+    }
+}
+
+// Picks out the canisters which are forecast to run low before the job next runs plus the lead time, skipping
+// any which were topped up too recently or haven't reported their balance since their last proactive top-up, and
+// marks them as having a top-up in progress
+fn prepare(state: &mut State) -> (Vec<CanisterId>, Cycles) {
+    let now = state.env.now();
+    let amount = state.data.max_top_up_amount;
+    let lead_time = state.data.proactive_top_up_lead_time;
+    if lead_time == 0 {
+        return (Vec::new(), amount);
+    }
+
+    let due_before = now + lead_time + INTERVAL.as_millis() as u64;
+    let min_interval = state.data.min_interval;
+    let mut available = state.env.cycles_balance().saturating_sub(state.data.min_cycles_balance);
+
+    let candidates: Vec<_> = state
+        .data
+        .canisters
+        .iter()
+        .filter(|(_, c)| !c.top_up_in_progress() && c.can_top_up_proactively())
+        .filter(|(_, c)| !c.latest_top_up().is_some_and(|t| t + min_interval > now))
+        .filter(|(_, c)| c.next_top_up_due().is_some_and(|d| d <= due_before))
+        .map(|(id, _)| *id)
+        .collect();
+
+    let mut to_top_up = Vec::new();
+    for canister_id in candidates {
+        if available < amount {
+            break;
+        }
+        if let Some(canister) = state.data.canisters.get_mut(&canister_id) {
+            canister.set_top_up_in_progress(true);
+            available -= amount;
+            to_top_up.push(canister_id);
+        }
+    }
+    (to_top_up, amount)
+}
+
+async fn top_up_canister(canister_id: CanisterId, amount: Cycles) {
+    info!(%canister_id, amount, "Topping up canister proactively");
+
+    let result = deposit_cycles(canister_id, amount).await;
+
+    mutate_state(|state| {
+        if let Some(canister) = state.data.canisters.get_mut(&canister_id) {
+            canister.set_top_up_in_progress(false);
+            if result.is_ok() {
+                canister.record_top_up(amount, None, true, state.env.now());
+            }
+        }
+    });
+}
+
+async fn refresh_icp_balance() {
+    let (ledger, this_canister_id) = read_state(|state| (state.data.ledger_canister, state.env.canister_id()));
+
+    let args = AccountBalanceArgs {
+        account: default_ledger_account(this_canister_id),
+    };
+
+    match ic_ledger_types::account_balance(ledger, args).await {
+        Ok(balance) => mutate_state(|state| set_icp_balance(balance, state)),
+        Err(error) => error!(?error, "Failed to get ICP balance"),
+    }
+}
+
+fn set_icp_balance(balance: Tokens, state: &mut State) {
+    state.data.icp_balance = Some(Timestamped::new(balance, state.env.now()));
+}
@@ -8,6 +8,7 @@ use std::cell::RefCell;
 use std::collections::HashSet;
 use types::{BuildVersion, CanisterId, Cycles, Milliseconds, TimestampMillis, Timestamped};
 use utils::env::Environment;
+use utils::time::DAY_IN_MS;
 
 mod guards;
 mod jobs;
@@ -54,6 +55,7 @@ impl State {
             icp_burn_amount: self.data.icp_burn_amount,
             ledger_canister: self.data.ledger_canister,
             cycles_minting_canister: self.data.cycles_minting_canister,
+            proactive_top_up_lead_time: self.data.proactive_top_up_lead_time,
         }
     }
 }
@@ -70,6 +72,13 @@ struct Data {
     pub ledger_canister: CanisterId,
     pub cycles_minting_canister: CanisterId,
     pub cycles_top_up_pending_notification: Option<BlockIndex>,
+    // Canisters forecast to run low within this time are topped up without waiting for them to ask
+    #[serde(default = "default_proactive_top_up_lead_time")]
+    pub proactive_top_up_lead_time: Milliseconds,
+    #[serde(default)]
+    pub icp_balance: Option<Timestamped<Tokens>>,
+    #[serde(default)]
+    pub cycles_per_icp: Option<Timestamped<Cycles>>,
     #[serde(default)]
     pub rng_seed: [u8; 32],
     pub test_mode: bool,
@@ -100,12 +109,19 @@ impl Data {
             ledger_canister,
             cycles_minting_canister,
             cycles_top_up_pending_notification: None,
+            proactive_top_up_lead_time: default_proactive_top_up_lead_time(),
+            icp_balance: None,
+            cycles_per_icp: None,
             rng_seed: [0; 32],
             test_mode,
         }
     }
 }
 
+fn default_proactive_top_up_lead_time() -> Milliseconds {
+    DAY_IN_MS
+}
+
 #[derive(CandidType, Serialize, Debug)]
 pub struct Metrics {
     pub now: TimestampMillis,
@@ -123,4 +139,5 @@ pub struct Metrics {
     pub icp_burn_amount: Tokens,
     pub ledger_canister: CanisterId,
     pub cycles_minting_canister: CanisterId,
+    pub proactive_top_up_lead_time: Milliseconds,
 }
@@ -2,7 +2,11 @@ use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::Entry::Vacant;
 use std::collections::{BinaryHeap, HashMap};
-use types::{CanisterId, Cycles, TimestampMillis};
+use types::{CanisterId, Cycles, Milliseconds, TimestampMillis};
+use utils::time::DAY_IN_MS;
+
+// The burn rate is estimated from at most this many of each canister's most recent top-ups
+const MAX_TOP_UPS_FOR_BURN_RATE: usize = 10;
 
 #[derive(Serialize, Deserialize)]
 pub struct Canisters {
@@ -21,6 +25,9 @@ impl Canisters {
                             added: now,
                             top_ups: Vec::new(),
                             top_up_in_progress: false,
+                            balance_estimate: None,
+                            low_balance: None,
+                            balance_reported: None,
                         },
                     )
                 })
@@ -34,6 +41,9 @@ impl Canisters {
                 added: now,
                 top_ups: Vec::new(),
                 top_up_in_progress: false,
+                balance_estimate: None,
+                low_balance: None,
+                balance_reported: None,
             });
             true
         } else {
@@ -45,6 +55,10 @@ impl Canisters {
         self.canisters.get_mut(canister_id)
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = (&CanisterId, &Canister)> {
+        self.canisters.iter()
+    }
+
     pub fn metrics(&self) -> Vec<CanisterMetrics> {
         self.canisters
             .iter()
@@ -92,6 +106,22 @@ pub struct Canister {
     added: TimestampMillis,
     top_ups: Vec<CyclesTopUp>,
     top_up_in_progress: bool,
+    // The canister's balance immediately after its latest top-up, either derived from the balance it reported
+    // when requesting cycles or, for proactive top-ups, estimated from its burn rate
+    #[serde(default)]
+    balance_estimate: Option<BalanceEstimate>,
+    // The balance the canister reported the last time it requested cycles, ie. the level at which it runs low
+    #[serde(default)]
+    low_balance: Option<Cycles>,
+    // When the canister last reported its balance
+    #[serde(default)]
+    balance_reported: Option<TimestampMillis>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+struct BalanceEstimate {
+    timestamp: TimestampMillis,
+    cycles: Cycles,
 }
 
 impl Canister {
@@ -107,8 +137,97 @@ impl Canister {
         self.top_ups.last().map(|t| t.date)
     }
 
-    pub fn record_top_up(&mut self, amount: Cycles, now: TimestampMillis) {
-        self.top_ups.push(CyclesTopUp { date: now, amount });
+    pub fn record_top_up(&mut self, amount: Cycles, reported_balance: Option<Cycles>, proactive: bool, now: TimestampMillis) {
+        if let Some(balance) = reported_balance.or_else(|| self.estimated_balance(now)) {
+            self.balance_estimate = Some(BalanceEstimate {
+                timestamp: now,
+                cycles: balance + amount,
+            });
+        }
+        if reported_balance.is_some() {
+            self.low_balance = reported_balance;
+            self.balance_reported = Some(now);
+        }
+        self.top_ups.push(CyclesTopUp {
+            date: now,
+            amount,
+            proactive,
+        });
+    }
+
+    // Between two consecutive requested top-ups a canister burns roughly the cycles it was given in between,
+    // since each request is made once the balance has fallen back to the same low level. Proactive top-ups aren't
+    // made at that level so they can't bound the period, but the cycles they added were still burned within it.
+    pub fn burn_rate_per_day(&self) -> Option<Cycles> {
+        let requested: Vec<_> = self
+            .top_ups
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| !t.proactive)
+            .map(|(index, _)| index)
+            .collect();
+
+        let start = requested.len().saturating_sub(MAX_TOP_UPS_FOR_BURN_RATE);
+        let (first, last) = (*requested[start..].first()?, *requested.last()?);
+
+        let elapsed = self.top_ups[last].date.saturating_sub(self.top_ups[first].date);
+        if elapsed == 0 {
+            return None;
+        }
+
+        let burned: Cycles = self.top_ups[first..last].iter().map(|t| t.amount).sum();
+        Some(burned * DAY_IN_MS as Cycles / elapsed as Cycles)
+    }
+
+    // Once topped up proactively, a canister must report its balance again before its next proactive top-up, so
+    // that canisters which have stopped requesting cycles don't keep being topped up based on a stale forecast
+    pub fn can_top_up_proactively(&self) -> bool {
+        self.top_ups
+            .iter()
+            .rev()
+            .find(|t| t.proactive)
+            .map_or(true, |t| self.balance_reported.map_or(false, |r| r > t.date))
+    }
+
+    pub fn estimated_balance(&self, now: TimestampMillis) -> Option<Cycles> {
+        let estimate = self.balance_estimate?;
+        let burn_rate = self.burn_rate_per_day()?;
+        let elapsed = now.saturating_sub(estimate.timestamp);
+
+        Some(
+            estimate
+                .cycles
+                .saturating_sub(burn_rate * elapsed as Cycles / DAY_IN_MS as Cycles),
+        )
+    }
+
+    // The time at which the canister is expected to run low and request more cycles
+    pub fn next_top_up_due(&self) -> Option<TimestampMillis> {
+        let burn_rate = self.burn_rate_per_day()?;
+        if burn_rate == 0 {
+            return None;
+        }
+
+        let (from, cycles_until_low) = match (self.balance_estimate, self.low_balance) {
+            (Some(estimate), Some(low_balance)) => (estimate.timestamp, estimate.cycles.saturating_sub(low_balance)),
+            _ => {
+                let latest = self.top_ups.last()?;
+                (latest.date, latest.amount)
+            }
+        };
+
+        Some(from + (cycles_until_low * DAY_IN_MS as Cycles / burn_rate) as Milliseconds)
+    }
+
+    pub fn runway_days(&self, now: TimestampMillis) -> Option<f64> {
+        let burn_rate = self.burn_rate_per_day().filter(|r| *r > 0)?;
+        let balance = self.estimated_balance(now)?;
+
+        Some(balance as f64 / burn_rate as f64)
+    }
+
+    pub fn proactive_top_ups(&self) -> u32 {
+        self.top_ups.iter().filter(|t| t.proactive).count() as u32
     }
 }
 
@@ -116,6 +235,8 @@ impl Canister {
 struct CyclesTopUp {
     pub date: TimestampMillis,
     pub amount: Cycles,
+    #[serde(default)]
+    pub proactive: bool,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -124,3 +245,84 @@ pub struct CanisterMetrics {
     added: TimestampMillis,
     top_ups: Vec<CyclesTopUp>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T: Cycles = 1_000_000_000_000;
+
+    fn canister() -> Canister {
+        Canister {
+            added: 0,
+            top_ups: Vec::new(),
+            top_up_in_progress: false,
+            balance_estimate: None,
+            low_balance: None,
+            balance_reported: None,
+        }
+    }
+
+    #[test]
+    fn burn_rate_requires_multiple_top_ups() {
+        let mut canister = canister();
+        assert!(canister.burn_rate_per_day().is_none());
+
+        canister.record_top_up(5 * T, Some(T), false, 0);
+        assert!(canister.burn_rate_per_day().is_none());
+
+        canister.record_top_up(5 * T, Some(T), false, 2 * DAY_IN_MS);
+        assert_eq!(canister.burn_rate_per_day(), Some(5 * T / 2));
+    }
+
+    #[test]
+    fn forecast_uses_reported_balance() {
+        let mut canister = canister();
+        canister.record_top_up(4 * T, Some(T), false, 0);
+        canister.record_top_up(4 * T, Some(T), false, 2 * DAY_IN_MS);
+
+        // 5T after the second top-up, burning 2T per day
+        assert_eq!(canister.estimated_balance(3 * DAY_IN_MS), Some(3 * T));
+        assert_eq!(canister.runway_days(3 * DAY_IN_MS), Some(1.5));
+        assert_eq!(canister.next_top_up_due(), Some(4 * DAY_IN_MS));
+    }
+
+    #[test]
+    fn proactive_top_up_extends_estimate() {
+        let mut canister = canister();
+        canister.record_top_up(4 * T, Some(T), false, 0);
+        canister.record_top_up(4 * T, Some(T), false, 2 * DAY_IN_MS);
+        canister.record_top_up(4 * T, None, true, 3 * DAY_IN_MS);
+
+        assert_eq!(canister.estimated_balance(3 * DAY_IN_MS), Some(7 * T));
+        assert_eq!(canister.proactive_top_ups(), 1);
+    }
+
+    #[test]
+    fn proactive_top_ups_only_count_towards_burn_rate_between_requests() {
+        let mut canister = canister();
+        canister.record_top_up(4 * T, Some(T), false, 0);
+        canister.record_top_up(4 * T, Some(T), false, 2 * DAY_IN_MS);
+        canister.record_top_up(4 * T, None, true, 3 * DAY_IN_MS);
+        assert_eq!(canister.burn_rate_per_day(), Some(2 * T));
+
+        canister.record_top_up(4 * T, Some(T), false, 6 * DAY_IN_MS);
+        assert_eq!(canister.burn_rate_per_day(), Some(2 * T));
+    }
+
+    #[test]
+    fn proactive_top_ups_require_a_reported_balance() {
+        let mut canister = canister();
+        canister.record_top_up(4 * T, Some(T), false, 0);
+        assert!(canister.can_top_up_proactively());
+
+        canister.record_top_up(4 * T, None, true, DAY_IN_MS);
+        assert!(!canister.can_top_up_proactively());
+
+        canister.record_top_up(4 * T, None, false, 2 * DAY_IN_MS);
+        assert!(!canister.can_top_up_proactively());
+
+        canister.record_top_up(4 * T, Some(T), false, 3 * DAY_IN_MS);
+        assert!(canister.can_top_up_proactively());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{read_state, State};
+use cycles_dispenser_canister::cycles_forecast::{Response::*, *};
+use ic_cdk_macros::query;
+use ic_ledger_types::Tokens;
+use types::Cycles;
+
+#[query]
+fn cycles_forecast(_args: Args) -> Response {
+    read_state(cycles_forecast_impl)
+}
+
+fn cycles_forecast_impl(state: &State) -> Response {
+    let now = state.env.now();
+
+    let canisters: Vec<_> = state
+        .data
+        .canisters
+        .iter()
+        .map(|(canister_id, c)| CanisterForecast {
+            canister_id: *canister_id,
+            burn_rate_per_day: c.burn_rate_per_day(),
+            estimated_cycles_balance: c.estimated_balance(now),
+            runway_days: c.runway_days(now),
+            next_top_up_due: c.next_top_up_due(),
+            latest_top_up: c.latest_top_up(),
+            proactive_top_ups: c.proactive_top_ups(),
+        })
+        .collect();
+
+    let cycles_balance = state.env.cycles_balance();
+    let min_cycles_balance = state.data.min_cycles_balance;
+    let cycles_dispensed_per_day: Cycles = canisters.iter().filter_map(|c| c.burn_rate_per_day).sum();
+    let icp_balance = state.data.icp_balance.as_ref().map(|b| b.value);
+    let cycles_per_icp = state.data.cycles_per_icp.as_ref().map(|c| c.value);
+
+    let available_cycles = cycles_balance.saturating_sub(min_cycles_balance);
+    let cycles_from_icp = icp_balance
+        .zip(cycles_per_icp)
+        .map(|(icp, rate)| icp.e8s() as Cycles * rate / Tokens::SUBDIVIDABLE_BY as Cycles);
+
+    Success(SuccessResult {
+        timestamp: now,
+        dispenser: DispenserForecast {
+            cycles_balance,
+            min_cycles_balance,
+            cycles_dispensed_per_day,
+            runway_days: runway_days(available_cycles, cycles_dispensed_per_day),
+            icp_balance,
+            icp_balance_updated: state.data.icp_balance.as_ref().map(|b| b.timestamp),
+            cycles_per_icp,
+            runway_days_including_icp: cycles_from_icp
+                .and_then(|c| runway_days(available_cycles + c, cycles_dispensed_per_day)),
+        },
+        canisters,
+    })
+}
+
+fn runway_days(cycles: Cycles, cycles_per_day: Cycles) -> Option<f64> {
+    (cycles_per_day > 0).then(|| cycles as f64 / cycles_per_day as f64)
+}
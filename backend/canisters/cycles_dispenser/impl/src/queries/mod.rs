@@ -1 +1,2 @@
+mod cycles_forecast;
 mod http_request;
@@ -12,6 +12,7 @@ use types::{CanisterId, Cycles, Milliseconds, TimestampMillis};
 #[update]
 #[trace]
 async fn c2c_request_cycles(args: Args) -> Response {
+    let cycles_balance = args.cycles_balance;
     let PrepareResult { canister_id, amount } = match mutate_state(|state| prepare(args, state)) {
         Ok(c) => c,
         Err(response) => return response,
@@ -19,7 +20,7 @@ async fn c2c_request_cycles(args: Args) -> Response {
 
     let result = top_up_canister(canister_id, amount).await;
 
-    mutate_state(|state| commit(&canister_id, result.is_ok().then_some(amount), state));
+    mutate_state(|state| commit(&canister_id, result.is_ok().then_some(amount), cycles_balance, state));
 
     match result {
         Ok(_) => Success(amount),
@@ -55,11 +56,11 @@ fn prepare(args: Args, state: &mut State) -> Result<PrepareResult, Response> {
     }
 }
 
-fn commit(canister_id: &CanisterId, top_up_amount: Option<Cycles>, state: &mut State) {
+fn commit(canister_id: &CanisterId, top_up_amount: Option<Cycles>, cycles_balance: Option<Cycles>, state: &mut State) {
     if let Some(canister) = state.data.canisters.get_mut(canister_id) {
         canister.set_top_up_in_progress(false);
         if let Some(amount) = top_up_amount {
-            canister.record_top_up(amount, state.env.now())
+            canister.record_top_up(amount, cycles_balance, false, state.env.now())
         }
     }
 }
@@ -23,5 +23,8 @@ fn update_config_impl(args: Args, state: &mut State) -> Response {
     if let Some(icp_burn_amount) = args.icp_burn_amount {
         state.data.icp_burn_amount = icp_burn_amount;
     }
+    if let Some(lead_time) = args.proactive_top_up_lead_time {
+        state.data.proactive_top_up_lead_time = lead_time;
+    }
     Success
 }
@@ -1,6 +1,9 @@
 use candid::{CandidType, Principal};
 use serde::{Deserialize, Serialize};
-use types::{AccessGate, BuildVersion, CanisterId, Document, GroupPermissions, GroupSubtype, Milliseconds, Rules, UserId};
+use types::{
+    AccessGate, BuildVersion, CanisterId, DetachedChannel, Document, GroupPermissions, GroupSubtype, Milliseconds, Rules,
+    UserId,
+};
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct Args {
@@ -25,4 +28,6 @@ pub struct Args {
     pub gate: Option<AccessGate>,
     pub wasm_version: BuildVersion,
     pub test_mode: bool,
+    #[serde(default)]
+    pub detached_channel: Option<DetachedChannel>,
 }
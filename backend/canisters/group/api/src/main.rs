@@ -2,13 +2,19 @@ use candid_gen::generate_candid_method;
 
 #[allow(deprecated)]
 fn main() {
+    generate_candid_method!(group, content_filters, query);
     generate_candid_method!(group, deleted_message, query);
     generate_candid_method!(group, events, query);
     generate_candid_method!(group, events_by_index, query);
     generate_candid_method!(group, events_window, query);
+    generate_candid_method!(group, instruction_counts, query);
     generate_candid_method!(group, invite_code, query);
+    generate_candid_method!(group, invite_links, query);
+    generate_candid_method!(group, join_requests, query);
     generate_candid_method!(group, local_user_index, query);
+    generate_candid_method!(group, message_edit_history, query);
     generate_candid_method!(group, messages_by_message_index, query);
+    generate_candid_method!(group, moderation_queue, query);
     generate_candid_method!(group, thread_previews, query);
     generate_candid_method!(group, public_summary, query);
     generate_candid_method!(group, rules, query);
@@ -19,29 +25,38 @@ fn main() {
     generate_candid_method!(group, summary_updates, query);
 
     generate_candid_method!(group, add_reaction, update);
+    generate_candid_method!(group, approve_join_requests, update);
     generate_candid_method!(group, block_user, update);
     generate_candid_method!(group, change_role, update);
     generate_candid_method!(group, claim_prize, update);
     generate_candid_method!(group, convert_into_community, update);
+    generate_candid_method!(group, create_invite_link, update);
     generate_candid_method!(group, decline_invitation, update);
     generate_candid_method!(group, delete_messages, update);
     generate_candid_method!(group, disable_invite_code, update);
     generate_candid_method!(group, edit_message_v2, update);
     generate_candid_method!(group, enable_invite_code, update);
+    generate_candid_method!(group, export_chat, update);
     generate_candid_method!(group, follow_thread, update);
+    generate_candid_method!(group, moderate_report, update);
+    generate_candid_method!(group, mute_member, update);
     generate_candid_method!(group, pin_message_v2, update);
     generate_candid_method!(group, register_poll_vote, update);
     generate_candid_method!(group, register_proposal_vote, update);
     generate_candid_method!(group, register_proposal_vote_v2, update);
+    generate_candid_method!(group, reject_join_requests, update);
     generate_candid_method!(group, remove_participant, update);
     generate_candid_method!(group, remove_reaction, update);
     generate_candid_method!(group, report_message, update);
     generate_candid_method!(group, reset_invite_code, update);
+    generate_candid_method!(group, revoke_invite_link, update);
     generate_candid_method!(group, send_message_v2, update);
+    generate_candid_method!(group, set_content_filters, update);
     generate_candid_method!(group, toggle_mute_notifications, update);
     generate_candid_method!(group, unblock_user, update);
     generate_candid_method!(group, undelete_messages, update);
     generate_candid_method!(group, unfollow_thread, update);
+    generate_candid_method!(group, unmute_member, update);
     generate_candid_method!(group, unpin_message, update);
     generate_candid_method!(group, update_group_v2, update);
 
@@ -0,0 +1,14 @@
+use candid::CandidType;
+use instruction_counts_log::InstructionCountsSummary;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // Defaults to `DEFAULT_REGRESSION_THRESHOLD_PERCENT`
+    pub regression_threshold_percent: Option<u32>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(InstructionCountsSummary),
+}
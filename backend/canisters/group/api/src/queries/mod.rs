@@ -1,14 +1,21 @@
+pub mod c2c_check_content_filters;
 pub mod c2c_events_internal;
 pub mod c2c_name_and_members;
 pub mod c2c_summary;
 pub mod c2c_summary_updates;
+pub mod content_filters;
 pub mod deleted_message;
 pub mod events;
 pub mod events_by_index;
 pub mod events_window;
+pub mod instruction_counts;
 pub mod invite_code;
+pub mod invite_links;
+pub mod join_requests;
 pub mod local_user_index;
+pub mod message_edit_history;
 pub mod messages_by_message_index;
+pub mod moderation_queue;
 pub mod public_summary;
 pub mod rules;
 pub mod search_messages;
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use types::{MessageForForwarding, MessageIndex};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(MessageForForwarding),
+    CallerNotInGroup,
+    MessageNotFound,
+    NotForwardable,
+}
@@ -0,0 +1,23 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{GroupInviteLink, GroupRole, TimestampMillis};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub name: String,
+    pub expires_at: Option<TimestampMillis>,
+    pub max_uses: Option<u32>,
+    pub role: Option<GroupRole>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(GroupInviteLink),
+    NotAuthorized,
+    UserSuspended,
+    ChatFrozen,
+    NameTooLong(u32),
+    ExpiryInPast,
+    InvalidMaxUses,
+    TooManyLinks(u32),
+}
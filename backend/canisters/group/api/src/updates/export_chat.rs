@@ -0,0 +1,25 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ExportFormat, MessageIndex};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub format: ExportFormat,
+    pub from_message_index: Option<MessageIndex>,
+    // Set when continuing a thread which was split across pages
+    pub from_thread_message_index: Option<MessageIndex>,
+    pub max_messages: u32,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    CallerNotInGroup,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub data: String,
+    pub next_message_index: Option<MessageIndex>,
+    pub next_thread_message_index: Option<MessageIndex>,
+}
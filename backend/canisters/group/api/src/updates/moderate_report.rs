@@ -0,0 +1,23 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ModerationAction, ModerationReportId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub report_id: ModerationReportId,
+    pub action: ModerationAction,
+    pub notes: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    CallerNotInGroup,
+    NotAuthorized,
+    UserSuspended,
+    ChatFrozen,
+    ReportNotFound,
+    AlreadyResolved,
+    InvalidDuration,
+    InternalError(String),
+}
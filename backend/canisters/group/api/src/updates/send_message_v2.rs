@@ -1,8 +1,8 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use types::{
-    EventIndex, GroupReplyContext, InvalidPollReason, MessageContentInitial, MessageId, MessageIndex, TimestampMillis, User,
-    Version,
+    ContentFilterMatch, ContentFilterReason, EventIndex, ForwardedFrom, GroupReplyContext, InvalidPollReason,
+    MessageContentInitial, MessageId, MessageIndex, TimestampMillis, User, Version,
 };
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -17,6 +17,9 @@ pub struct Args {
     pub forwarding: bool,
     pub rules_accepted: Option<Version>,
     pub correlation_id: u64,
+    // Only accepted when the message is forwarded by the sender's user canister
+    #[serde(default)]
+    pub forwarded_from: Option<ForwardedFrom>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -32,6 +35,9 @@ pub enum Response {
     InvalidRequest(String),
     ChatFrozen,
     RulesNotAccepted,
+    ContentRejected(ContentFilterReason),
+    RateLimited(TimestampMillis),
+    UserMuted(TimestampMillis),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -40,4 +46,5 @@ pub struct SuccessResult {
     pub message_index: MessageIndex,
     pub timestamp: TimestampMillis,
     pub expires_at: Option<TimestampMillis>,
+    pub content_filter_match: Option<ContentFilterMatch>,
 }
@@ -2,6 +2,7 @@ use canister_client::{generate_c2c_call, generate_candid_c2c_call};
 use group_canister::*;
 
 // Queries
+generate_c2c_call!(c2c_check_content_filters);
 generate_c2c_call!(c2c_events_internal);
 generate_c2c_call!(c2c_name_and_members);
 generate_c2c_call!(c2c_summary);
@@ -20,7 +21,9 @@ generate_c2c_call!(c2c_freeze_group);
 generate_c2c_call!(c2c_invite_users);
 generate_c2c_call!(c2c_join_group);
 generate_c2c_call!(c2c_leave_group);
+generate_c2c_call!(c2c_message_for_forwarding);
 generate_c2c_call!(c2c_report_message_v2);
+generate_c2c_call!(c2c_request_to_join);
 generate_c2c_call!(c2c_set_user_suspended);
 generate_c2c_call!(c2c_start_import_into_community);
 generate_c2c_call!(c2c_tip_message);
@@ -16,6 +16,14 @@ pub fn caller_is_user_index_or_local_user_index() -> Result<(), String> {
     }
 }
 
+pub fn caller_is_local_user_index() -> Result<(), String> {
+    if read_state(|state| state.is_caller_local_user_index()) {
+        Ok(())
+    } else {
+        Err("Caller is not the local_user_index".to_string())
+    }
+}
+
 pub fn caller_is_group_index_or_local_group_index() -> Result<(), String> {
     if read_state(|state| state.is_caller_group_index() || state.is_caller_local_group_index()) {
         Ok(())
@@ -39,3 +47,11 @@ pub fn caller_is_community_being_imported_into() -> Result<(), String> {
         Err("Caller is not the community this group is being imported into".to_string())
     }
 }
+
+pub fn caller_is_notifications_canister() -> Result<(), String> {
+    if read_state(|state| state.is_caller_notifications_canister()) {
+        Ok(())
+    } else {
+        Err("Caller is not the notifications canister".to_string())
+    }
+}
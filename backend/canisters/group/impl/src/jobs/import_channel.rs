@@ -0,0 +1,149 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, RuntimeState};
+use community_canister::c2c_export_channel::{Args, Response};
+use group_community_common::ChannelExport;
+use ic_cdk_timers::TimerId;
+use std::cell::Cell;
+use std::time::Duration;
+use tracing::{info, trace};
+use types::{ChannelId, ChannelMovedTo, CommunityId, Empty};
+
+const PAGE_SIZE: u32 = 19 * 102 * 1024; // Roughly 1.9MB (1.9 * 1024 * 1024)
+
+thread_local! {
+    static TIMER_ID: Cell<Option<TimerId>> = Cell::default();
+}
+
+pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
+    if TIMER_ID.get().is_none() && state.data.channel_being_imported.is_some() {
+        let timer_id = ic_cdk_timers::set_timer_interval(Duration::ZERO, run);
+        TIMER_ID.set(Some(timer_id));
+        trace!("'import_channel' job started");
+        true
+    } else {
+        false
+    }
+}
+
+fn run() {
+    match mutate_state(next_batch) {
+        Some(Some((community_id, channel_id, from))) => ic_cdk::spawn(import_channel(community_id, channel_id, from)),
+        Some(None) => {}
+        None => {
+            if let Some(timer_id) = TIMER_ID.take() {
+                ic_cdk_timers::clear_timer(timer_id);
+                trace!("'import_channel' job stopped");
+            }
+        }
+    }
+}
+
+// Returns `None` once there is nothing left to import
+fn next_batch(state: &mut RuntimeState) -> Option<Option<(CommunityId, ChannelId, u64)>> {
+    let now = state.env.now();
+    let channel = state.data.channel_being_imported.as_mut().filter(|c| !c.is_complete())?;
+
+    Some(
+        channel
+            .next_batch(now)
+            .map(|from| (channel.community_id(), channel.channel_id(), from)),
+    )
+}
+
+async fn import_channel(community_id: CommunityId, channel_id: ChannelId, from: u64) {
+    info!(%community_id, %channel_id, from, "'import_channel' starting");
+    match community_canister_c2c_client::c2c_export_channel(
+        community_id.into(),
+        &Args {
+            channel_id,
+            from,
+            page_size: PAGE_SIZE,
+        },
+    )
+    .await
+    {
+        Ok(Response::Success(bytes)) => {
+            mutate_state(|state| {
+                if let Some(channel) = state.data.channel_being_imported.as_mut() {
+                    if channel.mark_batch_complete(&bytes) {
+                        ic_cdk_timers::set_timer(Duration::ZERO, finalize_channel_import);
+
+                        // We set a timer to trigger an upgrade in case deserializing the channel requires
+                        // more instructions than are allowed in a normal update call
+                        ic_cdk_timers::set_timer(Duration::from_secs(10), trigger_upgrade_to_finalize_import);
+
+                        info!(%community_id, %channel_id, "Channel data imported");
+                    }
+                }
+            });
+        }
+        Ok(Response::ChannelNotFound) => {
+            // The community is no longer detaching the channel into this group so the import is
+            // abandoned, leaving the group with just its creator
+            info!(%community_id, %channel_id, "Channel import abandoned");
+            mutate_state(|state| state.data.channel_being_imported = None);
+        }
+        Ok(Response::DestinationNotSet) => {
+            // The community records this group as the channel's destination once the group has
+            // been created, which may be after this job has started
+            mark_batch_failed("Destination not set".to_string());
+        }
+        Err(error) => mark_batch_failed(format!("{error:?}")),
+    }
+}
+
+fn mark_batch_failed(error_message: String) {
+    mutate_state(|state| {
+        let now = state.env.now();
+        if let Some(channel) = state.data.channel_being_imported.as_mut() {
+            channel.mark_batch_failed(error_message, now);
+        }
+        start_job_if_required(state);
+    });
+}
+
+pub(crate) fn finalize_channel_import() {
+    mutate_state(|state| {
+        if !state.data.channel_being_imported.as_ref().map_or(false, |c| c.is_complete()) {
+            return;
+        }
+
+        info!("'finalize_channel_import' starting");
+        let initial_instruction_count = ic_cdk::api::instruction_counter();
+
+        let import = state.data.channel_being_imported.take().unwrap();
+        let export = ChannelExport::deserialize(import.bytes());
+
+        // User groups only exist within communities so they are dropped
+        state.data.chat = export.chat;
+        state.data.invite_links = export.invite_links;
+        state.data.principal_to_user_id_map.extend(export.principals);
+
+        state.data.fire_and_forget_handler.send(
+            import.community_id().into(),
+            "c2c_mark_channel_move_complete_msgpack".to_string(),
+            msgpack::serialize_then_unwrap(community_canister::c2c_mark_channel_move_complete::Args {
+                channel_id: import.channel_id(),
+                moved_to: ChannelMovedTo::Group(state.env.canister_id().into()),
+                latest_message_index: state.data.chat.events.main_events_list().latest_message_index(),
+            }),
+        );
+
+        handle_activity_notification(state);
+
+        let instruction_count = ic_cdk::api::instruction_counter() - initial_instruction_count;
+        info!(instruction_count, "'finalize_channel_import' completed");
+    });
+}
+
+fn trigger_upgrade_to_finalize_import() {
+    mutate_state(|state| {
+        if state.data.channel_being_imported.is_some() {
+            state.data.fire_and_forget_handler.send(
+                state.data.local_group_index_canister_id,
+                "c2c_trigger_upgrade_msgpack".to_string(),
+                msgpack::serialize_then_unwrap(Empty {}),
+            );
+        }
+    });
+}
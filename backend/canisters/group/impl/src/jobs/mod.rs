@@ -1,7 +1,9 @@
 use crate::RuntimeState;
 
+pub mod import_channel;
 pub mod make_pending_payments;
 
 pub(crate) fn start(state: &RuntimeState) {
+    import_channel::start_job_if_required(state);
     make_pending_payments::start_job_if_required(state);
 }
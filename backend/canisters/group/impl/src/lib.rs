@@ -1,4 +1,5 @@
 use crate::memory::{get_instruction_counts_data_memory, get_instruction_counts_index_memory};
+use crate::model::channel_being_imported::ChannelBeingImported;
 use crate::model::new_joiner_rewards::{NewJoinerRewardMetrics, NewJoinerRewardStatus, NewJoinerRewards};
 use crate::new_joiner_rewards::process_new_joiner_reward;
 use crate::timer_job_types::{RemoveExpiredEventsJob, TimerJob};
@@ -12,19 +13,27 @@ use fire_and_forget_handler::FireAndForgetHandler;
 use group_chat_core::{
     AddResult as AddMemberResult, GroupChatCore, GroupMemberInternal, GroupRoleInternal, InvitedUsersResult, UserInvitation,
 };
-use group_community_common::{PaymentReceipts, PaymentRecipient, PendingPayment, PendingPaymentReason, PendingPaymentsQueue};
-use instruction_counts_log::{InstructionCountEntry, InstructionCountFunctionId, InstructionCountsLog};
+use group_community_common::{
+    InviteLinks, JoinRequests, PaymentReceipts, PaymentRecipient, PendingPayment, PendingPaymentReason, PendingPaymentsQueue,
+};
+use http_request::{MetricsRegistry, RegisterMetrics};
+use instruction_counts_log::{
+    upgrade_instructions_histogram, InstructionCountEntry, InstructionCountFunctionId, InstructionCountSamples,
+    InstructionCountsLog, InstructionCountsSummary, DEFAULT_REGRESSION_THRESHOLD_PERCENT,
+};
+use local_user_index_canister::c2c_notify_join_request_outcome::{self, JoinRequestOutcome};
 use msgpack::serialize_then_unwrap;
-use notifications_canister::c2c_push_notification;
+use notifications_canister::{c2c_push_link_preview_request, c2c_push_notification};
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use types::{
-    AccessGate, BuildVersion, CanisterId, ChatMetrics, CommunityId, Cryptocurrency, Cycles, Document, Empty, EventIndex,
-    FrozenGroupInfo, GroupCanisterGroupChatSummary, GroupMembership, GroupPermissions, GroupSubtype, MessageIndex,
-    Milliseconds, Notification, PaymentGate, Rules, TimestampMillis, Timestamped, UserId, MAX_THREADS_IN_SUMMARY,
+    AccessGate, BuildVersion, CanisterId, Chat, ChatMetrics, CommunityId, ContentFilterReason, Cryptocurrency, Cycles,
+    Document, Empty, EventIndex, FrozenGroupInfo, GroupCanisterGroupChatSummary, GroupMembership, GroupPermissions, GroupRole,
+    GroupSubtype, MessageContent, MessageId, MessageIndex, Milliseconds, Notification, PaymentGate, Rules, TimestampMillis,
+    Timestamped, UserId, MAX_THREADS_IN_SUMMARY,
 };
 use utils::consts::OPENCHAT_BOT_USER_ID;
 use utils::env::Environment;
@@ -76,6 +85,10 @@ impl RuntimeState {
         self.env.caller() == self.data.local_group_index_canister_id
     }
 
+    pub fn is_caller_notifications_canister(&self) -> bool {
+        self.env.caller() == self.data.notifications_canister_id
+    }
+
     pub fn is_caller_community_being_imported_into(&self) -> bool {
         if let Some(community_id) = self
             .data
@@ -104,6 +117,31 @@ impl RuntimeState {
         }
     }
 
+    pub fn request_link_previews(
+        &self,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        content: &MessageContent,
+    ) {
+        if let MessageContent::Text(text) = content {
+            let urls = link_previews::extract_urls(&text.text);
+            if !urls.is_empty() {
+                let args = c2c_push_link_preview_request::Args {
+                    authorizer: Some(self.data.local_group_index_canister_id),
+                    chat: Chat::Group(self.env.canister_id().into()),
+                    thread_root_message_index,
+                    message_id,
+                    urls,
+                };
+                ic_cdk::spawn(request_link_previews_inner(self.data.notifications_canister_id, args));
+            }
+        }
+
+        async fn request_link_previews_inner(canister_id: CanisterId, args: c2c_push_link_preview_request::Args) {
+            let _ = notifications_canister_c2c_client::c2c_push_link_preview_request(canister_id, &args).await;
+        }
+    }
+
     pub fn queue_access_gate_payments(&mut self, gate: PaymentGate) {
         // The amount available is the gate amount less the approval fee and the transfer_from fee
         let amount_available = gate.amount - 2 * gate.fee;
@@ -207,6 +245,7 @@ impl RuntimeState {
             events_ttl: events_ttl.value,
             events_ttl_last_updated: events_ttl.timestamp,
             gate: chat.gate.value.clone(),
+            slow_mode: chat.slow_mode.value.clone(),
             rules_accepted: membership.rules_accepted,
             membership: Some(membership),
         }
@@ -313,7 +352,13 @@ impl RuntimeState {
             events_in_last_day,
             new_joiner_rewards: self.data.new_joiner_rewards.as_ref().map(|r| r.metrics()),
             frozen: self.data.is_frozen(),
-            instruction_counts: self.data.instruction_counts_log.iter().collect(),
+            instruction_counts: self
+                .data
+                .instruction_counts_log
+                .iter()
+                .filter(|e| e.function_id().is_upgrade())
+                .collect(),
+            instruction_counts_summary: self.data.instruction_counts_summary(DEFAULT_REGRESSION_THRESHOLD_PERCENT),
             community_being_imported_into: self
                 .data
                 .community_being_imported_into
@@ -350,6 +395,10 @@ struct Data {
     pub proposals_bot_user_id: UserId,
     pub invite_code: Option<u64>,
     pub invite_code_enabled: bool,
+    #[serde(default)]
+    pub invite_links: InviteLinks<GroupRole>,
+    #[serde(default)]
+    pub join_requests: JoinRequests,
     pub new_joiner_rewards: Option<NewJoinerRewards>,
     pub frozen: Timestamped<Option<FrozenGroupInfo>>,
     pub timer_jobs: TimerJobs<TimerJob>,
@@ -357,6 +406,8 @@ struct Data {
     pub activity_notification_state: ActivityNotificationState,
     #[serde(skip, default = "init_instruction_counts_log")]
     pub instruction_counts_log: InstructionCountsLog,
+    #[serde(default)]
+    pub instruction_count_samples: InstructionCountSamples,
     pub test_mode: bool,
     pub community_being_imported_into: Option<CommunityBeingImportedInto>,
     pub serialized_chat_state: Option<ByteBuf>,
@@ -364,6 +415,8 @@ struct Data {
     pub rng_seed: [u8; 32],
     pub pending_payments_queue: PendingPaymentsQueue,
     pub total_payment_receipts: PaymentReceipts,
+    #[serde(default)]
+    pub channel_being_imported: Option<ChannelBeingImported>,
 }
 
 fn init_instruction_counts_log() -> InstructionCountsLog {
@@ -424,17 +477,21 @@ impl Data {
             test_mode,
             invite_code: None,
             invite_code_enabled: false,
+            invite_links: InviteLinks::default(),
+            join_requests: JoinRequests::default(),
             new_joiner_rewards: None,
             frozen: Timestamped::default(),
             timer_jobs: TimerJobs::default(),
             fire_and_forget_handler: FireAndForgetHandler::default(),
             instruction_counts_log: init_instruction_counts_log(),
+            instruction_count_samples: InstructionCountSamples::default(),
             community_being_imported_into: None,
             serialized_chat_state: None,
             next_event_expiry: None,
             rng_seed: [0; 32],
             pending_payments_queue: PendingPaymentsQueue::default(),
             total_payment_receipts: PaymentReceipts::default(),
+            channel_being_imported: None,
         }
     }
 
@@ -462,15 +519,17 @@ impl Data {
         self.chat.members.get_mut(&user_id)
     }
 
+    // A group created by detaching a channel from a community is frozen until the channel has been
+    // imported into it
     pub fn is_frozen(&self) -> bool {
-        self.frozen.is_some()
+        self.frozen.is_some() || self.channel_being_imported.is_some()
     }
 
-    pub fn is_accessible(&self, caller: Principal, invite_code: Option<u64>) -> bool {
+    pub fn is_accessible(&self, caller: Principal, invite_code: Option<u64>, now: TimestampMillis) -> bool {
         self.chat.is_public.value
             || self.get_member(caller).is_some()
             || self.get_invitation(caller).is_some()
-            || self.is_invite_code_valid(invite_code)
+            || self.is_invite_code_valid(invite_code, now)
     }
 
     pub fn get_invitation(&self, caller: Principal) -> Option<&UserInvitation> {
@@ -515,13 +574,23 @@ impl Data {
         }
     }
 
-    pub fn record_instructions_count(&self, function_id: InstructionCountFunctionId, now: TimestampMillis) {
+    pub fn instruction_counts_summary(&self, regression_threshold_percent: u32) -> InstructionCountsSummary {
+        self.instruction_count_samples
+            .summarize(&self.instruction_counts_log, regression_threshold_percent)
+    }
+
+    pub fn record_instructions_count(&mut self, function_id: InstructionCountFunctionId, now: TimestampMillis) {
         let wasm_version = WASM_VERSION.with_borrow(|v| **v);
         let instructions_count = ic_cdk::api::instruction_counter();
 
-        let _ = self
-            .instruction_counts_log
-            .record(function_id, instructions_count, wasm_version, now);
+        if function_id.is_upgrade() {
+            let _ = self
+                .instruction_counts_log
+                .record(function_id, instructions_count, wasm_version, now);
+        } else {
+            self.instruction_count_samples
+                .record(function_id, instructions_count, wasm_version);
+        }
     }
 
     pub fn mark_group_updated_in_user_canister(&self, user_id: UserId) {
@@ -532,13 +601,49 @@ impl Data {
         );
     }
 
-    fn is_invite_code_valid(&self, invite_code: Option<u64>) -> bool {
-        if self.invite_code_enabled {
-            if let Some(provided_code) = invite_code {
-                if let Some(stored_code) = self.invite_code {
-                    return provided_code == stored_code;
-                }
+    pub fn notify_message_deleted_by_content_filter(&self, user_id: UserId, reason: ContentFilterReason) {
+        let args = user_canister::c2c_notify_message_deleted_by_content_filter::Args {
+            chat_name: self.chat.name.value.clone(),
+            community_name: None,
+            reason,
+        };
+        self.fire_and_forget_handler.send(
+            user_id.into(),
+            "c2c_notify_message_deleted_by_content_filter_msgpack".to_string(),
+            serialize_then_unwrap(args),
+        );
+    }
+
+    pub fn notify_join_request_outcome(
+        &self,
+        user_id: UserId,
+        local_user_index_canister_id: CanisterId,
+        outcome: JoinRequestOutcome,
+    ) {
+        let args = c2c_notify_join_request_outcome::Args {
+            user_id,
+            name: self.chat.name.value.clone(),
+            outcome,
+        };
+        self.fire_and_forget_handler.send(
+            local_user_index_canister_id,
+            "c2c_notify_join_request_outcome_msgpack".to_string(),
+            serialize_then_unwrap(args),
+        );
+    }
+
+    pub fn expire_join_requests(&mut self, now: TimestampMillis) {
+        for (user_id, request) in self.join_requests.remove_expired(now) {
+            self.notify_join_request_outcome(user_id, request.local_user_index_canister_id, JoinRequestOutcome::Expired);
+        }
+    }
+
+    fn is_invite_code_valid(&self, invite_code: Option<u64>, now: TimestampMillis) -> bool {
+        if let Some(provided_code) = invite_code {
+            if self.invite_code_enabled && self.invite_code == Some(provided_code) {
+                return true;
             }
+            return self.invite_links.get_valid(provided_code, now).is_some();
         }
 
         false
@@ -568,11 +673,64 @@ pub struct Metrics {
     pub new_joiner_rewards: Option<NewJoinerRewardMetrics>,
     pub frozen: bool,
     pub instruction_counts: Vec<InstructionCountEntry>,
+    pub instruction_counts_summary: InstructionCountsSummary,
     pub community_being_imported_into: Option<CommunityId>,
     pub serialized_chat_state_bytes: u64,
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const MEMBERS: &str = "group_members";
+        const MEMBERS_HELP: &str = "Members of the group, by role";
+
+        let upgrade_instructions = upgrade_instructions_histogram(&self.instruction_counts);
+
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge("group_public", "Whether the group is public", u8::from(self.public))
+            .gauge("group_frozen", "Whether the group is frozen", u8::from(self.frozen))
+            .gauge("group_date_created_ms", "When the group was created", self.date_created)
+            .gauge_with_labels(MEMBERS, MEMBERS_HELP, &[("role", "all")], self.members)
+            .gauge_with_labels(MEMBERS, MEMBERS_HELP, &[("role", "moderator")], self.moderators)
+            .gauge_with_labels(MEMBERS, MEMBERS_HELP, &[("role", "admin")], self.admins)
+            .gauge_with_labels(MEMBERS, MEMBERS_HELP, &[("role", "owner")], self.owners)
+            .gauge("group_blocked_users", "Users blocked from the group", self.blocked)
+            .gauge("group_invited_users", "Users invited to the group", self.invited)
+            .gauge(
+                "group_messages_last_hour",
+                "Messages sent in the last hour",
+                self.messages_in_last_hour,
+            )
+            .gauge(
+                "group_messages_last_day",
+                "Messages sent in the last day",
+                self.messages_in_last_day,
+            )
+            .gauge("group_events_last_hour", "Events in the last hour", self.events_in_last_hour)
+            .gauge("group_events_last_day", "Events in the last day", self.events_in_last_day)
+            .gauge(
+                "group_serialized_chat_state_bytes",
+                "Size of the chat state serialized for importing into a community",
+                self.serialized_chat_state_bytes,
+            )
+            .histogram(
+                "group_upgrade_instructions",
+                "Instructions used by upgrades",
+                &upgrade_instructions,
+            );
+
+        self.instruction_counts_summary.register_metrics(registry);
+        self.chat_metrics.register_metrics(registry);
+    }
+}
+
 fn run_regular_jobs() {
     mutate_state(|state| state.regular_jobs.run(state.env.deref(), &mut state.data));
 }
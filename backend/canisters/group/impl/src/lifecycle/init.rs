@@ -1,4 +1,5 @@
 use crate::lifecycle::{init_env, init_state};
+use crate::model::channel_being_imported::ChannelBeingImported;
 use crate::Data;
 use canister_tracing_macros::trace;
 use group_canister::init::Args;
@@ -13,7 +14,7 @@ fn init(args: Args) {
 
     let env = init_env([0; 32]);
 
-    let data = Data::new(
+    let mut data = Data::new(
         args.is_public,
         args.name,
         args.description,
@@ -36,6 +37,7 @@ fn init(args: Args) {
         args.permissions_v2,
         args.gate,
     );
+    data.channel_being_imported = args.detached_channel.map(ChannelBeingImported::new);
 
     init_state(env, data, args.wasm_version);
 
@@ -1,6 +1,7 @@
+use crate::jobs::import_channel::finalize_channel_import;
 use crate::lifecycle::{init_env, init_state};
 use crate::memory::get_upgrades_memory;
-use crate::{read_state, Data};
+use crate::{mutate_state, Data};
 use canister_logger::LogEntry;
 use canister_tracing_macros::trace;
 use group_canister::post_upgrade::Args;
@@ -22,9 +23,11 @@ fn post_upgrade(args: Args) {
     let env = init_env(data.rng_seed);
     init_state(env, data, args.wasm_version);
 
+    finalize_channel_import();
+
     info!(version = %args.wasm_version, "Post-upgrade complete");
 
-    read_state(|state| {
+    mutate_state(|state| {
         let now = state.env.now();
         state
             .data
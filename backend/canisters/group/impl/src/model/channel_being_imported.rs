@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::min;
+use types::{ChannelId, CommunityId, DetachedChannel, Milliseconds, TimestampMillis};
+use utils::time::{MINUTE_IN_MS, SECOND_IN_MS};
+
+// Failed batches are retried after a delay which doubles after each consecutive failure
+const MAX_RETRY_DELAY: Milliseconds = 5 * MINUTE_IN_MS;
+
+// The serialized channel being pulled in pages from the community which it is being detached from
+#[derive(Serialize, Deserialize)]
+pub struct ChannelBeingImported {
+    community_id: CommunityId,
+    channel_id: ChannelId,
+    total_bytes: u64,
+    current_batch_started: Option<TimestampMillis>,
+    #[serde(with = "serde_bytes")]
+    bytes: Vec<u8>,
+    error_message: Option<String>,
+    #[serde(default)]
+    failed_batches: u32,
+    #[serde(default)]
+    retry_after: Option<TimestampMillis>,
+}
+
+impl ChannelBeingImported {
+    pub fn new(channel: DetachedChannel) -> ChannelBeingImported {
+        ChannelBeingImported {
+            community_id: channel.community_id,
+            channel_id: channel.channel_id,
+            total_bytes: channel.total_bytes,
+            current_batch_started: None,
+            bytes: Vec::with_capacity(channel.total_bytes as usize),
+            error_message: None,
+            failed_batches: 0,
+            retry_after: None,
+        }
+    }
+
+    pub fn community_id(&self) -> CommunityId {
+        self.community_id
+    }
+
+    pub fn channel_id(&self) -> ChannelId {
+        self.channel_id
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    // Returns the offset to import the next page from, or `None` if a page is already being imported
+    // or the import is complete
+    pub fn next_batch(&mut self, now: TimestampMillis) -> Option<u64> {
+        if self.current_batch_started.is_none() && !self.is_complete() && self.retry_after.map_or(true, |ts| now >= ts) {
+            self.current_batch_started = Some(now);
+            Some(self.bytes.len() as u64)
+        } else {
+            None
+        }
+    }
+
+    // Returns true if the channel bytes have all been imported, else false
+    pub fn mark_batch_complete(&mut self, bytes: &[u8]) -> bool {
+        self.current_batch_started = None;
+        self.error_message = None;
+        self.failed_batches = 0;
+        self.retry_after = None;
+        self.bytes.extend_from_slice(bytes);
+        self.is_complete()
+    }
+
+    pub fn mark_batch_failed(&mut self, error_message: String, now: TimestampMillis) {
+        let delay = min(SECOND_IN_MS << min(self.failed_batches, 16), MAX_RETRY_DELAY);
+        self.current_batch_started = None;
+        self.error_message = Some(error_message);
+        self.failed_batches += 1;
+        self.retry_after = Some(now + delay);
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.bytes.len() as u64 == self.total_bytes
+    }
+}
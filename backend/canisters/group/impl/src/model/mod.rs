@@ -1 +1,2 @@
+pub mod channel_being_imported;
 pub mod new_joiner_rewards;
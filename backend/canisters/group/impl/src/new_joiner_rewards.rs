@@ -82,6 +82,7 @@ fn send_reward_transferred_message(user_id: UserId, transfer: nns::CompletedCryp
         mentioned: Vec::new(),
         replies_to: None,
         forwarded: false,
+        forwarded_from: None,
         correlation_id: 0,
         now: state.env.now(),
     });
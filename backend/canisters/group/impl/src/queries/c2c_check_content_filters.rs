@@ -0,0 +1,33 @@
+use crate::{read_state, RuntimeState};
+use canister_api_macros::query_msgpack;
+use group_canister::c2c_check_content_filters::{Response::*, *};
+use types::{ContentFilterAction, ContentFilterMatch};
+
+// Called by user canisters before they make the transfer for a message which includes crypto
+#[query_msgpack]
+fn c2c_check_content_filters(args: Args) -> Response {
+    read_state(|state| c2c_check_content_filters_impl(args, state))
+}
+
+fn c2c_check_content_filters_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        match state.data.chat.content_filter_match(
+            user_id,
+            args.text.as_deref(),
+            args.mention_count as usize,
+            None,
+            state.data.proposals_bot_user_id,
+        ) {
+            // Messages which would be deleted are rejected up front so that the sender isn't charged for them
+            Some(ContentFilterMatch {
+                action: ContentFilterAction::Reject | ContentFilterAction::DeleteAndWarn,
+                reason,
+            }) => ContentRejected(reason),
+            _ => Success,
+        }
+    } else {
+        CallerNotInGroup
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{read_state, RuntimeState};
+use group_canister::content_filters::{Response::*, *};
+use group_chat_core::ContentFiltersResult;
+use ic_cdk_macros::query;
+
+#[query]
+fn content_filters(_args: Args) -> Response {
+    read_state(content_filters_impl)
+}
+
+fn content_filters_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        match state.data.chat.content_filters(user_id) {
+            ContentFiltersResult::Success(filters) => Success(filters),
+            ContentFiltersResult::UserNotInGroup => CallerNotInGroup,
+            ContentFiltersResult::NotAuthorized => NotAuthorized,
+        }
+    } else {
+        CallerNotInGroup
+    }
+}
@@ -0,0 +1,22 @@
+use crate::read_state;
+use crate::RuntimeState;
+use group_canister::invite_links::{Response::*, *};
+use ic_cdk_macros::query;
+
+#[query]
+fn invite_links(_: Args) -> Response {
+    read_state(invite_links_impl)
+}
+
+fn invite_links_impl(state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+    if let Some(member) = state.data.get_member(caller) {
+        if member.role.can_invite_users(&state.data.chat.permissions) {
+            return Success(SuccessResult {
+                links: state.data.invite_links.list(),
+            });
+        }
+    }
+
+    NotAuthorized
+}
@@ -0,0 +1,27 @@
+use crate::{read_state, RuntimeState};
+use group_canister::message_edit_history::{Response::*, *};
+use group_chat_core::MessageEditHistoryResult;
+use ic_cdk_macros::query;
+
+#[query]
+fn message_edit_history(args: Args) -> Response {
+    read_state(|state| message_edit_history_impl(args, state))
+}
+
+fn message_edit_history_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        match state
+            .data
+            .chat
+            .message_edit_history(user_id, args.thread_root_message_index, args.message_id, state.env.now())
+        {
+            MessageEditHistoryResult::Success(revisions) => Success(SuccessResult { revisions }),
+            MessageEditHistoryResult::UserNotInGroup => CallerNotInGroup,
+            MessageEditHistoryResult::MessageNotFound => MessageNotFound,
+        }
+    } else {
+        CallerNotInGroup
+    }
+}
@@ -1,16 +1,23 @@
 use crate::RuntimeState;
 use types::TimestampMillis;
 
+mod c2c_check_content_filters;
 mod c2c_events_internal;
 mod c2c_name_and_members;
+mod content_filters;
 mod deleted_message;
 mod events;
 mod events_by_index;
 mod events_window;
 mod http_request;
+mod instruction_counts;
 mod invite_code;
+mod invite_links;
+mod join_requests;
 mod local_user_index;
+mod message_edit_history;
 mod messages_by_message_index;
+mod moderation_queue;
 mod public_summary;
 mod rules;
 mod search_messages;
@@ -0,0 +1,26 @@
+use crate::{read_state, RuntimeState};
+use group_canister::moderation_queue::{Response::*, *};
+use group_chat_core::ModerationQueueResult;
+use ic_cdk_macros::query;
+
+#[query]
+fn moderation_queue(args: Args) -> Response {
+    read_state(|state| moderation_queue_impl(args, state))
+}
+
+fn moderation_queue_impl(args: Args, state: &RuntimeState) -> Response {
+    let caller = state.env.caller();
+
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        match state.data.chat.moderation_queue(user_id, args.since, args.include_resolved) {
+            ModerationQueueResult::Success(result) => Success(SuccessResult {
+                reports: result.reports,
+                decisions: result.decisions,
+            }),
+            ModerationQueueResult::UserNotInGroup => CallerNotInGroup,
+            ModerationQueueResult::NotAuthorized => NotAuthorized,
+        }
+    } else {
+        CallerNotInGroup
+    }
+}
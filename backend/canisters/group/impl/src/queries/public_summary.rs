@@ -13,7 +13,7 @@ fn public_summary(args: Args) -> Response {
 fn public_summary_impl(args: Args, state: &RuntimeState) -> Response {
     let caller = state.env.caller();
 
-    if !state.data.is_accessible(caller, args.invite_code) {
+    if !state.data.is_accessible(caller, args.invite_code, state.env.now()) {
         return NotAuthorized;
     }
 
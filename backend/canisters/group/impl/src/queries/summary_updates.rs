@@ -92,6 +92,7 @@ fn summary_updates_impl(args: Args, state: &RuntimeState) -> Response {
             events_ttl: updates.events_ttl,
             events_ttl_last_updated: updates.events_ttl_last_updated,
             gate: updates.gate,
+            slow_mode: updates.slow_mode,
             rules_accepted: membership.rules_accepted,
             membership: Some(membership),
         },
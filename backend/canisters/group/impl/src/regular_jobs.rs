@@ -1,13 +1,14 @@
 use crate::Data;
 use utils::env::Environment;
 use utils::regular_jobs::{RegularJob, RegularJobs};
-use utils::time::MINUTE_IN_MS;
+use utils::time::{HOUR_IN_MS, MINUTE_IN_MS};
 
 pub(crate) fn build() -> RegularJobs<Data> {
     let check_cycles_balance = RegularJob::new("Check cycles balance", check_cycles_balance, 5 * MINUTE_IN_MS);
     let retry_deleting_files = RegularJob::new("Retry deleting files", retry_deleting_files, MINUTE_IN_MS);
+    let expire_join_requests = RegularJob::new("Expire join requests", expire_join_requests, HOUR_IN_MS);
 
-    RegularJobs::new(vec![check_cycles_balance, retry_deleting_files])
+    RegularJobs::new(vec![check_cycles_balance, retry_deleting_files, expire_join_requests])
 }
 
 fn check_cycles_balance(_: &dyn Environment, data: &mut Data) {
@@ -17,3 +18,7 @@ fn check_cycles_balance(_: &dyn Environment, data: &mut Data) {
 fn retry_deleting_files(_: &dyn Environment, _: &mut Data) {
     storage_bucket_client::retry_failed();
 }
+
+fn expire_join_requests(env: &dyn Environment, data: &mut Data) {
+    data.expire_join_requests(env.now());
+}
@@ -4,7 +4,7 @@ use chat_events::MessageContentInternal;
 use ledger_utils::process_transaction;
 use serde::{Deserialize, Serialize};
 use tracing::error;
-use types::{BlobReference, CanisterId, MessageId, MessageIndex, PendingCryptoTransaction};
+use types::{BlobReference, CanisterId, MessageId, MessageIndex, PendingCryptoTransaction, UserId};
 use utils::consts::MEMO_PRIZE_REFUND;
 use utils::time::MINUTE_IN_MS;
 
@@ -16,6 +16,7 @@ pub enum TimerJob {
     RefundPrize(RefundPrizeJob),
     MakeTransfer(MakeTransferJob),
     RemoveExpiredEvents(RemoveExpiredEventsJob),
+    UnmuteMember(UnmuteMemberJob),
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -49,6 +50,11 @@ pub struct MakeTransferJob {
 #[derive(Serialize, Deserialize, Clone)]
 pub struct RemoveExpiredEventsJob;
 
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UnmuteMemberJob {
+    pub user_id: UserId,
+}
+
 impl Job for TimerJob {
     fn execute(self) {
         match self {
@@ -58,6 +64,7 @@ impl Job for TimerJob {
             TimerJob::RefundPrize(job) => job.execute(),
             TimerJob::MakeTransfer(job) => job.execute(),
             TimerJob::RemoveExpiredEvents(job) => job.execute(),
+            TimerJob::UnmuteMember(job) => job.execute(),
         }
     }
 }
@@ -66,13 +73,12 @@ impl Job for HardDeleteMessageContentJob {
     fn execute(self) {
         let mut follow_on_jobs = Vec::new();
         mutate_state(|state| {
-            if let Some((content, sender)) = state
+            if let Some((content, sender, files_to_delete)) = state
                 .data
                 .chat
                 .events
                 .remove_deleted_message_content(self.thread_root_message_index, self.message_id)
             {
-                let files_to_delete = content.blob_references();
                 if !files_to_delete.is_empty() {
                     // If there was already a job queued up to delete these files, cancel it
                     state.data.timer_jobs.cancel_jobs(|job| {
@@ -187,3 +193,14 @@ impl Job for RemoveExpiredEventsJob {
         mutate_state(|state| state.run_event_expiry_job());
     }
 }
+
+impl Job for UnmuteMemberJob {
+    fn execute(self) {
+        mutate_state(|state| {
+            let now = state.env.now();
+            if state.data.chat.unmute_member_if_expired(self.user_id, now) {
+                handle_activity_notification(state);
+            }
+        });
+    }
+}
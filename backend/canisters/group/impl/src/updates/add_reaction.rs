@@ -5,6 +5,7 @@ use chat_events::Reader;
 use group_canister::add_reaction::{Response::*, *};
 use group_chat_core::AddRemoveReactionResult;
 use ic_cdk_macros::update;
+use instruction_counts_log::InstructionCountFunctionId;
 use types::{EventIndex, GroupReactionAddedNotification, Notification, UserId};
 
 #[update]
@@ -12,7 +13,13 @@ use types::{EventIndex, GroupReactionAddedNotification, Notification, UserId};
 fn add_reaction(args: Args) -> Response {
     run_regular_jobs();
 
-    mutate_state(|state| add_reaction_impl(args, state))
+    mutate_state(|state| {
+        let response = add_reaction_impl(args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::AddReaction, state.env.now());
+        response
+    })
 }
 
 fn add_reaction_impl(args: Args, state: &mut RuntimeState) -> Response {
@@ -20,6 +27,11 @@ fn add_reaction_impl(args: Args, state: &mut RuntimeState) -> Response {
         return ChatFrozen;
     }
 
+    // Custom emoji belong to a community's packs so can't be used in groups
+    if args.reaction.is_custom_emoji() {
+        return InvalidReaction;
+    }
+
     let caller = state.env.caller();
     if let Some(user_id) = state.data.lookup_user_id(caller) {
         let now = state.env.now();
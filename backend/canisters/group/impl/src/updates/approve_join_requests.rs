@@ -0,0 +1,127 @@
+use crate::updates::c2c_join_group::c2c_join_group_impl;
+use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
+use candid::Principal;
+use canister_tracing_macros::trace;
+use gated_groups::{check_if_passes_gate, CheckGateArgs, CheckIfPassesGateResult};
+use group_canister::approve_join_requests::{Response::*, *};
+use group_canister::c2c_join_group;
+use ic_cdk_macros::update;
+use local_user_index_canister::c2c_notify_join_request_outcome::JoinRequestOutcome;
+use types::{AccessGate, UserId};
+
+#[update]
+#[trace]
+async fn approve_join_requests(args: Args) -> Response {
+    run_regular_jobs();
+
+    let PrepareResult {
+        applicants,
+        not_found,
+        gate,
+    } = match read_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    let mut approved = Vec::new();
+    let mut failed = Vec::new();
+
+    for (user_id, principal) in applicants {
+        // Approved applicants must still pass the group's access gate
+        if let Some(gate) = gate.as_ref() {
+            let check_gate_args = read_state(|state| CheckGateArgs {
+                gate: gate.clone(),
+                user_index_canister: state.data.user_index_canister_id,
+                user_id,
+                this_canister: state.env.canister_id(),
+                now_nanos: state.env.now_nanos(),
+            });
+            if !matches!(check_if_passes_gate(check_gate_args).await, CheckIfPassesGateResult::Success) {
+                failed.push(user_id);
+                continue;
+            }
+        }
+
+        if mutate_state(|state| commit(user_id, principal, state)) {
+            approved.push(user_id);
+        } else {
+            failed.push(user_id);
+        }
+    }
+
+    Success(SuccessResult {
+        approved,
+        not_found,
+        failed,
+    })
+}
+
+struct PrepareResult {
+    applicants: Vec<(UserId, Principal)>,
+    not_found: Vec<UserId>,
+    gate: Option<AccessGate>,
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<PrepareResult, Response> {
+    if state.data.is_frozen() {
+        return Err(ChatFrozen);
+    }
+
+    let caller = state.env.caller();
+    if let Some(member) = state.data.get_member(caller) {
+        if member.suspended.value {
+            return Err(UserSuspended);
+        }
+        if !member.role.can_invite_users(&state.data.chat.permissions) {
+            return Err(NotAuthorized);
+        }
+
+        let mut applicants = Vec::new();
+        let mut not_found = Vec::new();
+        for user_id in args.user_ids.iter().copied() {
+            if let Some(request) = state.data.join_requests.get(&user_id) {
+                applicants.push((user_id, request.principal));
+            } else {
+                not_found.push(user_id);
+            }
+        }
+
+        Ok(PrepareResult {
+            applicants,
+            not_found,
+            gate: state.data.chat.gate.value.clone(),
+        })
+    } else {
+        Err(CallerNotInGroup)
+    }
+}
+
+fn commit(user_id: UserId, principal: Principal, state: &mut RuntimeState) -> bool {
+    // The request may have been dealt with by another admin while the gate was being checked
+    if state.data.join_requests.get(&user_id).is_none() {
+        return false;
+    }
+
+    let args = c2c_join_group::Args {
+        user_id,
+        principal,
+        invite_code: None,
+        correlation_id: 0,
+        is_platform_moderator: false,
+        is_bot: false,
+    };
+
+    match c2c_join_group_impl(args, state) {
+        c2c_join_group::Response::Success(summary) | c2c_join_group::Response::AlreadyInGroupV2(summary) => {
+            if let Some(request) = state.data.join_requests.remove(&user_id) {
+                state.data.notify_join_request_outcome(
+                    user_id,
+                    request.local_user_index_canister_id,
+                    JoinRequestOutcome::GroupJoined(summary.latest_message.as_ref().map(|m| m.event.message_index)),
+                );
+            }
+            true
+        }
+        _ => false,
+    }
+}
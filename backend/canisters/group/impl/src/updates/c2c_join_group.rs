@@ -24,7 +24,15 @@ async fn c2c_join_group(args: Args) -> Response {
         Err(response) => return response,
     };
 
-    mutate_state(|state| c2c_join_group_impl(args, state))
+    mutate_state(|state| {
+        // Checked again since the state may have changed while the gate was being checked, eg. other users may have
+        // taken the invite link up to its max uses
+        if let Err(response) = is_permitted_to_join(&args, state) {
+            response
+        } else {
+            c2c_join_group_impl(args, state)
+        }
+    })
 }
 
 fn is_permitted_to_join(args: &Args, state: &RuntimeState) -> Result<Option<CheckGateArgs>, Response> {
@@ -35,7 +43,7 @@ fn is_permitted_to_join(args: &Args, state: &RuntimeState) -> Result<Option<Chec
         Ok(None)
     } else if state.data.is_frozen() {
         Err(ChatFrozen)
-    } else if !state.data.is_accessible(args.principal, args.invite_code) {
+    } else if !state.data.is_accessible(args.principal, args.invite_code, state.env.now()) {
         Err(NotInvited)
     } else if let Some(limit) = state.data.chat.members.user_limit_reached() {
         Err(ParticipantLimitReached(limit))
@@ -53,7 +61,7 @@ fn is_permitted_to_join(args: &Args, state: &RuntimeState) -> Result<Option<Chec
     }
 }
 
-fn c2c_join_group_impl(args: Args, state: &mut RuntimeState) -> Response {
+pub(crate) fn c2c_join_group_impl(args: Args, state: &mut RuntimeState) -> Response {
     let now = state.env.now();
     let min_visible_event_index;
     let min_visible_message_index;
@@ -102,6 +110,7 @@ fn c2c_join_group_impl(args: Args, state: &mut RuntimeState) -> Response {
     }) {
         AddResult::Success(participant) => {
             let invitation = state.data.chat.invited_users.remove(&args.user_id, now);
+            state.data.join_requests.remove(&args.user_id);
 
             let event = MemberJoined {
                 user_id: args.user_id,
@@ -115,7 +124,15 @@ fn c2c_join_group_impl(args: Args, state: &mut RuntimeState) -> Response {
 
             new_event = true;
 
-            let summary = state.summary(&participant);
+            if let Some(code) = args.invite_code {
+                let chat = &mut state.data.chat;
+                state.data.invite_links.apply(code, now, |created_by, role| {
+                    chat.change_role(created_by, args.user_id, role, false, false, now);
+                });
+            }
+
+            let member = state.data.chat.members.get(&args.user_id).unwrap_or(&participant);
+            let summary = state.summary(member);
 
             // If there is a payment gate on this group then queue payments to owner(s) and treasury
             if let Some(AccessGate::Payment(gate)) = state.data.chat.gate.value.as_ref() {
@@ -0,0 +1,31 @@
+use crate::{read_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use group_canister::c2c_message_for_forwarding::{Response::*, *};
+use group_chat_core::MessageForForwardingResult;
+use types::Chat;
+
+// Called by user canisters when forwarding a message from this group to other chats
+#[update_msgpack]
+#[trace]
+fn c2c_message_for_forwarding(args: Args) -> Response {
+    run_regular_jobs();
+
+    read_state(|state| c2c_message_for_forwarding_impl(args, state))
+}
+
+fn c2c_message_for_forwarding_impl(args: Args, state: &RuntimeState) -> Response {
+    let user_id = state.env.caller().into();
+    let chat = Chat::Group(state.env.canister_id().into());
+
+    match state
+        .data
+        .chat
+        .message_for_forwarding(user_id, chat, false, args.thread_root_message_index, args.message_index)
+    {
+        MessageForForwardingResult::Success(message) => Success(message),
+        MessageForForwardingResult::UserNotInGroup => CallerNotInGroup,
+        MessageForForwardingResult::MessageNotFound => MessageNotFound,
+        MessageForForwardingResult::NotForwardable => NotForwardable,
+    }
+}
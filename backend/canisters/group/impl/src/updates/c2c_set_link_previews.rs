@@ -0,0 +1,28 @@
+use crate::guards::caller_is_notifications_canister;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use group_canister::c2c_set_link_previews::{Response::*, *};
+
+#[update_msgpack(guard = "caller_is_notifications_canister")]
+#[trace]
+fn c2c_set_link_previews(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_set_link_previews_impl(args, state))
+}
+
+fn c2c_set_link_previews_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let now = state.env.now();
+
+    if state
+        .data
+        .chat
+        .events
+        .set_link_previews(args.thread_root_message_index, args.message_id, None, args.link_previews, now)
+    {
+        Success
+    } else {
+        MessageNotFound
+    }
+}
@@ -7,6 +7,7 @@ use canister_tracing_macros::trace;
 use chat_events::DeleteMessageResult;
 use group_canister::delete_messages::{Response::*, *};
 use group_chat_core::DeleteMessagesResult;
+use instruction_counts_log::InstructionCountFunctionId;
 use types::{CanisterId, UserId};
 use user_index_canister_c2c_client::lookup_user;
 use utils::consts::OPENCHAT_BOT_USER_ID;
@@ -34,7 +35,13 @@ async fn delete_messages(args: Args) -> Response {
         }
     }
 
-    mutate_state(|state| delete_messages_impl(user_id, args, state))
+    mutate_state(|state| {
+        let response = delete_messages_impl(user_id, args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::DeleteMessages, state.env.now());
+        response
+    })
 }
 
 struct PrepareResult {
@@ -4,13 +4,20 @@ use canister_tracing_macros::trace;
 use chat_events::{EditMessageArgs, EditMessageResult};
 use group_canister::edit_message_v2::{Response::*, *};
 use ic_cdk_macros::update;
+use instruction_counts_log::InstructionCountFunctionId;
 
 #[update]
 #[trace]
 fn edit_message_v2(args: Args) -> Response {
     run_regular_jobs();
 
-    mutate_state(|state| edit_message_impl(args, state))
+    mutate_state(|state| {
+        let response = edit_message_impl(args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::EditMessage, state.env.now());
+        response
+    })
 }
 
 fn edit_message_impl(args: Args, state: &mut RuntimeState) -> Response {
@@ -1,11 +1,15 @@
 pub mod add_reaction;
+pub mod approve_join_requests;
 pub mod c2c_delete_group;
 pub mod c2c_export_group;
 pub mod c2c_freeze_group;
 pub mod c2c_invite_users;
 pub mod c2c_join_group;
 pub mod c2c_leave_group;
+pub mod c2c_message_for_forwarding;
 pub mod c2c_report_message_v2;
+pub mod c2c_request_to_join;
+pub mod c2c_set_link_previews;
 pub mod c2c_set_user_suspended;
 pub mod c2c_start_import_into_community;
 pub mod c2c_tip_message;
@@ -16,24 +20,32 @@ pub mod cancel_invites;
 pub mod change_role;
 pub mod claim_prize;
 pub mod convert_into_community;
+pub mod create_invite_link;
 pub mod decline_invitation;
 pub mod delete_messages;
 pub mod disable_invite_code;
 pub mod edit_message;
 pub mod enable_invite_code;
+pub mod export_chat;
 pub mod follow_thread;
+pub mod moderate_report;
+pub mod mute_member;
 pub mod pin_message;
 pub mod register_poll_vote;
 pub mod register_proposal_vote;
 pub mod register_proposal_vote_v2;
+pub mod reject_join_requests;
 pub mod remove_participant;
 pub mod remove_reaction;
 pub mod report_message;
+pub mod revoke_invite_link;
 pub mod send_message;
+pub mod set_content_filters;
 pub mod toggle_mute_notifications;
 pub mod unblock_user;
 pub mod undelete_messages;
 pub mod unfollow_thread;
+pub mod unmute_member;
 pub mod unpin_message;
 pub mod update_group_v2;
 pub mod wallet_receive;
@@ -0,0 +1,92 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::updates::mute_member::schedule_unmute;
+use crate::updates::remove_participant::remove_participant_impl;
+use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use group_canister::moderate_report::{Response::*, *};
+use group_chat_core::CanModerateReportResult;
+use ic_cdk_macros::update;
+use types::{ModerationAction, UserId};
+
+#[update]
+#[trace]
+async fn moderate_report(args: Args) -> Response {
+    run_regular_jobs();
+
+    let sender = match read_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    if matches!(args.action, ModerationAction::RemoveSender | ModerationAction::BlockSender) {
+        let block = matches!(args.action, ModerationAction::BlockSender);
+
+        match remove_participant_impl(sender, block).await {
+            group_canister::remove_participant::Response::Success => {}
+            // The sender may have already left the group
+            group_canister::remove_participant::Response::UserNotInGroup => {}
+            group_canister::remove_participant::Response::NotAuthorized => return NotAuthorized,
+            group_canister::remove_participant::Response::InternalError(error) => return InternalError(error),
+            response => return InternalError(format!("{response:?}")),
+        }
+    }
+
+    mutate_state(|state| commit(args, state))
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<UserId, Response> {
+    if state.data.is_frozen() {
+        return Err(ChatFrozen);
+    }
+
+    let caller = state.env.caller();
+
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        match state.data.chat.can_moderate_report(user_id, args.report_id) {
+            CanModerateReportResult::Yes(sender) => Ok(sender),
+            result => Err(map_error(result)),
+        }
+    } else {
+        Err(CallerNotInGroup)
+    }
+}
+
+fn commit(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        let now = state.env.now();
+        let suspend_sender = matches!(args.action, ModerationAction::SuspendSender(_));
+
+        match state
+            .data
+            .chat
+            .moderate_report(user_id, args.report_id, args.action, args.notes, now)
+        {
+            CanModerateReportResult::Yes(sender) => {
+                if suspend_sender {
+                    if let Some(muted_until) = state.data.chat.members.muted_until(&sender, now) {
+                        schedule_unmute(sender, muted_until, state);
+                    }
+                }
+                handle_activity_notification(state);
+                Success
+            }
+            result => map_error(result),
+        }
+    } else {
+        CallerNotInGroup
+    }
+}
+
+fn map_error(result: CanModerateReportResult) -> Response {
+    match result {
+        CanModerateReportResult::Yes(_) => Success,
+        CanModerateReportResult::UserSuspended => UserSuspended,
+        CanModerateReportResult::UserNotInGroup => CallerNotInGroup,
+        CanModerateReportResult::NotAuthorized => NotAuthorized,
+        CanModerateReportResult::ReportNotFound => ReportNotFound,
+        CanModerateReportResult::AlreadyResolved => AlreadyResolved,
+        CanModerateReportResult::InvalidDuration => InvalidDuration,
+    }
+}
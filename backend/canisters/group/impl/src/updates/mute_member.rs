@@ -0,0 +1,56 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::timer_job_types::UnmuteMemberJob;
+use crate::{mutate_state, run_regular_jobs, RuntimeState, TimerJob};
+use canister_tracing_macros::trace;
+use group_canister::mute_member::{Response::*, *};
+use group_chat_core::MuteMemberResult;
+use ic_cdk_macros::update;
+use types::{TimestampMillis, UserId};
+
+#[update]
+#[trace]
+fn mute_member(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| mute_member_impl(args, state))
+}
+
+fn mute_member_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        let now = state.env.now();
+
+        match state.data.chat.mute_member(user_id, args.user_id, args.duration, now) {
+            MuteMemberResult::Success(muted_until) => {
+                schedule_unmute(args.user_id, muted_until, state);
+                handle_activity_notification(state);
+                Success(SuccessResult { muted_until })
+            }
+            MuteMemberResult::UserNotInGroup => CallerNotInGroup,
+            MuteMemberResult::TargetUserNotInGroup => UserNotInGroup,
+            MuteMemberResult::UserSuspended => UserSuspended,
+            MuteMemberResult::NotAuthorized => NotAuthorized,
+            MuteMemberResult::CannotMuteSelf => CannotMuteSelf,
+            MuteMemberResult::InvalidDuration => InvalidDuration,
+        }
+    } else {
+        CallerNotInGroup
+    }
+}
+
+pub(crate) fn schedule_unmute(user_id: UserId, muted_until: TimestampMillis, state: &mut RuntimeState) {
+    // Replace any job left over from a previous mute of the same member
+    state
+        .data
+        .timer_jobs
+        .cancel_jobs(|j| matches!(j, TimerJob::UnmuteMember(job) if job.user_id == user_id));
+    state.data.timer_jobs.enqueue_job(
+        TimerJob::UnmuteMember(UnmuteMemberJob { user_id }),
+        muted_until,
+        state.env.now(),
+    );
+}
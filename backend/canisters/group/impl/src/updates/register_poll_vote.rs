@@ -4,13 +4,20 @@ use canister_tracing_macros::trace;
 use chat_events::{RegisterPollVoteArgs, RegisterPollVoteResult};
 use group_canister::register_poll_vote::{Response::*, *};
 use ic_cdk_macros::update;
+use instruction_counts_log::InstructionCountFunctionId;
 
 #[update]
 #[trace]
 async fn register_poll_vote(args: Args) -> Response {
     run_regular_jobs();
 
-    mutate_state(|state| register_poll_vote_impl(args, state))
+    mutate_state(|state| {
+        let response = register_poll_vote_impl(args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::RegisterPollVote, state.env.now());
+        response
+    })
 }
 
 fn register_poll_vote_impl(args: Args, state: &mut RuntimeState) -> Response {
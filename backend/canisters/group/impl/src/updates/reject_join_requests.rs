@@ -0,0 +1,57 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use group_canister::reject_join_requests::{Response::*, *};
+use ic_cdk_macros::update;
+use local_user_index_canister::c2c_notify_join_request_outcome::JoinRequestOutcome;
+
+const MAX_REASON_LENGTH: u32 = 500;
+
+#[update]
+#[trace]
+fn reject_join_requests(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| reject_join_requests_impl(args, state))
+}
+
+fn reject_join_requests_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    if let Some(member) = state.data.get_member(caller) {
+        if member.suspended.value {
+            return UserSuspended;
+        }
+        if !member.role.can_invite_users(&state.data.chat.permissions) {
+            return NotAuthorized;
+        }
+        if args
+            .reason
+            .as_ref()
+            .map_or(false, |r| r.chars().count() as u32 > MAX_REASON_LENGTH)
+        {
+            return ReasonTooLong(MAX_REASON_LENGTH);
+        }
+
+        let mut rejected = Vec::new();
+        let mut not_found = Vec::new();
+        for user_id in args.user_ids {
+            if let Some(request) = state.data.join_requests.remove(&user_id) {
+                state.data.notify_join_request_outcome(
+                    user_id,
+                    request.local_user_index_canister_id,
+                    JoinRequestOutcome::Rejected(args.reason.clone()),
+                );
+                rejected.push(user_id);
+            } else {
+                not_found.push(user_id);
+            }
+        }
+
+        Success(SuccessResult { rejected, not_found })
+    } else {
+        CallerNotInGroup
+    }
+}
@@ -25,7 +25,7 @@ async fn remove_participant(args: Args) -> Response {
     remove_participant_impl(args.user_id, false).await
 }
 
-async fn remove_participant_impl(user_to_remove: UserId, block: bool) -> Response {
+pub(crate) async fn remove_participant_impl(user_to_remove: UserId, block: bool) -> Response {
     // Check the caller can remove the user
     let prepare_result = match read_state(|state| prepare(user_to_remove, block, state)) {
         Ok(ok) => ok,
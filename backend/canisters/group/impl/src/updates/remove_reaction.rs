@@ -3,13 +3,20 @@ use canister_tracing_macros::trace;
 use group_canister::remove_reaction::{Response::*, *};
 use group_chat_core::AddRemoveReactionResult;
 use ic_cdk_macros::update;
+use instruction_counts_log::InstructionCountFunctionId;
 
 #[update]
 #[trace]
 fn remove_reaction(args: Args) -> Response {
     run_regular_jobs();
 
-    mutate_state(|state| remove_reaction_impl(args, state))
+    mutate_state(|state| {
+        let response = remove_reaction_impl(args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::RemoveReaction, state.env.now());
+        response
+    })
 }
 
 fn remove_reaction_impl(args: Args, state: &mut RuntimeState) -> Response {
@@ -0,0 +1,37 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use group_canister::revoke_invite_link::{Response::*, *};
+use ic_cdk_macros::update;
+
+#[update]
+#[trace]
+fn revoke_invite_link(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| revoke_invite_link_impl(args, state))
+}
+
+fn revoke_invite_link_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    if let Some(member) = state.data.get_member(caller) {
+        if member.suspended.value {
+            return UserSuspended;
+        }
+
+        if member.role.can_invite_users(&state.data.chat.permissions) {
+            return if state.data.invite_links.revoke(args.code) {
+                handle_activity_notification(state);
+                Success
+            } else {
+                LinkNotFound
+            };
+        }
+    }
+
+    NotAuthorized
+}
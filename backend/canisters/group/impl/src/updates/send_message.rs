@@ -6,14 +6,24 @@ use canister_timer_jobs::TimerJobs;
 use canister_tracing_macros::trace;
 use group_canister::send_message_v2::{Response::*, *};
 use group_chat_core::SendMessageResult;
-use types::{EventWrapper, GroupMessageNotification, Message, MessageContent, MessageIndex, Notification, TimestampMillis};
+use instruction_counts_log::InstructionCountFunctionId;
+use types::{
+    ContentFilterAction, ContentFilterMatch, EventWrapper, GroupMessageNotification, Message, MessageContent,
+    MessageContentInitial, MessageIndex, Notification, TimestampMillis,
+};
 
 #[update_candid_and_msgpack]
 #[trace]
 fn send_message_v2(args: Args) -> Response {
     run_regular_jobs();
 
-    mutate_state(|state| send_message_impl(args, state))
+    mutate_state(|state| {
+        let response = send_message_impl(args, state);
+        state
+            .data
+            .record_instructions_count(InstructionCountFunctionId::SendMessage, state.env.now());
+        response
+    })
 }
 
 fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
@@ -21,10 +31,19 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
         return ChatFrozen;
     }
 
+    // Stickers belong to a community's packs so can't be sent in groups
+    if matches!(args.content, MessageContentInitial::Sticker(_)) {
+        return InvalidRequest("Stickers can only be sent in community channels".to_string());
+    }
+
     let caller = state.env.caller();
     if let Some(user_id) = state.data.lookup_user_id(caller) {
         let now = state.env.now();
 
+        // Provenance is only trusted when it is set by the sender's user canister, which looks the
+        // message up in the chat it was forwarded from
+        let forwarded_from = args.forwarded_from.filter(|_| caller == user_id.into());
+
         match state.data.chat.send_message(
             user_id,
             args.thread_root_message_index,
@@ -33,8 +52,10 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
             args.replies_to,
             args.mentioned.iter().map(|u| u.user_id).collect(),
             args.forwarding,
+            forwarded_from,
             args.rules_accepted,
             state.data.proposals_bot_user_id,
+            None,
             now,
         ) {
             SendMessageResult::Success(result) => {
@@ -76,6 +97,16 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
                 });
 
                 state.push_notification(result.users_to_notify, notification);
+                state.request_link_previews(args.thread_root_message_index, args.message_id, content);
+
+                if let Some(ContentFilterMatch {
+                    action: ContentFilterAction::DeleteAndWarn,
+                    reason,
+                }) = result.content_filter_match
+                {
+                    state.data.notify_message_deleted_by_content_filter(user_id, reason);
+                }
+
                 handle_activity_notification(state);
 
                 Success(SuccessResult {
@@ -83,6 +114,7 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
                     message_index,
                     timestamp: now,
                     expires_at,
+                    content_filter_match: result.content_filter_match,
                 })
             }
             SendMessageResult::ThreadMessageNotFound => ThreadMessageNotFound,
@@ -94,6 +126,9 @@ fn send_message_impl(args: Args, state: &mut RuntimeState) -> Response {
             SendMessageResult::UserSuspended => UserSuspended,
             SendMessageResult::RulesNotAccepted => RulesNotAccepted,
             SendMessageResult::InvalidRequest(error) => InvalidRequest(error),
+            SendMessageResult::ContentRejected(reason) => ContentRejected(reason),
+            SendMessageResult::RateLimited(next_message_allowed) => RateLimited(next_message_allowed),
+            SendMessageResult::UserMuted(muted_until) => UserMuted(muted_until),
         }
     } else {
         CallerNotInGroup
@@ -0,0 +1,38 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use group_canister::set_content_filters::{Response::*, *};
+use group_chat_core::SetContentFiltersResult;
+use ic_cdk_macros::update;
+
+#[update]
+#[trace]
+fn set_content_filters(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| set_content_filters_impl(args, state))
+}
+
+fn set_content_filters_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        let now = state.env.now();
+
+        match state.data.chat.set_content_filters(user_id, args.content_filters, now) {
+            SetContentFiltersResult::Success => {
+                handle_activity_notification(state);
+                Success
+            }
+            SetContentFiltersResult::UserSuspended => UserSuspended,
+            SetContentFiltersResult::UserNotInGroup => CallerNotInGroup,
+            SetContentFiltersResult::NotAuthorized => NotAuthorized,
+            SetContentFiltersResult::InvalidContentFilters(error) => InvalidContentFilters(error),
+        }
+    } else {
+        CallerNotInGroup
+    }
+}
@@ -0,0 +1,44 @@
+use crate::activity_notifications::handle_activity_notification;
+use crate::{mutate_state, run_regular_jobs, RuntimeState, TimerJob};
+use canister_tracing_macros::trace;
+use group_canister::unmute_member::{Response::*, *};
+use group_chat_core::UnmuteMemberResult;
+use ic_cdk_macros::update;
+
+#[update]
+#[trace]
+fn unmute_member(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| unmute_member_impl(args, state))
+}
+
+fn unmute_member_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if state.data.is_frozen() {
+        return ChatFrozen;
+    }
+
+    let caller = state.env.caller();
+    if let Some(user_id) = state.data.lookup_user_id(caller) {
+        let now = state.env.now();
+
+        match state.data.chat.unmute_member(user_id, args.user_id, now) {
+            UnmuteMemberResult::Success => {
+                state
+                    .data
+                    .timer_jobs
+                    .cancel_jobs(|j| matches!(j, TimerJob::UnmuteMember(job) if job.user_id == args.user_id));
+
+                handle_activity_notification(state);
+                Success
+            }
+            UnmuteMemberResult::UserNotInGroup => CallerNotInGroup,
+            UnmuteMemberResult::TargetUserNotInGroup => UserNotInGroup,
+            UnmuteMemberResult::TargetUserNotMuted => UserNotMuted,
+            UnmuteMemberResult::UserSuspended => UserSuspended,
+            UnmuteMemberResult::NotAuthorized => NotAuthorized,
+        }
+    } else {
+        CallerNotInGroup
+    }
+}
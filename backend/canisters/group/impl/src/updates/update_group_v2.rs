@@ -146,6 +146,9 @@ fn commit(my_user_id: UserId, args: Args, state: &mut RuntimeState) -> SuccessRe
         args.gate,
         args.public,
         args.events_ttl,
+        args.slow_mode,
+        args.edit_history_enabled,
+        args.hide_forwarded_from,
         state.env.now(),
     );
 
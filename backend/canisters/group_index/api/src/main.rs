@@ -19,6 +19,8 @@ fn main() {
     generate_candid_method!(group_index, set_community_moderation_flags, update);
     generate_candid_method!(group_index, set_community_upgrade_concurrency, update);
     generate_candid_method!(group_index, set_group_upgrade_concurrency, update);
+    generate_candid_method!(group_index, update_community_upgrade_rollout, update);
+    generate_candid_method!(group_index, update_group_upgrade_rollout, update);
 
     candid::export_service!();
     std::print!("{}", __export_service());
@@ -0,0 +1,29 @@
+use candid::Principal;
+use serde::{Deserialize, Serialize};
+use types::{AccessGate, ChannelId, ChatId, Document, GroupPermissions, GroupSubtype, Milliseconds, Rules, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub user_principal: Principal,
+    pub channel_id: ChannelId,
+    pub total_bytes: u64,
+    pub is_public: bool,
+    pub name: String,
+    pub description: String,
+    pub rules: Rules,
+    pub subtype: Option<GroupSubtype>,
+    pub avatar: Option<Document>,
+    pub history_visible_to_new_joiners: bool,
+    pub permissions_v2: Option<GroupPermissions>,
+    pub events_ttl: Option<Milliseconds>,
+    pub gate: Option<AccessGate>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(ChatId),
+    NameTaken,
+    CyclesBalanceTooLow,
+    InternalError(String),
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use types::{ChannelId, CommunityId, UserId};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub community_id: CommunityId,
+    pub channel_id: ChannelId,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(u64),
+    CommunityNotFound,
+    UserNotInCommunity,
+    UserNotCommunityOwner,
+    UserNotChannelOwner,
+    UserSuspended,
+    ChannelNotFound,
+    ChannelAlreadyBeingMoved,
+    CommunityFrozen,
+    InternalError(String),
+}
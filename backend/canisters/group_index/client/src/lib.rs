@@ -11,3 +11,4 @@ generate_update_call!(add_local_group_index_canister);
 generate_update_call!(upgrade_community_canister_wasm);
 generate_update_call!(upgrade_group_canister_wasm);
 generate_update_call!(upgrade_local_group_index_canister_wasm);
+generate_update_call!(upload_wasm_chunk);
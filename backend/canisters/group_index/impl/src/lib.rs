@@ -10,6 +10,7 @@ use crate::model::public_groups::PublicGroups;
 use candid::{CandidType, Principal};
 use canister_state_macros::canister_state;
 use fire_and_forget_handler::FireAndForgetHandler;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use model::local_group_index_map::LocalGroupIndexMap;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
@@ -18,7 +19,7 @@ use types::{
     BuildVersion, CanisterId, CanisterWasm, ChatId, CommunityId, Cycles, FrozenGroupInfo, Milliseconds, TimestampMillis,
     Timestamped, UserId,
 };
-use utils::canister::{CanistersRequiringUpgrade, FailedUpgradeCount};
+use utils::canister::{CanistersRequiringUpgrade, ChunkedWasms, FailedUpgradeCount};
 use utils::env::Environment;
 use utils::time::MINUTE_IN_MS;
 
@@ -97,6 +98,7 @@ impl RuntimeState {
             canister_upgrades_in_progress: canister_upgrades_metrics.in_progress as u64,
             governance_principals: self.data.governance_principals.iter().copied().collect(),
             group_wasm_version: self.data.group_canister_wasm.version,
+            community_wasm_version: self.data.community_canister_wasm.version,
             local_group_index_wasm_version: self.data.local_group_index_canister_wasm_for_new_canisters.version,
             local_group_indexes: self.data.local_index_map.iter().map(|(c, i)| (*c, i.clone())).collect(),
             canister_ids: CanisterIds {
@@ -126,6 +128,8 @@ struct Data {
     pub cycles_dispenser_canister_id: CanisterId,
     pub proposals_bot_user_id: UserId,
     pub canisters_requiring_upgrade: CanistersRequiringUpgrade,
+    #[serde(default)]
+    pub chunked_wasms: ChunkedWasms,
     pub test_mode: bool,
     pub total_cycles_spent_on_canisters: Cycles,
     pub cached_hot_groups: CachedHotGroups,
@@ -164,6 +168,7 @@ impl Data {
             cycles_dispenser_canister_id,
             proposals_bot_user_id,
             canisters_requiring_upgrade: CanistersRequiringUpgrade::default(),
+            chunked_wasms: ChunkedWasms::default(),
             test_mode,
             total_cycles_spent_on_canisters: 0,
             cached_hot_groups: CachedHotGroups::default(),
@@ -245,6 +250,7 @@ impl Default for Data {
             cycles_dispenser_canister_id: Principal::anonymous(),
             proposals_bot_user_id: Principal::anonymous().into(),
             canisters_requiring_upgrade: CanistersRequiringUpgrade::default(),
+            chunked_wasms: ChunkedWasms::default(),
             test_mode: true,
             total_cycles_spent_on_canisters: 0,
             cached_hot_groups: CachedHotGroups::default(),
@@ -286,11 +292,130 @@ pub struct Metrics {
     pub canister_upgrades_pending: u64,
     pub canister_upgrades_in_progress: u64,
     pub group_wasm_version: BuildVersion,
+    pub community_wasm_version: BuildVersion,
     pub local_group_index_wasm_version: BuildVersion,
     pub local_group_indexes: Vec<(CanisterId, LocalGroupIndex)>,
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const GROUPS: &str = "group_index_groups";
+        const GROUPS_HELP: &str = "Groups, by visibility and status";
+        const COMMUNITIES: &str = "group_index_communities";
+        const COMMUNITIES_HELP: &str = "Communities, by visibility and status";
+
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .counter(
+                "group_index_cycles_spent_on_canisters",
+                "Cycles spent creating and topping up canisters",
+                self.total_cycles_spent_on_canisters,
+            );
+
+        for (visibility, all, active, deleted) in [
+            (
+                "public",
+                self.public_groups,
+                self.active_public_groups,
+                self.deleted_public_groups,
+            ),
+            (
+                "private",
+                self.private_groups,
+                self.active_private_groups,
+                self.deleted_private_groups,
+            ),
+        ] {
+            registry
+                .gauge_with_labels(GROUPS, GROUPS_HELP, &[("visibility", visibility), ("status", "all")], all)
+                .gauge_with_labels(
+                    GROUPS,
+                    GROUPS_HELP,
+                    &[("visibility", visibility), ("status", "active")],
+                    active,
+                )
+                .gauge_with_labels(
+                    GROUPS,
+                    GROUPS_HELP,
+                    &[("visibility", visibility), ("status", "deleted")],
+                    deleted,
+                );
+        }
+
+        for (visibility, all, active, deleted) in [
+            (
+                "public",
+                self.public_communities,
+                self.active_public_communities,
+                self.deleted_public_communities,
+            ),
+            (
+                "private",
+                self.private_communities,
+                self.active_private_communities,
+                self.deleted_private_communities,
+            ),
+        ] {
+            registry
+                .gauge_with_labels(
+                    COMMUNITIES,
+                    COMMUNITIES_HELP,
+                    &[("visibility", visibility), ("status", "all")],
+                    all,
+                )
+                .gauge_with_labels(
+                    COMMUNITIES,
+                    COMMUNITIES_HELP,
+                    &[("visibility", visibility), ("status", "active")],
+                    active,
+                )
+                .gauge_with_labels(
+                    COMMUNITIES,
+                    COMMUNITIES_HELP,
+                    &[("visibility", visibility), ("status", "deleted")],
+                    deleted,
+                );
+        }
+
+        registry
+            .gauge(
+                "group_index_group_deleted_notifications_pending",
+                "Group deleted notifications waiting to be sent",
+                self.group_deleted_notifications_pending,
+            )
+            .gauge(
+                "group_index_community_deleted_notifications_pending",
+                "Community deleted notifications waiting to be sent",
+                self.community_deleted_notifications_pending,
+            )
+            .gauge("group_index_frozen_groups", "Frozen groups", self.frozen_groups.len())
+            .gauge(
+                "group_index_frozen_communities",
+                "Frozen communities",
+                self.frozen_communities.len(),
+            )
+            .gauge(
+                "group_index_local_group_indexes",
+                "Local group indexes",
+                self.local_group_indexes.len(),
+            )
+            .canister_upgrades(
+                "local_group_index",
+                self.canister_upgrades_completed,
+                self.canister_upgrades_pending,
+                self.canister_upgrades_in_progress,
+                self.canister_upgrades_failed.iter().map(|f| f.count).sum(),
+            );
+    }
+}
+
 #[derive(CandidType, Serialize, Deserialize, Debug, Default)]
 pub struct CachedMetrics {
     pub last_run: TimestampMillis,
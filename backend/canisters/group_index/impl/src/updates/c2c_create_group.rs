@@ -36,6 +36,7 @@ async fn c2c_create_group(args: Args) -> Response {
         permissions_v2: args.permissions_v2,
         events_ttl: args.events_ttl,
         gate: args.gate.clone(),
+        detached_channel: None,
     };
 
     match local_group_index_canister_c2c_client::c2c_create_group(local_group_index_canister, &c2c_create_group_args).await {
@@ -86,11 +87,11 @@ async fn validate_caller() -> Result<(UserId, Principal), Response> {
     }
 }
 
-struct PrepareResult {
+pub(crate) struct PrepareResult {
     pub local_group_index_canister: CanisterId,
 }
 
-fn prepare(name: &str, is_public: bool, state: &mut RuntimeState) -> Result<PrepareResult, Response> {
+pub(crate) fn prepare(name: &str, is_public: bool, state: &mut RuntimeState) -> Result<PrepareResult, Response> {
     let now = state.env.now();
 
     if is_public && !state.data.public_group_and_community_names.reserve_name(name, now) {
@@ -106,18 +107,18 @@ fn prepare(name: &str, is_public: bool, state: &mut RuntimeState) -> Result<Prep
     }
 }
 
-struct CommitArgs {
-    is_public: bool,
-    chat_id: ChatId,
-    name: String,
-    description: String,
-    subtype: Option<GroupSubtype>,
-    avatar_id: Option<u128>,
-    gate: Option<AccessGate>,
-    local_group_index_canister: CanisterId,
+pub(crate) struct CommitArgs {
+    pub is_public: bool,
+    pub chat_id: ChatId,
+    pub name: String,
+    pub description: String,
+    pub subtype: Option<GroupSubtype>,
+    pub avatar_id: Option<u128>,
+    pub gate: Option<AccessGate>,
+    pub local_group_index_canister: CanisterId,
 }
 
-fn commit(args: CommitArgs, state: &mut RuntimeState) {
+pub(crate) fn commit(args: CommitArgs, state: &mut RuntimeState) {
     let now = state.env.now();
     if args.is_public {
         state
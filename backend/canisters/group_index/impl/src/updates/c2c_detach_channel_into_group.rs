@@ -0,0 +1,72 @@
+use crate::guards::caller_is_community_canister;
+use crate::updates::c2c_create_group::{commit, prepare, CommitArgs, PrepareResult};
+use crate::{mutate_state, read_state};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use group_index_canister::c2c_create_group::Response as CreateGroupResponse;
+use group_index_canister::c2c_detach_channel_into_group::{Response::*, *};
+use types::{CommunityId, DetachedChannel, Document};
+
+#[update_msgpack(guard = "caller_is_community_canister")]
+#[trace]
+async fn c2c_detach_channel_into_group(args: Args) -> Response {
+    let community_id: CommunityId = read_state(|state| state.env.caller().into());
+    let avatar_id = Document::id(&args.avatar);
+
+    let PrepareResult {
+        local_group_index_canister,
+    } = match mutate_state(|state| prepare(&args.name, args.is_public, state)) {
+        Ok(ok) => ok,
+        Err(CreateGroupResponse::NameTaken) => return NameTaken,
+        Err(response) => return InternalError(format!("{response:?}")),
+    };
+
+    let c2c_create_group_args = local_group_index_canister::c2c_create_group::Args {
+        created_by_user_id: args.user_id,
+        created_by_user_principal: args.user_principal,
+        is_public: args.is_public,
+        name: args.name.clone(),
+        description: args.description.clone(),
+        rules: args.rules,
+        subtype: args.subtype.clone(),
+        avatar: args.avatar,
+        history_visible_to_new_joiners: args.history_visible_to_new_joiners,
+        permissions_v2: args.permissions_v2,
+        events_ttl: args.events_ttl,
+        gate: args.gate.clone(),
+        detached_channel: Some(DetachedChannel {
+            community_id,
+            channel_id: args.channel_id,
+            total_bytes: args.total_bytes,
+        }),
+    };
+
+    match local_group_index_canister_c2c_client::c2c_create_group(local_group_index_canister, &c2c_create_group_args).await {
+        Ok(local_group_index_canister::c2c_create_group::Response::Success(result)) => {
+            mutate_state(|state| {
+                commit(
+                    CommitArgs {
+                        is_public: args.is_public,
+                        chat_id: result.chat_id,
+                        name: args.name,
+                        description: args.description,
+                        subtype: args.subtype,
+                        avatar_id,
+                        gate: args.gate,
+                        local_group_index_canister,
+                    },
+                    state,
+                )
+            });
+            Success(result.chat_id)
+        }
+        Ok(local_group_index_canister::c2c_create_group::Response::CyclesBalanceTooLow) => CyclesBalanceTooLow,
+        Ok(local_group_index_canister::c2c_create_group::Response::InternalError(error)) => InternalError(error),
+        Err(error) => {
+            if args.is_public {
+                mutate_state(|state| state.data.public_group_and_community_names.unreserve_name(&args.name));
+            }
+            InternalError(format!("{error:?}"))
+        }
+    }
+}
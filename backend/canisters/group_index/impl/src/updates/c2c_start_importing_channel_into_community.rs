@@ -0,0 +1,49 @@
+use crate::guards::caller_is_community_canister;
+use crate::{read_state, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use group_index_canister::c2c_start_importing_channel_into_community::{Response::*, *};
+use types::CommunityId;
+
+#[update_msgpack(guard = "caller_is_community_canister")]
+#[trace]
+async fn c2c_start_importing_channel_into_community(args: Args) -> Response {
+    let community_id = match read_state(|state| prepare(&args, state)) {
+        Ok(c) => c,
+        Err(response) => return response,
+    };
+
+    match community_canister_c2c_client::c2c_start_channel_export(
+        args.community_id.into(),
+        &community_canister::c2c_start_channel_export::Args {
+            user_id: args.user_id,
+            channel_id: args.channel_id,
+            community_id,
+        },
+    )
+    .await
+    {
+        Ok(community_canister::c2c_start_channel_export::Response::Success(total_bytes)) => Success(total_bytes),
+        Ok(community_canister::c2c_start_channel_export::Response::UserNotInCommunity) => UserNotInCommunity,
+        Ok(community_canister::c2c_start_channel_export::Response::UserNotCommunityOwner) => UserNotCommunityOwner,
+        Ok(community_canister::c2c_start_channel_export::Response::UserNotChannelOwner) => UserNotChannelOwner,
+        Ok(community_canister::c2c_start_channel_export::Response::UserSuspended) => UserSuspended,
+        Ok(community_canister::c2c_start_channel_export::Response::ChannelNotFound) => ChannelNotFound,
+        Ok(community_canister::c2c_start_channel_export::Response::ChannelAlreadyBeingMoved) => ChannelAlreadyBeingMoved,
+        Ok(community_canister::c2c_start_channel_export::Response::CommunityFrozen) => CommunityFrozen,
+        Err(error) => InternalError(format!("{error:?}")),
+    }
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<CommunityId, Response> {
+    let community_id = CommunityId::from(state.env.caller());
+
+    if args.community_id != community_id
+        && (state.data.public_communities.get(&args.community_id).is_some()
+            || state.data.private_communities.get(&args.community_id).is_some())
+    {
+        Ok(community_id)
+    } else {
+        Err(CommunityNotFound)
+    }
+}
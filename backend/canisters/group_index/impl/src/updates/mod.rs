@@ -5,12 +5,14 @@ pub mod c2c_create_community;
 pub mod c2c_create_group;
 pub mod c2c_delete_community;
 pub mod c2c_delete_group;
+pub mod c2c_detach_channel_into_group;
 pub mod c2c_make_community_private;
 pub mod c2c_make_private;
 pub mod c2c_mark_active;
 pub mod c2c_mark_community_active;
 pub mod c2c_mark_group_import_complete;
 pub mod c2c_report_message;
+pub mod c2c_start_importing_channel_into_community;
 pub mod c2c_start_importing_group_into_community;
 pub mod c2c_update_community;
 pub mod c2c_update_group;
@@ -23,7 +25,10 @@ pub mod set_community_upgrade_concurrency;
 pub mod set_group_upgrade_concurrency;
 pub mod set_max_concurrent_community_canister_upgrades;
 pub mod set_max_concurrent_group_canister_upgrades;
+pub mod update_community_upgrade_rollout;
+pub mod update_group_upgrade_rollout;
 pub mod upgrade_community_canister_wasm;
 pub mod upgrade_group_canister_wasm;
 pub mod upgrade_local_group_index_canister_wasm;
+pub mod upload_wasm_chunk;
 pub mod wallet_receive;
@@ -0,0 +1,42 @@
+use crate::read_state;
+use canister_tracing_macros::trace;
+use group_index_canister::update_community_upgrade_rollout::{Response::*, *};
+use ic_cdk_macros::update;
+use tracing::info;
+use types::CanisterId;
+use user_index_canister_c2c_client::{lookup_user, LookupUserError};
+
+#[update]
+#[trace]
+async fn update_community_upgrade_rollout(args: Args) -> Response {
+    let (caller, user_index_canister_id, local_group_index_canisters) = read_state(|state| {
+        (
+            state.env.caller(),
+            state.data.user_index_canister_id,
+            state.data.local_index_map.canisters().copied().collect::<Vec<CanisterId>>(),
+        )
+    });
+
+    match lookup_user(caller, user_index_canister_id).await {
+        Ok(user) if user.is_platform_operator => (),
+        Ok(_) | Err(LookupUserError::UserNotFound) => return NotAuthorized,
+        Err(LookupUserError::InternalError(error)) => return InternalError(error),
+    };
+
+    let action = args.action;
+    let args = local_group_index_canister::c2c_update_community_upgrade_rollout::Args { action };
+
+    let futures: Vec<_> = local_group_index_canisters
+        .into_iter()
+        .map(|canister_id| local_group_index_canister_c2c_client::c2c_update_community_upgrade_rollout(canister_id, &args))
+        .collect();
+
+    let result = futures::future::join_all(futures).await;
+
+    if let Some(first_error) = result.into_iter().filter_map(|res| res.err()).next() {
+        InternalError(format!("{first_error:?}"))
+    } else {
+        info!(?action, "Community upgrade rollout updated");
+        Success
+    }
+}
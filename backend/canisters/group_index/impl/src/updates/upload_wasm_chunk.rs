@@ -0,0 +1,16 @@
+use crate::guards::caller_is_governance_principal;
+use crate::{mutate_state, RuntimeState};
+use canister_api_macros::proposal;
+use canister_tracing_macros::trace;
+use group_index_canister::upload_wasm_chunk::*;
+
+#[proposal(guard = "caller_is_governance_principal")]
+#[trace]
+fn upload_wasm_chunk(args: Args) -> Response {
+    mutate_state(|state| upload_wasm_chunk_impl(args, state))
+}
+
+fn upload_wasm_chunk_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let now = state.env.now();
+    state.data.chunked_wasms.push_chunk(args, now)
+}
@@ -1,6 +1,6 @@
 use candid::{CandidType, Principal};
 use serde::{Deserialize, Serialize};
-use types::{AccessGate, ChatId, Document, GroupPermissions, GroupSubtype, Milliseconds, Rules, UserId};
+use types::{AccessGate, ChatId, DetachedChannel, Document, GroupPermissions, GroupSubtype, Milliseconds, Rules, UserId};
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
 pub struct Args {
@@ -16,6 +16,8 @@ pub struct Args {
     pub permissions_v2: Option<GroupPermissions>,
     pub events_ttl: Option<Milliseconds>,
     pub gate: Option<AccessGate>,
+    #[serde(default)]
+    pub detached_channel: Option<DetachedChannel>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use types::UpgradeRolloutAction;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub action: UpgradeRolloutAction,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    NoChange,
+}
@@ -1,6 +1,6 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
-use types::UpgradeCanisterWasmArgs;
+use types::{ChunkedWasmError, Hash, UpgradeCanisterWasmArgs};
 
 pub type Args = UpgradeCanisterWasmArgs;
 
@@ -8,4 +8,15 @@ pub type Args = UpgradeCanisterWasmArgs;
 pub enum Response {
     Success,
     VersionNotHigher,
+    ChunkedWasmNotFound,
+    ChunkedWasmHashMismatch(Hash),
+}
+
+impl From<ChunkedWasmError> for Response {
+    fn from(error: ChunkedWasmError) -> Self {
+        match error {
+            ChunkedWasmError::NotFound => Response::ChunkedWasmNotFound,
+            ChunkedWasmError::HashMismatch(hash) => Response::ChunkedWasmHashMismatch(hash),
+        }
+    }
 }
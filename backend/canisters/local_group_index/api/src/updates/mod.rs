@@ -8,5 +8,8 @@ pub mod c2c_set_group_upgrade_concurrency;
 pub mod c2c_set_max_concurrent_community_upgrades;
 pub mod c2c_set_max_concurrent_group_upgrades;
 pub mod c2c_trigger_upgrade;
+pub mod c2c_update_community_upgrade_rollout;
+pub mod c2c_update_group_upgrade_rollout;
 pub mod c2c_upgrade_community_canister_wasm;
 pub mod c2c_upgrade_group_canister_wasm;
+pub mod c2c_upload_wasm_chunk;
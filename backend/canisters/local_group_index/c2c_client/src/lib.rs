@@ -15,5 +15,8 @@ generate_c2c_call!(c2c_set_group_upgrade_concurrency);
 generate_c2c_call!(c2c_set_max_concurrent_community_upgrades);
 generate_c2c_call!(c2c_set_max_concurrent_group_upgrades);
 generate_c2c_call!(c2c_trigger_upgrade);
+generate_c2c_call!(c2c_update_community_upgrade_rollout);
+generate_c2c_call!(c2c_update_group_upgrade_rollout);
 generate_c2c_call!(c2c_upgrade_community_canister_wasm);
 generate_c2c_call!(c2c_upgrade_group_canister_wasm);
+generate_c2c_call!(c2c_upload_wasm_chunk);
@@ -1,11 +1,14 @@
 use crate::model::local_community_map::LocalCommunityMap;
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use model::local_group_map::LocalGroupMap;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
-use types::{BuildVersion, CanisterId, CanisterWasm, Cycles, Milliseconds, TimestampMillis, Timestamped, UserId};
+use types::{
+    BuildVersion, CanisterId, CanisterWasm, Cycles, Milliseconds, TimestampMillis, Timestamped, UpgradeRolloutSummary, UserId,
+};
 use utils::canister;
-use utils::canister::{CanistersRequiringUpgrade, FailedUpgradeCount};
+use utils::canister::{CanistersRequiringUpgrade, ChunkedWasms, FailedUpgradeCount};
 use utils::consts::CYCLES_REQUIRED_FOR_UPGRADE;
 use utils::env::Environment;
 
@@ -76,10 +79,12 @@ impl RuntimeState {
             group_upgrades_failed: group_upgrades_metrics.failed,
             group_upgrades_pending: group_upgrades_metrics.pending as u64,
             group_upgrades_in_progress: group_upgrades_metrics.in_progress as u64,
+            group_upgrade_rollout: group_upgrades_metrics.rollout,
             community_upgrades_completed: community_upgrades_metrics.completed,
             community_upgrades_failed: community_upgrades_metrics.failed,
             community_upgrades_pending: community_upgrades_metrics.pending as u64,
             community_upgrades_in_progress: community_upgrades_metrics.in_progress as u64,
+            community_upgrade_rollout: community_upgrades_metrics.rollout,
             group_wasm_version: self.data.group_canister_wasm_for_new_canisters.version,
             community_wasm_version: self.data.community_canister_wasm_for_new_canisters.version,
             max_concurrent_group_upgrades: self.data.max_concurrent_group_upgrades,
@@ -112,6 +117,8 @@ struct Data {
     pub notifications_canister_id: CanisterId,
     pub groups_requiring_upgrade: CanistersRequiringUpgrade,
     pub communities_requiring_upgrade: CanistersRequiringUpgrade,
+    #[serde(default)]
+    pub chunked_wasms: ChunkedWasms,
     pub cycles_dispenser_canister_id: CanisterId,
     pub proposals_bot_user_id: UserId,
     pub canister_pool: canister::Pool,
@@ -153,6 +160,7 @@ impl Data {
             proposals_bot_user_id,
             groups_requiring_upgrade: CanistersRequiringUpgrade::default(),
             communities_requiring_upgrade: CanistersRequiringUpgrade::default(),
+            chunked_wasms: ChunkedWasms::default(),
             canister_pool: canister::Pool::new(canister_pool_target_size),
             total_cycles_spent_on_canisters: 0,
             test_mode,
@@ -180,10 +188,12 @@ pub struct Metrics {
     pub group_upgrades_failed: Vec<FailedUpgradeCount>,
     pub group_upgrades_pending: u64,
     pub group_upgrades_in_progress: u64,
+    pub group_upgrade_rollout: Option<UpgradeRolloutSummary>,
     pub community_upgrades_completed: u64,
     pub community_upgrades_failed: Vec<FailedUpgradeCount>,
     pub community_upgrades_pending: u64,
     pub community_upgrades_in_progress: u64,
+    pub community_upgrade_rollout: Option<UpgradeRolloutSummary>,
     pub group_wasm_version: BuildVersion,
     pub community_wasm_version: BuildVersion,
     pub max_concurrent_group_upgrades: u32,
@@ -193,6 +203,55 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .counter(
+                "local_group_index_cycles_spent_on_canisters",
+                "Cycles spent creating and topping up group and community canisters",
+                self.total_cycles_spent_on_canisters,
+            )
+            .gauge(
+                "local_group_index_local_groups",
+                "Groups on this local group index",
+                self.local_group_count,
+            )
+            .gauge(
+                "local_group_index_local_communities",
+                "Communities on this local group index",
+                self.local_community_count,
+            )
+            .gauge(
+                "local_group_index_canisters_in_pool",
+                "Canisters ready to be used for new groups and communities",
+                self.canisters_in_pool,
+            )
+            .canister_upgrades(
+                "group",
+                self.group_upgrades_completed,
+                self.group_upgrades_pending,
+                self.group_upgrades_in_progress,
+                self.group_upgrades_failed.iter().map(|f| f.count).sum(),
+            )
+            .canister_upgrades(
+                "community",
+                self.community_upgrades_completed,
+                self.community_upgrades_pending,
+                self.community_upgrades_in_progress,
+                self.community_upgrades_failed.iter().map(|f| f.count).sum(),
+            )
+            .canister_upgrade_rollout("group", self.group_upgrade_rollout.as_ref())
+            .canister_upgrade_rollout("community", self.community_upgrade_rollout.as_ref());
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CanisterIds {
     pub user_index: CanisterId,
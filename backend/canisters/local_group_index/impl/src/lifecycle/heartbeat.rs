@@ -37,7 +37,10 @@ mod upgrade_groups {
         let (canister_id, force) = state.data.groups_requiring_upgrade.try_take_next()?;
 
         initialize_upgrade(canister_id, force, state).or_else(|| {
-            state.data.groups_requiring_upgrade.mark_skipped(&canister_id);
+            state
+                .data
+                .groups_requiring_upgrade
+                .mark_skipped(&canister_id, state.env.now());
             None
         })
     }
@@ -103,17 +106,23 @@ mod upgrade_groups {
             );
         }
 
-        state.data.groups_requiring_upgrade.mark_success(&canister_id);
+        state
+            .data
+            .groups_requiring_upgrade
+            .mark_success(&canister_id, state.env.now());
     }
 
     fn on_failure(canister_id: CanisterId, from_version: BuildVersion, to_version: BuildVersion, state: &mut RuntimeState) {
         mark_upgrade_complete(canister_id.into(), None, state);
 
-        state.data.groups_requiring_upgrade.mark_failure(FailedUpgrade {
-            canister_id,
-            from_version,
-            to_version,
-        });
+        state.data.groups_requiring_upgrade.mark_failure(
+            FailedUpgrade {
+                canister_id,
+                from_version,
+                to_version,
+            },
+            state.env.now(),
+        );
     }
 
     fn mark_upgrade_complete(chat_id: ChatId, new_wasm_version: Option<BuildVersion>, state: &mut RuntimeState) {
@@ -149,7 +158,10 @@ mod upgrade_communities {
         let (canister_id, force) = state.data.communities_requiring_upgrade.try_take_next()?;
 
         initialize_upgrade(canister_id, force, state).or_else(|| {
-            state.data.communities_requiring_upgrade.mark_skipped(&canister_id);
+            state
+                .data
+                .communities_requiring_upgrade
+                .mark_skipped(&canister_id, state.env.now());
             None
         })
     }
@@ -215,17 +227,23 @@ mod upgrade_communities {
             );
         }
 
-        state.data.communities_requiring_upgrade.mark_success(&canister_id);
+        state
+            .data
+            .communities_requiring_upgrade
+            .mark_success(&canister_id, state.env.now());
     }
 
     fn on_failure(canister_id: CanisterId, from_version: BuildVersion, to_version: BuildVersion, state: &mut RuntimeState) {
         mark_upgrade_complete(canister_id.into(), None, state);
 
-        state.data.communities_requiring_upgrade.mark_failure(FailedUpgrade {
-            canister_id,
-            from_version,
-            to_version,
-        });
+        state.data.communities_requiring_upgrade.mark_failure(
+            FailedUpgrade {
+                canister_id,
+                from_version,
+                to_version,
+            },
+            state.env.now(),
+        );
     }
 
     fn mark_upgrade_complete(community_id: CommunityId, new_wasm_version: Option<BuildVersion>, state: &mut RuntimeState) {
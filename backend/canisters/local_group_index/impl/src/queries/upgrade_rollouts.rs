@@ -0,0 +1,15 @@
+use crate::{read_state, RuntimeState};
+use ic_cdk_macros::query;
+use local_group_index_canister::upgrade_rollouts::{Response::*, *};
+
+#[query]
+fn upgrade_rollouts(_args: Args) -> Response {
+    read_state(upgrade_rollouts_impl)
+}
+
+fn upgrade_rollouts_impl(state: &RuntimeState) -> Response {
+    Success(SuccessResult {
+        groups: state.data.groups_requiring_upgrade.rollout(),
+        communities: state.data.communities_requiring_upgrade.rollout(),
+    })
+}
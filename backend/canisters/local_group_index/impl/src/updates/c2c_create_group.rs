@@ -87,6 +87,7 @@ fn prepare(args: Args, state: &mut RuntimeState) -> Result<PrepareOk, Response>
         gate: args.gate,
         wasm_version: canister_wasm.version,
         test_mode: state.data.test_mode,
+        detached_channel: args.detached_channel,
     };
 
     Ok(PrepareOk {
@@ -0,0 +1,20 @@
+use crate::guards::caller_is_group_index_canister;
+use crate::mutate_state;
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use local_group_index_canister::c2c_update_community_upgrade_rollout::{Response::*, *};
+use tracing::info;
+
+#[update_msgpack(guard = "caller_is_group_index_canister")]
+#[trace]
+fn c2c_update_community_upgrade_rollout(args: Args) -> Response {
+    mutate_state(|state| {
+        let now = state.env.now();
+        if state.data.communities_requiring_upgrade.update_rollout(args.action, now) {
+            info!(action = ?args.action, "Community upgrade rollout updated");
+            Success
+        } else {
+            NoChange
+        }
+    })
+}
@@ -14,7 +14,11 @@ fn c2c_upgrade_community_canister_wasm(args: Args) -> Response {
     mutate_state(|state| c2c_upgrade_community_canister_wasm_impl(args, state))
 }
 
-fn c2c_upgrade_community_canister_wasm_impl(args: Args, state: &mut RuntimeState) -> Response {
+fn c2c_upgrade_community_canister_wasm_impl(mut args: Args, state: &mut RuntimeState) -> Response {
+    if let Err(error) = state.data.chunked_wasms.resolve(&mut args) {
+        return error.into();
+    }
+
     let version = args.wasm.version;
 
     if !state.data.test_mode && Some(version) <= min_canister_version(&state.data) {
@@ -43,6 +47,12 @@ fn c2c_upgrade_community_canister_wasm_impl(args: Args, state: &mut RuntimeState
             state.data.communities_requiring_upgrade.enqueue(canister_id, false);
         }
 
+        if let Some(rollout) = args.rollout {
+            state
+                .data
+                .communities_requiring_upgrade
+                .start_rollout(version, rollout, state.env.now());
+        }
         let canisters_queued_for_upgrade = state.data.communities_requiring_upgrade.count_pending();
         info!(%version, canisters_queued_for_upgrade, "Community canister wasm upgraded");
         Success
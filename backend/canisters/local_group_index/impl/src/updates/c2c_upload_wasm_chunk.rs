@@ -0,0 +1,16 @@
+use crate::guards::caller_is_group_index_canister;
+use crate::{mutate_state, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use local_group_index_canister::c2c_upload_wasm_chunk::*;
+
+#[update_msgpack(guard = "caller_is_group_index_canister")]
+#[trace]
+fn c2c_upload_wasm_chunk(args: Args) -> Response {
+    mutate_state(|state| c2c_upload_wasm_chunk_impl(args, state))
+}
+
+fn c2c_upload_wasm_chunk_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let now = state.env.now();
+    state.data.chunked_wasms.push_chunk(args, now)
+}
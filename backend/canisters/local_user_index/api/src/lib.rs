@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use types::nns::CryptoAmount;
 use types::{
     ChannelLatestMessageIndex, ChatId, CommunityId, Cryptocurrency, DiamondMembershipPlanDuration, MessageContent,
-    MessageIndex, PhoneNumber, ReferralType, SuspensionDuration, TimestampMillis, UserId,
+    MessageIndex, PhoneNumber, ReferralType, SuspensionDuration, TimestampMillis, UpgradeRolloutAction, UserId,
 };
 
 mod lifecycle;
@@ -24,6 +24,7 @@ pub enum Event {
     SuperAdminStatusChanged(PlatformModeratorStatusChanged),
     MaxConcurrentCanisterUpgradesChanged(MaxConcurrentCanisterUpgradesChanged),
     UserUpgradeConcurrencyChanged(UserUpgradeConcurrencyChanged),
+    UserUpgradeRolloutUpdated(UserUpgradeRolloutUpdated),
     UserSuspended(UserSuspended),
     UserJoinedGroup(UserJoinedGroup),
     UserJoinedCommunityOrChannel(UserJoinedCommunityOrChannel),
@@ -85,6 +86,11 @@ pub struct UserUpgradeConcurrencyChanged {
     pub value: u32,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UserUpgradeRolloutUpdated {
+    pub action: UpgradeRolloutAction,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct UserSuspended {
     pub user_id: UserId,
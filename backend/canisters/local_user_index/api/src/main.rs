@@ -2,6 +2,8 @@ use candid_gen::generate_candid_method;
 
 #[allow(deprecated)]
 fn main() {
+    generate_candid_method!(local_user_index, upgrade_rollout, query);
+
     generate_candid_method!(local_user_index, invite_users_to_channel, update);
     generate_candid_method!(local_user_index, invite_users_to_community, update);
     generate_candid_method!(local_user_index, invite_users_to_group, update);
@@ -10,6 +12,8 @@ fn main() {
     generate_candid_method!(local_user_index, join_group, update);
     generate_candid_method!(local_user_index, register_user, update);
     generate_candid_method!(local_user_index, report_message_v2, update);
+    generate_candid_method!(local_user_index, request_to_join_community, update);
+    generate_candid_method!(local_user_index, request_to_join_group, update);
 
     candid::export_service!();
     std::print!("{}", __export_service());
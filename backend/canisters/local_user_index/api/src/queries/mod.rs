@@ -1,3 +1,4 @@
 pub mod c2c_can_push_notifications;
 pub mod c2c_lookup_user;
 pub mod c2c_user_principals;
+pub mod upgrade_rollout;
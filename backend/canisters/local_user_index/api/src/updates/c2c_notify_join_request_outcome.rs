@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{ChannelLatestMessageIndex, MessageIndex, UserId};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub user_id: UserId,
+    pub name: String,
+    pub outcome: JoinRequestOutcome,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum JoinRequestOutcome {
+    GroupJoined(Option<MessageIndex>),
+    CommunityJoined(Vec<ChannelLatestMessageIndex>),
+    Rejected(Option<String>),
+    Expired,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    RequestNotFound,
+}
@@ -0,0 +1,4 @@
+use types::{UploadWasmChunkArgs, UploadWasmChunkResponse};
+
+pub type Args = UploadWasmChunkArgs;
+pub type Response = UploadWasmChunkResponse;
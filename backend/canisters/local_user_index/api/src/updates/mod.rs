@@ -1,8 +1,10 @@
 pub mod c2c_create_user;
 pub mod c2c_notify_events;
+pub mod c2c_notify_join_request_outcome;
 pub mod c2c_notify_low_balance;
 pub mod c2c_notify_user_index_events;
 pub mod c2c_upgrade_user_canister_wasm;
+pub mod c2c_upload_wasm_chunk;
 pub mod invite_users_to_channel;
 pub mod invite_users_to_community;
 pub mod invite_users_to_group;
@@ -11,3 +13,5 @@ pub mod join_community;
 pub mod join_group;
 pub mod register_user;
 pub mod report_message_v2;
+pub mod request_to_join_community;
+pub mod request_to_join_group;
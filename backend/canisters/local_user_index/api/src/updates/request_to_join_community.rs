@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::CommunityId;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub community_id: CommunityId,
+    pub message: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AlreadyInCommunity,
+    AlreadyRequested,
+    CommunityNotFound,
+    CommunityIsPublic,
+    MessageTooLong(u32),
+    TooManyRequests(u32),
+    UserBlocked,
+    UserSuspended,
+    CommunityFrozen,
+    InternalError(String),
+}
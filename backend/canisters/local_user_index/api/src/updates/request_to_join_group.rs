@@ -0,0 +1,24 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::ChatId;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub chat_id: ChatId,
+    pub message: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    AlreadyInGroup,
+    AlreadyRequested,
+    GroupNotFound,
+    GroupIsPublic,
+    MessageTooLong(u32),
+    TooManyRequests(u32),
+    Blocked,
+    UserSuspended,
+    ChatFrozen,
+    InternalError(String),
+}
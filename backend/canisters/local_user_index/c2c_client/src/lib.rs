@@ -12,6 +12,7 @@ generate_c2c_call!(c2c_user_principals);
 generate_c2c_call!(c2c_notify_low_balance);
 generate_c2c_call!(c2c_notify_user_index_events);
 generate_c2c_call!(c2c_upgrade_user_canister_wasm);
+generate_c2c_call!(c2c_upload_wasm_chunk);
 
 generate_candid_c2c_call!(join_group);
 
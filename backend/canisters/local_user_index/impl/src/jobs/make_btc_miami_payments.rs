@@ -95,6 +95,7 @@ fn send_oc_bot_messages(pending_payment: &PendingPayment, block_index: BlockInde
         }),
         MessageContent::Text(TextContent {
             text: "🤔 No one to send your ckBTC to? Invite your friends to chat!".to_string(),
+            link_previews: None,
         }),
         MessageContent::Custom(CustomContent {
             kind: "user_referral_card".to_string(),
@@ -105,18 +106,22 @@ fn send_oc_bot_messages(pending_payment: &PendingPayment, block_index: BlockInde
                 "🤝 ...or connect with fellow Bitcoiners and win prizes in the [Operation Miami](/{}) chat",
                 if state.data.test_mode { "ueyan-5iaaa-aaaaf-bifxa-cai" } else { "pbo6v-oiaaa-aaaar-ams6q-cai" }
             ),
+            link_previews: None,
         }),
         MessageContent::Text(TextContent {
             text: format!(
                 "🎲 ...or play Satoshi Dice with the [Satoshi Dice](/{}) chat bot",
                 if state.data.test_mode { "uuw5d-uiaaa-aaaar-anzeq-cai" } else { "wznbi-caaaa-aaaar-anvea-cai" }
             ),
+            link_previews: None,
         }),
         MessageContent::Text(TextContent {
             text: "👀 View projects, wallets, and DEXs that support ckBTC [here](https://internetcomputer.org/ecosystem/?tag=Bitcoin)".to_string(),
+            link_previews: None,
         }),
         MessageContent::Text(TextContent {
             text: "🧐 Find out more about OpenChat [here](/home)".to_string(),
+            link_previews: None,
         }),
     ];
 
@@ -16,7 +16,7 @@ thread_local! {
 
 pub(crate) fn start_job_if_required(state: &RuntimeState) -> bool {
     if TIMER_ID.get().is_none()
-        && (state.data.canisters_requiring_upgrade.count_pending() > 0
+        && (state.data.canisters_requiring_upgrade.has_upgrades_ready()
             || state.data.canisters_requiring_upgrade.count_in_progress() > 0)
     {
         let timer_id = ic_cdk_timers::set_timer_interval(Duration::ZERO, run);
@@ -41,9 +41,10 @@ fn run() {
 
 fn next_batch(state: &mut RuntimeState) -> Option<Vec<CanisterToUpgrade>> {
     let count_in_progress = state.data.canisters_requiring_upgrade.count_in_progress();
-    let count_pending = state.data.canisters_requiring_upgrade.count_pending();
+    // Pending upgrades may be held back by a paused rollout, in which case the job stops until it is resumed
+    let upgrades_ready = state.data.canisters_requiring_upgrade.has_upgrades_ready();
 
-    if count_in_progress == 0 && count_pending == 0 {
+    if count_in_progress == 0 && !upgrades_ready {
         None
     } else {
         let user_upgrade_concurrency = state.data.user_upgrade_concurrency as usize;
@@ -60,7 +61,10 @@ fn try_get_next(state: &mut RuntimeState) -> Option<CanisterToUpgrade> {
     let (canister_id, force) = state.data.canisters_requiring_upgrade.try_take_next()?;
 
     initialize_upgrade(canister_id, force, state).or_else(|| {
-        state.data.canisters_requiring_upgrade.mark_skipped(&canister_id);
+        state
+            .data
+            .canisters_requiring_upgrade
+            .mark_skipped(&canister_id, state.env.now());
         None
     })
 }
@@ -126,17 +130,23 @@ fn on_success(canister_id: CanisterId, to_version: BuildVersion, top_up: Option<
         );
     }
 
-    state.data.canisters_requiring_upgrade.mark_success(&canister_id);
+    state
+        .data
+        .canisters_requiring_upgrade
+        .mark_success(&canister_id, state.env.now());
 }
 
 fn on_failure(canister_id: CanisterId, from_version: BuildVersion, to_version: BuildVersion, state: &mut RuntimeState) {
     mark_upgrade_complete(canister_id.into(), None, state);
 
-    state.data.canisters_requiring_upgrade.mark_failure(FailedUpgrade {
-        canister_id,
-        from_version,
-        to_version,
-    });
+    state.data.canisters_requiring_upgrade.mark_failure(
+        FailedUpgrade {
+            canister_id,
+            from_version,
+            to_version,
+        },
+        state.env.now(),
+    );
 }
 
 fn mark_upgrade_complete(canister_id: UserId, new_wasm_version: Option<BuildVersion>, state: &mut RuntimeState) {
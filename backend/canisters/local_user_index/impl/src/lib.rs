@@ -1,8 +1,10 @@
 use crate::model::btc_miami_payments_queue::BtcMiamiPaymentsQueue;
+use crate::model::pending_join_requests::PendingJoinRequests;
 use crate::model::referral_codes::{ReferralCodes, ReferralTypeMetrics};
 use crate::timer_job_types::TimerJob;
 use canister_state_macros::canister_state;
 use canister_timer_jobs::TimerJobs;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use local_user_index_canister::GlobalUser;
 use model::global_user_map::GlobalUserMap;
 use model::local_user_map::LocalUserMap;
@@ -11,12 +13,13 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use types::{
     BuildVersion, CanisterId, CanisterWasm, ChannelLatestMessageIndex, ChatId, CommunityCanisterChannelSummary,
-    CommunityCanisterCommunitySummary, CommunityId, Cycles, MessageContent, ReferralType, TimestampMillis, Timestamped, UserId,
+    CommunityCanisterCommunitySummary, CommunityId, Cycles, MessageContent, MessageIndex, ReferralType, TimestampMillis,
+    Timestamped, UpgradeRolloutSummary, UserId,
 };
 use user_canister::Event as UserEvent;
 use user_index_canister::Event as UserIndexEvent;
 use utils::canister;
-use utils::canister::{CanistersRequiringUpgrade, FailedUpgradeCount};
+use utils::canister::{CanistersRequiringUpgrade, ChunkedWasms, FailedUpgradeCount};
 use utils::canister_event_sync_queue::CanisterEventSyncQueue;
 use utils::consts::CYCLES_REQUIRED_FOR_UPGRADE;
 use utils::env::Environment;
@@ -96,6 +99,26 @@ impl RuntimeState {
         }
     }
 
+    pub fn notify_user_joined_group(&mut self, user_id: UserId, chat_id: ChatId, latest_message_index: Option<MessageIndex>) {
+        if self.data.local_users.get(&user_id).is_some() {
+            self.push_event_to_user(
+                user_id,
+                UserEvent::UserJoinedGroup(Box::new(user_canister::UserJoinedGroup {
+                    chat_id,
+                    latest_message_index,
+                })),
+            );
+        } else {
+            self.push_event_to_user_index(UserIndexEvent::UserJoinedGroup(Box::new(
+                user_index_canister::UserJoinedGroup {
+                    user_id,
+                    chat_id,
+                    latest_message_index,
+                },
+            )));
+        }
+    }
+
     pub fn notify_user_joined_community(&mut self, user_id: UserId, community: &CommunityCanisterCommunitySummary) {
         let channels = community
             .channels
@@ -125,7 +148,7 @@ impl RuntimeState {
         );
     }
 
-    fn notify_user_joined_community_or_channel(
+    pub fn notify_user_joined_community_or_channel(
         &mut self,
         user_id: UserId,
         community_id: CommunityId,
@@ -166,6 +189,7 @@ impl RuntimeState {
             canister_upgrades_failed: canister_upgrades_metrics.failed,
             canister_upgrades_pending: canister_upgrades_metrics.pending as u64,
             canister_upgrades_in_progress: canister_upgrades_metrics.in_progress as u64,
+            canister_upgrade_rollout: canister_upgrades_metrics.rollout,
             user_wasm_version: self.data.user_canister_wasm_for_new_canisters.version,
             max_concurrent_canister_upgrades: self.data.max_concurrent_canister_upgrades,
             user_upgrade_concurrency: self.data.user_upgrade_concurrency,
@@ -195,6 +219,8 @@ struct Data {
     pub cycles_dispenser_canister_id: CanisterId,
     pub internet_identity_canister_id: CanisterId,
     pub canisters_requiring_upgrade: CanistersRequiringUpgrade,
+    #[serde(default)]
+    pub chunked_wasms: ChunkedWasms,
     pub canister_pool: canister::Pool,
     pub total_cycles_spent_on_canisters: Cycles,
     pub user_event_sync_queue: CanisterEventSyncQueue<UserEvent>,
@@ -206,6 +232,8 @@ struct Data {
     pub referral_codes: ReferralCodes,
     pub timer_jobs: TimerJobs<TimerJob>,
     pub btc_miami_payments_queue: BtcMiamiPaymentsQueue,
+    #[serde(default)]
+    pub pending_join_requests: PendingJoinRequests,
     pub rng_seed: [u8; 32],
 }
 
@@ -240,6 +268,7 @@ impl Data {
             cycles_dispenser_canister_id,
             internet_identity_canister_id,
             canisters_requiring_upgrade: CanistersRequiringUpgrade::default(),
+            chunked_wasms: ChunkedWasms::default(),
             canister_pool: canister::Pool::new(canister_pool_target_size),
             total_cycles_spent_on_canisters: 0,
             user_event_sync_queue: CanisterEventSyncQueue::default(),
@@ -251,6 +280,7 @@ impl Data {
             referral_codes: ReferralCodes::default(),
             timer_jobs: TimerJobs::default(),
             btc_miami_payments_queue: BtcMiamiPaymentsQueue::default(),
+            pending_join_requests: PendingJoinRequests::default(),
             rng_seed: [0; 32],
         }
     }
@@ -271,6 +301,7 @@ pub struct Metrics {
     pub canister_upgrades_failed: Vec<FailedUpgradeCount>,
     pub canister_upgrades_pending: u64,
     pub canister_upgrades_in_progress: u64,
+    pub canister_upgrade_rollout: Option<UpgradeRolloutSummary>,
     pub user_wasm_version: BuildVersion,
     pub max_concurrent_canister_upgrades: u32,
     pub user_upgrade_concurrency: u32,
@@ -279,6 +310,72 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const REFERRAL_CODES: &str = "local_user_index_referral_codes";
+        const REFERRAL_CODES_HELP: &str = "Referral codes, by type and whether they have been claimed";
+
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .counter(
+                "local_user_index_cycles_spent_on_canisters",
+                "Cycles spent creating and topping up user canisters",
+                self.total_cycles_spent_on_canisters,
+            )
+            .gauge(
+                "local_user_index_local_users",
+                "Users on this local user index",
+                self.local_user_count,
+            )
+            .gauge(
+                "local_user_index_global_users",
+                "Users across all local user indexes",
+                self.global_user_count,
+            )
+            .gauge(
+                "local_user_index_canisters_in_pool",
+                "Canisters ready to be used for new users",
+                self.canisters_in_pool,
+            )
+            .gauge(
+                "local_user_index_user_events_queue_length",
+                "Events queued to be sent to user canisters",
+                self.user_events_queue_length,
+            )
+            .canister_upgrades(
+                "user",
+                self.canister_upgrades_completed,
+                self.canister_upgrades_pending,
+                self.canister_upgrades_in_progress,
+                self.canister_upgrades_failed.iter().map(|f| f.count).sum(),
+            )
+            .canister_upgrade_rollout("user", self.canister_upgrade_rollout.as_ref());
+
+        for (referral_type, metrics) in self.referral_codes.iter() {
+            let referral_type = format!("{referral_type:?}");
+            registry
+                .gauge_with_labels(
+                    REFERRAL_CODES,
+                    REFERRAL_CODES_HELP,
+                    &[("type", &referral_type), ("status", "claimed")],
+                    metrics.claimed,
+                )
+                .gauge_with_labels(
+                    REFERRAL_CODES,
+                    REFERRAL_CODES_HELP,
+                    &[("type", &referral_type), ("status", "unclaimed")],
+                    metrics.total - metrics.claimed,
+                );
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CanisterIds {
     pub user_index: CanisterId,
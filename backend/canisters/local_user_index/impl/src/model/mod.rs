@@ -1,4 +1,5 @@
 pub mod btc_miami_payments_queue;
 pub mod global_user_map;
 pub mod local_user_map;
+pub mod pending_join_requests;
 pub mod referral_codes;
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{CanisterId, TimestampMillis, UserId};
+
+// Join requests which have been forwarded to group or community canisters and are awaiting a decision. Outcomes are
+// only relayed to users if they correspond to one of these requests.
+#[derive(Serialize, Deserialize, Default)]
+pub struct PendingJoinRequests {
+    requests: BTreeMap<(CanisterId, UserId), TimestampMillis>,
+}
+
+impl PendingJoinRequests {
+    pub fn add(&mut self, canister_id: CanisterId, user_id: UserId, now: TimestampMillis) {
+        self.requests.insert((canister_id, user_id), now);
+    }
+
+    pub fn remove(&mut self, canister_id: CanisterId, user_id: UserId) -> bool {
+        self.requests.remove(&(canister_id, user_id)).is_some()
+    }
+}
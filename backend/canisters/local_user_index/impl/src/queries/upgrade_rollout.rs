@@ -0,0 +1,14 @@
+use crate::{read_state, RuntimeState};
+use ic_cdk_macros::query;
+use local_user_index_canister::upgrade_rollout::{Response::*, *};
+
+#[query]
+fn upgrade_rollout(_args: Args) -> Response {
+    read_state(upgrade_rollout_impl)
+}
+
+fn upgrade_rollout_impl(state: &RuntimeState) -> Response {
+    Success(SuccessResult {
+        rollout: state.data.canisters_requiring_upgrade.rollout(),
+    })
+}
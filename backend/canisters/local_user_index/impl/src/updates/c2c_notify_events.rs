@@ -104,6 +104,13 @@ fn handle_event(event: Event, state: &mut RuntimeState) {
             state.data.user_upgrade_concurrency = min(state.data.max_concurrent_canister_upgrades, ev.value);
             info!("User upgrade concurrency set to {}", ev.value);
         }
+        Event::UserUpgradeRolloutUpdated(ev) => {
+            let now = state.env.now();
+            if state.data.canisters_requiring_upgrade.update_rollout(ev.action, now) {
+                info!(action = ?ev.action, "User upgrade rollout updated");
+                crate::jobs::upgrade_canisters::start_job_if_required(state);
+            }
+        }
         Event::UserJoinedGroup(ev) => {
             state.push_event_to_user(
                 ev.user_id,
@@ -0,0 +1,49 @@
+use crate::{mutate_state, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use local_user_index_canister::c2c_notify_join_request_outcome::{Response::*, *};
+use types::{MessageContent, TextContent};
+
+// No guard is needed here since outcomes are only accepted for join requests which this canister forwarded to the
+// calling group or community canister
+#[update_msgpack]
+#[trace]
+fn c2c_notify_join_request_outcome(args: Args) -> Response {
+    mutate_state(|state| c2c_notify_join_request_outcome_impl(args, state))
+}
+
+fn c2c_notify_join_request_outcome_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let caller = state.env.caller();
+
+    if !state.data.pending_join_requests.remove(caller, args.user_id) {
+        return RequestNotFound;
+    }
+
+    let text = match args.outcome {
+        JoinRequestOutcome::GroupJoined(latest_message_index) => {
+            state.notify_user_joined_group(args.user_id, caller.into(), latest_message_index);
+            format!("Your request to join \"{}\" has been approved.", args.name)
+        }
+        JoinRequestOutcome::CommunityJoined(channels) => {
+            state.notify_user_joined_community_or_channel(args.user_id, caller.into(), channels);
+            format!("Your request to join \"{}\" has been approved.", args.name)
+        }
+        JoinRequestOutcome::Rejected(reason) => {
+            let mut text = format!("Your request to join \"{}\" has been rejected.", args.name);
+            if let Some(reason) = reason {
+                text.push_str(&format!("\n\nReason: {reason}"));
+            }
+            text
+        }
+        JoinRequestOutcome::Expired => format!("Your request to join \"{}\" has expired.", args.name),
+    };
+
+    state.push_oc_bot_message_to_user(
+        args.user_id,
+        MessageContent::Text(TextContent {
+            text,
+            link_previews: None,
+        }),
+    );
+    Success
+}
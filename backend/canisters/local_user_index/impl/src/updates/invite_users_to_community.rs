@@ -60,7 +60,10 @@ fn commit(
     let text = format!(
         "You have been invited to the community [{community_name}](/community/{community_id}) by @UserId({invited_by})."
     );
-    let message = MessageContent::Text(TextContent { text });
+    let message = MessageContent::Text(TextContent {
+        text,
+        link_previews: None,
+    });
 
     for user_id in invited_users {
         state.push_oc_bot_message_to_user(user_id, message.clone());
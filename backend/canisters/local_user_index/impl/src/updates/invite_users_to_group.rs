@@ -52,7 +52,10 @@ fn prepare(args: &Args, state: &RuntimeState) -> PrepareResult {
 
 fn commit(invited_by: UserId, group_id: ChatId, group_name: String, invited_users: Vec<UserId>, state: &mut RuntimeState) {
     let text = format!("You have been invited to the group [{group_name}](/group/{group_id}) by @UserId({invited_by}).");
-    let message = MessageContent::Text(TextContent { text });
+    let message = MessageContent::Text(TextContent {
+        text,
+        link_previews: None,
+    });
 
     for user_id in invited_users {
         state.push_oc_bot_message_to_user(user_id, message.clone());
@@ -1,11 +1,8 @@
 use crate::guards::caller_is_openchat_user;
-use crate::{mutate_state, read_state, RuntimeState};
+use crate::{mutate_state, read_state};
 use canister_tracing_macros::trace;
 use ic_cdk_macros::update;
 use local_user_index_canister::join_group::{Response::*, *};
-use types::{ChatId, MessageIndex, UserId};
-use user_canister::Event as UserEvent;
-use user_index_canister::Event as UserIndexEvent;
 
 #[update(guard = "caller_is_openchat_user")]
 #[trace]
@@ -25,11 +22,10 @@ async fn join_group(args: Args) -> Response {
             group_canister::c2c_join_group::Response::Success(s)
             | group_canister::c2c_join_group::Response::AlreadyInGroupV2(s) => {
                 mutate_state(|state| {
-                    commit(
+                    state.notify_user_joined_group(
                         user_details.user_id,
                         args.chat_id,
                         s.latest_message.as_ref().map(|m| m.event.message_index),
-                        state,
                     );
                 });
                 Success(s)
@@ -46,23 +42,3 @@ async fn join_group(args: Args) -> Response {
         Err(error) => InternalError(format!("Failed to call 'group::c2c_join_group': {error:?}")),
     }
 }
-
-fn commit(user_id: UserId, chat_id: ChatId, latest_message_index: Option<MessageIndex>, state: &mut RuntimeState) {
-    if state.data.local_users.get(&user_id).is_some() {
-        state.push_event_to_user(
-            user_id,
-            UserEvent::UserJoinedGroup(Box::new(user_canister::UserJoinedGroup {
-                chat_id,
-                latest_message_index,
-            })),
-        );
-    } else {
-        state.push_event_to_user_index(UserIndexEvent::UserJoinedGroup(Box::new(
-            user_index_canister::UserJoinedGroup {
-                user_id,
-                chat_id,
-                latest_message_index,
-            },
-        )));
-    }
-}
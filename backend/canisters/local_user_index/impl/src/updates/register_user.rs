@@ -138,15 +138,22 @@ fn prepare(args: &Args, state: &mut RuntimeState) -> Result<PrepareOk, Response>
         vec![
             MessageContent::Text(TextContent {
                 text: "Welcome to OpenChat!!".to_string(),
+                link_previews: None,
             }),
             MessageContent::Text(TextContent {
                 text: format!("Wait a moment {}, your SATS are coming below 👇", args.username),
+                link_previews: None,
             }),
         ]
     } else {
         welcome_messages()
             .into_iter()
-            .map(|t| MessageContent::Text(TextContent { text: t }))
+            .map(|t| {
+                MessageContent::Text(TextContent {
+                    text: t,
+                    link_previews: None,
+                })
+            })
             .collect()
     };
 
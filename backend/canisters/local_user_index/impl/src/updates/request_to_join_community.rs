@@ -0,0 +1,39 @@
+use crate::guards::caller_is_openchat_user;
+use crate::{mutate_state, read_state};
+use canister_tracing_macros::trace;
+use ic_cdk_macros::update;
+use local_user_index_canister::request_to_join_community::{Response::*, *};
+
+#[update(guard = "caller_is_openchat_user")]
+#[trace]
+async fn request_to_join_community(args: Args) -> Response {
+    let user_details = read_state(|state| state.calling_user());
+
+    let c2c_args = community_canister::c2c_request_to_join::Args {
+        user_id: user_details.user_id,
+        principal: user_details.principal,
+        message: args.message,
+    };
+    match community_canister_c2c_client::c2c_request_to_join(args.community_id.into(), &c2c_args).await {
+        Ok(response) => match response {
+            community_canister::c2c_request_to_join::Response::Success => {
+                mutate_state(|state| {
+                    let now = state.env.now();
+                    state
+                        .data
+                        .pending_join_requests
+                        .add(args.community_id.into(), user_details.user_id, now)
+                });
+                Success
+            }
+            community_canister::c2c_request_to_join::Response::AlreadyInCommunity => AlreadyInCommunity,
+            community_canister::c2c_request_to_join::Response::AlreadyRequested => AlreadyRequested,
+            community_canister::c2c_request_to_join::Response::CommunityIsPublic => CommunityIsPublic,
+            community_canister::c2c_request_to_join::Response::MessageTooLong(max) => MessageTooLong(max),
+            community_canister::c2c_request_to_join::Response::TooManyRequests(max) => TooManyRequests(max),
+            community_canister::c2c_request_to_join::Response::UserBlocked => UserBlocked,
+            community_canister::c2c_request_to_join::Response::CommunityFrozen => CommunityFrozen,
+        },
+        Err(error) => InternalError(format!("Failed to call 'community::c2c_request_to_join': {error:?}")),
+    }
+}
@@ -0,0 +1,39 @@
+use crate::guards::caller_is_openchat_user;
+use crate::{mutate_state, read_state};
+use canister_tracing_macros::trace;
+use ic_cdk_macros::update;
+use local_user_index_canister::request_to_join_group::{Response::*, *};
+
+#[update(guard = "caller_is_openchat_user")]
+#[trace]
+async fn request_to_join_group(args: Args) -> Response {
+    let user_details = read_state(|state| state.calling_user());
+
+    let c2c_args = group_canister::c2c_request_to_join::Args {
+        user_id: user_details.user_id,
+        principal: user_details.principal,
+        message: args.message,
+    };
+    match group_canister_c2c_client::c2c_request_to_join(args.chat_id.into(), &c2c_args).await {
+        Ok(response) => match response {
+            group_canister::c2c_request_to_join::Response::Success => {
+                mutate_state(|state| {
+                    let now = state.env.now();
+                    state
+                        .data
+                        .pending_join_requests
+                        .add(args.chat_id.into(), user_details.user_id, now)
+                });
+                Success
+            }
+            group_canister::c2c_request_to_join::Response::AlreadyInGroup => AlreadyInGroup,
+            group_canister::c2c_request_to_join::Response::AlreadyRequested => AlreadyRequested,
+            group_canister::c2c_request_to_join::Response::GroupIsPublic => GroupIsPublic,
+            group_canister::c2c_request_to_join::Response::MessageTooLong(max) => MessageTooLong(max),
+            group_canister::c2c_request_to_join::Response::TooManyRequests(max) => TooManyRequests(max),
+            group_canister::c2c_request_to_join::Response::Blocked => Blocked,
+            group_canister::c2c_request_to_join::Response::ChatFrozen => ChatFrozen,
+        },
+        Err(error) => InternalError(format!("Failed to call 'group::c2c_request_to_join': {error:?}")),
+    }
+}
@@ -11,6 +11,7 @@ pub use queries::*;
 pub use updates::*;
 
 pub const ICDEX_EXCHANGE_ID: ExchangeId = ExchangeId::new(1);
+pub const ICPSWAP_EXCHANGE_ID: ExchangeId = ExchangeId::new(2);
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct ExchangeInfo {
@@ -18,6 +19,31 @@ pub struct ExchangeInfo {
     pub name: String,
 }
 
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub enum StrategyConfig {
+    #[default]
+    Ladder,
+    InventorySkewed(InventorySkewedConfig),
+    VolatilityAdjusted(VolatilityAdjustedConfig),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct InventorySkewedConfig {
+    // The percentage of the total inventory value we aim to hold in the base token
+    pub target_base_percent: u8,
+    // How many price increments to shift the ladder by when the inventory is entirely one token
+    pub max_skew_increments: u64,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct VolatilityAdjustedConfig {
+    // The number of recent market snapshots to measure volatility over
+    pub window: u32,
+    // The percentage of the recent price range (in increments) to add to the spread
+    pub multiplier_percent: u32,
+    pub max_extra_spread: u64,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct ExchangeId(u32);
 
@@ -2,6 +2,9 @@ use candid_gen::generate_candid_method;
 
 #[allow(deprecated)]
 fn main() {
+    generate_candid_method!(market_maker, backtest, query);
+    generate_candid_method!(market_maker, profit_and_loss, query);
+
     generate_candid_method!(market_maker, update_config, update);
 
     candid::export_service!();
@@ -0,0 +1,55 @@
+use crate::{ExchangeId, StrategyConfig};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::TimestampMillis;
+
+pub const MAX_SNAPSHOTS: u32 = 10_000;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub exchange_id: ExchangeId,
+    // Defaults to the strategy currently configured for the exchange
+    pub strategy: Option<StrategyConfig>,
+    pub from: TimestampMillis,
+    pub to: TimestampMillis,
+    // Defaults to, and is capped at, MAX_SNAPSHOTS
+    pub max_snapshots: Option<u32>,
+    // The simulated balances at the start of the period, used by inventory based strategies
+    pub initial_base_balance: Option<u128>,
+    pub initial_quote_balance: Option<u128>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    ExchangeNotFound,
+    InvalidPeriod,
+    NoSnapshots,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub strategy: StrategyConfig,
+    pub from: TimestampMillis,
+    pub to: TimestampMillis,
+    pub snapshots: u32,
+    pub simulated: TradingResult,
+    // The result of replaying the orders the market maker actually made, cancelled and had filled
+    // on the exchange over the same period
+    pub live: TradingResult,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug, Default, Eq, PartialEq)]
+pub struct TradingResult {
+    pub orders_made: u32,
+    pub orders_cancelled: u32,
+    pub bids_filled: u32,
+    pub asks_filled: u32,
+    pub base_bought: u128,
+    pub base_sold: u128,
+    pub quote_spent: u128,
+    pub quote_received: u128,
+    // The net change in value across both tokens, denominated in the quote token and marked at
+    // the final snapshot's price
+    pub net_value_change: i128,
+}
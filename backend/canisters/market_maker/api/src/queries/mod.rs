@@ -1 +1,3 @@
+pub mod backtest;
 pub mod list_exchanges;
+pub mod profit_and_loss;
@@ -0,0 +1,46 @@
+use crate::ExchangeId;
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::TimestampMillis;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    // Defaults to all exchanges
+    pub exchange_id: Option<ExchangeId>,
+    // If set, snapshots taken since this time are included
+    pub history_since: Option<TimestampMillis>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Response {
+    pub exchanges: Vec<ExchangeProfitAndLoss>,
+}
+
+// Amounts are in the smallest units of each token and prices are in quote token units per whole
+// base token. Inventory is the net change from fills, so may be negative.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ExchangeProfitAndLoss {
+    pub exchange_id: ExchangeId,
+    pub fills: u32,
+    pub base_bought: u128,
+    pub base_sold: u128,
+    pub quote_spent: u128,
+    pub quote_received: u128,
+    pub base_inventory: i128,
+    pub quote_inventory: i128,
+    pub average_cost: u64,
+    pub latest_price: u64,
+    pub realised_pnl: i128,
+    pub unrealised_pnl: i128,
+    pub history: Vec<ProfitAndLossSnapshot>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ProfitAndLossSnapshot {
+    pub timestamp: TimestampMillis,
+    pub base_inventory: i128,
+    pub quote_inventory: i128,
+    pub price: u64,
+    pub realised_pnl: i128,
+    pub unrealised_pnl: i128,
+}
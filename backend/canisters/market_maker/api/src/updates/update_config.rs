@@ -1,4 +1,4 @@
-use crate::ExchangeId;
+use crate::{ExchangeId, StrategyConfig};
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +16,7 @@ pub struct Args {
     pub max_orders_per_direction: Option<u32>,
     pub max_orders_to_make_per_iteration: Option<u32>,
     pub max_orders_to_cancel_per_iteration: Option<u32>,
+    pub strategy: Option<StrategyConfig>,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -1,7 +1,9 @@
-use canister_client::generate_update_call;
+use canister_client::{generate_query_call, generate_update_call};
 use market_maker_canister::*;
 
 // Queries
+generate_query_call!(backtest);
+generate_query_call!(profit_and_loss);
 
 // Update
 generate_update_call!(update_config);
@@ -0,0 +1,229 @@
+use crate::model::market_snapshots::MarketSnapshot;
+use crate::model::orders_log::{Action, LogEntry};
+use crate::strategies::{Inventory, OrderPlan, Strategy, StrategyInput, BASE_UNITS_PER_WHOLE};
+use crate::Config;
+use market_maker_canister::backtest::TradingResult;
+use types::{AggregatedOrders, Order, OrderType, TimestampMillis};
+
+pub struct Simulation {
+    pub snapshots: u32,
+    pub from: TimestampMillis,
+    pub to: TimestampMillis,
+    pub latest_price: u64,
+    pub result: TradingResult,
+}
+
+// Replays the recorded market snapshots through the strategy, tracking the orders it would have
+// made. A simulated bid is treated as filled once the market trades below its price or the best
+// ask drops to its price, and vice versa for asks. This ignores queue position and the impact our
+// own orders would have had on the market, so the results are an approximation.
+pub fn simulate(
+    snapshots: impl Iterator<Item = MarketSnapshot>,
+    strategy: &dyn Strategy,
+    config: &Config,
+    initial_inventory: Inventory,
+) -> Simulation {
+    let mut simulation = Simulation {
+        snapshots: 0,
+        from: 0,
+        to: 0,
+        latest_price: 0,
+        result: TradingResult::default(),
+    };
+    let mut open_orders: Vec<Order> = Vec::new();
+    let mut previous_open_orders: Option<AggregatedOrders> = None;
+    let mut recent_prices = Vec::new();
+    let mut next_order_id = 0;
+    let history_required = strategy.price_history_required();
+
+    for snapshot in snapshots {
+        if simulation.snapshots == 0 {
+            simulation.from = snapshot.timestamp;
+        }
+        simulation.snapshots += 1;
+        simulation.to = snapshot.timestamp;
+        simulation.latest_price = snapshot.latest_price;
+
+        let result = &mut simulation.result;
+        open_orders.retain(|o| {
+            let filled = match o.order_type {
+                OrderType::Bid => snapshot.latest_price < o.price || snapshot.best_ask.is_some_and(|a| a <= o.price),
+                OrderType::Ask => snapshot.latest_price > o.price || snapshot.best_bid.is_some_and(|b| b >= o.price),
+            };
+            if filled {
+                record_fill(result, o.order_type, o.price, o.amount);
+            }
+            !filled
+        });
+
+        if let (Some(current_bid), Some(current_ask)) = (snapshot.best_bid, snapshot.best_ask) {
+            let inventory = Inventory {
+                base: (initial_inventory.base + result.base_bought).saturating_sub(result.base_sold),
+                quote: (initial_inventory.quote + result.quote_received).saturating_sub(result.quote_spent),
+            };
+
+            let OrderPlan {
+                orders_to_make,
+                orders_to_cancel,
+            } = strategy.plan(&StrategyInput {
+                current_bid,
+                current_ask,
+                my_open_orders: &open_orders,
+                my_previous_open_orders: previous_open_orders.as_ref(),
+                inventory: Some(inventory),
+                recent_prices: &recent_prices[recent_prices.len().saturating_sub(history_required)..],
+                config,
+            });
+
+            for order in orders_to_cancel {
+                open_orders.retain(|o| o.id != order.id);
+                result.orders_cancelled += 1;
+            }
+            for order in orders_to_make {
+                open_orders.push(Order {
+                    order_type: order.order_type,
+                    id: next_order_id.to_string(),
+                    price: order.price,
+                    amount: order.amount,
+                });
+                next_order_id += 1;
+                result.orders_made += 1;
+            }
+
+            previous_open_orders = Some(open_orders.as_slice().into());
+        }
+
+        recent_prices.push(snapshot.latest_price);
+    }
+
+    set_net_value_change(&mut simulation.result, simulation.latest_price);
+
+    simulation
+}
+
+// Replays the orders the market maker actually made, cancelled and had filled, so that the
+// simulated result can be compared against what really happened over the same period
+pub fn replay(entries: impl Iterator<Item = LogEntry>, latest_price: u64) -> TradingResult {
+    let mut result = TradingResult::default();
+    for entry in entries {
+        match entry.action {
+            Action::OrderMade(_) => result.orders_made += 1,
+            Action::OrderCancelled(_) => result.orders_cancelled += 1,
+            Action::OrderFilled(fill) => record_fill(&mut result, fill.order_type, fill.price, fill.amount),
+        }
+    }
+    set_net_value_change(&mut result, latest_price);
+    result
+}
+
+fn record_fill(result: &mut TradingResult, order_type: OrderType, price: u64, amount: u64) {
+    let quote_amount = amount as u128 * price as u128 / BASE_UNITS_PER_WHOLE;
+    match order_type {
+        OrderType::Bid => {
+            result.bids_filled += 1;
+            result.base_bought += amount as u128;
+            result.quote_spent += quote_amount;
+        }
+        OrderType::Ask => {
+            result.asks_filled += 1;
+            result.base_sold += amount as u128;
+            result.quote_received += quote_amount;
+        }
+    }
+}
+
+fn set_net_value_change(result: &mut TradingResult, latest_price: u64) {
+    let base_change = result.base_bought as i128 - result.base_sold as i128;
+    let quote_change = result.quote_received as i128 - result.quote_spent as i128;
+    result.net_value_change = base_change * latest_price as i128 / BASE_UNITS_PER_WHOLE as i128 + quote_change;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::orders_log::Fill;
+    use crate::strategies::ladder::Ladder;
+    use market_maker_canister::{StrategyConfig, ICDEX_EXCHANGE_ID};
+    use types::{CancelOrderRequest, MakeOrderRequest};
+
+    #[test]
+    fn bids_filled_when_price_drops() {
+        let config = Config {
+            enabled: true,
+            price_increment: 10,
+            order_size: BASE_UNITS_PER_WHOLE as u64,
+            min_order_size: BASE_UNITS_PER_WHOLE as u64,
+            max_buy_price: 1000,
+            min_sell_price: 0,
+            spread: 2,
+            min_orders_per_direction: 3,
+            max_orders_per_direction: 5,
+            max_orders_to_make_per_iteration: 10,
+            max_orders_to_cancel_per_iteration: 10,
+            strategy: StrategyConfig::Ladder,
+        };
+
+        let snapshots = [(500, 490, 510), (400, 390, 410)].map(|(latest_price, bid, ask)| MarketSnapshot {
+            timestamp: latest_price,
+            exchange_id: ICDEX_EXCHANGE_ID,
+            latest_price,
+            best_bid: Some(bid),
+            best_ask: Some(ask),
+        });
+
+        let simulation = simulate(snapshots.into_iter(), &Ladder, &config, Inventory::default());
+
+        assert_eq!(simulation.snapshots, 2);
+        assert_eq!(simulation.from, 500);
+        assert_eq!(simulation.to, 400);
+        assert_eq!(simulation.result.bids_filled, 3);
+        assert_eq!(simulation.result.asks_filled, 0);
+        assert_eq!(simulation.result.base_bought, 3 * BASE_UNITS_PER_WHOLE);
+        assert!(simulation.result.net_value_change < 0);
+    }
+
+    #[test]
+    fn replay_totals_logged_orders_and_fills() {
+        let whole = BASE_UNITS_PER_WHOLE as u64;
+        let actions = [
+            Action::OrderMade(MakeOrderRequest {
+                order_type: OrderType::Bid,
+                price: 500,
+                amount: whole,
+            }),
+            Action::OrderMade(MakeOrderRequest {
+                order_type: OrderType::Ask,
+                price: 600,
+                amount: whole,
+            }),
+            Action::OrderCancelled(CancelOrderRequest { id: "1".to_string() }),
+            Action::OrderFilled(Fill {
+                order_type: OrderType::Bid,
+                price: 500,
+                amount: whole,
+            }),
+        ];
+        let entries = actions.into_iter().map(|action| LogEntry {
+            timestamp: 0,
+            exchange_id: ICDEX_EXCHANGE_ID,
+            action,
+        });
+
+        let result = replay(entries, 450);
+
+        assert_eq!(
+            result,
+            TradingResult {
+                orders_made: 2,
+                orders_cancelled: 1,
+                bids_filled: 1,
+                asks_filled: 0,
+                base_bought: whole as u128,
+                base_sold: 0,
+                quote_spent: 500,
+                quote_received: 0,
+                net_value_change: -50,
+            }
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use crate::exchanges::Exchange;
+use crate::model::pending_fills::{FillStage, PendingFill};
+use crate::{mutate_state, read_state};
+use async_trait::async_trait;
+use ic_cdk::api::call::{CallResult, RejectionCode};
+use icpswap_client::ICPSwapClient;
+use icrc_ledger_types::icrc1::transfer::{TransferArg, TransferError};
+use market_maker_canister::{ExchangeId, ICPSWAP_EXCHANGE_ID};
+use rand::RngCore;
+use std::collections::BTreeMap;
+use tracing::error;
+use types::{AggregatedOrders, CancelOrderRequest, CanisterId, MakeOrderRequest, MarketState, Order, OrderType, TokenInfo};
+use utils::time::NANOS_PER_MILLISECOND;
+
+// ICPSwap is an AMM, so it has no orderbook and no way of placing resting limit orders. Instead we
+// quote the pool in each direction to get the prices at which it will currently buy and sell, and
+// hold the orders made by the market maker locally. Once the pool price crosses an order's limit
+// price, the `fill_icpswap_orders` job executes it as a swap. Orders which are filled then drop out
+// of `my_open_orders`, which is exactly how a taken order appears on an orderbook exchange.
+pub struct ICPSwapExchange {
+    this_canister_id: CanisterId,
+    swap_canister_id: CanisterId,
+    quote_token: TokenInfo,
+    base_token: TokenInfo,
+    base_is_token0: bool,
+}
+
+impl ICPSwapExchange {
+    pub fn new(
+        this_canister_id: CanisterId,
+        swap_canister_id: CanisterId,
+        quote_token: TokenInfo,
+        base_token: TokenInfo,
+        base_is_token0: bool,
+    ) -> ICPSwapExchange {
+        ICPSwapExchange {
+            this_canister_id,
+            swap_canister_id,
+            quote_token,
+            base_token,
+            base_is_token0,
+        }
+    }
+
+    fn client(&self, sell_base: bool) -> ICPSwapClient {
+        let (token0, token1) = if self.base_is_token0 {
+            (self.base_token.clone(), self.quote_token.clone())
+        } else {
+            (self.quote_token.clone(), self.base_token.clone())
+        };
+
+        ICPSwapClient::new(
+            self.this_canister_id,
+            self.swap_canister_id,
+            token0,
+            token1,
+            sell_base == self.base_is_token0,
+        )
+    }
+
+    // The prices (in quote token units per whole base token) at which the pool will currently buy
+    // and sell one whole base token
+    pub async fn best_bid_and_ask(&self) -> CallResult<(u64, u64)> {
+        let units_per_whole = self.base_token_units_per_whole();
+
+        let bid = self.client(true).quote(units_per_whole).await?;
+        let base_out = self.client(false).quote(bid).await?;
+        let ask = if base_out > 0 { bid * units_per_whole / base_out } else { u64::MAX as u128 };
+
+        Ok((bid as u64, ask as u64))
+    }
+
+    // Makes the call for the fill's current stage, returning the stage which follows it, or `None`
+    // once the fill is complete
+    pub async fn process_fill(&self, fill: &PendingFill) -> CallResult<Option<FillStage>> {
+        let units_per_whole = self.base_token_units_per_whole();
+        let price = fill.order.price as u128;
+        let sell_base = matches!(fill.order.order_type, OrderType::Ask);
+
+        let (input_token, output_token, amount_in) = if sell_base {
+            (&self.base_token, &self.quote_token, fill.order.amount as u128)
+        } else {
+            (
+                &self.quote_token,
+                &self.base_token,
+                fill.order.amount as u128 * price / units_per_whole,
+            )
+        };
+
+        let amount_to_swap = amount_in.saturating_sub(input_token.fee);
+        let client = self.client(sell_base);
+
+        match fill.stage {
+            FillStage::Transfer => {
+                // The creation time is fixed per fill so that the ledger deduplicates any retries
+                match icrc_ledger_canister_c2c_client::icrc1_transfer(
+                    input_token.ledger,
+                    &TransferArg {
+                        from_subaccount: None,
+                        to: client.deposit_account(),
+                        fee: Some(input_token.fee.into()),
+                        created_at_time: Some(fill.created * NANOS_PER_MILLISECOND),
+                        memo: None,
+                        amount: amount_in.into(),
+                    },
+                )
+                .await?
+                {
+                    Ok(_) | Err(TransferError::Duplicate { .. }) => Ok(Some(FillStage::Deposit)),
+                    Err(error) => Err((RejectionCode::Unknown, format!("{error:?}"))),
+                }
+            }
+            FillStage::Deposit => {
+                client.deposit(amount_in).await?;
+                Ok(Some(FillStage::Swap))
+            }
+            FillStage::Swap => {
+                // The swap only succeeds if the pool gives us at least the order's limit price
+                let min_amount_out = if sell_base {
+                    amount_to_swap * price / units_per_whole
+                } else {
+                    amount_to_swap * units_per_whole / price
+                };
+
+                match client.swap(amount_to_swap, min_amount_out).await {
+                    Ok(amount_out) => Ok(Some(FillStage::WithdrawOutput(amount_out))),
+                    Err(error) => {
+                        error!(?error, order_id = %fill.order.id, "Failed to swap on ICPSwap");
+                        Ok(Some(FillStage::WithdrawInput))
+                    }
+                }
+            }
+            FillStage::WithdrawOutput(amount_out) => {
+                client.withdraw(amount_out.saturating_sub(output_token.fee)).await?;
+                Ok(None)
+            }
+            FillStage::WithdrawInput => {
+                self.client(!sell_base)
+                    .withdraw(amount_to_swap.saturating_sub(input_token.fee))
+                    .await?;
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn is_crossed(order: &Order, bid: u64, ask: u64) -> bool {
+        match order.order_type {
+            OrderType::Bid => order.price >= ask,
+            OrderType::Ask => order.price <= bid,
+        }
+    }
+
+    fn base_token_units_per_whole(&self) -> u128 {
+        10u128.pow(self.base_token.decimals as u32)
+    }
+}
+
+#[async_trait]
+impl Exchange for ICPSwapExchange {
+    fn exchange_id(&self) -> ExchangeId {
+        ICPSWAP_EXCHANGE_ID
+    }
+
+    async fn market_state(&self) -> CallResult<MarketState> {
+        let (bid, ask) = self.best_bid_and_ask().await?;
+
+        // Orders the pool price has crossed are filled by the `fill_icpswap_orders` job, but remain
+        // open until their swap has succeeded
+        let my_open_orders = read_state(|state| {
+            state
+                .data
+                .resting_orders
+                .get(&ICPSWAP_EXCHANGE_ID)
+                .into_iter()
+                .flatten()
+                .chain(state.data.pending_fills.open_orders())
+                .cloned()
+                .collect()
+        });
+
+        // The pool has no orderbook so we represent it as a single level either side of the price
+        let units_per_whole = self.base_token_units_per_whole() as u64;
+        let orderbook = AggregatedOrders {
+            bids: BTreeMap::from([(bid, units_per_whole)]),
+            asks: BTreeMap::from([(ask, units_per_whole)]),
+        };
+
+        Ok(MarketState {
+            latest_price: (bid + ask) / 2,
+            my_open_orders,
+            orderbook,
+        })
+    }
+
+    async fn make_orders(&self, orders: Vec<MakeOrderRequest>) -> CallResult<()> {
+        mutate_state(|state| {
+            let now = state.env.now();
+            for order in orders {
+                let id = format!("{:016x}", state.env.rng().next_u64());
+                state.data.resting_orders.entry(ICPSWAP_EXCHANGE_ID).or_default().push(Order {
+                    order_type: order.order_type,
+                    id,
+                    price: order.price,
+                    amount: order.amount,
+                });
+                state.data.orders_log.log_order_made(ICPSWAP_EXCHANGE_ID, order, now);
+            }
+        });
+        Ok(())
+    }
+
+    async fn cancel_orders(&self, orders: Vec<CancelOrderRequest>) -> CallResult<()> {
+        mutate_state(|state| {
+            let now = state.env.now();
+            for order in orders {
+                if let Some(resting_orders) = state.data.resting_orders.get_mut(&ICPSWAP_EXCHANGE_ID) {
+                    resting_orders.retain(|o| o.id != order.id);
+                }
+                state.data.pending_fills.cancel(&order.id);
+                state.data.orders_log.log_order_cancelled(ICPSWAP_EXCHANGE_ID, order, now);
+            }
+        });
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use market_maker_canister::ExchangeId;
 use types::{CancelOrderRequest, MakeOrderRequest, MarketState};
 
 pub mod icdex;
+pub mod icpswap;
 
 #[async_trait]
 pub trait Exchange {
@@ -0,0 +1,101 @@
+use crate::exchanges::icpswap::ICPSwapExchange;
+use crate::model::pending_fills::FillStage;
+use crate::{mutate_state, read_state, RuntimeState};
+use ic_cdk::api::call::CallResult;
+use market_maker_canister::ICPSWAP_EXCHANGE_ID;
+use std::time::Duration;
+use tracing::{error, trace};
+use types::Milliseconds;
+use utils::time::MINUTE_IN_MS;
+
+const FILL_ICPSWAP_ORDERS_INTERVAL: Milliseconds = MINUTE_IN_MS;
+const MAX_ATTEMPTS_PER_STAGE: u32 = 10;
+
+pub fn start_job() {
+    ic_cdk_timers::set_timer_interval(Duration::from_millis(FILL_ICPSWAP_ORDERS_INTERVAL), run);
+}
+
+fn run() {
+    if let Some(exchange) = read_state(|state| {
+        (has_resting_orders(state) || !state.data.pending_fills.is_empty()).then(|| state.icpswap_exchange())
+    }) {
+        ic_cdk::spawn(run_async(exchange));
+    }
+}
+
+async fn run_async(exchange: ICPSwapExchange) {
+    if read_state(has_resting_orders) {
+        if let Err(error) = queue_crossed_orders(&exchange).await {
+            error!(?error, "Failed to get prices from ICPSwap");
+        }
+    }
+
+    while let Some(fill) = mutate_state(|state| state.data.pending_fills.take_next(state.env.now())) {
+        let result = exchange.process_fill(&fill).await;
+        let order_id = fill.order.id.clone();
+
+        mutate_state(|state| {
+            let now = state.env.now();
+            match result {
+                Ok(Some(stage)) => state.data.pending_fills.advance(&order_id, stage, now),
+                Ok(None) => {
+                    state.data.pending_fills.remove(&order_id);
+                    if fill.stage == FillStage::WithdrawInput {
+                        // The swap failed, so the order rests again until the pool price next crosses it
+                        state
+                            .data
+                            .resting_orders
+                            .entry(ICPSWAP_EXCHANGE_ID)
+                            .or_default()
+                            .push(fill.order);
+                    } else {
+                        trace!(order_id, "ICPSwap order filled");
+                    }
+                }
+                Err(error) => {
+                    let attempts = state
+                        .data
+                        .pending_fills
+                        .retry_later(&order_id, now + FILL_ICPSWAP_ORDERS_INTERVAL);
+
+                    if attempts >= MAX_ATTEMPTS_PER_STAGE {
+                        // The tokens are left wherever the last successful stage put them, so the
+                        // logged fill is needed to recover them manually
+                        error!(?error, ?fill, "Abandoned ICPSwap fill");
+                        state.data.pending_fills.remove(&order_id);
+                    } else {
+                        error!(?error, order_id, stage = ?fill.stage, attempts, "Failed to process ICPSwap fill");
+                    }
+                }
+            }
+        });
+    }
+}
+
+// Crossed orders are moved out of the resting orders before any calls are made to fill them, so
+// that each order can only be filled once
+async fn queue_crossed_orders(exchange: &ICPSwapExchange) -> CallResult<()> {
+    let (bid, ask) = exchange.best_bid_and_ask().await?;
+
+    mutate_state(|state| {
+        let now = state.env.now();
+        if let Some(orders) = state.data.resting_orders.get_mut(&ICPSWAP_EXCHANGE_ID) {
+            let (crossed, resting): (Vec<_>, Vec<_>) = orders.drain(..).partition(|o| ICPSwapExchange::is_crossed(o, bid, ask));
+
+            *orders = resting;
+            for order in crossed {
+                state.data.pending_fills.add(order, now);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+fn has_resting_orders(state: &RuntimeState) -> bool {
+    state
+        .data
+        .resting_orders
+        .get(&ICPSWAP_EXCHANGE_ID)
+        .map_or(false, |orders| !orders.is_empty())
+}
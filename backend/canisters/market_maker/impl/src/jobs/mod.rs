@@ -1,7 +1,9 @@
 use crate::RuntimeState;
 
+pub mod fill_icpswap_orders;
 pub mod run_market_maker;
 
 pub(crate) fn start(_state: &RuntimeState) {
+    fill_icpswap_orders::start_job();
     run_market_maker::start_job();
 }
@@ -1,23 +1,31 @@
+use crate::exchanges::icpswap::ICPSwapExchange;
 use crate::exchanges::Exchange;
+use crate::model::market_snapshots::MarketSnapshots;
 use crate::model::orders_log::OrdersLog;
+use crate::model::pending_fills::PendingFills;
+use crate::model::profit_and_loss::ProfitAndLoss;
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use icdex_client::ICDexClient;
-use market_maker_canister::{ExchangeId, ICDEX_EXCHANGE_ID};
+use market_maker_canister::profit_and_loss::ExchangeProfitAndLoss;
+use market_maker_canister::{ExchangeId, StrategyConfig, ICDEX_EXCHANGE_ID, ICPSWAP_EXCHANGE_ID};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use types::{
-    AggregatedOrders, BuildVersion, CancelOrderRequest, CanisterId, Cryptocurrency, Cycles, MakeOrderRequest, TimestampMillis,
-    Timestamped, TokenInfo,
+    AggregatedOrders, BuildVersion, CancelOrderRequest, CanisterId, Cryptocurrency, Cycles, MakeOrderRequest, Order,
+    TimestampMillis, Timestamped, TokenInfo,
 };
 use utils::env::Environment;
 
+mod backtest;
 mod exchanges;
 mod jobs;
 mod lifecycle;
 mod memory;
 mod model;
 mod queries;
+mod strategies;
 mod updates;
 
 thread_local! {
@@ -41,26 +49,45 @@ impl RuntimeState {
             ICDEX_EXCHANGE_ID => Some(Box::new(ICDexClient::new(
                 self.env.canister_id(),
                 CanisterId::from_text("3we4s-lyaaa-aaaak-aegrq-cai").unwrap(),
-                TokenInfo {
-                    token: Cryptocurrency::InternetComputer,
-                    ledger: self.data.icp_ledger_canister_id,
-                    decimals: 8,
-                    fee: 10_000,
-                },
-                TokenInfo {
-                    token: Cryptocurrency::CHAT,
-                    ledger: self.data.chat_ledger_canister_id,
-                    decimals: 8,
-                    fee: 100_000,
-                },
+                self.icp_token_info(),
+                self.chat_token_info(),
                 10_000_000,
                 |order| on_order_made(ICDEX_EXCHANGE_ID, order),
                 |order| on_order_cancelled(ICDEX_EXCHANGE_ID, order),
             ))),
+            ICPSWAP_EXCHANGE_ID => Some(Box::new(self.icpswap_exchange())),
             _ => None,
         }
     }
 
+    pub fn icpswap_exchange(&self) -> ICPSwapExchange {
+        ICPSwapExchange::new(
+            self.env.canister_id(),
+            CanisterId::from_text("ne2vj-6yaaa-aaaag-qb3ia-cai").unwrap(),
+            self.icp_token_info(),
+            self.chat_token_info(),
+            true,
+        )
+    }
+
+    fn icp_token_info(&self) -> TokenInfo {
+        TokenInfo {
+            token: Cryptocurrency::InternetComputer,
+            ledger: self.data.icp_ledger_canister_id,
+            decimals: 8,
+            fee: 10_000,
+        }
+    }
+
+    fn chat_token_info(&self) -> TokenInfo {
+        TokenInfo {
+            token: Cryptocurrency::CHAT,
+            ledger: self.data.chat_ledger_canister_id,
+            decimals: 8,
+            fee: 100_000,
+        }
+    }
+
     pub fn metrics(&self) -> Metrics {
         Metrics {
             memory_used: utils::memory::used(),
@@ -71,6 +98,7 @@ impl RuntimeState {
             exchanges: self.data.exchange_config.clone(),
             my_open_orders: self.data.my_open_orders.clone(),
             market_makers_in_progress: self.data.market_makers_in_progress.clone(),
+            profit_and_loss: self.data.profit_and_loss.summaries(None, None),
             canister_ids: CanisterIds {
                 user_index: self.data.user_index_canister_id,
                 cycles_dispenser: self.data.cycles_dispenser_canister_id,
@@ -92,6 +120,15 @@ struct Data {
     pub my_open_orders: HashMap<ExchangeId, AggregatedOrders>,
     pub market_makers_in_progress: HashMap<ExchangeId, TimestampMillis>,
     #[serde(default)]
+    pub market_snapshots: MarketSnapshots,
+    #[serde(default)]
+    pub profit_and_loss: ProfitAndLoss,
+    // Orders held locally for exchanges which can't hold resting limit orders themselves (eg. AMMs)
+    #[serde(default)]
+    pub resting_orders: HashMap<ExchangeId, Vec<Order>>,
+    #[serde(default)]
+    pub pending_fills: PendingFills,
+    #[serde(default)]
     pub rng_seed: [u8; 32],
     pub test_mode: bool,
 }
@@ -113,6 +150,10 @@ impl Data {
             orders_log: OrdersLog::default(),
             my_open_orders: HashMap::new(),
             market_makers_in_progress: HashMap::new(),
+            market_snapshots: MarketSnapshots::default(),
+            profit_and_loss: ProfitAndLoss::default(),
+            resting_orders: HashMap::new(),
+            pending_fills: PendingFills::default(),
             rng_seed: [0; 32],
             test_mode,
         }
@@ -129,9 +170,75 @@ pub struct Metrics {
     pub exchanges: HashMap<ExchangeId, Config>,
     pub my_open_orders: HashMap<ExchangeId, AggregatedOrders>,
     pub market_makers_in_progress: HashMap<ExchangeId, TimestampMillis>,
+    pub profit_and_loss: Vec<ExchangeProfitAndLoss>,
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const OPEN_ORDERS: &str = "market_maker_open_orders";
+        const OPEN_ORDERS_HELP: &str = "Open orders, by exchange and side";
+
+        registry.canister(
+            self.now,
+            self.memory_used,
+            self.cycles_balance,
+            self.wasm_version,
+            &self.git_commit_id,
+        );
+
+        for (exchange_id, config) in self.exchanges.iter() {
+            let exchange_id = exchange_id.to_string();
+            registry.gauge_with_labels(
+                "market_maker_exchange_enabled",
+                "Whether market making is enabled on each exchange",
+                &[("exchange", &exchange_id)],
+                u8::from(config.enabled),
+            );
+        }
+
+        for (exchange_id, orders) in self.my_open_orders.iter() {
+            let exchange_id = exchange_id.to_string();
+            registry
+                .gauge_with_labels(
+                    OPEN_ORDERS,
+                    OPEN_ORDERS_HELP,
+                    &[("exchange", &exchange_id), ("side", "bid")],
+                    orders.bids.len(),
+                )
+                .gauge_with_labels(
+                    OPEN_ORDERS,
+                    OPEN_ORDERS_HELP,
+                    &[("exchange", &exchange_id), ("side", "ask")],
+                    orders.asks.len(),
+                );
+        }
+
+        for pnl in self.profit_and_loss.iter() {
+            let exchange_id = pnl.exchange_id.to_string();
+            registry
+                .gauge_with_labels(
+                    "market_maker_realised_pnl",
+                    "Realised profit and loss in quote token units, by exchange",
+                    &[("exchange", &exchange_id)],
+                    pnl.realised_pnl,
+                )
+                .gauge_with_labels(
+                    "market_maker_unrealised_pnl",
+                    "Unrealised profit and loss in quote token units, by exchange",
+                    &[("exchange", &exchange_id)],
+                    pnl.unrealised_pnl,
+                )
+                .counter_with_labels(
+                    "market_maker_fills",
+                    "Orders filled, by exchange",
+                    &[("exchange", &exchange_id)],
+                    pnl.fills,
+                );
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CanisterIds {
     pub user_index: CanisterId,
@@ -140,7 +247,7 @@ pub struct CanisterIds {
     pub chat_ledger: CanisterId,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Config {
     enabled: bool,
     price_increment: u64,
@@ -153,6 +260,8 @@ pub struct Config {
     max_orders_per_direction: u32,
     max_orders_to_make_per_iteration: u32,
     max_orders_to_cancel_per_iteration: u32,
+    #[serde(default)]
+    strategy: StrategyConfig,
 }
 
 fn on_order_made(exchange_id: ExchangeId, order: MakeOrderRequest) {
@@ -6,6 +6,8 @@ use ic_stable_structures::{
 const UPGRADES: MemoryId = MemoryId::new(0);
 const ORDERS_LOG_INDEX: MemoryId = MemoryId::new(1);
 const ORDERS_LOG_DATA: MemoryId = MemoryId::new(2);
+const MARKET_SNAPSHOTS_INDEX: MemoryId = MemoryId::new(3);
+const MARKET_SNAPSHOTS_DATA: MemoryId = MemoryId::new(4);
 
 pub type Memory = VirtualMemory<DefaultMemoryImpl>;
 
@@ -26,6 +28,14 @@ pub fn get_orders_log_data_memory() -> Memory {
     get_memory(ORDERS_LOG_DATA)
 }
 
+pub fn get_market_snapshots_index_memory() -> Memory {
+    get_memory(MARKET_SNAPSHOTS_INDEX)
+}
+
+pub fn get_market_snapshots_data_memory() -> Memory {
+    get_memory(MARKET_SNAPSHOTS_DATA)
+}
+
 fn get_memory(id: MemoryId) -> Memory {
     MEMORY_MANAGER.with(|m| m.get(id))
 }
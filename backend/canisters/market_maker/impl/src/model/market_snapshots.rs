@@ -0,0 +1,76 @@
+use crate::memory::{get_market_snapshots_data_memory, get_market_snapshots_index_memory, Memory};
+use crate::model::first_index_from;
+use ic_stable_structures::storable::Bound;
+use ic_stable_structures::{StableLog, Storable};
+use market_maker_canister::ExchangeId;
+use msgpack::{deserialize_then_unwrap, serialize_then_unwrap};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use types::TimestampMillis;
+
+// The market state observed on each run of the market maker, recorded so that strategies can be
+// evaluated against real market conditions without trading real funds
+#[derive(Serialize, Deserialize)]
+pub struct MarketSnapshots {
+    #[serde(skip, default = "init_log")]
+    log: StableLog<MarketSnapshot, Memory, Memory>,
+}
+
+impl MarketSnapshots {
+    pub fn push(&mut self, snapshot: MarketSnapshot) {
+        self.log.append(&snapshot).unwrap();
+    }
+
+    pub fn iter_from(&self, from: TimestampMillis) -> impl Iterator<Item = MarketSnapshot> + '_ {
+        let start = first_index_from(self.log.len(), from, |i| self.log.get(i).unwrap().timestamp);
+        (start..self.log.len()).filter_map(|i| self.log.get(i))
+    }
+
+    // Returns up to `count` of the most recent prices for the exchange, oldest first
+    pub fn recent_prices(&self, exchange_id: ExchangeId, count: usize) -> Vec<u64> {
+        let mut prices: Vec<_> = (0..self.log.len())
+            .rev()
+            .filter_map(|i| self.log.get(i))
+            .filter(|s| s.exchange_id == exchange_id)
+            .map(|s| s.latest_price)
+            .take(count)
+            .collect();
+
+        prices.reverse();
+        prices
+    }
+}
+
+fn init_log() -> StableLog<MarketSnapshot, Memory, Memory> {
+    let index_memory = get_market_snapshots_index_memory();
+    let data_memory = get_market_snapshots_data_memory();
+
+    StableLog::init(index_memory, data_memory).unwrap()
+}
+
+impl Default for MarketSnapshots {
+    fn default() -> Self {
+        MarketSnapshots { log: init_log() }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MarketSnapshot {
+    pub timestamp: TimestampMillis,
+    pub exchange_id: ExchangeId,
+    pub latest_price: u64,
+    pub best_bid: Option<u64>,
+    pub best_ask: Option<u64>,
+}
+
+impl Storable for MarketSnapshot {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(serialize_then_unwrap(self))
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        deserialize_then_unwrap(bytes.as_ref())
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
@@ -1 +1,38 @@
+use types::TimestampMillis;
+
+pub mod market_snapshots;
 pub mod orders_log;
+pub mod pending_fills;
+pub mod profit_and_loss;
+
+// Entries are appended to the logs in time order, so the first entry at or after `from` can be
+// found with a binary search rather than by scanning the whole log
+fn first_index_from(len: u64, from: TimestampMillis, timestamp: impl Fn(u64) -> TimestampMillis) -> u64 {
+    let mut low = 0;
+    let mut high = len;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if timestamp(mid) < from {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_index_from_finds_first_entry_at_or_after_timestamp() {
+        let timestamps = [10, 20, 20, 30];
+        let find = |from| first_index_from(timestamps.len() as u64, from, |i| timestamps[i as usize]);
+
+        assert_eq!(find(0), 0);
+        assert_eq!(find(20), 1);
+        assert_eq!(find(21), 3);
+        assert_eq!(find(31), 4);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::memory::{get_orders_log_data_memory, get_orders_log_index_memory, Memory};
+use crate::model::first_index_from;
 use ic_stable_structures::storable::Bound;
 use ic_stable_structures::{StableLog, Storable};
 use market_maker_canister::ExchangeId;
@@ -6,7 +7,7 @@ use msgpack::{deserialize_then_unwrap, serialize_then_unwrap};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
-use types::{CancelOrderRequest, MakeOrderRequest, TimestampMillis};
+use types::{CancelOrderRequest, MakeOrderRequest, OrderType, TimestampMillis};
 
 #[derive(Serialize, Deserialize)]
 pub struct OrdersLog {
@@ -23,10 +24,19 @@ impl OrdersLog {
         self.log(exchange_id, Action::OrderCancelled(order), now);
     }
 
+    pub fn log_order_filled(&mut self, exchange_id: ExchangeId, fill: Fill, now: TimestampMillis) {
+        self.log(exchange_id, Action::OrderFilled(fill), now);
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = LogEntry> + '_ {
         self.log.iter()
     }
 
+    pub fn iter_from(&self, from: TimestampMillis) -> impl Iterator<Item = LogEntry> + '_ {
+        let start = first_index_from(self.log.len(), from, |i| self.log.get(i).unwrap().timestamp);
+        (start..self.log.len()).filter_map(|i| self.log.get(i))
+    }
+
     pub fn len(&self) -> u64 {
         self.log.len()
     }
@@ -57,15 +67,23 @@ impl Default for OrdersLog {
 
 #[derive(Serialize, Deserialize)]
 pub struct LogEntry {
-    timestamp: TimestampMillis,
-    exchange_id: ExchangeId,
-    action: Action,
+    pub timestamp: TimestampMillis,
+    pub exchange_id: ExchangeId,
+    pub action: Action,
 }
 
 #[derive(Serialize, Deserialize)]
-enum Action {
+pub enum Action {
     OrderMade(MakeOrderRequest),
     OrderCancelled(CancelOrderRequest),
+    OrderFilled(Fill),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct Fill {
+    pub order_type: OrderType,
+    pub price: u64,
+    pub amount: u64,
 }
 
 impl Storable for LogEntry {
@@ -96,6 +114,13 @@ impl Display for LogEntry {
             Action::OrderCancelled(o) => {
                 format!("Order cancelled. Id: {}", o.id)
             }
+            Action::OrderFilled(f) => {
+                let order_type = f.order_type;
+                let price = f.price as f64 / 100000000f64;
+                let amount = f.amount as f64 / 100000000f64;
+
+                format!("Order filled. Type: {order_type}. Price: {price}. Amount: {amount}")
+            }
         };
 
         write!(f, "{timestamp} ExchangeId: {exchange_id}. {msg}")
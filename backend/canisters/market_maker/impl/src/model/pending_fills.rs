@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{Order, TimestampMillis};
+
+// Orders which the ICPSwap pool price has crossed and which are being executed as swaps. Each fill
+// takes several calls, so the stage reached is recorded after each one, allowing a failed call to
+// be retried without repeating the calls which have already succeeded.
+#[derive(Serialize, Deserialize, Default)]
+pub struct PendingFills {
+    fills: BTreeMap<String, PendingFill>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PendingFill {
+    pub order: Order,
+    pub stage: FillStage,
+    pub created: TimestampMillis,
+    pub attempts: u32,
+    pub next_attempt: TimestampMillis,
+    // Not persisted, so that a fill which was in progress when the canister was upgraded is resumed
+    #[serde(skip)]
+    pub in_progress: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FillStage {
+    // Transfer the input tokens to this canister's deposit account on the pool
+    Transfer,
+    // Deposit the transferred tokens into the pool
+    Deposit,
+    // Swap the deposited tokens, at no worse than the order's limit price
+    Swap,
+    // The swap succeeded, so withdraw its output
+    WithdrawOutput(u128),
+    // The swap failed, so withdraw the input tokens and return the order to the resting orders
+    WithdrawInput,
+}
+
+impl PendingFills {
+    pub fn add(&mut self, order: Order, now: TimestampMillis) {
+        self.fills.insert(
+            order.id.clone(),
+            PendingFill {
+                order,
+                stage: FillStage::Transfer,
+                created: now,
+                attempts: 0,
+                next_attempt: now,
+                in_progress: false,
+            },
+        );
+    }
+
+    // Marks the next fill which is due to be processed as in progress and returns it
+    pub fn take_next(&mut self, now: TimestampMillis) -> Option<PendingFill> {
+        let fill = self.fills.values_mut().find(|f| !f.in_progress && f.next_attempt <= now)?;
+
+        fill.in_progress = true;
+        Some(fill.clone())
+    }
+
+    pub fn advance(&mut self, order_id: &str, stage: FillStage, now: TimestampMillis) {
+        if let Some(fill) = self.fills.get_mut(order_id) {
+            fill.stage = stage;
+            fill.attempts = 0;
+            fill.next_attempt = now;
+            fill.in_progress = false;
+        }
+    }
+
+    // Returns the number of attempts made at the fill's current stage
+    pub fn retry_later(&mut self, order_id: &str, next_attempt: TimestampMillis) -> u32 {
+        if let Some(fill) = self.fills.get_mut(order_id) {
+            fill.attempts += 1;
+            fill.next_attempt = next_attempt;
+            fill.in_progress = false;
+            fill.attempts
+        } else {
+            0
+        }
+    }
+
+    pub fn remove(&mut self, order_id: &str) -> Option<PendingFill> {
+        self.fills.remove(order_id)
+    }
+
+    // Fills can only be cancelled before any of their calls have been made
+    pub fn cancel(&mut self, order_id: &str) -> bool {
+        let can_cancel = self
+            .fills
+            .get(order_id)
+            .map_or(false, |f| f.stage == FillStage::Transfer && f.attempts == 0 && !f.in_progress);
+
+        if can_cancel {
+            self.fills.remove(order_id);
+        }
+        can_cancel
+    }
+
+    // Orders remain open until their swap has succeeded
+    pub fn open_orders(&self) -> impl Iterator<Item = &Order> {
+        self.fills
+            .values()
+            .filter(|f| !matches!(f.stage, FillStage::WithdrawOutput(_)))
+            .map(|f| &f.order)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fills.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::OrderType;
+
+    #[test]
+    fn fill_not_taken_again_while_in_progress() {
+        let mut pending_fills = PendingFills::default();
+        pending_fills.add(order("1"), 0);
+
+        assert!(pending_fills.take_next(0).is_some());
+        assert!(pending_fills.take_next(0).is_none());
+        assert!(!pending_fills.cancel("1"));
+
+        pending_fills.advance("1", FillStage::Swap, 10);
+        assert_eq!(pending_fills.take_next(10).unwrap().stage, FillStage::Swap);
+
+        assert_eq!(pending_fills.retry_later("1", 100), 1);
+        assert!(pending_fills.take_next(99).is_none());
+        assert!(pending_fills.take_next(100).is_some());
+    }
+
+    #[test]
+    fn order_open_until_swap_succeeds() {
+        let mut pending_fills = PendingFills::default();
+        pending_fills.add(order("1"), 0);
+        pending_fills.add(order("2"), 0);
+
+        pending_fills.advance("1", FillStage::WithdrawOutput(100), 0);
+        pending_fills.advance("2", FillStage::WithdrawInput, 0);
+
+        let open: Vec<_> = pending_fills.open_orders().map(|o| o.id.as_str()).collect();
+        assert_eq!(open, vec!["2"]);
+    }
+
+    fn order(id: &str) -> Order {
+        Order {
+            order_type: OrderType::Bid,
+            id: id.to_string(),
+            price: 100,
+            amount: 1,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::model::orders_log::Fill;
+use crate::strategies::BASE_UNITS_PER_WHOLE;
+use market_maker_canister::profit_and_loss::{ExchangeProfitAndLoss, ProfitAndLossSnapshot};
+use market_maker_canister::ExchangeId;
+use serde::{Deserialize, Serialize};
+use std::cmp::min;
+use std::collections::{HashMap, VecDeque};
+use types::{Milliseconds, OrderType, TimestampMillis};
+use utils::time::{DAY_IN_MS, HOUR_IN_MS};
+
+const SNAPSHOT_INTERVAL: Milliseconds = HOUR_IN_MS;
+const MAX_SNAPSHOTS: usize = (90 * DAY_IN_MS / SNAPSHOT_INTERVAL) as usize;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ProfitAndLoss {
+    exchanges: HashMap<ExchangeId, ExchangeAccount>,
+}
+
+// Tracks the position built up by fills on a single exchange using the average cost method. Buying
+// while long (or selling while short) blends the fill price into the average cost, whereas fills
+// which reduce the position realise the difference between the fill price and the average cost.
+#[derive(Serialize, Deserialize, Default)]
+struct ExchangeAccount {
+    fills: u32,
+    base_bought: u128,
+    base_sold: u128,
+    quote_spent: u128,
+    quote_received: u128,
+    position: i128,
+    average_cost: u64,
+    realised: i128,
+    latest_price: u64,
+    history: VecDeque<ProfitAndLossSnapshot>,
+}
+
+impl ProfitAndLoss {
+    pub fn record_fill(&mut self, exchange_id: ExchangeId, fill: &Fill) {
+        self.exchanges.entry(exchange_id).or_default().record_fill(fill);
+    }
+
+    pub fn mark_price(&mut self, exchange_id: ExchangeId, price: u64, now: TimestampMillis) {
+        if price > 0 {
+            self.exchanges.entry(exchange_id).or_default().mark_price(price, now);
+        }
+    }
+
+    pub fn summaries(
+        &self,
+        exchange_id: Option<ExchangeId>,
+        history_since: Option<TimestampMillis>,
+    ) -> Vec<ExchangeProfitAndLoss> {
+        let mut summaries: Vec<_> = self
+            .exchanges
+            .iter()
+            .filter(|(id, _)| exchange_id.is_none() || exchange_id == Some(**id))
+            .map(|(id, account)| account.summary(*id, history_since))
+            .collect();
+
+        summaries.sort_unstable_by_key(|s| s.exchange_id);
+        summaries
+    }
+}
+
+impl ExchangeAccount {
+    fn record_fill(&mut self, fill: &Fill) {
+        let amount = fill.amount as i128;
+        let price = fill.price as i128;
+        let quote_amount = (amount * price / BASE_UNITS_PER_WHOLE as i128) as u128;
+
+        let signed_amount = match fill.order_type {
+            OrderType::Bid => {
+                self.base_bought += fill.amount as u128;
+                self.quote_spent += quote_amount;
+                amount
+            }
+            OrderType::Ask => {
+                self.base_sold += fill.amount as u128;
+                self.quote_received += quote_amount;
+                -amount
+            }
+        };
+        self.fills += 1;
+
+        let average_cost = self.average_cost as i128;
+        if self.position == 0 || self.position.signum() == signed_amount.signum() {
+            let new_position = self.position + signed_amount;
+            self.average_cost = ((self.position.abs() * average_cost + amount * price) / new_position.abs()) as u64;
+            self.position = new_position;
+        } else {
+            let closed = min(amount, self.position.abs());
+            let profit_per_whole = if self.position > 0 { price - average_cost } else { average_cost - price };
+            self.realised += closed * profit_per_whole / BASE_UNITS_PER_WHOLE as i128;
+            self.position += signed_amount;
+
+            if self.position == 0 {
+                self.average_cost = 0;
+            } else if self.position.signum() == signed_amount.signum() {
+                // The position has flipped, so the remainder was opened at the fill price
+                self.average_cost = fill.price;
+            }
+        }
+    }
+
+    fn mark_price(&mut self, price: u64, now: TimestampMillis) {
+        self.latest_price = price;
+
+        let next_snapshot_due = self
+            .history
+            .back()
+            .map(|s| s.timestamp + SNAPSHOT_INTERVAL)
+            .unwrap_or_default();
+
+        if now >= next_snapshot_due {
+            self.history.push_back(ProfitAndLossSnapshot {
+                timestamp: now,
+                base_inventory: self.base_inventory(),
+                quote_inventory: self.quote_inventory(),
+                price,
+                realised_pnl: self.realised,
+                unrealised_pnl: self.unrealised(),
+            });
+            if self.history.len() > MAX_SNAPSHOTS {
+                self.history.pop_front();
+            }
+        }
+    }
+
+    fn unrealised(&self) -> i128 {
+        if self.latest_price == 0 {
+            return 0;
+        }
+        self.position * (self.latest_price as i128 - self.average_cost as i128) / BASE_UNITS_PER_WHOLE as i128
+    }
+
+    fn base_inventory(&self) -> i128 {
+        self.base_bought as i128 - self.base_sold as i128
+    }
+
+    fn quote_inventory(&self) -> i128 {
+        self.quote_received as i128 - self.quote_spent as i128
+    }
+
+    fn summary(&self, exchange_id: ExchangeId, history_since: Option<TimestampMillis>) -> ExchangeProfitAndLoss {
+        ExchangeProfitAndLoss {
+            exchange_id,
+            fills: self.fills,
+            base_bought: self.base_bought,
+            base_sold: self.base_sold,
+            quote_spent: self.quote_spent,
+            quote_received: self.quote_received,
+            base_inventory: self.base_inventory(),
+            quote_inventory: self.quote_inventory(),
+            average_cost: self.average_cost,
+            latest_price: self.latest_price,
+            realised_pnl: self.realised,
+            unrealised_pnl: self.unrealised(),
+            history: history_since
+                .map(|since| self.history.iter().filter(|s| s.timestamp >= since).cloned().collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHOLE: u64 = BASE_UNITS_PER_WHOLE as u64;
+
+    fn fill(order_type: OrderType, price: u64, wholes: u64) -> Fill {
+        Fill {
+            order_type,
+            price,
+            amount: wholes * WHOLE,
+        }
+    }
+
+    #[test]
+    fn average_cost_and_realised_pnl() {
+        let mut account = ExchangeAccount::default();
+
+        account.record_fill(&fill(OrderType::Bid, 100, 1));
+        account.record_fill(&fill(OrderType::Bid, 200, 1));
+        assert_eq!(account.position, 2 * WHOLE as i128);
+        assert_eq!(account.average_cost, 150);
+
+        account.record_fill(&fill(OrderType::Ask, 300, 1));
+        assert_eq!(account.realised, 150);
+        assert_eq!(account.average_cost, 150);
+
+        account.mark_price(100, 0);
+        assert_eq!(account.unrealised(), -50);
+
+        // Closes the remaining long and opens a short at the fill price
+        account.record_fill(&fill(OrderType::Ask, 100, 2));
+        assert_eq!(account.realised, 100);
+        assert_eq!(account.position, -(WHOLE as i128));
+        assert_eq!(account.average_cost, 100);
+        assert_eq!(account.base_inventory(), -(WHOLE as i128));
+        assert_eq!(account.quote_inventory(), 200);
+    }
+}
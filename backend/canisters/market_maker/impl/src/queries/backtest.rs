@@ -0,0 +1,62 @@
+use crate::backtest::{replay, simulate};
+use crate::strategies::Inventory;
+use crate::{read_state, strategies, RuntimeState};
+use ic_cdk_macros::query;
+use market_maker_canister::backtest::{Response::*, *};
+
+#[query]
+fn backtest(args: Args) -> Response {
+    read_state(|state| backtest_impl(args, state))
+}
+
+fn backtest_impl(args: Args, state: &RuntimeState) -> Response {
+    let mut config = match state.data.exchange_config.get(&args.exchange_id) {
+        Some(c) => c.clone(),
+        None => return ExchangeNotFound,
+    };
+    if let Some(strategy) = args.strategy {
+        config.strategy = strategy;
+    }
+
+    if args.from > args.to {
+        return InvalidPeriod;
+    }
+
+    let max_snapshots = args.max_snapshots.map_or(MAX_SNAPSHOTS, |m| m.min(MAX_SNAPSHOTS));
+    let snapshots = state
+        .data
+        .market_snapshots
+        .iter_from(args.from)
+        .take_while(|s| s.timestamp <= args.to)
+        .filter(|s| s.exchange_id == args.exchange_id)
+        .take(max_snapshots as usize);
+
+    let strategy = strategies::build(&config.strategy);
+    let initial_inventory = Inventory {
+        base: args.initial_base_balance.unwrap_or_default(),
+        quote: args.initial_quote_balance.unwrap_or_default(),
+    };
+
+    let simulation = simulate(snapshots, strategy.as_ref(), &config, initial_inventory);
+    if simulation.snapshots == 0 {
+        return NoSnapshots;
+    }
+
+    // Only replay up to the last snapshot simulated, since the snapshot count may have been capped
+    let orders_log_entries = state
+        .data
+        .orders_log
+        .iter_from(simulation.from)
+        .take_while(|e| e.timestamp <= simulation.to)
+        .filter(|e| e.exchange_id == args.exchange_id);
+    let live = replay(orders_log_entries, simulation.latest_price);
+
+    Success(SuccessResult {
+        strategy: config.strategy,
+        from: simulation.from,
+        to: simulation.to,
+        snapshots: simulation.snapshots,
+        simulated: simulation.result,
+        live,
+    })
+}
@@ -1,21 +1,23 @@
 use crate::{read_state, RuntimeState};
-use http_request::{build_json_response, build_response, encode_logs, extract_route, Route};
+use http_request::{
+    build_json_response, build_metrics_response, build_response, encode_logs, extract_route, LogsQuery, MetricsFormat, Route,
+};
 use ic_cdk_macros::query;
 use std::io::Write;
-use types::{HttpRequest, HttpResponse, TimestampMillis};
+use types::{HttpRequest, HttpResponse};
 
 #[query]
 fn http_request(request: HttpRequest) -> HttpResponse {
-    fn get_logs_impl(since: Option<TimestampMillis>) -> HttpResponse {
-        encode_logs(canister_logger::export_logs(), since.unwrap_or(0))
+    fn get_logs_impl(query: LogsQuery) -> HttpResponse {
+        encode_logs(canister_logger::export_logs(), query)
     }
 
-    fn get_traces_impl(since: Option<TimestampMillis>) -> HttpResponse {
-        encode_logs(canister_logger::export_traces(), since.unwrap_or(0))
+    fn get_traces_impl(query: LogsQuery) -> HttpResponse {
+        encode_logs(canister_logger::export_traces(), query)
     }
 
-    fn get_metrics_impl(state: &RuntimeState) -> HttpResponse {
-        build_json_response(&state.metrics())
+    fn get_metrics_impl(format: MetricsFormat, state: &RuntimeState) -> HttpResponse {
+        build_metrics_response(&state.metrics(), format)
     }
 
     fn get_order_logs(state: &RuntimeState) -> HttpResponse {
@@ -30,11 +32,16 @@ fn http_request(request: HttpRequest) -> HttpResponse {
         build_response(body, "text/plain")
     }
 
+    fn get_profit_and_loss(state: &RuntimeState) -> HttpResponse {
+        build_json_response(&state.data.profit_and_loss.summaries(None, Some(0)))
+    }
+
     match extract_route(&request.url) {
-        Route::Logs(since) => get_logs_impl(since),
-        Route::Traces(since) => get_traces_impl(since),
-        Route::Metrics => read_state(get_metrics_impl),
+        Route::Logs(query) => get_logs_impl(query),
+        Route::Traces(query) => get_traces_impl(query),
+        Route::Metrics(format) => read_state(|state| get_metrics_impl(format, state)),
         Route::Other(p, _) if p == "orders" => read_state(get_order_logs),
+        Route::Other(p, _) if p == "pnl" => read_state(get_profit_and_loss),
         _ => HttpResponse::not_found(),
     }
 }
@@ -1 +1,3 @@
+mod backtest;
 mod http_request;
+mod profit_and_loss;
@@ -0,0 +1,14 @@
+use crate::{read_state, RuntimeState};
+use ic_cdk_macros::query;
+use market_maker_canister::profit_and_loss::*;
+
+#[query]
+fn profit_and_loss(args: Args) -> Response {
+    read_state(|state| profit_and_loss_impl(args, state))
+}
+
+fn profit_and_loss_impl(args: Args, state: &RuntimeState) -> Response {
+    Response {
+        exchanges: state.data.profit_and_loss.summaries(args.exchange_id, args.history_since),
+    }
+}
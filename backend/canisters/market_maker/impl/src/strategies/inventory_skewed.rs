@@ -0,0 +1,97 @@
+use crate::strategies::{ladder, Inventory, OrderPlan, Strategy, StrategyInput, BASE_UNITS_PER_WHOLE};
+use market_maker_canister::InventorySkewedConfig;
+use std::cmp::{max, min};
+
+// Runs the ladder strategy but shifts all prices down when holding more of the base token than
+// the target, and up when holding less, so that trades tend to bring the inventory back into
+// balance.
+pub struct InventorySkewed {
+    config: InventorySkewedConfig,
+}
+
+impl InventorySkewed {
+    pub fn new(config: InventorySkewedConfig) -> InventorySkewed {
+        InventorySkewed { config }
+    }
+
+    // Positive when holding too much of the base token, negative when holding too little
+    fn skew_in_increments(&self, inventory: Inventory, price: u64) -> i64 {
+        let base_value = inventory.base * price as u128 / BASE_UNITS_PER_WHOLE;
+        let total_value = base_value + inventory.quote;
+        if total_value == 0 {
+            return 0;
+        }
+
+        let base_percent = (base_value * 100 / total_value) as i128;
+        let target_percent = min(self.config.target_base_percent, 100) as i128;
+        let deviation = base_percent - target_percent;
+        let headroom = if deviation > 0 { 100 - target_percent } else { target_percent };
+        if headroom == 0 {
+            return 0;
+        }
+
+        (deviation * self.config.max_skew_increments as i128 / headroom) as i64
+    }
+}
+
+impl Strategy for InventorySkewed {
+    fn plan(&self, input: &StrategyInput) -> OrderPlan {
+        let config = input.config;
+        let (mut max_bid_price, mut min_ask_price) = ladder::price_limits(input, config);
+
+        if let Some(inventory) = input.inventory {
+            let mid_price = (input.current_bid + input.current_ask) / 2;
+            let skew = self.skew_in_increments(inventory, mid_price);
+            let shift = skew.unsigned_abs() * config.price_increment;
+
+            // Never shift so far that our orders would cross the current best bid or ask
+            if skew > 0 {
+                max_bid_price = max_bid_price.saturating_sub(shift);
+                min_ask_price = max(
+                    min_ask_price.saturating_sub(shift),
+                    max(input.current_bid + config.price_increment, config.min_sell_price),
+                );
+            } else if skew < 0 {
+                min_ask_price = min_ask_price.saturating_add(shift);
+                max_bid_price = min(
+                    max_bid_price.saturating_add(shift),
+                    min(input.current_ask.saturating_sub(config.price_increment), config.max_buy_price),
+                );
+            }
+        }
+
+        ladder::plan_for_price_limits(input, max_bid_price, min_ask_price, config)
+    }
+
+    fn requires_inventory(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(50, 100, 0, 10)]
+    #[test_case(50, 100, 100, 0)]
+    #[test_case(50, 0, 100, -10)]
+    #[test_case(50, 0, 0, 0)]
+    #[test_case(50, 300, 100, 5)]
+    #[test_case(25, 100, 300, 0)]
+    #[test_case(0, 100, 100, 5)]
+    fn skew_in_increments_tests(target_base_percent: u8, base_whole: u128, quote_whole: u128, expected: i64) {
+        let strategy = InventorySkewed::new(InventorySkewedConfig {
+            target_base_percent,
+            max_skew_increments: 10,
+        });
+
+        // Price of 1 whole quote token per whole base token
+        let inventory = Inventory {
+            base: base_whole * BASE_UNITS_PER_WHOLE,
+            quote: quote_whole * BASE_UNITS_PER_WHOLE,
+        };
+
+        assert_eq!(strategy.skew_in_increments(inventory, BASE_UNITS_PER_WHOLE as u64), expected);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Config;
+use market_maker_canister::StrategyConfig;
+use types::{AggregatedOrders, CancelOrderRequest, MakeOrderRequest, Order};
+
+pub mod inventory_skewed;
+pub mod ladder;
+pub mod volatility_adjusted;
+
+// Prices are quoted in units of the quote token per whole base token, and the base token (CHAT)
+// has 8 decimals
+pub const BASE_UNITS_PER_WHOLE: u128 = 100_000_000;
+
+pub trait Strategy {
+    fn plan(&self, input: &StrategyInput) -> OrderPlan;
+
+    fn requires_inventory(&self) -> bool {
+        false
+    }
+
+    fn price_history_required(&self) -> usize {
+        0
+    }
+}
+
+pub struct StrategyInput<'a> {
+    pub current_bid: u64,
+    pub current_ask: u64,
+    pub my_open_orders: &'a [Order],
+    pub my_previous_open_orders: Option<&'a AggregatedOrders>,
+    pub inventory: Option<Inventory>,
+    // Oldest first
+    pub recent_prices: &'a [u64],
+    pub config: &'a Config,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Inventory {
+    pub base: u128,
+    pub quote: u128,
+}
+
+#[derive(Default)]
+pub struct OrderPlan {
+    pub orders_to_make: Vec<MakeOrderRequest>,
+    pub orders_to_cancel: Vec<CancelOrderRequest>,
+}
+
+pub fn build(config: &StrategyConfig) -> Box<dyn Strategy> {
+    match config {
+        StrategyConfig::Ladder => Box::new(ladder::Ladder),
+        StrategyConfig::InventorySkewed(c) => Box::new(inventory_skewed::InventorySkewed::new(c.clone())),
+        StrategyConfig::VolatilityAdjusted(c) => Box::new(volatility_adjusted::VolatilityAdjusted::new(c.clone())),
+    }
+}
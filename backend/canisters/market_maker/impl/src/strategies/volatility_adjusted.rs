@@ -0,0 +1,67 @@
+use crate::strategies::{ladder, OrderPlan, Strategy, StrategyInput};
+use market_maker_canister::VolatilityAdjustedConfig;
+use std::cmp::min;
+
+// Runs the ladder strategy but widens the spread in proportion to how far the price has moved
+// over the recent window, so that the bot quotes more conservatively while the market is volatile.
+pub struct VolatilityAdjusted {
+    config: VolatilityAdjustedConfig,
+}
+
+impl VolatilityAdjusted {
+    pub fn new(config: VolatilityAdjustedConfig) -> VolatilityAdjusted {
+        VolatilityAdjusted { config }
+    }
+
+    fn extra_spread(&self, recent_prices: &[u64], price_increment: u64) -> u64 {
+        let window = recent_prices.iter().rev().take(self.config.window as usize).copied();
+
+        let (min_price, max_price) = window.fold((u64::MAX, 0), |(lo, hi), p| (lo.min(p), hi.max(p)));
+        if max_price <= min_price || price_increment == 0 {
+            return 0;
+        }
+
+        let range_in_increments = (max_price - min_price) / price_increment;
+
+        min(
+            range_in_increments * self.config.multiplier_percent as u64 / 100,
+            self.config.max_extra_spread,
+        )
+    }
+}
+
+impl Strategy for VolatilityAdjusted {
+    fn plan(&self, input: &StrategyInput) -> OrderPlan {
+        let mut config = input.config.clone();
+        config.spread += self.extra_spread(input.recent_prices, config.price_increment);
+
+        let (max_bid_price, min_ask_price) = ladder::price_limits(input, &config);
+
+        ladder::plan_for_price_limits(input, max_bid_price, min_ask_price, &config)
+    }
+
+    fn price_history_required(&self) -> usize {
+        self.config.window as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&[], 0)]
+    #[test_case(&[100, 100, 100], 0)]
+    #[test_case(&[100, 140, 120], 2)]
+    #[test_case(&[0, 100, 140, 120], 2; "only the window is considered")]
+    #[test_case(&[100, 300, 200], 5)]
+    fn extra_spread_tests(recent_prices: &[u64], expected: u64) {
+        let strategy = VolatilityAdjusted::new(VolatilityAdjustedConfig {
+            window: 3,
+            multiplier_percent: 50,
+            max_extra_spread: 5,
+        });
+
+        assert_eq!(strategy.extra_spread(recent_prices, 10), expected);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{mutate_state, read_state, RuntimeState};
+use crate::{mutate_state, read_state, Config, RuntimeState};
 use canister_tracing_macros::trace;
 use ic_cdk_macros::update;
 use market_maker_canister::update_config::{Response::*, *};
@@ -19,6 +19,11 @@ async fn update_config(args: Args) -> Response {
 }
 
 fn update_config_impl(args: Args, state: &mut RuntimeState) -> Response {
+    // Supported exchanges start off disabled and only run once a price increment has been set
+    if !state.data.exchange_config.contains_key(&args.exchange_id) && state.get_exchange_client(args.exchange_id).is_some() {
+        state.data.exchange_config.insert(args.exchange_id, Config::default());
+    }
+
     if let Some(config) = state.data.exchange_config.get_mut(&args.exchange_id) {
         update_if_some(args.enabled, &mut config.enabled);
         update_if_some(args.price_increment, &mut config.price_increment);
@@ -37,6 +42,7 @@ fn update_config_impl(args: Args, state: &mut RuntimeState) -> Response {
             args.max_orders_to_cancel_per_iteration,
             &mut config.max_orders_to_cancel_per_iteration,
         );
+        update_if_some(args.strategy, &mut config.strategy);
         Success
     } else {
         ExchangeNotFound
@@ -1,5 +1,6 @@
 use candid::Principal;
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use k256::pkcs8::EncodePublicKey;
 use k256::PublicKey;
 use nns_governance_canister::types::Neuron;
@@ -124,6 +125,29 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge(
+                "neuron_controller_governance_principals",
+                "Principals allowed to make governance calls",
+                self.governance_principals.len(),
+            )
+            .gauge(
+                "neuron_controller_neurons",
+                "Neurons controlled by the canister",
+                self.neurons.len(),
+            );
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CanisterIds {
     pub nns_governance_canister_id: CanisterId,
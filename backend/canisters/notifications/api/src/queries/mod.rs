@@ -1,3 +1,4 @@
 pub mod latest_notification_index;
+pub mod link_preview_requests;
 pub mod notification_candid_check;
 pub mod notifications;
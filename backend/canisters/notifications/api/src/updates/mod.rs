@@ -1,3 +1,6 @@
+pub mod c2c_push_link_preview_request;
 pub mod c2c_push_notification;
 pub mod c2c_sync_index;
+pub mod remove_link_preview_requests;
 pub mod remove_notifications;
+pub mod set_link_previews;
@@ -0,0 +1,17 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::LinkPreview;
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub request_index: u64,
+    pub link_previews: Vec<LinkPreview>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success,
+    RequestNotFound,
+    MessageNotFound,
+    InternalError(String),
+}
@@ -4,5 +4,6 @@ use notifications_canister::*;
 // Queries
 
 // Updates
+generate_c2c_call!(c2c_push_link_preview_request);
 generate_c2c_call!(c2c_push_notification);
 generate_c2c_call!(c2c_sync_index);
@@ -3,7 +3,10 @@ use notifications_canister::*;
 
 // Queries
 generate_query_call!(latest_notification_index);
+generate_query_call!(link_preview_requests);
 generate_query_call!(notifications);
 
 // Updates
+generate_update_call!(remove_link_preview_requests);
 generate_update_call!(remove_notifications);
+generate_update_call!(set_link_previews);
@@ -2,10 +2,11 @@ use crate::model::authorized_principals::AuthorizedPrincipals;
 use crate::model::subscriptions::Subscriptions;
 use candid::Principal;
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::HashSet;
-use types::{BuildVersion, CanisterId, Cycles, NotificationEnvelope, TimestampMillis, Timestamped};
+use types::{BuildVersion, CanisterId, Cycles, LinkPreviewRequest, NotificationEnvelope, TimestampMillis, Timestamped};
 use utils::env::Environment;
 use utils::event_stream::EventStream;
 
@@ -50,6 +51,7 @@ impl RuntimeState {
             queued_notifications: self.data.notifications.len() as u32,
             latest_notification_index: self.data.notifications.latest_event_index(),
             subscriptions: self.data.subscriptions.total(),
+            queued_link_preview_requests: self.data.link_preview_requests.len() as u32,
             push_service_principals: self.data.push_service_principals.iter().copied().collect(),
             principals_authorized: self.data.authorized_principals.count_authorized() as u64,
             principals_blocked: self.data.authorized_principals.count_blocked() as u64,
@@ -70,6 +72,8 @@ struct Data {
     pub notifications: EventStream<NotificationEnvelope>,
     pub subscriptions: Subscriptions,
     #[serde(default)]
+    pub link_preview_requests: EventStream<LinkPreviewRequest>,
+    #[serde(default)]
     pub rng_seed: [u8; 32],
     pub test_mode: bool,
 }
@@ -89,6 +93,7 @@ impl Data {
             cycles_dispenser_canister_id,
             notifications: EventStream::default(),
             subscriptions: Subscriptions::default(),
+            link_preview_requests: EventStream::default(),
             rng_seed: [0; 32],
             test_mode,
         }
@@ -105,12 +110,61 @@ pub struct Metrics {
     pub queued_notifications: u32,
     pub latest_notification_index: u64,
     pub subscriptions: u64,
+    pub queued_link_preview_requests: u32,
     pub push_service_principals: Vec<Principal>,
     pub principals_authorized: u64,
     pub principals_blocked: u64,
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge(
+                "notifications_queued",
+                "Notifications waiting to be pushed",
+                self.queued_notifications,
+            )
+            .counter(
+                "notifications_pushed",
+                "Notifications pushed since the canister was created",
+                self.latest_notification_index,
+            )
+            .gauge(
+                "notifications_subscriptions",
+                "Push notification subscriptions",
+                self.subscriptions,
+            )
+            .gauge(
+                "notifications_queued_link_preview_requests",
+                "Link preview requests waiting to be processed",
+                self.queued_link_preview_requests,
+            )
+            .gauge(
+                "notifications_push_service_principals",
+                "Principals allowed to pull notifications",
+                self.push_service_principals.len(),
+            )
+            .gauge(
+                "notifications_principals_authorized",
+                "Principals authorized to push notifications",
+                self.principals_authorized,
+            )
+            .gauge(
+                "notifications_principals_blocked",
+                "Principals blocked from pushing notifications",
+                self.principals_blocked,
+            );
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CanisterIds {
     pub notifications_index: CanisterId,
@@ -0,0 +1,21 @@
+use crate::guards::caller_is_push_service;
+use crate::{read_state, RuntimeState};
+use ic_cdk_macros::query;
+use notifications_canister::link_preview_requests::{Response::*, *};
+
+const MAX_REQUESTS_PER_BATCH: u32 = 100;
+
+#[query(guard = "caller_is_push_service")]
+fn link_preview_requests(args: Args) -> Response {
+    read_state(|state| link_preview_requests_impl(args, state))
+}
+
+fn link_preview_requests_impl(args: Args, state: &RuntimeState) -> Response {
+    Success(SuccessResult {
+        requests: state
+            .data
+            .link_preview_requests
+            .get(args.from_request_index, MAX_REQUESTS_PER_BATCH),
+        timestamp: state.env.now(),
+    })
+}
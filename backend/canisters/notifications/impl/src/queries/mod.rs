@@ -1,3 +1,4 @@
 mod http_request;
 mod latest_notification_index;
+mod link_preview_requests;
 mod notifications;
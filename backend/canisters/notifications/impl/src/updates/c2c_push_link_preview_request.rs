@@ -0,0 +1,30 @@
+use crate::updates::c2c_push_notification::is_caller_authorized;
+use crate::{mutate_state, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use notifications_canister::c2c_push_link_preview_request::{Response::*, *};
+use types::LinkPreviewRequest;
+
+#[update_msgpack]
+#[trace]
+async fn c2c_push_link_preview_request(args: Args) -> Response {
+    match is_caller_authorized(args.authorizer).await {
+        Ok(true) => mutate_state(|state| c2c_push_link_preview_request_impl(args, state)),
+        Ok(false) => Blocked,
+        Err(error) => InternalError(error),
+    }
+}
+
+fn c2c_push_link_preview_request_impl(args: Args, state: &mut RuntimeState) -> Response {
+    if !args.urls.is_empty() {
+        state.data.link_preview_requests.add(LinkPreviewRequest {
+            canister_id: state.env.caller(),
+            chat: args.chat,
+            thread_root_message_index: args.thread_root_message_index,
+            message_id: args.message_id,
+            urls: args.urls,
+            timestamp: state.env.now(),
+        });
+    }
+    Success
+}
@@ -9,23 +9,25 @@ use types::{CanPushNotificationsArgs, CanPushNotificationsResponse, CanisterId,
 #[update_msgpack]
 #[trace]
 async fn c2c_push_notification(args: Args) -> Response {
-    match read_state(|state| can_push_notifications(&args, state)) {
-        CanPushNotificationsResult::Blocked => return Blocked,
+    match is_caller_authorized(args.authorizer).await {
+        Ok(true) => mutate_state(|state| c2c_push_notification_impl(args.recipients, args.notification_bytes, state)),
+        Ok(false) => Blocked,
+        Err(error) => InternalError(error),
+    }
+}
+
+// Returns whether the caller is allowed to push notifications (and link preview requests). If the
+// caller hasn't been seen before, this is checked with the authorizer and the outcome cached.
+pub(crate) async fn is_caller_authorized(authorizer: Option<CanisterId>) -> Result<bool, String> {
+    match read_state(|state| can_push_notifications(authorizer, state)) {
+        CanPushNotificationsResult::Authorized => Ok(true),
+        CanPushNotificationsResult::Blocked => Ok(false),
         CanPushNotificationsResult::Unknown(caller, authorizer) => {
-            match check_if_caller_is_authorized(caller, authorizer).await {
-                Ok(authorized) => {
-                    mutate_state(|state| state.data.authorized_principals.add_principal(caller, authorized));
-                    if !authorized {
-                        return Blocked;
-                    }
-                }
-                Err(response) => return response,
-            }
+            let authorized = check_if_caller_is_authorized(caller, authorizer).await?;
+            mutate_state(|state| state.data.authorized_principals.add_principal(caller, authorized));
+            Ok(authorized)
         }
-        _ => {}
     }
-
-    mutate_state(|state| c2c_push_notification_impl(args.recipients, args.notification_bytes, state))
 }
 
 enum CanPushNotificationsResult {
@@ -34,13 +36,13 @@ enum CanPushNotificationsResult {
     Unknown(Principal, CanisterId), // (Caller, Authorizer)
 }
 
-fn can_push_notifications(args: &Args, state: &RuntimeState) -> CanPushNotificationsResult {
+fn can_push_notifications(authorizer: Option<CanisterId>, state: &RuntimeState) -> CanPushNotificationsResult {
     let caller = state.env.caller();
     if let Some(authorized) = state.data.authorized_principals.can_push_notifications(&caller) {
         if authorized {
             return CanPushNotificationsResult::Authorized;
         }
-    } else if let Some(authorizer) = args.authorizer {
+    } else if let Some(authorizer) = authorizer {
         if state.data.authorized_principals.is_authorizer(&authorizer) {
             return CanPushNotificationsResult::Unknown(caller, authorizer);
         }
@@ -64,12 +66,12 @@ fn c2c_push_notification_impl(recipients: Vec<UserId>, notification_bytes: ByteB
     Success
 }
 
-async fn check_if_caller_is_authorized(caller: Principal, authorizer: CanisterId) -> Result<bool, Response> {
+async fn check_if_caller_is_authorized(caller: Principal, authorizer: CanisterId) -> Result<bool, String> {
     let args = CanPushNotificationsArgs { principal: caller };
 
     match c2c_can_push_notifications(authorizer, &args).await {
         Ok(CanPushNotificationsResponse::Success(authorized)) => Ok(authorized),
-        Err(error) => Err(InternalError(format!("{error:?}"))),
+        Err(error) => Err(format!("{error:?}")),
     }
 }
 
@@ -1,4 +1,7 @@
+mod c2c_push_link_preview_request;
 mod c2c_push_notification;
 mod c2c_sync_index;
+mod remove_link_preview_requests;
 mod remove_notifications;
+mod set_link_previews;
 mod wallet_receive;
@@ -0,0 +1,16 @@
+use crate::guards::caller_is_push_service;
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_cdk_macros::update;
+use notifications_canister::remove_link_preview_requests::{Response::*, *};
+
+#[update(guard = "caller_is_push_service")]
+#[trace]
+fn remove_link_preview_requests(args: Args) -> Response {
+    mutate_state(|state| remove_link_preview_requests_impl(args, state))
+}
+
+fn remove_link_preview_requests_impl(args: Args, state: &mut RuntimeState) -> Response {
+    state.data.link_preview_requests.remove(args.up_to_request_index);
+    Success
+}
@@ -0,0 +1,47 @@
+use crate::guards::caller_is_push_service;
+use crate::read_state;
+use canister_tracing_macros::trace;
+use ic_cdk_macros::update;
+use notifications_canister::set_link_previews::{Response::*, *};
+use types::{SetLinkPreviewsArgs, SetLinkPreviewsResponse};
+
+// Called by the link preview fetcher once it has built the previews for a request. These are then
+// passed on to the canister holding the message, which only accepts them from its notifications
+// canister.
+#[update(guard = "caller_is_push_service")]
+#[trace]
+async fn set_link_previews(args: Args) -> Response {
+    let request = match read_state(|state| {
+        state
+            .data
+            .link_preview_requests
+            .get(args.request_index, 1)
+            .into_iter()
+            .find(|r| r.index == args.request_index)
+    }) {
+        Some(request) => request.value,
+        None => return RequestNotFound,
+    };
+
+    let c2c_args = SetLinkPreviewsArgs {
+        chat: request.chat,
+        thread_root_message_index: request.thread_root_message_index,
+        message_id: request.message_id,
+        link_previews: args.link_previews,
+    };
+
+    match c2c_set_link_previews(request.canister_id, &c2c_args).await {
+        Ok(SetLinkPreviewsResponse::Success) => Success,
+        Ok(SetLinkPreviewsResponse::MessageNotFound) => MessageNotFound,
+        Err(error) => InternalError(format!("{error:?}")),
+    }
+}
+
+mod c2c_set_link_previews {
+    use types::{SetLinkPreviewsArgs, SetLinkPreviewsResponse};
+
+    pub type Args = SetLinkPreviewsArgs;
+    pub type Response = SetLinkPreviewsResponse;
+}
+
+canister_client::generate_c2c_call!(c2c_set_link_previews);
@@ -5,3 +5,4 @@ pub mod remove_subscription;
 pub mod remove_subscriptions;
 pub mod remove_subscriptions_for_user;
 pub mod upgrade_notifications_canister_wasm;
+pub mod upload_wasm_chunk;
@@ -8,3 +8,4 @@ generate_update_call!(add_notifications_canister);
 generate_update_call!(push_subscription);
 generate_update_call!(remove_subscriptions);
 generate_update_call!(upgrade_notifications_canister_wasm);
+generate_update_call!(upload_wasm_chunk);
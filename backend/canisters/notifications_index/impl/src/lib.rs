@@ -2,12 +2,13 @@ use crate::model::notifications_canister::NotificationsCanister;
 use crate::model::subscriptions::Subscriptions;
 use candid::Principal;
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use notifications_index_canister::{NotificationsIndexEvent, SubscriptionAdded, SubscriptionRemoved};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use types::{BuildVersion, CanisterId, CanisterWasm, Cycles, SubscriptionInfo, TimestampMillis, Timestamped, UserId};
-use utils::canister::CanistersRequiringUpgrade;
+use utils::canister::{CanistersRequiringUpgrade, ChunkedWasms};
 use utils::canister_event_sync_queue::CanisterEventSyncQueue;
 use utils::env::Environment;
 
@@ -119,6 +120,8 @@ struct Data {
     pub notifications_canister_wasm_for_new_canisters: CanisterWasm,
     pub notifications_canister_wasm_for_upgrades: CanisterWasm,
     pub canisters_requiring_upgrade: CanistersRequiringUpgrade,
+    #[serde(default)]
+    pub chunked_wasms: ChunkedWasms,
     pub notifications_index_event_sync_queue: CanisterEventSyncQueue<NotificationsIndexEvent>,
     #[serde(default)]
     pub rng_seed: [u8; 32],
@@ -145,6 +148,7 @@ impl Data {
             notifications_canister_wasm_for_new_canisters: notifications_canister_wasm.clone(),
             notifications_canister_wasm_for_upgrades: notifications_canister_wasm,
             canisters_requiring_upgrade: CanistersRequiringUpgrade::default(),
+            chunked_wasms: ChunkedWasms::default(),
             notifications_index_event_sync_queue: CanisterEventSyncQueue::default(),
             rng_seed: [0; 32],
             test_mode,
@@ -168,6 +172,39 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge(
+                "notifications_index_subscriptions",
+                "Push notification subscriptions",
+                self.subscriptions,
+            )
+            .gauge(
+                "notifications_index_users",
+                "Users with push notification subscriptions",
+                self.users,
+            )
+            .gauge(
+                "notifications_index_push_service_principals",
+                "Principals allowed to pull notifications",
+                self.push_service_principals.len(),
+            )
+            .gauge(
+                "notifications_index_notifications_canisters",
+                "Notifications canisters",
+                self.notifications_canisters.len(),
+            );
+    }
+}
+
 #[derive(Serialize, Debug)]
 pub struct CanisterIds {
     pub user_index: CanisterId,
@@ -5,4 +5,5 @@ mod remove_subscription;
 mod remove_subscriptions;
 mod remove_subscriptions_for_user;
 mod upgrade_notifications_canister_wasm;
+mod upload_wasm_chunk;
 mod wallet_receive;
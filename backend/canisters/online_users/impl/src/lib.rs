@@ -1,6 +1,7 @@
 use crate::model::last_online_dates::LastOnlineDates;
 use crate::model::principal_to_user_id_map::PrincipalToUserIdMap;
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use types::{BuildVersion, CanisterId, Cycles, TimestampMillis, Timestamped};
@@ -86,6 +87,57 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const ACTIVE_USERS: &str = "online_users_active";
+        const ACTIVE_USERS_HELP: &str = "Users who have been online within each period";
+
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .counter(
+                "online_users_mark_as_online",
+                "Calls to mark_as_online",
+                self.mark_as_online_count,
+            )
+            .gauge_with_labels(
+                ACTIVE_USERS,
+                ACTIVE_USERS_HELP,
+                &[("period", "5m")],
+                self.active_users.last_5_minutes,
+            )
+            .gauge_with_labels(
+                ACTIVE_USERS,
+                ACTIVE_USERS_HELP,
+                &[("period", "1h")],
+                self.active_users.last_hour,
+            )
+            .gauge_with_labels(
+                ACTIVE_USERS,
+                ACTIVE_USERS_HELP,
+                &[("period", "1d")],
+                self.active_users.last_day,
+            )
+            .gauge_with_labels(
+                ACTIVE_USERS,
+                ACTIVE_USERS_HELP,
+                &[("period", "7d")],
+                self.active_users.last_7_days,
+            )
+            .gauge_with_labels(
+                ACTIVE_USERS,
+                ACTIVE_USERS_HELP,
+                &[("period", "30d")],
+                self.active_users.last_30_days,
+            );
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct ActiveUsers {
     timestamp: TimestampMillis,
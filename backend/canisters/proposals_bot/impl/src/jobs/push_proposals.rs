@@ -67,6 +67,7 @@ async fn push_group_proposal(governance_canister_id: CanisterId, group_id: ChatI
         forwarding: false,
         rules_accepted: None,
         correlation_id: 0,
+        forwarded_from: None,
     };
 
     let response = group_canister_c2c_client::send_message_v2(group_id.into(), &send_message_args).await;
@@ -97,6 +98,8 @@ async fn push_channel_proposal(
         channel_id,
         community_rules_accepted: None,
         channel_rules_accepted: None,
+        forum_topic: None,
+        forwarded_from: None,
     };
 
     let response = community_canister_c2c_client::send_message(community_id.into(), &send_message_args).await;
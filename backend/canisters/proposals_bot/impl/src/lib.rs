@@ -4,6 +4,7 @@ use candid::{CandidType, Principal};
 use canister_state_macros::canister_state;
 use canister_timer_jobs::TimerJobs;
 use fire_and_forget_handler::FireAndForgetHandler;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::cell::RefCell;
@@ -126,6 +127,54 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const PROPOSALS: &str = "proposals_bot_proposals";
+        const PROPOSALS_HELP: &str = "Proposals being tracked, by governance canister and status";
+
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge(
+                "proposals_bot_nervous_systems",
+                "Nervous systems whose proposals are pushed",
+                self.nervous_systems.len(),
+            )
+            .gauge(
+                "proposals_bot_finished_proposals_to_process",
+                "Finished proposals waiting to be processed",
+                self.finished_proposals_to_process.len(),
+            )
+            .gauge(
+                "proposals_bot_registry_synced_up_to_ms",
+                "The latest registry update which has been synced",
+                self.registry_synced_up_to,
+            );
+
+        for ns in self.nervous_systems.iter() {
+            let governance_canister_id = ns.governance_canister_id.to_string();
+            registry
+                .gauge_with_labels(
+                    PROPOSALS,
+                    PROPOSALS_HELP,
+                    &[("governance_canister_id", &governance_canister_id), ("status", "queued")],
+                    ns.queued_proposals.len(),
+                )
+                .gauge_with_labels(
+                    PROPOSALS,
+                    PROPOSALS_HELP,
+                    &[("governance_canister_id", &governance_canister_id), ("status", "active")],
+                    ns.active_proposals.len(),
+                );
+        }
+    }
+}
+
 #[derive(CandidType, Serialize, Debug)]
 pub struct NervousSystemMetrics {
     pub governance_canister_id: CanisterId,
@@ -2,6 +2,7 @@ use crate::model::nervous_systems::{NervousSystemMetrics, NervousSystems};
 use crate::model::tokens::{TokenMetrics, Tokens};
 use candid::Principal;
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use registry_canister::NervousSystemDetails;
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
@@ -157,6 +158,35 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge(
+                "registry_governance_principals",
+                "Principals allowed to make governance calls",
+                self.governance_principals.len(),
+            )
+            .gauge("registry_tokens", "Tokens in the registry", self.tokens.len())
+            .gauge(
+                "registry_nervous_systems",
+                "Nervous systems in the registry",
+                self.nervous_systems.len(),
+            )
+            .gauge(
+                "registry_failed_sns_launches",
+                "SNS launches which failed",
+                self.failed_sns_launches.len(),
+            );
+    }
+}
+
 #[derive(Serialize)]
 pub struct CanisterIds {
     pub proposals_bot: CanisterId,
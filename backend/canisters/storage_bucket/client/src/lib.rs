@@ -0,0 +1,7 @@
+use canister_client::generate_update_call;
+use storage_bucket_canister::*;
+
+// Queries
+
+// Updates
+generate_update_call!(upload_chunk_v2);
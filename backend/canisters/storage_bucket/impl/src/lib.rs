@@ -3,6 +3,7 @@ use crate::model::index_sync_state::{EventToSync, IndexSyncState};
 use crate::model::users::Users;
 use candid::{CandidType, Principal};
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use types::{BuildVersion, CanisterId, Cycles, FileId, TimestampMillis, Timestamped};
@@ -121,6 +122,32 @@ pub struct Metrics {
     pub freezing_limit: Cycles,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge("storage_bucket_users", "Users with files in this bucket", self.user_count)
+            .gauge("storage_bucket_files", "Files stored in this bucket", self.file_count)
+            .gauge("storage_bucket_blobs", "Blobs stored in this bucket", self.blob_count)
+            .gauge(
+                "storage_bucket_index_sync_queue_length",
+                "Events waiting to be synced to the storage index",
+                self.index_sync_queue_length,
+            )
+            .gauge(
+                "storage_bucket_freezing_limit_cycles",
+                "The bucket's freezing limit",
+                self.freezing_limit,
+            );
+    }
+}
+
 pub fn calc_chunk_count(chunk_size: u32, total_size: u64) -> u32 {
     (((total_size - 1) / (chunk_size as u64)) + 1) as u32
 }
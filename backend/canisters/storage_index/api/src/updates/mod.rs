@@ -7,3 +7,4 @@ pub mod remove_user;
 pub mod set_bucket_full;
 pub mod update_user_id;
 pub mod upgrade_bucket_canister_wasm;
+pub mod upload_wasm_chunk;
@@ -1,7 +1,9 @@
-use canister_client::generate_update_call;
+use canister_client::{generate_query_call, generate_update_call};
 use storage_index_canister::*;
 
 // Queries
+generate_query_call!(allocated_bucket_v2);
 
 // Updates
 generate_update_call!(upgrade_bucket_canister_wasm);
+generate_update_call!(upload_wasm_chunk);
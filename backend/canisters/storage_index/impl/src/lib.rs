@@ -3,6 +3,7 @@ use crate::model::buckets::{BucketRecord, Buckets};
 use crate::model::files::Files;
 use candid::{CandidType, Principal};
 use canister_state_macros::canister_state;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
@@ -11,7 +12,7 @@ use types::{
     BuildVersion, CanisterId, CanisterWasm, Cycles, CyclesTopUp, FileAdded, FileRejected, FileRejectedReason, FileRemoved,
     TimestampMillis, Timestamped,
 };
-use utils::canister::{CanistersRequiringUpgrade, FailedUpgradeCount};
+use utils::canister::{CanistersRequiringUpgrade, ChunkedWasms, FailedUpgradeCount};
 use utils::env::Environment;
 
 mod guards;
@@ -94,6 +95,8 @@ struct Data {
     pub files: Files,
     pub buckets: Buckets,
     pub canisters_requiring_upgrade: CanistersRequiringUpgrade,
+    #[serde(default)]
+    pub chunked_wasms: ChunkedWasms,
     pub total_cycles_spent_on_canisters: Cycles,
     pub cycles_dispenser_config: CyclesDispenserConfig,
     #[serde(default)]
@@ -117,6 +120,7 @@ impl Data {
             files: Files::default(),
             buckets: Buckets::default(),
             canisters_requiring_upgrade: CanistersRequiringUpgrade::default(),
+            chunked_wasms: ChunkedWasms::default(),
             total_cycles_spent_on_canisters: 0,
             cycles_dispenser_config,
             rng_seed: [0; 32],
@@ -226,6 +230,62 @@ pub struct Metrics {
     pub cycles_dispenser_config: CyclesDispenserConfig,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const BUCKETS: &str = "storage_index_buckets";
+        const BUCKETS_HELP: &str = "Storage buckets, by whether they are accepting new files";
+
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge("storage_index_users", "Users allowed to upload files", self.user_count)
+            .gauge("storage_index_blobs", "Blobs stored across all buckets", self.blob_count)
+            .gauge(
+                "storage_index_blob_bytes",
+                "Bytes of blobs stored across all buckets",
+                self.total_blob_bytes,
+            )
+            .gauge("storage_index_files", "Files stored across all buckets", self.file_count)
+            .gauge(
+                "storage_index_file_bytes",
+                "Bytes of files stored across all buckets",
+                self.total_file_bytes,
+            )
+            .gauge_with_labels(BUCKETS, BUCKETS_HELP, &[("status", "active")], self.active_buckets.len())
+            .gauge_with_labels(BUCKETS, BUCKETS_HELP, &[("status", "full")], self.full_buckets.len())
+            .gauge(
+                "storage_index_bucket_upgrades_pending",
+                "Bucket upgrades waiting to start",
+                self.bucket_upgrades_pending,
+            )
+            .gauge(
+                "storage_index_bucket_upgrades_in_progress",
+                "Bucket upgrades in progress",
+                self.bucket_upgrades_in_progress,
+            )
+            .gauge(
+                "storage_index_bucket_upgrades_failed",
+                "Bucket upgrades which have failed",
+                self.bucket_upgrades_failed.iter().map(|f| f.count).sum::<usize>(),
+            );
+
+        for bucket in self.active_buckets.iter().chain(self.full_buckets.iter()) {
+            let canister_id = bucket.canister_id.to_string();
+            registry.gauge_with_labels(
+                "storage_index_bucket_bytes_used",
+                "Bytes used by each storage bucket",
+                &[("canister_id", &canister_id)],
+                bucket.bytes_used,
+            );
+        }
+    }
+}
+
 #[derive(CandidType, Serialize, Debug)]
 pub struct BucketMetrics {
     pub canister_id: CanisterId,
@@ -195,14 +195,20 @@ mod upgrade_canisters {
         if let Some(bucket) = state.data.buckets.get_mut(&canister_id) {
             bucket.wasm_version = to_version;
         }
-        state.data.canisters_requiring_upgrade.mark_success(&canister_id);
+        state
+            .data
+            .canisters_requiring_upgrade
+            .mark_success(&canister_id, state.env.now());
     }
 
     fn on_failure(canister_id: CanisterId, from_version: BuildVersion, to_version: BuildVersion, state: &mut RuntimeState) {
-        state.data.canisters_requiring_upgrade.mark_failure(FailedUpgrade {
-            canister_id,
-            from_version,
-            to_version,
-        });
+        state.data.canisters_requiring_upgrade.mark_failure(
+            FailedUpgrade {
+                canister_id,
+                from_version,
+                to_version,
+            },
+            state.env.now(),
+        );
     }
 }
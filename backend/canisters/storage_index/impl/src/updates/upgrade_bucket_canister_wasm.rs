@@ -10,7 +10,11 @@ fn upgrade_bucket_canister_wasm(args: Args) -> Response {
     mutate_state(|state| upgrade_bucket_canister_wasm_impl(args, state))
 }
 
-fn upgrade_bucket_canister_wasm_impl(args: Args, state: &mut RuntimeState) -> Response {
+fn upgrade_bucket_canister_wasm_impl(mut args: Args, state: &mut RuntimeState) -> Response {
+    if let Err(error) = state.data.chunked_wasms.resolve(&mut args) {
+        return error.into();
+    }
+
     let canisters_to_upgrade: Vec<_> = state
         .data
         .buckets
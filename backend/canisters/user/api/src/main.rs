@@ -10,6 +10,7 @@ fn main() {
     generate_candid_method!(user, events_window, query);
     generate_candid_method!(user, hot_group_exclusions, query);
     generate_candid_method!(user, initial_state, query);
+    generate_candid_method!(user, message_edit_history, query);
     generate_candid_method!(user, messages_by_message_index, query);
     generate_candid_method!(user, public_profile, query);
     generate_candid_method!(user, search_messages, query);
@@ -30,6 +31,8 @@ fn main() {
     generate_candid_method!(user, delete_group, update);
     generate_candid_method!(user, delete_messages, update);
     generate_candid_method!(user, edit_message_v2, update);
+    generate_candid_method!(user, export_chat, update);
+    generate_candid_method!(user, forward_message, update);
     generate_candid_method!(user, init_user_principal_migration, update);
     generate_candid_method!(user, leave_community, update);
     generate_candid_method!(user, leave_group, update);
@@ -6,6 +6,7 @@ pub mod events_by_index;
 pub mod events_window;
 pub mod hot_group_exclusions;
 pub mod initial_state;
+pub mod message_edit_history;
 pub mod messages_by_message_index;
 pub mod public_profile;
 pub mod saved_crypto_accounts;
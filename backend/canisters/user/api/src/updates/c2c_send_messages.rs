@@ -1,6 +1,6 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
-use types::{Chat, EventIndex, MessageContent, MessageId, MessageIndex};
+use types::{Chat, EventIndex, ForwardedFrom, MessageContent, MessageId, MessageIndex};
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct Args {
@@ -17,6 +17,8 @@ pub struct SendMessageArgs {
     pub content: MessageContent,
     pub replies_to: Option<C2CReplyContext>,
     pub forwarding: bool,
+    #[serde(default)]
+    pub forwarded_from: Option<ForwardedFrom>,
     pub correlation_id: u64,
 }
 
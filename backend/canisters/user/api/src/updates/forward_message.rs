@@ -0,0 +1,57 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use types::{Chat, EventIndex, MessageId, MessageIndex, TimestampMillis, Version};
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct Args {
+    pub chat: Chat,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: MessageIndex,
+    pub targets: Vec<ForwardTarget>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ForwardTarget {
+    pub chat: Chat,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub rules_accepted: Option<Version>,
+    pub community_rules_accepted: Option<Version>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum Response {
+    Success(SuccessResult),
+    ChatNotFound,
+    MessageNotFound,
+    NotForwardable,
+    NoTargets,
+    TooManyTargets(u32),
+    UserSuspended,
+    InternalError(String),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct SuccessResult {
+    pub results: Vec<ForwardTargetResult>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ForwardTargetResult {
+    pub chat: Chat,
+    pub outcome: ForwardOutcome,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum ForwardOutcome {
+    Success(ForwardedMessage),
+    Failed(String),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub struct ForwardedMessage {
+    pub event_index: EventIndex,
+    pub message_index: MessageIndex,
+    pub timestamp: TimestampMillis,
+    pub expires_at: Option<TimestampMillis>,
+}
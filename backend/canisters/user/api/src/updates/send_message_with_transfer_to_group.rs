@@ -1,8 +1,8 @@
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use types::{
-    ChatId, CompletedCryptoTransaction, Cryptocurrency, EventIndex, GroupReplyContext, MessageContentInitial, MessageId,
-    MessageIndex, TimestampMillis, User, Version,
+    ChatId, CompletedCryptoTransaction, ContentFilterReason, Cryptocurrency, EventIndex, GroupReplyContext,
+    MessageContentInitial, MessageId, MessageIndex, TimestampMillis, User, Version,
 };
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -33,6 +33,7 @@ pub enum Response {
     UserSuspended,
     ChatFrozen,
     RulesNotAccepted,
+    ContentRejected(ContentFilterReason),
     InternalError(String, CompletedCryptoTransaction),
 }
 
@@ -11,6 +11,7 @@ generate_c2c_call!(c2c_mark_read_v2);
 generate_c2c_call!(c2c_notify_community_deleted);
 generate_c2c_call!(c2c_notify_group_deleted);
 generate_c2c_call!(c2c_notify_events);
+generate_c2c_call!(c2c_notify_message_deleted_by_content_filter);
 generate_c2c_call!(c2c_remove_from_community);
 generate_c2c_call!(c2c_remove_from_group);
 generate_c2c_call!(c2c_revoke_super_admin);
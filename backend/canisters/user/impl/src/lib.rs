@@ -11,17 +11,18 @@ use candid::Principal;
 use canister_state_macros::canister_state;
 use canister_timer_jobs::TimerJobs;
 use fire_and_forget_handler::FireAndForgetHandler;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use model::contacts::Contacts;
 use model::favourite_chats::FavouriteChats;
-use notifications_canister::c2c_push_notification;
+use notifications_canister::{c2c_push_link_preview_request, c2c_push_notification};
 use serde::{Deserialize, Serialize};
 use serde_bytes::ByteBuf;
 use std::cell::RefCell;
 use std::collections::HashSet;
 use std::ops::Deref;
 use types::{
-    BuildVersion, CanisterId, Chat, ChatId, ChatMetrics, CommunityId, Cryptocurrency, Cycles, Document, Notification,
-    TimestampMillis, Timestamped, UserId,
+    BuildVersion, CanisterId, Chat, ChatId, ChatMetrics, CommunityId, Cryptocurrency, Cycles, Document, MessageContent,
+    MessageId, MessageIndex, Notification, TimestampMillis, Timestamped, UserId,
 };
 use user_canister::NamedAccount;
 use utils::env::Environment;
@@ -101,6 +102,32 @@ impl RuntimeState {
         }
     }
 
+    pub fn request_link_previews(
+        &self,
+        them: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        content: &MessageContent,
+    ) {
+        if let MessageContent::Text(text) = content {
+            let urls = link_previews::extract_urls(&text.text);
+            if !urls.is_empty() {
+                let args = c2c_push_link_preview_request::Args {
+                    authorizer: Some(self.data.local_user_index_canister_id),
+                    chat: Chat::Direct(them.into()),
+                    thread_root_message_index,
+                    message_id,
+                    urls,
+                };
+                ic_cdk::spawn(request_link_previews_inner(self.data.notifications_canister_id, args));
+            }
+        }
+
+        async fn request_link_previews_inner(canister_id: CanisterId, args: c2c_push_link_preview_request::Args) {
+            let _ = notifications_canister_c2c_client::c2c_push_link_preview_request(canister_id, &args).await;
+        }
+    }
+
     pub fn run_event_expiry_job(&mut self) {
         let now = self.env.now();
         let mut next_event_expiry = None;
@@ -284,6 +311,26 @@ pub struct Metrics {
     pub canister_ids: CanisterIds,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .gauge("user_direct_chats", "Direct chats the user is in", self.direct_chats)
+            .gauge("user_group_chats", "Group chats the user is in", self.group_chats)
+            .gauge("user_groups_created", "Groups created by the user", self.groups_created)
+            .gauge("user_blocked_users", "Users blocked by the user", self.blocked_users)
+            .gauge("user_created_ms", "When the user was created", self.created);
+
+        self.direct_chat_metrics.register_metrics(registry);
+    }
+}
+
 fn run_regular_jobs() {
     mutate_state(|state| state.regular_jobs.run(state.env.deref(), &mut state.data));
 }
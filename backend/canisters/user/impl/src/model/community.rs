@@ -73,6 +73,24 @@ impl Community {
         );
     }
 
+    pub fn import_channel(&mut self, channel_id: ChannelId, channel: Channel, now: TimestampMillis) {
+        self.channels.insert(
+            channel_id,
+            Channel {
+                channel_id,
+                imported: Some(now),
+                ..channel
+            },
+        );
+    }
+
+    pub fn remove_channel(&mut self, channel_id: &ChannelId, now: TimestampMillis) -> Option<Channel> {
+        self.unpin(channel_id, now);
+        let channel = self.channels.remove(channel_id)?;
+        self.last_changed_for_my_data = now;
+        Some(channel)
+    }
+
     pub fn to_summary(&self) -> user_canister::CommunitySummary {
         user_canister::CommunitySummary {
             community_id: self.community_id,
@@ -1,7 +1,9 @@
 use crate::updates::c2c_send_messages::{handle_message_impl, HandleMessageArgs};
 use crate::{RuntimeState, BASIC_GROUP_CREATION_LIMIT, PREMIUM_GROUP_CREATION_LIMIT};
 use ic_ledger_types::Tokens;
-use types::{ChannelId, CommunityId, EventWrapper, Message, MessageContent, SuspensionDuration, TextContent, UserId};
+use types::{
+    ChannelId, CommunityId, ContentFilterReason, EventWrapper, Message, MessageContent, SuspensionDuration, TextContent, UserId,
+};
 use user_canister::c2c_send_messages::C2CReplyContext;
 use user_canister::{PhoneNumberConfirmed, ReferredUserRegistered, StorageUpgraded, UserSuspended};
 use utils::consts::{OPENCHAT_BOT_USERNAME, OPENCHAT_BOT_USER_ID};
@@ -54,6 +56,29 @@ pub(crate) fn send_removed_from_group_or_community_message(
     send_text_message(text, false, state);
 }
 
+pub(crate) fn send_message_deleted_by_content_filter_message(
+    chat_name: String,
+    community_name: Option<String>,
+    reason: ContentFilterReason,
+    state: &mut RuntimeState,
+) {
+    let chat = match community_name {
+        Some(community_name) => format!("the \"{chat_name}\" channel of the \"{community_name}\" community"),
+        None => format!("the \"{chat_name}\" group"),
+    };
+    let reason = match reason {
+        ContentFilterReason::BlockedKeyword => "it contained a blocked word",
+        ContentFilterReason::BlockedPattern => "it matched a blocked pattern",
+        ContentFilterReason::BlockedLink => "it contained a link which isn't allowed",
+        ContentFilterReason::TooManyMentions => "it mentioned too many users",
+    };
+    let text = format!(
+        "Your message in {chat} was deleted because {reason}. Please check the chat's rules before sending any more messages."
+    );
+
+    send_text_message(text, false, state);
+}
+
 pub(crate) fn send_phone_number_confirmed_bot_message(event: &PhoneNumberConfirmed, state: &mut RuntimeState) {
     let storage_added = to_gb(event.storage_added);
     let new_group_limit = PREMIUM_GROUP_CREATION_LIMIT.to_string();
@@ -123,7 +148,10 @@ pub(crate) fn send_message(
 }
 
 pub(crate) fn send_text_message(text: String, mute_notification: bool, state: &mut RuntimeState) -> EventWrapper<Message> {
-    let content = MessageContent::Text(TextContent { text });
+    let content = MessageContent::Text(TextContent {
+        text,
+        link_previews: None,
+    });
     send_message(content, mute_notification, state)
 }
 
@@ -141,6 +169,7 @@ pub(crate) fn send_message_with_reply(
         content,
         replies_to,
         forwarding: false,
+        forwarded_from: None,
         correlation_id: 0,
         is_bot: true,
         sender_avatar_id: None,
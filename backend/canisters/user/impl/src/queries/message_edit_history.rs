@@ -0,0 +1,29 @@
+use crate::guards::caller_is_owner;
+use crate::{read_state, RuntimeState};
+use ic_cdk_macros::query;
+use types::EventIndex;
+use user_canister::message_edit_history::{Response::*, *};
+
+#[query(guard = "caller_is_owner")]
+fn message_edit_history(args: Args) -> Response {
+    read_state(|state| message_edit_history_impl(args, state))
+}
+
+fn message_edit_history_impl(args: Args, state: &RuntimeState) -> Response {
+    let my_user_id = state.env.canister_id().into();
+
+    if let Some(chat) = state.data.direct_chats.get(&args.user_id.into()) {
+        match chat.events.message_edit_history(
+            EventIndex::default(),
+            args.thread_root_message_index,
+            args.message_id,
+            Some(my_user_id),
+            state.env.now(),
+        ) {
+            Some(revisions) => Success(SuccessResult { revisions }),
+            None => MessageNotFound,
+        }
+    } else {
+        ChatNotFound
+    }
+}
@@ -10,6 +10,7 @@ pub mod events_window;
 pub mod hot_group_exclusions;
 pub mod http_request;
 pub mod initial_state;
+pub mod message_edit_history;
 pub mod messages_by_message_index;
 pub mod public_profile;
 pub mod saved_crypto_accounts;
@@ -96,12 +96,11 @@ impl Job for RetrySendingFailedMessagesJob {
 impl Job for HardDeleteMessageContentJob {
     fn execute(self) {
         mutate_state(|state| {
-            if let Some((content, _)) = state.data.direct_chats.get_mut(&self.chat_id).and_then(|chat| {
+            if let Some((_, _, files_to_delete)) = state.data.direct_chats.get_mut(&self.chat_id).and_then(|chat| {
                 chat.events
                     .remove_deleted_message_content(self.thread_root_message_index, self.message_id)
             }) {
                 if self.delete_files {
-                    let files_to_delete = content.blob_references();
                     if !files_to_delete.is_empty() {
                         // If there was already a job queued up to delete these files, cancel it
                         state.data.timer_jobs.cancel_jobs(|job| {
@@ -15,7 +15,8 @@ use utils::consts::OPENCHAT_BOT_USER_ID;
 fn add_reaction(args: Args) -> Response {
     run_regular_jobs();
 
-    if args.reaction.is_valid() {
+    // Custom emoji belong to a community's packs so can't be used in direct chats
+    if args.reaction.is_valid() && !args.reaction.is_custom_emoji() {
         mutate_state(|state| add_reaction_impl(args, state))
     } else {
         InvalidReaction
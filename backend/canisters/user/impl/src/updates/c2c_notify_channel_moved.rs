@@ -0,0 +1,81 @@
+use crate::guards::caller_is_known_group_or_community_canister;
+use crate::timer_job_types::TimerJob;
+use crate::{mutate_state, run_regular_jobs, Data, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use types::{ChannelMovedTo, Chat, CommunityId, TimestampMillis};
+use user_canister::c2c_notify_channel_moved::{Response::*, *};
+use user_canister::mark_read::ChannelMessagesRead;
+
+#[update_msgpack(guard = "caller_is_known_group_or_community_canister")]
+#[trace]
+fn c2c_notify_channel_moved(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_notify_channel_moved_impl(args, state))
+}
+
+fn c2c_notify_channel_moved_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let community_id: CommunityId = state.env.caller().into();
+    let now = state.env.now();
+
+    // Channels are only recorded within the user's communities once they have been read, so the
+    // user has the channel if they are in its community, and the community only notifies the
+    // channel's members
+    let channel = match state.data.communities.get_mut(&community_id) {
+        Some(community) => community.remove_channel(&args.channel_id, now),
+        None => return ChannelNotFound,
+    };
+
+    let previous_chat = Chat::Channel(community_id, args.channel_id);
+    let was_favourite = state.data.favourite_chats.remove(&previous_chat, now);
+
+    let new_chat = match args.moved_to {
+        ChannelMovedTo::Community(new_community_id, new_channel_id) => {
+            let (community, _) = state.data.communities.join(new_community_id, now);
+            if let Some(channel) = channel {
+                community.import_channel(new_channel_id, channel, now);
+            } else {
+                community.mark_read(
+                    vec![ChannelMessagesRead {
+                        channel_id: new_channel_id,
+                        read_up_to: args.latest_message_index,
+                        threads: Vec::new(),
+                        date_read_pinned: None,
+                    }],
+                    now,
+                );
+            }
+            Chat::Channel(new_community_id, new_channel_id)
+        }
+        ChannelMovedTo::Group(chat_id) => {
+            state.data.group_chats.join(chat_id, args.latest_message_index, now);
+            if let Some(channel) = channel {
+                if let Some(group) = state.data.group_chats.get_mut(&chat_id) {
+                    group.messages_read = channel.messages_read;
+                    group.archived = channel.archived;
+                }
+            }
+            Chat::Group(chat_id)
+        }
+    };
+
+    migrate_chat_references(previous_chat, new_chat, now, &mut state.data);
+
+    if was_favourite {
+        state.data.favourite_chats.add(new_chat, now);
+    }
+    Success
+}
+
+fn migrate_chat_references(previous_chat: Chat, new_chat: Chat, now: TimestampMillis, data: &mut Data) {
+    data.direct_chats.migrate_replies(previous_chat.into(), new_chat.into(), now);
+
+    for (_, job) in data.timer_jobs.jobs.values_mut() {
+        if let Some(TimerJob::MessageReminder(mr)) = job.borrow_mut().as_mut() {
+            if mr.chat == previous_chat {
+                mr.chat = new_chat;
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::guards::caller_is_known_group_or_community_canister;
+use crate::{mutate_state, openchat_bot, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use user_canister::c2c_notify_message_deleted_by_content_filter::{Response::*, *};
+
+#[update_msgpack(guard = "caller_is_known_group_or_community_canister")]
+#[trace]
+fn c2c_notify_message_deleted_by_content_filter(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_notify_message_deleted_by_content_filter_impl(args, state))
+}
+
+fn c2c_notify_message_deleted_by_content_filter_impl(args: Args, state: &mut RuntimeState) -> Response {
+    openchat_bot::send_message_deleted_by_content_filter_message(args.chat_name, args.community_name, args.reason, state);
+    Success
+}
@@ -6,8 +6,8 @@ use chat_events::{MessageContentInternal, PushMessageArgs, Reader, ReplyContextI
 use ic_cdk_macros::update;
 use rand::Rng;
 use types::{
-    CanisterId, DirectMessageNotification, EventWrapper, Message, MessageContent, MessageContentInitial, MessageId,
-    MessageIndex, Notification, TimestampMillis, UserId,
+    CanisterId, DirectMessageNotification, EventWrapper, ForwardedFrom, Message, MessageContent, MessageContentInitial,
+    MessageId, MessageIndex, Notification, TimestampMillis, UserId,
 };
 use user_canister::c2c_send_messages::{Response::*, *};
 
@@ -57,6 +57,7 @@ async fn c2c_send_messages_impl(args: Args) -> Response {
                     content: message.content,
                     replies_to: message.replies_to,
                     forwarding: message.forwarding,
+                    forwarded_from: message.forwarded_from,
                     correlation_id: message.correlation_id,
                     is_bot: false,
                     sender_avatar_id: args.sender_avatar_id,
@@ -108,6 +109,7 @@ async fn c2c_handle_bot_messages(
                     content: message.content.into(),
                     replies_to: None,
                     forwarding: false,
+                    forwarded_from: None,
                     correlation_id: 0,
                     is_bot: true,
                     sender_avatar_id: None,
@@ -129,6 +131,7 @@ pub(crate) struct HandleMessageArgs {
     pub content: MessageContent,
     pub replies_to: Option<C2CReplyContext>,
     pub forwarding: bool,
+    pub forwarded_from: Option<ForwardedFrom>,
     pub correlation_id: u64,
     pub is_bot: bool,
     pub sender_avatar_id: Option<u128>,
@@ -187,6 +190,7 @@ pub(crate) fn handle_message_impl(
         mentioned: Vec::new(),
         replies_to,
         forwarded: args.forwarding,
+        forwarded_from: args.forwarded_from,
         correlation_id: args.correlation_id,
         now: args.now,
     };
@@ -0,0 +1,59 @@
+use crate::{mutate_state, run_regular_jobs, RuntimeState};
+use canister_api_macros::update_msgpack;
+use canister_tracing_macros::trace;
+use msgpack::serialize_then_unwrap;
+use types::{Chat, SetLinkPreviewsArgs, UserId};
+use user_canister::c2c_set_link_previews::{Response::*, *};
+use utils::consts::OPENCHAT_BOT_USER_ID;
+
+#[update_msgpack]
+#[trace]
+fn c2c_set_link_previews(args: Args) -> Response {
+    run_regular_jobs();
+
+    mutate_state(|state| c2c_set_link_previews_impl(args, state))
+}
+
+// The previews for messages sent by this user come from the notifications canister, whereas those
+// for messages sent by the other user are forwarded on by the other user's canister
+fn c2c_set_link_previews_impl(args: Args, state: &mut RuntimeState) -> Response {
+    let chat_id = match args.chat {
+        Chat::Direct(chat_id) => chat_id,
+        _ => return MessageNotFound,
+    };
+
+    let caller = state.env.caller();
+    let my_user_id: UserId = state.env.canister_id().into();
+    let sender: UserId = if caller == state.data.notifications_canister_id {
+        my_user_id
+    } else if caller == chat_id.into() {
+        caller.into()
+    } else {
+        return MessageNotFound;
+    };
+
+    let now = state.env.now();
+    if let Some(chat) = state.data.direct_chats.get_mut(&chat_id) {
+        if chat.events.set_link_previews(
+            args.thread_root_message_index,
+            args.message_id,
+            Some(sender),
+            args.link_previews.clone(),
+            now,
+        ) {
+            let them: UserId = chat_id.into();
+            if sender == my_user_id && them != my_user_id && them != OPENCHAT_BOT_USER_ID {
+                state.data.fire_and_forget_handler.send(
+                    them.into(),
+                    "c2c_set_link_previews_msgpack".to_string(),
+                    serialize_then_unwrap(SetLinkPreviewsArgs {
+                        chat: Chat::Direct(my_user_id.into()),
+                        ..args
+                    }),
+                );
+            }
+            return Success;
+        }
+    }
+    MessageNotFound
+}
@@ -0,0 +1,72 @@
+use crate::guards::caller_is_owner;
+use crate::{read_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use chat_export::{build_export, ChatExport, ExportedChat, ExportedChatKind};
+use ic_cdk_macros::update;
+use types::{CanisterId, EventIndex};
+use user_canister::export_chat::{Response::*, *};
+use user_index_canister::users_v2::UserGroup;
+
+const MAX_MESSAGES_PER_PAGE: usize = 500;
+
+#[update(guard = "caller_is_owner")]
+#[trace]
+async fn export_chat(args: Args) -> Response {
+    run_regular_jobs();
+
+    let (mut export, user_index_canister_id) = match read_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    let mut user_ids = export.user_ids();
+    user_ids.insert(args.user_id);
+
+    let users_args = user_index_canister::users_v2::Args {
+        user_groups: vec![UserGroup {
+            users: user_ids.into_iter().collect(),
+            updated_since: 0,
+        }],
+    };
+    // If the usernames can't be resolved the export still succeeds, with users identified by their ids
+    if let Ok(user_index_canister::users_v2::Response::Success(result)) =
+        user_index_canister_c2c_client::users_v2(user_index_canister_id, &users_args).await
+    {
+        for user in result.users {
+            if user.user_id == args.user_id {
+                export.chat.name = format!("@{}", user.username);
+            }
+            export.set_user_details(user.user_id, user.username, user.display_name);
+        }
+    }
+
+    Success(SuccessResult {
+        data: chat_export::render(&export, args.format),
+        next_message_index: export.next_message_index,
+        next_thread_message_index: export.next_thread_message_index,
+    })
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<(ChatExport, CanisterId), Response> {
+    if let Some(chat) = state.data.direct_chats.get(&args.user_id.into()) {
+        let exported_chat = ExportedChat {
+            kind: ExportedChatKind::Direct,
+            id: args.user_id.to_string(),
+            name: args.user_id.to_string(),
+        };
+
+        let export = build_export(
+            &chat.events,
+            exported_chat,
+            EventIndex::default(),
+            args.from_message_index,
+            args.from_thread_message_index,
+            (args.max_messages as usize).min(MAX_MESSAGES_PER_PAGE),
+            state.env.now(),
+        );
+
+        Ok((export, state.data.user_index_canister_id))
+    } else {
+        Err(ChatNotFound)
+    }
+}
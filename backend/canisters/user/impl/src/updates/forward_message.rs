@@ -0,0 +1,218 @@
+use crate::guards::caller_is_owner;
+use crate::updates::send_message::send_forwarded_message;
+use crate::{mutate_state, read_state, run_regular_jobs, RuntimeState};
+use canister_tracing_macros::trace;
+use chat_events::Reader;
+use ic_cdk_macros::update;
+use types::{CanisterId, Chat, ForwardedFrom, MessageContent, MessageForForwarding, MessageIndex, UserId};
+use user_canister::forward_message::{Response::*, *};
+use user_canister::send_message_v2;
+
+const MAX_TARGETS: u32 = 10;
+
+#[update(guard = "caller_is_owner")]
+#[trace]
+async fn forward_message(args: Args) -> Response {
+    run_regular_jobs();
+
+    let (sender_name, sender_display_name) = match read_state(|state| prepare(&args, state)) {
+        Ok(ok) => ok,
+        Err(response) => return response,
+    };
+
+    let message = match get_message(args.chat, args.thread_root_message_index, args.message_index).await {
+        Ok(message) => message,
+        Err(response) => return response,
+    };
+
+    // Send the message to each of the targets concurrently
+    let futures: Vec<_> = args
+        .targets
+        .into_iter()
+        .map(|target| forward_to_target(target, message.clone(), sender_name.clone(), sender_display_name.clone()))
+        .collect();
+
+    let results = futures::future::join_all(futures).await;
+
+    Success(SuccessResult { results })
+}
+
+fn prepare(args: &Args, state: &RuntimeState) -> Result<(String, Option<String>), Response> {
+    if state.data.suspended.value {
+        Err(UserSuspended)
+    } else if args.targets.is_empty() {
+        Err(NoTargets)
+    } else if args.targets.len() > MAX_TARGETS as usize {
+        Err(TooManyTargets(MAX_TARGETS))
+    } else {
+        let is_member = match args.chat {
+            Chat::Direct(chat_id) => state.data.direct_chats.has(&chat_id),
+            Chat::Group(group_id) => state.data.group_chats.has(&group_id),
+            Chat::Channel(community_id, _) => state.data.communities.has(&community_id),
+        };
+
+        if is_member {
+            Ok((state.data.username.value.clone(), state.data.display_name.value.clone()))
+        } else {
+            Err(ChatNotFound)
+        }
+    }
+}
+
+async fn get_message(
+    chat: Chat,
+    thread_root_message_index: Option<MessageIndex>,
+    message_index: MessageIndex,
+) -> Result<MessageForForwarding, Response> {
+    match chat {
+        Chat::Direct(chat_id) => read_state(|state| {
+            let my_user_id: UserId = state.env.canister_id().into();
+            let message = state
+                .data
+                .direct_chats
+                .get(&chat_id)
+                .and_then(|c| {
+                    c.events
+                        .main_events_reader()
+                        .message_event(message_index.into(), Some(my_user_id))
+                })
+                .filter(|m| !matches!(m.event.content, MessageContent::Deleted(_)))
+                .ok_or(MessageNotFound)?;
+
+            // Direct chats are private so only the original sender is recorded
+            let forwarded_from = message.event.forwarded_from.unwrap_or(ForwardedFrom {
+                chat: None,
+                thread_root_message_index: None,
+                message_index: None,
+                sender: message.event.sender,
+                timestamp: message.timestamp,
+            });
+
+            message
+                .event
+                .content
+                .into_forwardable()
+                .map(|content| MessageForForwarding { content, forwarded_from })
+                .ok_or(NotForwardable)
+        }),
+        Chat::Group(group_id) => {
+            use group_canister::c2c_message_for_forwarding::{Args, Response};
+
+            let c2c_args = Args {
+                thread_root_message_index,
+                message_index,
+            };
+            match group_canister_c2c_client::c2c_message_for_forwarding(group_id.into(), &c2c_args).await {
+                Ok(Response::Success(message)) => Ok(message),
+                Ok(Response::CallerNotInGroup) => Err(ChatNotFound),
+                Ok(Response::MessageNotFound) => Err(MessageNotFound),
+                Ok(Response::NotForwardable) => Err(NotForwardable),
+                Err(error) => Err(InternalError(format!("{error:?}"))),
+            }
+        }
+        Chat::Channel(community_id, channel_id) => {
+            use community_canister::c2c_message_for_forwarding::{Args, Response};
+
+            let c2c_args = Args {
+                channel_id,
+                thread_root_message_index,
+                message_index,
+            };
+            match community_canister_c2c_client::c2c_message_for_forwarding(community_id.into(), &c2c_args).await {
+                Ok(Response::Success(message)) => Ok(message),
+                Ok(Response::UserNotInCommunity | Response::ChannelNotFound | Response::UserNotInChannel) => Err(ChatNotFound),
+                Ok(Response::MessageNotFound) => Err(MessageNotFound),
+                Ok(Response::NotForwardable) => Err(NotForwardable),
+                Err(error) => Err(InternalError(format!("{error:?}"))),
+            }
+        }
+    }
+}
+
+async fn forward_to_target(
+    target: ForwardTarget,
+    message: MessageForForwarding,
+    sender_name: String,
+    sender_display_name: Option<String>,
+) -> ForwardTargetResult {
+    let outcome = match target.chat {
+        Chat::Direct(chat_id) => {
+            let recipient: UserId = CanisterId::from(chat_id).into();
+            let send_message_args = send_message_v2::Args {
+                recipient,
+                thread_root_message_index: None,
+                message_id: target.message_id,
+                content: message.content,
+                replies_to: None,
+                forwarding: true,
+                correlation_id: 0,
+            };
+            match mutate_state(|state| send_forwarded_message(send_message_args, message.forwarded_from, state)) {
+                send_message_v2::Response::Success(result) => ForwardOutcome::Success(ForwardedMessage {
+                    event_index: result.event_index,
+                    message_index: result.message_index,
+                    timestamp: result.timestamp,
+                    expires_at: result.expires_at,
+                }),
+                response => ForwardOutcome::Failed(format!("{response:?}")),
+            }
+        }
+        Chat::Group(group_id) => {
+            let c2c_args = group_canister::send_message_v2::Args {
+                thread_root_message_index: target.thread_root_message_index,
+                message_id: target.message_id,
+                content: message.content,
+                sender_name,
+                sender_display_name,
+                replies_to: None,
+                mentioned: Vec::new(),
+                forwarding: true,
+                rules_accepted: target.rules_accepted,
+                correlation_id: 0,
+                forwarded_from: Some(message.forwarded_from),
+            };
+            match group_canister_c2c_client::send_message_v2(group_id.into(), &c2c_args).await {
+                Ok(group_canister::send_message_v2::Response::Success(result)) => ForwardOutcome::Success(ForwardedMessage {
+                    event_index: result.event_index,
+                    message_index: result.message_index,
+                    timestamp: result.timestamp,
+                    expires_at: result.expires_at,
+                }),
+                Ok(response) => ForwardOutcome::Failed(format!("{response:?}")),
+                Err(error) => ForwardOutcome::Failed(format!("{error:?}")),
+            }
+        }
+        Chat::Channel(community_id, channel_id) => {
+            let c2c_args = community_canister::send_message::Args {
+                channel_id,
+                thread_root_message_index: target.thread_root_message_index,
+                message_id: target.message_id,
+                content: message.content,
+                sender_name,
+                sender_display_name,
+                replies_to: None,
+                mentioned: Vec::new(),
+                forwarding: true,
+                community_rules_accepted: target.community_rules_accepted,
+                channel_rules_accepted: target.rules_accepted,
+                forum_topic: None,
+                forwarded_from: Some(message.forwarded_from),
+            };
+            match community_canister_c2c_client::send_message(community_id.into(), &c2c_args).await {
+                Ok(community_canister::send_message::Response::Success(result)) => ForwardOutcome::Success(ForwardedMessage {
+                    event_index: result.event_index,
+                    message_index: result.message_index,
+                    timestamp: result.timestamp,
+                    expires_at: result.expires_at,
+                }),
+                Ok(response) => ForwardOutcome::Failed(format!("{response:?}")),
+                Err(error) => ForwardOutcome::Failed(format!("{error:?}")),
+            }
+        }
+    };
+
+    ForwardTargetResult {
+        chat: target.chat,
+        outcome,
+    }
+}
@@ -10,13 +10,16 @@ pub mod c2c_grant_super_admin;
 pub mod c2c_mark_community_updated_for_user;
 pub mod c2c_mark_group_updated_for_user;
 pub mod c2c_mark_read_v2;
+pub mod c2c_notify_channel_moved;
 pub mod c2c_notify_community_deleted;
 pub mod c2c_notify_events;
 pub mod c2c_notify_group_deleted;
+pub mod c2c_notify_message_deleted_by_content_filter;
 pub mod c2c_remove_from_community;
 pub mod c2c_remove_from_group;
 pub mod c2c_revoke_super_admin;
 pub mod c2c_send_messages;
+pub mod c2c_set_link_previews;
 pub mod c2c_set_user_suspended;
 pub mod c2c_tip_message;
 pub mod c2c_toggle_reaction;
@@ -30,6 +33,8 @@ pub mod delete_direct_chat;
 pub mod delete_group;
 pub mod delete_messages;
 pub mod edit_message;
+pub mod export_chat;
+pub mod forward_message;
 pub mod init_user_principal_migration;
 pub mod leave_community;
 pub mod leave_group;
@@ -10,8 +10,8 @@ use ic_cdk_macros::update;
 use rand::Rng;
 use tracing::error;
 use types::{
-    BlobReference, CanisterId, CompletedCryptoTransaction, ContentValidationError, CryptoTransaction, EventWrapper, Message,
-    MessageContentInitial, MessageIndex, TimestampMillis, UserId,
+    BlobReference, CanisterId, CompletedCryptoTransaction, ContentValidationError, CryptoTransaction, EventWrapper,
+    ForwardedFrom, Message, MessageContentInitial, MessageIndex, TimestampMillis, UserId,
 };
 use user_canister::c2c_send_messages;
 use user_canister::c2c_send_messages::{C2CReplyContext, SendMessageArgs};
@@ -75,7 +75,17 @@ async fn send_message_v2(mut args: Args) -> Response {
         };
     }
 
-    mutate_state(|state| send_message_impl(args, completed_transfer, user_type, state))
+    mutate_state(|state| send_message_impl(args, completed_transfer, None, user_type, state))
+}
+
+// Used by `forward_message`, which has already looked up the message in the chat it is being
+// forwarded from. Messages can only be forwarded to existing direct chats.
+pub(crate) fn send_forwarded_message(args: Args, forwarded_from: ForwardedFrom, state: &mut RuntimeState) -> Response {
+    match validate_request(&args, state) {
+        ValidateRequestResult::Valid(_, user_type) => send_message_impl(args, None, Some(forwarded_from), user_type, state),
+        ValidateRequestResult::Invalid(response) => response,
+        ValidateRequestResult::RecipientUnknown(..) => RecipientNotFound,
+    }
 }
 
 enum UserType {
@@ -155,6 +165,7 @@ fn validate_request(args: &Args, state: &RuntimeState) -> ValidateRequestResult
 fn send_message_impl(
     args: Args,
     completed_transfer: Option<CompletedCryptoTransaction>,
+    forwarded_from: Option<ForwardedFrom>,
     user_type: UserType,
     state: &mut RuntimeState,
 ) -> Response {
@@ -170,6 +181,7 @@ fn send_message_impl(
         mentioned: Vec::new(),
         replies_to: args.replies_to.as_ref().map(|r| r.into()),
         forwarded: args.forwarding,
+        forwarded_from: forwarded_from.clone(),
         correlation_id: args.correlation_id,
         now,
     };
@@ -195,6 +207,8 @@ fn send_message_impl(
         &mut state.data.timer_jobs,
     );
 
+    state.request_link_previews(recipient, None, args.message_id, &message_event.event.content);
+
     if !user_type.is_self() {
         let send_message_args = SendMessageArgs {
             message_id: args.message_id,
@@ -218,6 +232,7 @@ fn send_message_impl(
                 }
             }),
             forwarding: args.forwarding,
+            forwarded_from,
             correlation_id: args.correlation_id,
         };
 
@@ -323,6 +338,7 @@ async fn send_to_bot_canister(recipient: UserId, message_index: MessageIndex, ar
                         mentioned: Vec::new(),
                         replies_to: None,
                         forwarded: false,
+                        forwarded_from: None,
                         correlation_id: 0,
                         now,
                     };
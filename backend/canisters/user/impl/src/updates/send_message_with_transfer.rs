@@ -2,8 +2,8 @@ use crate::crypto::process_transaction;
 use crate::guards::caller_is_owner;
 use crate::{read_state, run_regular_jobs, RuntimeState};
 use canister_tracing_macros::trace;
-use community_canister::send_message;
-use group_canister::send_message_v2;
+use community_canister::{c2c_check_content_filters as community_c2c_check_content_filters, send_message};
+use group_canister::{c2c_check_content_filters as group_c2c_check_content_filters, send_message_v2};
 use ic_cdk_macros::update;
 use types::{
     CompletedCryptoTransaction, CryptoContent, CryptoTransaction, MessageContentInitial, PendingCryptoTransaction,
@@ -38,6 +38,24 @@ async fn send_message_with_transfer_to_channel(
         PrepareResult::TransferCannotBeToSelf => return TransferCannotBeToSelf,
     };
 
+    // Check the content filters before making the transfer so that funds aren't sent with a message which
+    // is then rejected
+    let check_content_filters_args = community_c2c_check_content_filters::Args {
+        channel_id: args.channel_id,
+        text: args.content.text().map(|t| t.to_string()),
+        mention_count: args.mentioned.len() as u32,
+    };
+    match community_canister_c2c_client::c2c_check_content_filters(args.community_id.into(), &check_content_filters_args).await
+    {
+        Ok(community_c2c_check_content_filters::Response::Success) => {}
+        Ok(community_c2c_check_content_filters::Response::ContentRejected(reason)) => return ContentRejected(reason),
+        Ok(community_c2c_check_content_filters::Response::UserNotInCommunity) => return UserNotInCommunity(None),
+        Ok(community_c2c_check_content_filters::Response::ChannelNotFound) => {
+            return InvalidRequest("Channel not found".to_string())
+        }
+        Err(error) => return TransferFailed(format!("Unable to check content filters: {error:?}")),
+    }
+
     // Make the crypto transfer
     let completed_transaction = match process_transaction(pending_transaction).await {
         Ok(completed) => completed,
@@ -60,6 +78,8 @@ async fn send_message_with_transfer_to_channel(
         forwarding: false,
         community_rules_accepted: args.community_rules_accepted,
         channel_rules_accepted: args.channel_rules_accepted,
+        forum_topic: args.forum_topic,
+        forwarded_from: None,
     };
 
     // Send the message to the community
@@ -79,6 +99,13 @@ async fn send_message_with_transfer_to_channel(
             send_message::Response::CommunityFrozen => CommunityFrozen,
             send_message::Response::RulesNotAccepted => RulesNotAccepted,
             send_message::Response::CommunityRulesNotAccepted => CommunityRulesNotAccepted,
+            send_message::Response::ContentRejected(reason) => InternalError(
+                format!("Message rejected by content filter: {reason:?}"),
+                completed_transaction,
+            ),
+            send_message::Response::RateLimited(next_message_allowed) => {
+                InternalError(format!("Rate limited until {next_message_allowed}"), completed_transaction)
+            }
             send_message::Response::MessageEmpty
             | send_message::Response::InvalidPoll(_)
             | send_message::Response::NotAuthorized
@@ -116,6 +143,19 @@ async fn send_message_with_transfer_to_group(
         PrepareResult::TransferCannotBeToSelf => return TransferCannotBeToSelf,
     };
 
+    // Check the content filters before making the transfer so that funds aren't sent with a message which
+    // is then rejected
+    let check_content_filters_args = group_c2c_check_content_filters::Args {
+        text: args.content.text().map(|t| t.to_string()),
+        mention_count: args.mentioned.len() as u32,
+    };
+    match group_canister_c2c_client::c2c_check_content_filters(args.group_id.into(), &check_content_filters_args).await {
+        Ok(group_c2c_check_content_filters::Response::Success) => {}
+        Ok(group_c2c_check_content_filters::Response::ContentRejected(reason)) => return ContentRejected(reason),
+        Ok(group_c2c_check_content_filters::Response::CallerNotInGroup) => return CallerNotInGroup(None),
+        Err(error) => return TransferFailed(format!("Unable to check content filters: {error:?}")),
+    }
+
     // Make the crypto transfer
     let completed_transaction = match process_transaction(pending_transaction).await {
         Ok(completed) => completed,
@@ -137,6 +177,7 @@ async fn send_message_with_transfer_to_group(
         forwarding: false,
         rules_accepted: args.rules_accepted,
         correlation_id: args.correlation_id,
+        forwarded_from: None,
     };
 
     // Send the message to the group
@@ -153,6 +194,16 @@ async fn send_message_with_transfer_to_group(
             send_message_v2::Response::UserSuspended => UserSuspended,
             send_message_v2::Response::ChatFrozen => ChatFrozen,
             send_message_v2::Response::RulesNotAccepted => RulesNotAccepted,
+            send_message_v2::Response::ContentRejected(reason) => InternalError(
+                format!("Message rejected by content filter: {reason:?}"),
+                completed_transaction,
+            ),
+            send_message_v2::Response::RateLimited(next_message_allowed) => {
+                InternalError(format!("Rate limited until {next_message_allowed}"), completed_transaction)
+            }
+            send_message_v2::Response::UserMuted(muted_until) => {
+                InternalError(format!("User muted until {muted_until}"), completed_transaction)
+            }
             send_message_v2::Response::MessageEmpty
             | send_message_v2::Response::InvalidPoll(_)
             | send_message_v2::Response::NotAuthorized
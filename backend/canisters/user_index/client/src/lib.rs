@@ -20,3 +20,4 @@ generate_update_call!(remove_platform_operator);
 generate_update_call!(set_username);
 generate_update_call!(upgrade_local_user_index_canister_wasm);
 generate_update_call!(upgrade_user_canister_wasm);
+generate_update_call!(upload_wasm_chunk);
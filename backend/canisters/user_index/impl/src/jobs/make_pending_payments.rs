@@ -96,7 +96,7 @@ fn inform_referrer(pending_payment: &PendingPayment, block_index: BlockIndex, st
         amount_text = format!("[{}]({})", amount_text, link);
     }
 
-    let message = MessageContent::Text(TextContent { text: format!("You have received a referral reward of {}. This is because one of the users you referred has made a Diamond membership payment.", amount_text) });
+    let message = MessageContent::Text(TextContent { text: format!("You have received a referral reward of {}. This is because one of the users you referred has made a Diamond membership payment.", amount_text), link_previews: None });
 
     state.push_event_to_local_user_index(
         user_id,
@@ -69,7 +69,10 @@ fn try_get_next(state: &mut RuntimeState) -> GetNextResult {
     {
         Some(v) => v,
         None => {
-            state.data.canisters_requiring_upgrade.mark_skipped(&canister_id);
+            state
+                .data
+                .canisters_requiring_upgrade
+                .mark_skipped(&canister_id, state.env.now());
             return GetNextResult::Continue;
         }
     };
@@ -108,14 +111,20 @@ fn on_success(canister_id: CanisterId, to_version: BuildVersion, state: &mut Run
     if let Some(local_user_index) = state.data.local_index_map.get_mut(&canister_id) {
         local_user_index.set_wasm_version(to_version);
 
-        state.data.canisters_requiring_upgrade.mark_success(&canister_id);
+        state
+            .data
+            .canisters_requiring_upgrade
+            .mark_success(&canister_id, state.env.now());
     }
 }
 
 fn on_failure(canister_id: CanisterId, from_version: BuildVersion, to_version: BuildVersion, state: &mut RuntimeState) {
-    state.data.canisters_requiring_upgrade.mark_failure(FailedUpgrade {
-        canister_id,
-        from_version,
-        to_version,
-    });
+    state.data.canisters_requiring_upgrade.mark_failure(
+        FailedUpgrade {
+            canister_id,
+            from_version,
+            to_version,
+        },
+        state.env.now(),
+    );
 }
@@ -8,6 +8,7 @@ use candid::Principal;
 use canister_state_macros::canister_state;
 use canister_timer_jobs::TimerJobs;
 use fire_and_forget_handler::FireAndForgetHandler;
+use http_request::{MetricsRegistry, RegisterMetrics};
 use local_user_index_canister::Event as LocalUserIndexEvent;
 use model::local_user_index_map::LocalUserIndexMap;
 use model::pending_modclub_submissions_queue::{PendingModclubSubmission, PendingModclubSubmissionsQueue};
@@ -19,7 +20,7 @@ use std::collections::{HashMap, HashSet};
 use types::{
     BuildVersion, CanisterId, CanisterWasm, ChatId, Cryptocurrency, Cycles, Milliseconds, TimestampMillis, Timestamped, UserId,
 };
-use utils::canister::{CanistersRequiringUpgrade, FailedUpgradeCount};
+use utils::canister::{CanistersRequiringUpgrade, ChunkedWasms, FailedUpgradeCount};
 use utils::canister_event_sync_queue::CanisterEventSyncQueue;
 use utils::consts::DEV_TEAM_DFX_PRINCIPAL;
 use utils::env::Environment;
@@ -195,6 +196,8 @@ struct Data {
     pub notifications_index_canister_id: CanisterId,
     pub proposals_bot_canister_id: CanisterId,
     pub canisters_requiring_upgrade: CanistersRequiringUpgrade,
+    #[serde(default)]
+    pub chunked_wasms: ChunkedWasms,
     pub total_cycles_spent_on_canisters: Cycles,
     pub cycles_dispenser_canister_id: CanisterId,
     pub storage_index_canister_id: CanisterId,
@@ -245,6 +248,7 @@ impl Data {
             proposals_bot_canister_id,
             cycles_dispenser_canister_id,
             canisters_requiring_upgrade: CanistersRequiringUpgrade::default(),
+            chunked_wasms: ChunkedWasms::default(),
             total_cycles_spent_on_canisters: 0,
             storage_index_canister_id,
             storage_index_user_sync_queue: OpenStorageUserSyncQueue::default(),
@@ -296,6 +300,7 @@ impl Default for Data {
             notifications_index_canister_id: Principal::anonymous(),
             proposals_bot_canister_id: Principal::anonymous(),
             canisters_requiring_upgrade: CanistersRequiringUpgrade::default(),
+            chunked_wasms: ChunkedWasms::default(),
             cycles_dispenser_canister_id: Principal::anonymous(),
             total_cycles_spent_on_canisters: 0,
             storage_index_canister_id: Principal::anonymous(),
@@ -350,6 +355,104 @@ pub struct Metrics {
     pub reporting_metrics: ReportingMetrics,
 }
 
+impl RegisterMetrics for Metrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const DIAMOND_PAYMENTS: &str = "user_index_diamond_payments";
+        const DIAMOND_PAYMENTS_HELP: &str = "Diamond membership payments taken, by type";
+
+        registry
+            .canister(
+                self.now,
+                self.memory_used,
+                self.cycles_balance,
+                self.wasm_version,
+                &self.git_commit_id,
+            )
+            .counter(
+                "user_index_cycles_spent_on_canisters",
+                "Cycles spent creating and topping up canisters",
+                self.total_cycles_spent_on_canisters,
+            )
+            .counter("user_index_users_created", "Users created", self.users_created)
+            .gauge(
+                "user_index_diamond_members",
+                "Diamond members",
+                self.diamond_members.users.total,
+            )
+            .gauge(
+                "user_index_recurring_diamond_members",
+                "Diamond members whose membership renews automatically",
+                self.diamond_members.users.recurring,
+            )
+            .counter_with_labels(
+                DIAMOND_PAYMENTS,
+                DIAMOND_PAYMENTS_HELP,
+                &[("type", "manual")],
+                self.diamond_members.payments.manual_payments_taken,
+            )
+            .counter_with_labels(
+                DIAMOND_PAYMENTS,
+                DIAMOND_PAYMENTS_HELP,
+                &[("type", "recurring")],
+                self.diamond_members.payments.recurring_payments_taken,
+            )
+            .counter(
+                "user_index_diamond_payments_failed_insufficient_funds",
+                "Recurring diamond membership payments which failed due to insufficient funds",
+                self.diamond_members
+                    .payments
+                    .recurring_payments_failed_due_to_insufficient_funds,
+            )
+            .canister_upgrades(
+                "local_user_index",
+                self.canister_upgrades_completed,
+                self.canister_upgrades_pending,
+                self.canister_upgrades_in_progress,
+                self.canister_upgrades_failed.iter().map(|f| f.count).sum(),
+            )
+            .gauge(
+                "user_index_platform_moderators",
+                "Platform moderators",
+                self.platform_moderators,
+            )
+            .gauge("user_index_platform_operators", "Platform operators", self.platform_operators)
+            .gauge(
+                "user_index_events_queue_length",
+                "Events queued to be sent to local user indexes",
+                self.user_index_events_queue_length,
+            )
+            .gauge(
+                "user_index_local_user_indexes",
+                "Local user indexes",
+                self.local_user_indexes.len(),
+            )
+            .gauge(
+                "user_index_pending_modclub_submissions",
+                "Reported messages waiting to be submitted to Modclub",
+                self.pending_modclub_submissions,
+            )
+            .counter(
+                "user_index_messages_reported",
+                "Messages reported",
+                self.reporting_metrics.messages_reported,
+            )
+            .gauge(
+                "user_index_messages_pending_outcome",
+                "Reported messages awaiting an outcome",
+                self.reporting_metrics.messages_pending_outcome,
+            );
+
+        for (token, amount) in self.diamond_members.payments.amount_raised.iter() {
+            registry.counter_with_labels(
+                "user_index_diamond_amount_raised",
+                "Amount raised from diamond membership payments, by token",
+                &[("token", token.token_symbol())],
+                *amount,
+            );
+        }
+    }
+}
+
 #[derive(Serialize, Debug, Default)]
 pub struct DiamondMembershipMetrics {
     pub users: DiamondMembershipUserMetrics,
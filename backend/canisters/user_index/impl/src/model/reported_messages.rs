@@ -209,7 +209,10 @@ pub fn build_message_to_sender(reported_message: &ReportedMessage) -> LocalUserI
 fn build_oc_bot_message(text: String, user_id: UserId) -> LocalUserIndexEvent {
     LocalUserIndexEvent::OpenChatBotMessage(Box::new(OpenChatBotMessage {
         user_id,
-        message: MessageContent::Text(TextContent { text }),
+        message: MessageContent::Text(TextContent {
+            text,
+            link_previews: None,
+        }),
     }))
 }
 
@@ -119,6 +119,7 @@ If you would like to extend your Diamond membership you will need to top up your
                                         Tokens::from_e8s(price_e8s),
                                         Tokens::from_e8s(balance)
                                     ),
+                                    link_previews: None,
                                 }),
                             })),
                         );
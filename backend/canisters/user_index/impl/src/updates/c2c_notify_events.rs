@@ -141,7 +141,8 @@ fn process_new_user(
                 message: MessageContent::Text(TextContent {
                     text: format!("Unfortunately the username \"{original_username}\" was taken so your username has been changed to \"{username}\".
 
-You can change your username at any time by clicking \"Profile settings\" from the main menu.")
+You can change your username at any time by clicking \"Profile settings\" from the main menu."),
+                    link_previews: None,
                 }),
             })),
         );
@@ -186,13 +186,18 @@ https://github.com/open-chat-labs/open-chat/commit/e93865ea29b5bab8a9f0b01052938
             message_index: 27461.into(),
             message_id: 188960262885472233086330058967164649472.into(),
             sender: Principal::from_text("3skqk-iqaaa-aaaaf-aaa3q-cai").unwrap().into(),
-            content: types::MessageContent::Text(TextContent { text }),
+            content: types::MessageContent::Text(TextContent {
+                text,
+                link_previews: None,
+            }),
             replies_to: None,
             reactions: Vec::new(),
             tips: Tips::default(),
             thread_summary: None,
             edited: false,
             forwarded: false,
+            forwarded_from: None,
+            imported: None,
             last_updated: None,
         };
 
@@ -229,6 +234,8 @@ https://github.com/open-chat-labs/open-chat/commit/e93865ea29b5bab8a9f0b01052938
             thread_summary: None,
             edited: false,
             forwarded: false,
+            forwarded_from: None,
+            imported: None,
             last_updated: None,
         };
 
@@ -269,6 +276,8 @@ https://github.com/open-chat-labs/open-chat/commit/e93865ea29b5bab8a9f0b01052938
             thread_summary: None,
             edited: false,
             forwarded: false,
+            forwarded_from: None,
+            imported: None,
             last_updated: None,
         };
 
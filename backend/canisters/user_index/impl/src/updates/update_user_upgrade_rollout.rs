@@ -0,0 +1,23 @@
+use crate::guards::caller_is_platform_operator;
+use crate::{mutate_state, RuntimeState};
+use canister_tracing_macros::trace;
+use ic_cdk_macros::update;
+use local_user_index_canister::{Event, UserUpgradeRolloutUpdated};
+use tracing::info;
+use user_index_canister::update_user_upgrade_rollout::{Response::*, *};
+
+#[update(guard = "caller_is_platform_operator")]
+#[trace]
+async fn update_user_upgrade_rollout(args: Args) -> Response {
+    mutate_state(|state| update_user_upgrade_rollout_impl(args, state))
+}
+
+fn update_user_upgrade_rollout_impl(args: Args, state: &mut RuntimeState) -> Response {
+    state.push_event_to_all_local_user_indexes(
+        Event::UserUpgradeRolloutUpdated(UserUpgradeRolloutUpdated { action: args.action }),
+        None,
+    );
+
+    info!(action = ?args.action, "User upgrade rollout update requested");
+    Success
+}
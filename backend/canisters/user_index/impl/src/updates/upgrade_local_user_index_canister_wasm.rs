@@ -14,7 +14,11 @@ fn upgrade_local_user_index_canister_wasm(args: Args) -> Response {
     mutate_state(|state| upgrade_local_user_index_canister_wasm_impl(args, state))
 }
 
-fn upgrade_local_user_index_canister_wasm_impl(args: Args, state: &mut RuntimeState) -> Response {
+fn upgrade_local_user_index_canister_wasm_impl(mut args: Args, state: &mut RuntimeState) -> Response {
+    if let Err(error) = state.data.chunked_wasms.resolve(&mut args) {
+        return error.into();
+    }
+
     let version = args.wasm.version;
 
     if !state.data.test_mode && Some(version) <= min_canister_version(&state.data) {
@@ -1,23 +1,24 @@
 use crate::guards::caller_is_governance_principal;
-use crate::{mutate_state, read_state, RuntimeState};
+use crate::{mutate_state, RuntimeState};
 use canister_api_macros::proposal;
 use canister_tracing_macros::trace;
-use ic_cdk::api::call::CallResult;
+use local_user_index_canister::c2c_upgrade_user_canister_wasm::Response as C2cResponse;
 use tracing::info;
-use types::{CanisterId, CanisterWasm, UpgradeCanisterWasmArgs, UpgradesFilter};
+use types::{CanisterId, CanisterWasm, UpgradeCanisterWasmArgs, UpgradesFilter, WASM_CHUNK_SIZE};
 use user_index_canister::upgrade_user_canister_wasm::{Response::*, *};
-use utils::canister::build_filter_map;
+use utils::canister::{build_filter_map, upload_wasm_in_chunks};
 
 #[proposal(guard = "caller_is_governance_principal")]
 #[trace]
 async fn upgrade_user_canister_wasm(args: Args) -> Response {
     let version = args.wasm.version;
     let use_for_new_canisters = args.use_for_new_canisters.unwrap_or(true);
+    let rollout = args.rollout.clone();
 
     let PrepareResult {
         wasm,
         local_user_index_canisters,
-    } = match read_state(|state| prepare(args, state)) {
+    } = match mutate_state(|state| prepare(args, state)) {
         Ok(ok) => ok,
         Err(response) => return response,
     };
@@ -31,6 +32,8 @@ async fn upgrade_user_canister_wasm(args: Args) -> Response {
                     wasm: wasm.clone(),
                     filter: Some(filter),
                     use_for_new_canisters: Some(use_for_new_canisters),
+                    rollout: rollout.clone(),
+                    wasm_hash: None,
                 },
             )
         })
@@ -39,7 +42,7 @@ async fn upgrade_user_canister_wasm(args: Args) -> Response {
     let result = futures::future::join_all(futures).await;
 
     if let Some(first_error) = result.into_iter().filter_map(|res| res.err()).next() {
-        InternalError(format!("{first_error:?}"))
+        InternalError(first_error)
     } else {
         if use_for_new_canisters {
             mutate_state(|state| {
@@ -57,7 +60,9 @@ struct PrepareResult {
     local_user_index_canisters: Vec<(CanisterId, UpgradesFilter)>,
 }
 
-fn prepare(args: Args, state: &RuntimeState) -> Result<PrepareResult, Response> {
+fn prepare(mut args: Args, state: &mut RuntimeState) -> Result<PrepareResult, Response> {
+    state.data.chunked_wasms.resolve(&mut args)?;
+
     let local_user_index_canister_ids: Vec<_> = state.data.local_index_map.canisters().copied().collect();
 
     let local_user_index_canisters = build_filter_map(local_user_index_canister_ids, args.filter.unwrap_or_default(), |c| {
@@ -70,9 +75,24 @@ fn prepare(args: Args, state: &RuntimeState) -> Result<PrepareResult, Response>
     })
 }
 
+// Wasms too large to be sent in a single message are first uploaded to the local index in chunks
 async fn c2c_upgrade_user_canister_wasm(
     canister_id: CanisterId,
-    args: local_user_index_canister::c2c_upgrade_user_canister_wasm::Args,
-) -> CallResult<local_user_index_canister::c2c_upgrade_user_canister_wasm::Response> {
-    local_user_index_canister_c2c_client::c2c_upgrade_user_canister_wasm(canister_id, &args).await
+    mut args: local_user_index_canister::c2c_upgrade_user_canister_wasm::Args,
+) -> Result<(), String> {
+    if args.wasm.module.len() > WASM_CHUNK_SIZE {
+        let wasm_hash = upload_wasm_in_chunks(canister_id, &args.wasm.module, |canister_id, chunk| async move {
+            local_user_index_canister_c2c_client::c2c_upload_wasm_chunk(canister_id, &chunk).await
+        })
+        .await?;
+
+        args.wasm.module = Vec::new();
+        args.wasm_hash = Some(wasm_hash);
+    }
+
+    match local_user_index_canister_c2c_client::c2c_upgrade_user_canister_wasm(canister_id, &args).await {
+        Ok(C2cResponse::Success | C2cResponse::VersionNotHigher) => Ok(()),
+        Ok(response) => Err(format!("{response:?}")),
+        Err(error) => Err(format!("{error:?}")),
+    }
 }
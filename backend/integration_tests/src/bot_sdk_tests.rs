@@ -0,0 +1,92 @@
+use crate::rng::random_principal;
+use crate::wasms;
+use bot_sdk_test_harness::BotTestHarness;
+use candid::Principal;
+use types::{
+    icrc1, BotMessage, BuildVersion, CompletedCryptoTransaction, CryptoContent, CryptoTransaction, Cryptocurrency,
+    MessageContent, MessageContentInitial, UserId,
+};
+
+#[test]
+fn bot_only_accepts_ckbtc() {
+    let (mut harness, _) = install_satoshi_dice();
+    let user_id = random_principal().into();
+
+    let result = harness.send_direct_message(user_id, crypto_content(user_id, Cryptocurrency::CHAT, 1_000));
+
+    let texts = texts(result.messages);
+    assert_eq!(texts.len(), 1);
+    assert!(texts[0].contains("I only accept ckBTC"), "{texts:?}");
+}
+
+#[test]
+fn bot_state_survives_upgrade() {
+    let (mut harness, local_user_index_canister_id) = install_satoshi_dice();
+    let user_id: UserId = random_principal().into();
+
+    let response: satoshi_dice_canister::c2c_add_user::Response = harness.update_msgpack(
+        local_user_index_canister_id,
+        "c2c_add_user_msgpack",
+        &satoshi_dice_canister::c2c_add_user::Args { user_id },
+    );
+    assert!(matches!(response, satoshi_dice_canister::c2c_add_user::Response::Success));
+
+    harness.upgrade(
+        wasms::SATOSHI_DICE.module.clone(),
+        &satoshi_dice_canister::post_upgrade::Args {
+            wasm_version: BuildVersion::new(0, 0, 2),
+        },
+    );
+
+    // The user is only recognized if the bot's data was saved and restored across the upgrade
+    let result = harness.send_direct_message(user_id, crypto_content(user_id, Cryptocurrency::CKBTC, 1_000));
+
+    let texts = texts(result.messages);
+    assert!(texts[0].starts_with("Thanks for playing!"), "{texts:?}");
+}
+
+fn install_satoshi_dice() -> (BotTestHarness, Principal) {
+    let local_user_index_canister_id = random_principal();
+
+    let harness = BotTestHarness::new(
+        wasms::SATOSHI_DICE.module.clone(),
+        &satoshi_dice_canister::init::Args {
+            user_index_canister_id: random_principal(),
+            local_user_index_canister_id,
+            ck_btc_ledger_canister_id: Cryptocurrency::CKBTC.ledger_canister_id().unwrap(),
+            admins: Vec::new(),
+            wasm_version: BuildVersion::min(),
+            test_mode: true,
+        },
+    );
+
+    (harness, local_user_index_canister_id)
+}
+
+fn crypto_content(recipient: UserId, token: Cryptocurrency, amount: u128) -> MessageContent {
+    MessageContent::Crypto(CryptoContent {
+        recipient,
+        transfer: CryptoTransaction::Completed(CompletedCryptoTransaction::ICRC1(icrc1::CompletedCryptoTransaction {
+            ledger: token.ledger_canister_id().unwrap(),
+            fee: token.fee().unwrap(),
+            token,
+            amount,
+            from: icrc1::CryptoAccount::Mint,
+            to: icrc1::CryptoAccount::Mint,
+            memo: None,
+            created: 0,
+            block_index: 0,
+        })),
+        caption: None,
+    })
+}
+
+fn texts(messages: Vec<BotMessage>) -> Vec<String> {
+    messages
+        .into_iter()
+        .map(|m| match m.content {
+            MessageContentInitial::Text(t) => t.text,
+            content => panic!("Expected a text message: {content:?}"),
+        })
+        .collect()
+}
@@ -5,6 +5,7 @@ use community_canister::*;
 generate_query_call!(channel_summary);
 generate_query_call!(events);
 generate_query_call!(events_by_index);
+generate_query_call!(forum_topics);
 generate_query_call!(search_channel);
 generate_query_call!(selected_channel_initial);
 generate_query_call!(selected_initial);
@@ -33,6 +34,7 @@ generate_update_call!(unblock_user);
 generate_update_call!(undelete_messages);
 generate_update_call!(update_channel);
 generate_update_call!(update_community);
+generate_update_call!(update_forum_topic);
 generate_update_call!(update_user_group);
 
 pub mod happy_path {
@@ -107,7 +109,10 @@ pub mod happy_path {
                 channel_id,
                 thread_root_message_index,
                 message_id: message_id.unwrap_or_else(random_message_id),
-                content: MessageContentInitial::Text(TextContent { text: text.to_string() }),
+                content: MessageContentInitial::Text(TextContent {
+                    text: text.to_string(),
+                    link_previews: None,
+                }),
                 sender_name: sender.username(),
                 sender_display_name: None,
                 replies_to: None,
@@ -115,6 +120,8 @@ pub mod happy_path {
                 forwarding: false,
                 community_rules_accepted: None,
                 channel_rules_accepted: None,
+                forum_topic: None,
+                forwarded_from: None,
             },
         );
 
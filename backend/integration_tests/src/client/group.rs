@@ -20,11 +20,13 @@ generate_update_call!(convert_into_community);
 generate_update_call!(delete_messages);
 generate_update_call!(edit_message_v2);
 generate_update_call!(enable_invite_code);
+generate_update_call!(mute_member);
 generate_update_call!(pin_message_v2);
 generate_update_call!(register_poll_vote);
 generate_update_call!(remove_participant);
 generate_update_call!(remove_reaction);
 generate_update_call!(send_message_v2);
+generate_update_call!(set_content_filters);
 generate_update_call!(toggle_mute_notifications);
 generate_update_call!(unblock_user);
 generate_update_call!(undelete_messages);
@@ -56,7 +58,10 @@ pub mod happy_path {
             &group_canister::send_message_v2::Args {
                 thread_root_message_index,
                 message_id: message_id.unwrap_or_else(random_message_id),
-                content: MessageContentInitial::Text(TextContent { text: text.to_string() }),
+                content: MessageContentInitial::Text(TextContent {
+                    text: text.to_string(),
+                    link_previews: None,
+                }),
                 sender_name: sender.username(),
                 sender_display_name: None,
                 replies_to: None,
@@ -64,6 +69,7 @@ pub mod happy_path {
                 forwarding: false,
                 rules_accepted: None,
                 correlation_id: 0,
+                forwarded_from: None,
             },
         );
 
@@ -75,6 +75,8 @@ pub mod happy_path {
                 wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         );
 
@@ -98,6 +100,8 @@ pub mod happy_path {
                 wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         );
 
@@ -121,6 +125,8 @@ pub mod happy_path {
                 wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         );
 
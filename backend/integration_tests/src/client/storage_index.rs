@@ -90,6 +90,8 @@ pub mod happy_path {
                 wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         );
 
@@ -19,6 +19,7 @@ generate_update_call!(delete_direct_chat);
 generate_update_call!(delete_group);
 generate_update_call!(delete_messages);
 generate_update_call!(edit_message_v2);
+generate_update_call!(forward_message);
 generate_update_call!(leave_community);
 generate_update_call!(leave_group);
 generate_update_call!(mark_read);
@@ -57,7 +58,10 @@ pub mod happy_path {
                 recipient,
                 thread_root_message_index: None,
                 message_id: message_id.unwrap_or_else(random_message_id),
-                content: MessageContentInitial::Text(TextContent { text: text.to_string() }),
+                content: MessageContentInitial::Text(TextContent {
+                    text: text.to_string(),
+                    link_previews: None,
+                }),
                 replies_to: None,
                 forwarding: false,
                 correlation_id: 0,
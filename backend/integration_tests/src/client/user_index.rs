@@ -123,6 +123,8 @@ pub mod happy_path {
                 wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         );
 
@@ -146,6 +148,8 @@ pub mod happy_path {
                 wasm,
                 filter: None,
                 use_for_new_canisters: None,
+                rollout: None,
+                wasm_hash: None,
             },
         );
 
@@ -33,6 +33,9 @@ fn disappearing_messages_in_channel() {
             events_ttl: OptionUpdate::SetToSome(1000),
             gate: OptionUpdate::NoChange,
             public: None,
+            slow_mode: OptionUpdate::NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: None,
         },
     );
 
@@ -78,6 +81,9 @@ fn disappearing_messages_in_channel() {
             events_ttl: OptionUpdate::SetToNone,
             gate: OptionUpdate::NoChange,
             public: None,
+            slow_mode: OptionUpdate::NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: None,
         },
     );
 
@@ -0,0 +1,306 @@
+use crate::env::ENV;
+use crate::rng::{random_message_id, random_string};
+use crate::{client, CanisterIds, TestEnv, User};
+use candid::Principal;
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use std::time::Duration;
+use types::{
+    ChannelId, CommunityId, ForumSubtype, ForumTopicsSortOrder, GroupSubtype, MessageContentInitial, MessageIndex,
+    NewForumTopic, Rules, TextContent,
+};
+
+#[test]
+fn top_level_messages_must_start_topics() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData {
+        user1,
+        user2: _,
+        community_id,
+        channel_id,
+    } = init_test_data(env, canister_ids, *controller);
+
+    let response = send_topic_message(env, &user1, community_id, channel_id, None);
+    assert!(
+        matches!(response, community_canister::send_message::Response::InvalidRequest(_)),
+        "{response:?}"
+    );
+
+    let response = send_topic_message(env, &user1, community_id, channel_id, Some(new_topic("Title", &["other"])));
+    assert!(
+        matches!(response, community_canister::send_message::Response::InvalidRequest(_)),
+        "{response:?}"
+    );
+}
+
+#[test]
+fn topics_can_be_filtered_and_sorted() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData {
+        user1,
+        user2,
+        community_id,
+        channel_id,
+    } = init_test_data(env, canister_ids, *controller);
+
+    let bug = start_topic(env, &user1, community_id, channel_id, new_topic("Crash on start", &["bug"]));
+    env.advance_time(Duration::from_secs(1));
+    let question = start_topic(env, &user2, community_id, channel_id, new_topic("How do I?", &["question"]));
+    env.advance_time(Duration::from_secs(1));
+    let other_bug = start_topic(env, &user2, community_id, channel_id, new_topic("Wrong colour", &["Bug"]));
+    env.advance_time(Duration::from_secs(1));
+
+    client::community::happy_path::send_text_message(env, &user2, community_id, channel_id, Some(bug), "Same here", None);
+
+    // Topics are sorted by their latest activity by default, so the topic with a reply comes first
+    let topics = forum_topics(
+        env,
+        &user2,
+        community_id,
+        channel_id,
+        &["bug"],
+        None,
+        ForumTopicsSortOrder::LatestActivity,
+    );
+    assert_eq!(topics, vec![bug, other_bug]);
+
+    let topics = forum_topics(env, &user2, community_id, channel_id, &[], None, ForumTopicsSortOrder::Newest);
+    assert_eq!(topics, vec![other_bug, question, bug]);
+
+    // The creator of a topic can mark it as solved, whereas other members can't
+    let response = update_forum_topic(env, user2.principal, community_id, channel_id, bug, Some(true));
+    assert!(
+        matches!(response, community_canister::update_forum_topic::Response::NotAuthorized),
+        "{response:?}"
+    );
+    let response = update_forum_topic(env, user1.principal, community_id, channel_id, bug, Some(true));
+    assert!(
+        matches!(response, community_canister::update_forum_topic::Response::Success),
+        "{response:?}"
+    );
+
+    let topics = forum_topics(
+        env,
+        &user2,
+        community_id,
+        channel_id,
+        &["bug"],
+        Some(false),
+        ForumTopicsSortOrder::Newest,
+    );
+    assert_eq!(topics, vec![other_bug]);
+    let topics = forum_topics(
+        env,
+        &user2,
+        community_id,
+        channel_id,
+        &[],
+        Some(true),
+        ForumTopicsSortOrder::Newest,
+    );
+    assert_eq!(topics, vec![bug]);
+}
+
+#[test]
+fn updating_topic_updates_channel() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData {
+        user1,
+        user2,
+        community_id,
+        channel_id,
+    } = init_test_data(env, canister_ids, *controller);
+
+    let topic = start_topic(env, &user1, community_id, channel_id, new_topic("Crash on start", &["bug"]));
+
+    let last_updated = client::community::happy_path::channel_summary(env, &user2, community_id, channel_id).last_updated;
+
+    env.advance_time(Duration::from_secs(10));
+
+    let response = update_forum_topic(env, user1.principal, community_id, channel_id, topic, Some(true));
+    assert!(
+        matches!(response, community_canister::update_forum_topic::Response::Success),
+        "{response:?}"
+    );
+
+    let channel_summary = client::community::happy_path::channel_summary(env, &user2, community_id, channel_id);
+    assert!(channel_summary.last_updated > last_updated);
+}
+
+fn start_topic(
+    env: &mut PocketIc,
+    sender: &User,
+    community_id: CommunityId,
+    channel_id: ChannelId,
+    topic: NewForumTopic,
+) -> MessageIndex {
+    match send_topic_message(env, sender, community_id, channel_id, Some(topic)) {
+        community_canister::send_message::Response::Success(result) => result.message_index,
+        response => panic!("'send_message' error: {response:?}"),
+    }
+}
+
+fn send_topic_message(
+    env: &mut PocketIc,
+    sender: &User,
+    community_id: CommunityId,
+    channel_id: ChannelId,
+    forum_topic: Option<NewForumTopic>,
+) -> community_canister::send_message::Response {
+    client::community::send_message(
+        env,
+        sender.principal,
+        community_id.into(),
+        &community_canister::send_message::Args {
+            channel_id,
+            thread_root_message_index: None,
+            message_id: random_message_id(),
+            content: MessageContentInitial::Text(TextContent {
+                text: random_string(),
+                link_previews: None,
+            }),
+            sender_name: sender.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: Vec::new(),
+            forwarding: false,
+            community_rules_accepted: None,
+            channel_rules_accepted: None,
+            forum_topic,
+            forwarded_from: None,
+        },
+    )
+}
+
+fn forum_topics(
+    env: &PocketIc,
+    sender: &User,
+    community_id: CommunityId,
+    channel_id: ChannelId,
+    tags: &[&str],
+    solved: Option<bool>,
+    sort: ForumTopicsSortOrder,
+) -> Vec<MessageIndex> {
+    let response = client::community::forum_topics(
+        env,
+        sender.principal,
+        community_id.into(),
+        &community_canister::forum_topics::Args {
+            channel_id,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            solved,
+            sort,
+            page_index: 0,
+            page_size: 10,
+        },
+    );
+
+    match response {
+        community_canister::forum_topics::Response::Success(result) => result
+            .topics
+            .into_iter()
+            .map(|t| t.root_message.event.message_index)
+            .collect(),
+        response => panic!("'forum_topics' error: {response:?}"),
+    }
+}
+
+fn update_forum_topic(
+    env: &mut PocketIc,
+    sender: Principal,
+    community_id: CommunityId,
+    channel_id: ChannelId,
+    root_message_index: MessageIndex,
+    solved: Option<bool>,
+) -> community_canister::update_forum_topic::Response {
+    client::community::update_forum_topic(
+        env,
+        sender,
+        community_id.into(),
+        &community_canister::update_forum_topic::Args {
+            channel_id,
+            root_message_index,
+            title: None,
+            tags: None,
+            solved,
+            answer: None,
+        },
+    )
+}
+
+fn new_topic(title: &str, tags: &[&str]) -> NewForumTopic {
+    NewForumTopic {
+        title: title.to_string(),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+    }
+}
+
+fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Principal) -> TestData {
+    let user1 = client::register_diamond_user(env, canister_ids, controller);
+    let user2 = client::local_user_index::happy_path::register_user(env, canister_ids.local_user_index);
+    let community_id =
+        client::user::happy_path::create_community(env, &user1, &random_string(), true, vec!["general".to_string()]);
+    client::local_user_index::happy_path::join_community(env, user2.principal, canister_ids.local_user_index, community_id);
+
+    let response = client::community::create_channel(
+        env,
+        user1.principal,
+        community_id.into(),
+        &community_canister::create_channel::Args {
+            is_public: true,
+            name: random_string(),
+            description: "Forum".to_string(),
+            rules: Rules::default(),
+            subtype: Some(GroupSubtype::Forum(ForumSubtype {
+                available_tags: vec!["Bug".to_string(), "Question".to_string()],
+            })),
+            avatar: None,
+            history_visible_to_new_joiners: true,
+            permissions_v2: None,
+            events_ttl: None,
+            gate: None,
+        },
+    );
+
+    let channel_id = match response {
+        community_canister::create_channel::Response::Success(result) => result.channel_id,
+        response => panic!("'create_channel' error: {response:?}"),
+    };
+
+    env.tick();
+
+    TestData {
+        user1,
+        user2,
+        community_id,
+        channel_id,
+    }
+}
+
+struct TestData {
+    user1: User,
+    user2: User,
+    community_id: CommunityId,
+    channel_id: ChannelId,
+}
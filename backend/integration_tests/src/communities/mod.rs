@@ -2,6 +2,7 @@ mod cancel_invites_tests;
 mod convert_group_into_community_tests;
 mod create_channel_tests;
 mod disappearing_message_tests;
+mod forum_tests;
 mod import_group_tests;
 mod join_channel_tests;
 mod join_community_tests;
@@ -93,6 +93,7 @@ fn send_crypto_in_channel() {
             mentioned: Vec::new(),
             community_rules_accepted: None,
             channel_rules_accepted: None,
+            forum_topic: None,
         },
     );
 
@@ -160,6 +161,7 @@ fn send_prize_in_channel() {
             mentioned: Vec::new(),
             community_rules_accepted: None,
             channel_rules_accepted: None,
+            forum_topic: None,
         },
     );
 
@@ -648,7 +650,10 @@ fn send_dummy_message_with_rules(
             channel_id,
             thread_root_message_index: None,
             message_id: random_message_id(),
-            content: MessageContentInitial::Text(TextContent { text: "123".to_string() }),
+            content: MessageContentInitial::Text(TextContent {
+                text: "123".to_string(),
+                link_previews: None,
+            }),
             sender_name: sender.username(),
             sender_display_name: None,
             replies_to: None,
@@ -656,6 +661,8 @@ fn send_dummy_message_with_rules(
             forwarding: false,
             community_rules_accepted,
             channel_rules_accepted,
+            forum_topic: None,
+            forwarded_from: None,
         },
     )
 }
@@ -714,6 +721,9 @@ fn set_channel_rules(env: &mut PocketIc, sender: Principal, community_id: Commun
         events_ttl: OptionUpdate::NoChange,
         gate: OptionUpdate::NoChange,
         public: None,
+        slow_mode: OptionUpdate::NoChange,
+        edit_history_enabled: None,
+        hide_forwarded_from: None,
         channel_id,
     };
 
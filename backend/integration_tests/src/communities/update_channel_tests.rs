@@ -40,6 +40,9 @@ fn make_private_channel_public_succeeds() {
             events_ttl: OptionUpdate::NoChange,
             gate: OptionUpdate::NoChange,
             public: Some(true),
+            slow_mode: OptionUpdate::NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: None,
         },
     );
 
@@ -87,6 +87,7 @@ fn update_permissions_succeeds() {
             create_public_channel: Some(CommunityPermissionRole::Owners),
             create_private_channel: None,
             manage_user_groups: None,
+            manage_emoji_packs: None,
         }),
         gate: OptionUpdate::NoChange,
         public: None,
@@ -138,6 +139,7 @@ fn update_permissions_summary_updates_succeeds() {
             create_public_channel: Some(CommunityPermissionRole::Owners),
             create_private_channel: None,
             manage_user_groups: None,
+            manage_emoji_packs: None,
         }),
         gate: OptionUpdate::NoChange,
         public: None,
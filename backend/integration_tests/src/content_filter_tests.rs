@@ -0,0 +1,101 @@
+use crate::env::ENV;
+use crate::rng::{random_message_id, random_string};
+use crate::utils::tick_many;
+use crate::{client, TestEnv};
+use std::ops::Deref;
+use types::{
+    ChatEvent, ContentFilterAction, ContentFilterCondition, ContentFilterRule, ContentFilters, EventIndex, MessageContent,
+    MessageContentInitial, TextContent,
+};
+use utils::consts::OPENCHAT_BOT_USER_ID;
+
+#[test]
+fn delete_and_warn_deletes_message_and_warns_sender() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user1 = client::register_diamond_user(env, canister_ids, *controller);
+    let user2 = client::local_user_index::happy_path::register_user(env, canister_ids.local_user_index);
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+    client::local_user_index::happy_path::join_group(env, user2.principal, canister_ids.local_user_index, group_id);
+
+    let set_content_filters_response = client::group::set_content_filters(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::set_content_filters::Args {
+            content_filters: ContentFilters {
+                rules: vec![ContentFilterRule {
+                    condition: ContentFilterCondition::Keywords(vec!["spam".to_string()]),
+                    action: ContentFilterAction::DeleteAndWarn,
+                }],
+            },
+        },
+    );
+
+    assert!(matches!(
+        set_content_filters_response,
+        group_canister::set_content_filters::Response::Success
+    ));
+
+    let bot_messages_before =
+        client::user::happy_path::events(env, &user2, OPENCHAT_BOT_USER_ID, EventIndex::default(), true, 1000, 1000)
+            .events
+            .len();
+
+    let message_id = random_message_id();
+    let send_message_response = client::group::send_message_v2(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::send_message_v2::Args {
+            thread_root_message_index: None,
+            message_id,
+            content: MessageContentInitial::Text(TextContent {
+                text: "Buy my spam".to_string(),
+                link_previews: None,
+            }),
+            sender_name: user2.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: Vec::new(),
+            forwarding: false,
+            rules_accepted: None,
+            correlation_id: 0,
+            forwarded_from: None,
+        },
+    );
+
+    if let group_canister::send_message_v2::Response::Success(result) = send_message_response {
+        assert_eq!(
+            result.content_filter_match.map(|m| m.action),
+            Some(ContentFilterAction::DeleteAndWarn)
+        );
+    } else {
+        panic!("'send_message_v2' error: {send_message_response:?}");
+    }
+
+    tick_many(env, 3);
+
+    let events = client::group::happy_path::events(env, &user1, group_id, 0.into(), true, 50, 50).events;
+
+    assert!(events.iter().any(|e| matches!(
+        &e.event,
+        ChatEvent::Message(m) if m.message_id == message_id
+            && matches!(&m.content, MessageContent::Deleted(d) if d.deleted_by == OPENCHAT_BOT_USER_ID)
+    )));
+
+    let bot_messages =
+        client::user::happy_path::events(env, &user2, OPENCHAT_BOT_USER_ID, EventIndex::default(), true, 1000, 1000).events;
+
+    assert_eq!(bot_messages.len(), bot_messages_before + 1);
+    assert!(matches!(
+        &bot_messages.last().unwrap().event,
+        ChatEvent::Message(m) if matches!(&m.content, MessageContent::Text(t) if t.text.contains("was deleted"))
+    ));
+}
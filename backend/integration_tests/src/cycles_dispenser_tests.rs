@@ -35,6 +35,7 @@ fn icp_is_burned_into_cycles() {
             min_interval: None,
             max_top_up_amount: None,
             icp_burn_amount: Some(Tokens::from_e8s(10_000_000_000)),
+            proactive_top_up_lead_time: None,
         },
     );
 
@@ -0,0 +1,292 @@
+use crate::env::ENV;
+use crate::rng::{random_message_id, random_string};
+use crate::{client, TestEnv, User};
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use types::{
+    ChannelId, Chat, ChatEvent, ChatId, CommunityId, EventIndex, ForwardedFrom, MessageContent, MessageIndex, OptionUpdate,
+};
+use user_canister::forward_message::{ForwardOutcome, ForwardTarget, ForwardedMessage};
+
+#[test]
+fn forward_message_to_group_and_channel() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+    let source_group_id = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+    let target_group_id = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+    let community_id =
+        client::user::happy_path::create_community(env, &user, &random_string(), true, vec!["general".to_string()]);
+    let channel_id = client::community::happy_path::create_channel(env, user.principal, community_id, true, random_string());
+
+    let message_index =
+        client::group::happy_path::send_text_message(env, &user, source_group_id, None, "Hello", None).message_index;
+
+    let forwarded = forward_message(
+        env,
+        &user,
+        Chat::Group(source_group_id),
+        message_index,
+        vec![Chat::Group(target_group_id), Chat::Channel(community_id, channel_id)],
+    );
+    assert_eq!(forwarded.len(), 2);
+
+    let from_group = group_message_forwarded_from(env, &user, target_group_id, forwarded[0].event_index);
+    let from_channel = channel_message_forwarded_from(env, &user, community_id, channel_id, forwarded[1].event_index);
+
+    for forwarded_from in [from_group, from_channel] {
+        assert_eq!(forwarded_from.chat, Some(Chat::Group(source_group_id)));
+        assert_eq!(forwarded_from.message_index, Some(message_index));
+        assert_eq!(forwarded_from.sender, user.user_id);
+    }
+}
+
+#[test]
+fn forwarding_a_forwarded_message_keeps_its_original_provenance() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+    let group1 = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+    let group2 = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+    let group3 = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+
+    let message_index = client::group::happy_path::send_text_message(env, &user, group1, None, "Hello", None).message_index;
+
+    let forwarded = forward_message(env, &user, Chat::Group(group1), message_index, vec![Chat::Group(group2)]);
+    let forwarded = forward_message(
+        env,
+        &user,
+        Chat::Group(group2),
+        forwarded[0].message_index,
+        vec![Chat::Group(group3)],
+    );
+
+    let forwarded_from = group_message_forwarded_from(env, &user, group3, forwarded[0].event_index);
+    assert_eq!(forwarded_from.chat, Some(Chat::Group(group1)));
+    assert_eq!(forwarded_from.message_index, Some(message_index));
+}
+
+#[test]
+fn private_group_can_hide_where_messages_were_forwarded_from() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+    let source_group_id = client::user::happy_path::create_group(env, &user, &random_string(), false, true);
+    let target_group_id = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+
+    client::group::happy_path::update_group(
+        env,
+        user.principal,
+        source_group_id,
+        &group_canister::update_group_v2::Args {
+            name: None,
+            description: None,
+            rules: None,
+            avatar: OptionUpdate::NoChange,
+            permissions_v2: None,
+            events_ttl: OptionUpdate::NoChange,
+            public: None,
+            correlation_id: 0,
+            gate: OptionUpdate::NoChange,
+            slow_mode: OptionUpdate::NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: Some(true),
+        },
+    );
+
+    let message_index =
+        client::group::happy_path::send_text_message(env, &user, source_group_id, None, "Hello", None).message_index;
+
+    let forwarded = forward_message(
+        env,
+        &user,
+        Chat::Group(source_group_id),
+        message_index,
+        vec![Chat::Group(target_group_id)],
+    );
+
+    let forwarded_from = group_message_forwarded_from(env, &user, target_group_id, forwarded[0].event_index);
+    assert!(forwarded_from.chat.is_none());
+    assert!(forwarded_from.message_index.is_none());
+    assert_eq!(forwarded_from.sender, user.user_id);
+}
+
+#[test]
+fn public_channel_in_private_community_can_hide_where_messages_were_forwarded_from() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+    let community_id =
+        client::user::happy_path::create_community(env, &user, &random_string(), false, vec!["general".to_string()]);
+    let channel_id = client::community::happy_path::create_channel(env, user.principal, community_id, true, random_string());
+    let target_group_id = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+
+    client::community::happy_path::update_channel(
+        env,
+        user.principal,
+        community_id,
+        &community_canister::update_channel::Args {
+            channel_id,
+            name: None,
+            description: None,
+            rules: None,
+            avatar: OptionUpdate::NoChange,
+            permissions_v2: None,
+            events_ttl: OptionUpdate::NoChange,
+            gate: OptionUpdate::NoChange,
+            public: None,
+            slow_mode: OptionUpdate::NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: Some(true),
+        },
+    );
+
+    let message_index =
+        client::community::happy_path::send_text_message(env, &user, community_id, channel_id, None, "Hello", None)
+            .message_index;
+
+    let forwarded = forward_message(
+        env,
+        &user,
+        Chat::Channel(community_id, channel_id),
+        message_index,
+        vec![Chat::Group(target_group_id)],
+    );
+
+    let forwarded_from = group_message_forwarded_from(env, &user, target_group_id, forwarded[0].event_index);
+    assert!(forwarded_from.chat.is_none());
+    assert!(forwarded_from.message_index.is_none());
+}
+
+#[test]
+fn forward_message_requires_targets() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let user = client::register_diamond_user(env, canister_ids, *controller);
+    let group_id = client::user::happy_path::create_group(env, &user, &random_string(), true, true);
+    let message_index = client::group::happy_path::send_text_message(env, &user, group_id, None, "Hello", None).message_index;
+
+    let response = client::user::forward_message(
+        env,
+        user.principal,
+        user.canister(),
+        &forward_message_args(Chat::Group(group_id), message_index, Vec::new()),
+    );
+    assert!(
+        matches!(response, user_canister::forward_message::Response::NoTargets),
+        "{response:?}"
+    );
+
+    let response = client::user::forward_message(
+        env,
+        user.principal,
+        user.canister(),
+        &forward_message_args(Chat::Group(group_id), message_index, vec![Chat::Group(group_id); 11]),
+    );
+    assert!(
+        matches!(response, user_canister::forward_message::Response::TooManyTargets(10)),
+        "{response:?}"
+    );
+}
+
+fn forward_message(
+    env: &mut PocketIc,
+    user: &User,
+    chat: Chat,
+    message_index: MessageIndex,
+    targets: Vec<Chat>,
+) -> Vec<ForwardedMessage> {
+    let response = client::user::forward_message(
+        env,
+        user.principal,
+        user.canister(),
+        &forward_message_args(chat, message_index, targets),
+    );
+
+    match response {
+        user_canister::forward_message::Response::Success(result) => result
+            .results
+            .into_iter()
+            .map(|r| match r.outcome {
+                ForwardOutcome::Success(message) => message,
+                ForwardOutcome::Failed(error) => panic!("Failed to forward message to {:?}: {error}", r.chat),
+            })
+            .collect(),
+        response => panic!("'forward_message' error: {response:?}"),
+    }
+}
+
+fn forward_message_args(chat: Chat, message_index: MessageIndex, targets: Vec<Chat>) -> user_canister::forward_message::Args {
+    user_canister::forward_message::Args {
+        chat,
+        thread_root_message_index: None,
+        message_index,
+        targets: targets
+            .into_iter()
+            .map(|chat| ForwardTarget {
+                chat,
+                thread_root_message_index: None,
+                message_id: random_message_id(),
+                rules_accepted: None,
+                community_rules_accepted: None,
+            })
+            .collect(),
+    }
+}
+
+fn group_message_forwarded_from(env: &PocketIc, user: &User, group_id: ChatId, event_index: EventIndex) -> ForwardedFrom {
+    let events = client::group::happy_path::events_by_index(env, user, group_id, vec![event_index]);
+    extract_forwarded_from(events.events.into_iter().next().unwrap().event)
+}
+
+fn channel_message_forwarded_from(
+    env: &PocketIc,
+    user: &User,
+    community_id: CommunityId,
+    channel_id: ChannelId,
+    event_index: EventIndex,
+) -> ForwardedFrom {
+    let events = client::community::happy_path::events_by_index(env, user, community_id, channel_id, vec![event_index]);
+    extract_forwarded_from(events.events.into_iter().next().unwrap().event)
+}
+
+fn extract_forwarded_from(event: ChatEvent) -> ForwardedFrom {
+    match event {
+        ChatEvent::Message(m) => {
+            assert!(m.forwarded);
+            assert!(matches!(m.content, MessageContent::Text(_)));
+            m.forwarded_from.unwrap()
+        }
+        event => panic!("Expected a message: {event:?}"),
+    }
+}
@@ -5,9 +5,11 @@ use candid::Principal;
 use pocket_ic::PocketIc;
 use types::{CanisterId, Cycles, UserId};
 
+mod bot_sdk_tests;
 mod change_group_role_tests;
 mod client;
 mod communities;
+mod content_filter_tests;
 mod cycles_dispenser_tests;
 mod delete_direct_chat_tests;
 mod delete_group_tests;
@@ -16,10 +18,12 @@ mod diamond_membership_tests;
 mod disappearing_message_tests;
 mod env;
 mod fire_and_forget_handler_tests;
+mod forward_message_tests;
 mod freeze_group_tests;
 mod gated_group_tests;
 mod join_group_tests;
 mod last_online_date_tests;
+mod mute_member_tests;
 mod notification_tests;
 mod platform_moderator_tests;
 mod poll_tests;
@@ -0,0 +1,194 @@
+use crate::env::ENV;
+use crate::rng::{random_message_id, random_string};
+use crate::utils::tick_many;
+use crate::{client, CanisterIds, TestEnv, User};
+use candid::Principal;
+use pocket_ic::PocketIc;
+use std::ops::Deref;
+use std::time::Duration;
+use types::{ChatEvent, ChatId, MessageContentInitial, TextContent};
+use utils::time::DAY_IN_MS;
+
+#[test]
+fn muted_member_cannot_send_messages_until_mute_expires() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller);
+
+    let mute_response = client::group::mute_member(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::mute_member::Args {
+            user_id: user2.user_id,
+            duration: 60_000,
+        },
+    );
+
+    assert!(matches!(mute_response, group_canister::mute_member::Response::Success(_)));
+
+    let send_message_response = send_text_message(env, &user2, group_id);
+
+    assert!(matches!(
+        send_message_response,
+        group_canister::send_message_v2::Response::UserMuted(_)
+    ));
+
+    env.advance_time(Duration::from_secs(61));
+    tick_many(env, 3);
+
+    let send_message_response = send_text_message(env, &user2, group_id);
+
+    assert!(matches!(
+        send_message_response,
+        group_canister::send_message_v2::Response::Success(_)
+    ));
+
+    let events = client::group::happy_path::events(env, &user1, group_id, 0.into(), true, 50, 50).events;
+
+    assert!(events
+        .iter()
+        .any(|e| matches!(&e.event, ChatEvent::MemberMuted(m) if m.user_id == user2.user_id)));
+    assert!(events
+        .iter()
+        .any(|e| matches!(&e.event, ChatEvent::MemberUnmuted(m) if m.user_id == user2.user_id && m.unmuted_by.is_none())));
+}
+
+#[test]
+fn member_cannot_mute_admin() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller);
+
+    let mute_response = client::group::mute_member(
+        env,
+        user2.principal,
+        group_id.into(),
+        &group_canister::mute_member::Args {
+            user_id: user1.user_id,
+            duration: 60_000,
+        },
+    );
+
+    assert!(matches!(mute_response, group_canister::mute_member::Response::NotAuthorized));
+}
+
+#[test]
+fn mute_persists_after_leaving_and_rejoining() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller);
+
+    let mute_response = client::group::mute_member(
+        env,
+        user1.principal,
+        group_id.into(),
+        &group_canister::mute_member::Args {
+            user_id: user2.user_id,
+            duration: 60_000,
+        },
+    );
+
+    assert!(matches!(mute_response, group_canister::mute_member::Response::Success(_)));
+
+    client::user::happy_path::leave_group(env, &user2, group_id);
+    client::local_user_index::happy_path::join_group(env, user2.principal, canister_ids.local_user_index, group_id);
+
+    let send_message_response = send_text_message(env, &user2, group_id);
+
+    assert!(matches!(
+        send_message_response,
+        group_canister::send_message_v2::Response::UserMuted(_)
+    ));
+}
+
+#[test]
+fn mute_duration_must_be_valid() {
+    let mut wrapper = ENV.deref().get();
+    let TestEnv {
+        env,
+        canister_ids,
+        controller,
+        ..
+    } = wrapper.env();
+
+    let TestData { user1, user2, group_id } = init_test_data(env, canister_ids, *controller);
+
+    for duration in [0, 366 * DAY_IN_MS] {
+        let mute_response = client::group::mute_member(
+            env,
+            user1.principal,
+            group_id.into(),
+            &group_canister::mute_member::Args {
+                user_id: user2.user_id,
+                duration,
+            },
+        );
+
+        assert!(matches!(
+            mute_response,
+            group_canister::mute_member::Response::InvalidDuration
+        ));
+    }
+}
+
+fn send_text_message(env: &mut PocketIc, sender: &User, group_id: ChatId) -> group_canister::send_message_v2::Response {
+    client::group::send_message_v2(
+        env,
+        sender.principal,
+        group_id.into(),
+        &group_canister::send_message_v2::Args {
+            thread_root_message_index: None,
+            message_id: random_message_id(),
+            content: MessageContentInitial::Text(TextContent {
+                text: random_string(),
+                link_previews: None,
+            }),
+            sender_name: sender.username(),
+            sender_display_name: None,
+            replies_to: None,
+            mentioned: Vec::new(),
+            forwarding: false,
+            rules_accepted: None,
+            correlation_id: 0,
+            forwarded_from: None,
+        },
+    )
+}
+
+fn init_test_data(env: &mut PocketIc, canister_ids: &CanisterIds, controller: Principal) -> TestData {
+    let user1 = client::register_diamond_user(env, canister_ids, controller);
+    let user2 = client::local_user_index::happy_path::register_user(env, canister_ids.local_user_index);
+
+    let group_id = client::user::happy_path::create_group(env, &user1, &random_string(), true, true);
+
+    client::local_user_index::happy_path::join_group(env, user2.principal, canister_ids.local_user_index, group_id);
+
+    tick_many(env, 3);
+
+    TestData { user1, user2, group_id }
+}
+
+struct TestData {
+    user1: User,
+    user2: User,
+    group_id: ChatId,
+}
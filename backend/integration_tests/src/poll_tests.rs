@@ -128,6 +128,7 @@ fn polls_ended_correctly() {
             forwarding: false,
             rules_accepted: None,
             correlation_id: 0,
+            forwarded_from: None,
         },
     );
 
@@ -253,6 +254,7 @@ fn init_test_data(env: &mut PocketIc, local_user_index: CanisterId, poll_config:
             forwarding: false,
             rules_accepted: None,
             correlation_id: 0,
+            forwarded_from: None,
         },
     );
 
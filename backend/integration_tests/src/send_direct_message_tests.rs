@@ -39,7 +39,10 @@ fn empty_message_fails() {
         recipient: user2.user_id,
         thread_root_message_index: None,
         message_id: random_message_id(),
-        content: MessageContentInitial::Text(TextContent { text: String::default() }),
+        content: MessageContentInitial::Text(TextContent {
+            text: String::default(),
+            link_previews: None,
+        }),
         replies_to: None,
         forwarding: false,
         correlation_id: 0,
@@ -64,6 +67,7 @@ fn text_too_long_fails() {
         message_id: random_message_id(),
         content: MessageContentInitial::Text(TextContent {
             text: (0..5001).map(|_| '1').collect(),
+            link_previews: None,
         }),
         replies_to: None,
         forwarding: false,
@@ -53,7 +53,10 @@ fn suspend_user() {
             recipient: user2.user_id,
             thread_root_message_index: None,
             message_id: random_message_id(),
-            content: MessageContentInitial::Text(TextContent { text: "123".to_string() }),
+            content: MessageContentInitial::Text(TextContent {
+                text: "123".to_string(),
+                link_previews: None,
+            }),
             replies_to: None,
             forwarding: false,
             correlation_id: 0,
@@ -73,12 +76,16 @@ fn suspend_user() {
             message_id: random_message_id(),
             sender_name: user1.username(),
             sender_display_name: None,
-            content: MessageContentInitial::Text(TextContent { text: "123".to_string() }),
+            content: MessageContentInitial::Text(TextContent {
+                text: "123".to_string(),
+                link_previews: None,
+            }),
             replies_to: None,
             mentioned: Vec::new(),
             forwarding: false,
             rules_accepted: None,
             correlation_id: 0,
+            forwarded_from: None,
         },
     );
     assert!(matches!(
@@ -106,7 +113,10 @@ fn suspend_user() {
             recipient: user2.user_id,
             thread_root_message_index: None,
             message_id: random_message_id(),
-            content: MessageContentInitial::Text(TextContent { text: "123".to_string() }),
+            content: MessageContentInitial::Text(TextContent {
+                text: "123".to_string(),
+                link_previews: None,
+            }),
             replies_to: None,
             forwarding: false,
             correlation_id: 0,
@@ -126,12 +136,16 @@ fn suspend_user() {
             message_id: random_message_id(),
             sender_name: user1.username(),
             sender_display_name: None,
-            content: MessageContentInitial::Text(TextContent { text: "123".to_string() }),
+            content: MessageContentInitial::Text(TextContent {
+                text: "123".to_string(),
+                link_previews: None,
+            }),
             replies_to: None,
             mentioned: Vec::new(),
             forwarding: false,
             rules_accepted: None,
             correlation_id: 0,
+            forwarded_from: None,
         },
     );
     assert!(matches!(
@@ -35,6 +35,9 @@ fn update_group_name_succeeds() {
             public: None,
             correlation_id: 0,
             gate: NoChange,
+            slow_mode: NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: None,
         },
     );
 
@@ -78,6 +81,9 @@ fn change_casing_of_group_name_succeeds() {
             public: None,
             correlation_id: 0,
             gate: NoChange,
+            slow_mode: NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: None,
         },
     );
 
@@ -124,6 +130,9 @@ fn update_group_privacy_succeeds() {
             public: Some(false),
             correlation_id: 0,
             gate: NoChange,
+            slow_mode: NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: None,
         },
     );
 
@@ -172,6 +181,9 @@ fn make_private_group_public_succeeds() {
             gate: NoChange,
             public: Some(true),
             correlation_id: 0,
+            slow_mode: NoChange,
+            edit_history_enabled: None,
+            hide_forwarded_from: None,
         },
     );
 
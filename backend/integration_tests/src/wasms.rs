@@ -19,6 +19,7 @@ lazy_static! {
     pub static ref ONLINE_USERS: CanisterWasm = get_canister_wasm("online_users");
     pub static ref PROPOSALS_BOT: CanisterWasm = get_canister_wasm("proposals_bot");
     pub static ref REGISTRY: CanisterWasm = get_canister_wasm("registry");
+    pub static ref SATOSHI_DICE: CanisterWasm = get_canister_wasm("satoshi_dice");
     pub static ref SNS_WASM: CanisterWasm = get_canister_wasm("sns_wasm");
     pub static ref STORAGE_BUCKET: CanisterWasm = get_canister_wasm("storage_bucket");
     pub static ref STORAGE_INDEX: CanisterWasm = get_canister_wasm("storage_index");
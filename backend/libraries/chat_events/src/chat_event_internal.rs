@@ -5,11 +5,12 @@ use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::{HashMap, HashSet};
 use std::ops::{Deref, DerefMut};
 use types::{
-    is_default, is_empty_slice, AvatarChanged, ChannelId, Chat, ChatId, ChatMetrics, CommunityId, Cryptocurrency, DeletedBy,
-    DirectChatCreated, EventIndex, EventWrapperInternal, EventsTimeToLiveUpdated, GroupCreated, GroupDescriptionChanged,
-    GroupFrozen, GroupGateUpdated, GroupInviteCodeChanged, GroupNameChanged, GroupReplyContext, GroupRulesChanged,
-    GroupUnfrozen, GroupVisibilityChanged, MemberJoined, MemberLeft, MembersAdded, MembersAddedToDefaultChannel,
-    MembersRemoved, Message, MessageContent, MessageId, MessageIndex, MessagePinned, MessageUnpinned, MultiUserChat,
+    is_default, is_empty_slice, AvatarChanged, ChannelId, Chat, ChatId, ChatImportSource, ChatMetrics, CommunityId,
+    Cryptocurrency, DeletedBy, DirectChatCreated, EventIndex, EventWrapperInternal, EventsTimeToLiveUpdated, ForwardedFrom,
+    GroupCreated, GroupDescriptionChanged, GroupFrozen, GroupGateUpdated, GroupInviteCodeChanged, GroupNameChanged,
+    GroupReplyContext, GroupRulesChanged, GroupUnfrozen, GroupVisibilityChanged, ImportedMessageDetails, MemberJoined,
+    MemberLeft, MemberMuted, MemberUnmuted, MembersAdded, MembersAddedToDefaultChannel, MembersRemoved, Message,
+    MessageContent, MessageId, MessageIndex, MessagePinned, MessageRevision, MessageUnpinned, MultiUserChat,
     PermissionsChanged, PushIfNotContains, Reaction, ReplyContext, RoleChanged, ThreadSummary, TimestampMillis, Timestamped,
     Tips, UserId, UsersBlocked, UsersInvited, UsersUnblocked,
 };
@@ -66,6 +67,10 @@ pub enum ChatEventInternal {
     UsersInvited(Box<UsersInvited>),
     #[serde(rename = "adc")]
     MembersAddedToPublicChannel(Box<MembersAddedToPublicChannelInternal>),
+    #[serde(rename = "mm")]
+    MemberMuted(Box<MemberMuted>),
+    #[serde(rename = "mum")]
+    MemberUnmuted(Box<MemberUnmuted>),
     #[serde(rename = "e")]
     Empty,
 }
@@ -107,6 +112,8 @@ impl ChatEventInternal {
                 | ChatEventInternal::GroupGateUpdated(_)
                 | ChatEventInternal::UsersInvited(_)
                 | ChatEventInternal::MembersAddedToPublicChannel(_)
+                | ChatEventInternal::MemberMuted(_)
+                | ChatEventInternal::MemberUnmuted(_)
         )
     }
 
@@ -184,6 +191,29 @@ pub struct MessageInternal {
     pub thread_summary: Option<ThreadSummaryInternal>,
     #[serde(rename = "f", default, skip_serializing_if = "is_default")]
     pub forwarded: bool,
+    #[serde(rename = "ff", default, skip_serializing_if = "Option::is_none")]
+    pub forwarded_from: Option<ForwardedFromInternal>,
+    #[serde(rename = "h", default, skip_serializing_if = "is_empty_slice")]
+    pub edit_history: Vec<MessageRevisionInternal>,
+    #[serde(rename = "im", default, skip_serializing_if = "Option::is_none")]
+    pub imported: Option<ImportedMessageDetailsInternal>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MessageRevisionInternal {
+    #[serde(rename = "c")]
+    pub content: MessageContentInternal,
+    #[serde(rename = "t")]
+    pub timestamp: TimestampMillis,
+}
+
+impl MessageRevisionInternal {
+    pub fn hydrate(&self, my_user_id: Option<UserId>) -> MessageRevision {
+        MessageRevision {
+            content: self.content.hydrate(my_user_id),
+            timestamp: self.timestamp,
+        }
+    }
 }
 
 impl MessageInternal {
@@ -206,6 +236,8 @@ impl MessageInternal {
             tips: self.tips.clone(),
             edited: self.last_edited.is_some(),
             forwarded: self.forwarded,
+            forwarded_from: self.forwarded_from.as_ref().map(|f| f.hydrate()),
+            imported: self.imported.as_ref().map(|i| i.hydrate()),
             thread_summary: self.thread_summary.as_ref().map(|t| t.hydrate(my_user_id)),
             last_updated: self.last_updated,
         }
@@ -276,6 +308,7 @@ impl MessageInternal {
             MessageContentInternal::Custom(_) => {
                 incr(&mut metrics.custom_type_messages);
             }
+            MessageContentInternal::Sticker(_) => {}
         }
     }
 }
@@ -306,6 +339,74 @@ impl From<DeletedBy> for DeletedByInternal {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ImportedMessageDetailsInternal {
+    #[serde(rename = "s")]
+    pub source: ChatImportSource,
+    #[serde(rename = "n")]
+    pub sender_name: String,
+    #[serde(rename = "t")]
+    pub timestamp: TimestampMillis,
+}
+
+impl ImportedMessageDetailsInternal {
+    pub fn hydrate(&self) -> ImportedMessageDetails {
+        ImportedMessageDetails {
+            source: self.source,
+            sender_name: self.sender_name.clone(),
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+impl From<ImportedMessageDetails> for ImportedMessageDetailsInternal {
+    fn from(value: ImportedMessageDetails) -> Self {
+        ImportedMessageDetailsInternal {
+            source: value.source,
+            sender_name: value.sender_name,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ForwardedFromInternal {
+    #[serde(rename = "c", default, skip_serializing_if = "Option::is_none")]
+    pub chat: Option<ChatInternal>,
+    #[serde(rename = "r", default, skip_serializing_if = "Option::is_none")]
+    pub thread_root_message_index: Option<MessageIndex>,
+    #[serde(rename = "m", default, skip_serializing_if = "Option::is_none")]
+    pub message_index: Option<MessageIndex>,
+    #[serde(rename = "s")]
+    pub sender: UserId,
+    #[serde(rename = "t")]
+    pub timestamp: TimestampMillis,
+}
+
+impl ForwardedFromInternal {
+    pub fn hydrate(&self) -> ForwardedFrom {
+        ForwardedFrom {
+            chat: self.chat.map(|c| c.hydrate()),
+            thread_root_message_index: self.thread_root_message_index,
+            message_index: self.message_index,
+            sender: self.sender,
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+impl From<ForwardedFrom> for ForwardedFromInternal {
+    fn from(value: ForwardedFrom) -> Self {
+        ForwardedFromInternal {
+            chat: value.chat.map(|c| c.into()),
+            thread_root_message_index: value.thread_root_message_index,
+            message_index: value.message_index,
+            sender: value.sender,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct MembersAddedToPublicChannelInternal {
     #[serde(rename = "u")]
@@ -594,12 +695,13 @@ impl ChatMetricsInternal {
 #[cfg(test)]
 mod tests {
     use crate::{
-        ChatEventInternal, ChatInternal, DeletedByInternal, MessageContentInternal, MessageInternal, ReplyContextInternal,
-        TextContentInternal, ThreadSummaryInternal,
+        ChatEventInternal, ChatInternal, DeletedByInternal, ForwardedFromInternal, ImportedMessageDetailsInternal,
+        MessageContentInternal, MessageInternal, MessageRevisionInternal, ReplyContextInternal, TextContentInternal,
+        ThreadSummaryInternal,
     };
     use candid::Principal;
     use std::collections::{HashMap, HashSet};
-    use types::{EventWrapperInternal, Reaction, Tips};
+    use types::{ChatImportSource, EventWrapperInternal, Reaction, Tips};
 
     #[test]
     fn serialize_with_max_defaults() {
@@ -607,7 +709,10 @@ mod tests {
             message_index: 1.into(),
             message_id: 1.into(),
             sender: Principal::from_text("4bkt6-4aaaa-aaaaf-aaaiq-cai").unwrap().into(),
-            content: MessageContentInternal::Text(TextContentInternal { text: "123".to_string() }),
+            content: MessageContentInternal::Text(TextContentInternal {
+                text: "123".to_string(),
+                link_previews: Vec::new(),
+            }),
             replies_to: None,
             reactions: Vec::new(),
             tips: Tips::default(),
@@ -616,6 +721,9 @@ mod tests {
             deleted_by: None,
             thread_summary: None,
             forwarded: false,
+            forwarded_from: None,
+            edit_history: Vec::new(),
+            imported: None,
         };
 
         let message_bytes_len = msgpack::serialize_then_unwrap(&message).len();
@@ -646,7 +754,10 @@ mod tests {
             message_index: 1.into(),
             message_id: 1.into(),
             sender: principal.into(),
-            content: MessageContentInternal::Text(TextContentInternal { text: "123".to_string() }),
+            content: MessageContentInternal::Text(TextContentInternal {
+                text: "123".to_string(),
+                link_previews: Vec::new(),
+            }),
             replies_to: Some(ReplyContextInternal {
                 chat_if_other: Some((ChatInternal::Group(principal.into()), Some(1.into()))),
                 event_index: 1.into(),
@@ -667,6 +778,25 @@ mod tests {
                 latest_event_timestamp: 1,
             }),
             forwarded: true,
+            forwarded_from: Some(ForwardedFromInternal {
+                chat: Some(ChatInternal::Group(principal.into())),
+                thread_root_message_index: Some(1.into()),
+                message_index: Some(1.into()),
+                sender: principal.into(),
+                timestamp: 1,
+            }),
+            edit_history: vec![MessageRevisionInternal {
+                content: MessageContentInternal::Text(TextContentInternal {
+                    text: "123".to_string(),
+                    link_previews: Vec::new(),
+                }),
+                timestamp: 1,
+            }],
+            imported: Some(ImportedMessageDetailsInternal {
+                source: ChatImportSource::Telegram,
+                sender_name: "1".to_string(),
+                timestamp: 1,
+            }),
         };
 
         let message_bytes_len = msgpack::serialize_then_unwrap(&message).len();
@@ -682,7 +812,7 @@ mod tests {
         let event_bytes = msgpack::serialize_then_unwrap(&event);
         let event_bytes_len = event_bytes.len();
 
-        assert_eq!(message_bytes_len, 202);
+        assert_eq!(message_bytes_len, 287);
         assert_eq!(event_bytes_len, message_bytes_len + 18);
 
         let _deserialized: EventWrapperInternal<ChatEventInternal> = msgpack::deserialize_then_unwrap(&event_bytes);
@@ -13,13 +13,16 @@ use std::cmp::{max, Reverse};
 use std::collections::hash_map::Entry::{Occupied, Vacant};
 use std::collections::HashMap;
 use types::{
-    CanisterId, Chat, CompletedCryptoTransaction, Cryptocurrency, DirectChatCreated, EventIndex, EventWrapper,
-    EventsTimeToLiveUpdated, GroupCanisterThreadDetails, GroupCreated, GroupFrozen, GroupUnfrozen, Hash, HydratedMention,
-    Mention, Message, MessageContentInitial, MessageId, MessageIndex, MessageMatch, MessageReport, Milliseconds, MultiUserChat,
-    PendingCryptoTransaction, PollVotes, ProposalUpdate, PushEventResult, Reaction, RegisterVoteResult, TimestampMillis,
-    TimestampNanos, Timestamped, Tips, UserId, VoteOperation,
+    BlobReference, CanisterId, Chat, CompletedCryptoTransaction, Cryptocurrency, DirectChatCreated, EventIndex, EventWrapper,
+    EventsTimeToLiveUpdated, ForwardedFrom, GroupCanisterThreadDetails, GroupCreated, GroupFrozen, GroupUnfrozen, Hash,
+    HydratedMention, ImportedMessageDetails, LinkPreview, Mention, Message, MessageContentInitial, MessageId, MessageIndex,
+    MessageMatch, MessageReport, MessageRevision, Milliseconds, MultiUserChat, PendingCryptoTransaction, PollVotes,
+    ProposalUpdate, PushEventResult, Reaction, RegisterVoteResult, TimestampMillis, TimestampNanos, Timestamped, Tips, UserId,
+    VoteOperation,
 };
 
+const MAX_EDIT_HISTORY_REVISIONS: usize = 20;
+
 pub const OPENCHAT_BOT_USER_ID: UserId = UserId::new(Principal::from_slice(&[228, 104, 142, 9, 133, 211, 135, 217, 129, 1]));
 
 #[derive(Serialize, Deserialize)]
@@ -33,6 +36,8 @@ pub struct ChatEvents {
     events_ttl: Timestamped<Option<Milliseconds>>,
     expiring_events: ExpiringEvents,
     last_updated_timestamps: LastUpdatedTimestamps,
+    #[serde(default)]
+    edit_history_disabled: bool,
 }
 
 impl ChatEvents {
@@ -47,6 +52,7 @@ impl ChatEvents {
             events_ttl: Timestamped::new(events_ttl, now),
             expiring_events: ExpiringEvents::default(),
             last_updated_timestamps: LastUpdatedTimestamps::default(),
+            edit_history_disabled: false,
         };
 
         events.push_event(None, ChatEventInternal::DirectChatCreated(DirectChatCreated {}), 0, now);
@@ -71,6 +77,7 @@ impl ChatEvents {
             events_ttl: Timestamped::new(events_ttl, now),
             expiring_events: ExpiringEvents::default(),
             last_updated_timestamps: LastUpdatedTimestamps::default(),
+            edit_history_disabled: false,
         };
 
         events.push_event(
@@ -94,6 +101,20 @@ impl ChatEvents {
     }
 
     pub fn push_message(&mut self, args: PushMessageArgs) -> EventWrapper<Message> {
+        self.push_message_internal(args, None)
+    }
+
+    // Used when loading a chat's history from another platform, the message is marked as imported
+    // so that it can't be confused with messages sent natively
+    pub fn push_imported_message(&mut self, args: PushMessageArgs, imported: ImportedMessageDetails) -> EventWrapper<Message> {
+        self.push_message_internal(args, Some(imported.into()))
+    }
+
+    fn push_message_internal(
+        &mut self,
+        args: PushMessageArgs,
+        imported: Option<ImportedMessageDetailsInternal>,
+    ) -> EventWrapper<Message> {
         let events_list = if let Some(root_message_index) = args.thread_root_message_index {
             self.threads.entry(root_message_index).or_default()
         } else {
@@ -114,6 +135,9 @@ impl ChatEvents {
             deleted_by: None,
             thread_summary: None,
             forwarded: args.forwarded,
+            forwarded_from: args.forwarded_from.map(|f| f.into()),
+            edit_history: Vec::new(),
+            imported,
         };
 
         add_to_metrics(
@@ -156,37 +180,133 @@ impl ChatEvents {
     }
 
     pub fn edit_message(&mut self, args: EditMessageArgs) -> EditMessageResult {
-        if let Some((message, event_index)) = self.message_internal_mut(
-            args.min_visible_event_index,
-            args.thread_root_message_index,
-            args.message_id.into(),
-        ) {
-            if message.sender == args.sender {
-                if !matches!(message.content, MessageContentInternal::Deleted(_)) {
-                    message.content = args.content.into();
-                    message.last_updated = Some(args.now);
-                    message.last_edited = Some(args.now);
-                    self.last_updated_timestamps
-                        .mark_updated(args.thread_root_message_index, event_index, args.now);
+        let record_history = !self.edit_history_disabled;
+        let events_ttl = self.events_ttl.value;
 
-                    add_to_metrics(
-                        &mut self.metrics,
-                        &mut self.per_user_metrics,
-                        args.sender,
-                        |m| incr(&mut m.edits),
-                        args.now,
-                    );
+        if let Some(event) = self
+            .events_list_mut(args.min_visible_event_index, args.thread_root_message_index)
+            .and_then(|l| l.get_event_mut(args.message_id.into(), args.min_visible_event_index))
+        {
+            let event_index = event.index;
+            let event_timestamp = event.timestamp;
+
+            if let Some(message) = event.event.as_message_mut() {
+                if message.sender == args.sender {
+                    if !matches!(message.content, MessageContentInternal::Deleted(_)) {
+                        let previous_content = std::mem::replace(&mut message.content, args.content.into());
+                        if record_history {
+                            message.edit_history.push(MessageRevisionInternal {
+                                content: previous_content,
+                                timestamp: message.last_edited.unwrap_or(event_timestamp),
+                            });
+                            if let Some(ttl) = events_ttl {
+                                message.edit_history.retain(|r| r.timestamp + ttl > args.now);
+                            }
+                            if message.edit_history.len() > MAX_EDIT_HISTORY_REVISIONS {
+                                let excess = message.edit_history.len() - MAX_EDIT_HISTORY_REVISIONS;
+                                message.edit_history.drain(..excess);
+                            }
+                        }
+                        message.last_updated = Some(args.now);
+                        message.last_edited = Some(args.now);
+                        self.last_updated_timestamps
+                            .mark_updated(args.thread_root_message_index, event_index, args.now);
 
-                    return EditMessageResult::Success;
+                        add_to_metrics(
+                            &mut self.metrics,
+                            &mut self.per_user_metrics,
+                            args.sender,
+                            |m| incr(&mut m.edits),
+                            args.now,
+                        );
+
+                        return EditMessageResult::Success;
+                    }
+                } else {
+                    return EditMessageResult::NotAuthorized;
                 }
-            } else {
-                return EditMessageResult::NotAuthorized;
             }
         }
 
         EditMessageResult::NotFound
     }
 
+    // Attaches the previews fetched for the links in a text message. Previews of any links which are
+    // no longer in the text, due to the message having been edited since, are dropped. If `sender`
+    // is set, the message must have been sent by that user.
+    pub fn set_link_previews(
+        &mut self,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        sender: Option<UserId>,
+        mut link_previews: Vec<LinkPreview>,
+        now: TimestampMillis,
+    ) -> bool {
+        if let Some(event) = self
+            .events_list_mut(EventIndex::default(), thread_root_message_index)
+            .and_then(|l| l.get_event_mut(message_id.into(), EventIndex::default()))
+        {
+            let event_index = event.index;
+
+            if let Some(message) = event
+                .event
+                .as_message_mut()
+                .filter(|m| sender.map_or(true, |s| m.sender == s))
+            {
+                if let MessageContentInternal::Text(text) = &mut message.content {
+                    link_previews.retain(|p| text.text.contains(&p.url));
+                    text.link_previews = link_previews;
+                    message.last_updated = Some(now);
+                    self.last_updated_timestamps
+                        .mark_updated(thread_root_message_index, event_index, now);
+
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    // Returns each revision of the message, oldest first, ending with its current content
+    pub fn message_edit_history(
+        &self,
+        min_visible_event_index: EventIndex,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        my_user_id: Option<UserId>,
+        now: TimestampMillis,
+    ) -> Option<Vec<MessageRevision>> {
+        let event = self
+            .events_list(min_visible_event_index, thread_root_message_index)
+            .and_then(|l| l.get_event(message_id.into(), min_visible_event_index))?;
+
+        let message = event.event.as_message().filter(|m| m.deleted_by.is_none())?;
+        let events_ttl = self.events_ttl.value;
+
+        let mut revisions: Vec<_> = message
+            .edit_history
+            .iter()
+            .filter(|r| events_ttl.map_or(true, |ttl| r.timestamp + ttl > now))
+            .map(|r| r.hydrate(my_user_id))
+            .collect();
+
+        revisions.push(MessageRevision {
+            content: message.content.hydrate(my_user_id),
+            timestamp: message.last_edited.unwrap_or(event.timestamp),
+        });
+
+        Some(revisions)
+    }
+
+    pub fn edit_history_enabled(&self) -> bool {
+        !self.edit_history_disabled
+    }
+
+    pub fn set_edit_history_enabled(&mut self, enabled: bool) {
+        self.edit_history_disabled = !enabled;
+    }
+
     pub fn last_updated(&self) -> Option<TimestampMillis> {
         max(
             self.main.latest_event_timestamp(),
@@ -301,19 +421,28 @@ impl ChatEvents {
         }
     }
 
-    // The UserId returned is the message sender
+    // The UserId returned is the message sender. The message's edit history is removed along with its content, so the
+    // blob references returned include those of any previous revisions.
     pub fn remove_deleted_message_content(
         &mut self,
         thread_root_message_index: Option<MessageIndex>,
         message_id: MessageId,
-    ) -> Option<(MessageContentInternal, UserId)> {
+    ) -> Option<(MessageContentInternal, UserId, Vec<BlobReference>)> {
         let (message, _) = self.message_internal_mut(EventIndex::default(), thread_root_message_index, message_id.into())?;
 
         let deleted_by = message.deleted_by.clone()?;
 
         let content = std::mem::replace(&mut message.content, MessageContentInternal::Deleted(deleted_by));
+        let edit_history = std::mem::take(&mut message.edit_history);
+
+        let mut files = content.blob_references();
+        for blob_reference in edit_history.into_iter().flat_map(|r| r.content.blob_references()) {
+            if !files.contains(&blob_reference) {
+                files.push(blob_reference);
+            }
+        }
 
-        Some((content, message.sender))
+        Some((content, message.sender, files))
     }
 
     pub fn register_poll_vote(&mut self, args: RegisterPollVoteArgs) -> RegisterPollVoteResult {
@@ -647,6 +776,7 @@ impl ChatEvents {
                         mentioned: Vec::new(),
                         replies_to: None,
                         forwarded: false,
+                        forwarded_from: None,
                         correlation_id: 0,
                         now,
                     });
@@ -763,6 +893,7 @@ impl ChatEvents {
                     event_index,
                 }),
                 forwarded: false,
+                forwarded_from: None,
                 correlation_id: 0,
                 now,
             });
@@ -1321,6 +1452,7 @@ pub struct PushMessageArgs {
     pub mentioned: Vec<UserId>,
     pub replies_to: Option<ReplyContextInternal>,
     pub forwarded: bool,
+    pub forwarded_from: Option<ForwardedFrom>,
     pub correlation_id: u64,
     pub now: TimestampMillis,
 }
@@ -429,6 +429,8 @@ pub trait Reader {
             ChatEventInternal::GroupGateUpdated(g) => ChatEvent::GroupGateUpdated(*g.clone()),
             ChatEventInternal::UsersInvited(e) => ChatEvent::UsersInvited(*e.clone()),
             ChatEventInternal::MembersAddedToPublicChannel(m) => ChatEvent::MembersAddedToDefaultChannel(m.as_ref().into()),
+            ChatEventInternal::MemberMuted(m) => ChatEvent::MemberMuted(*m.clone()),
+            ChatEventInternal::MemberUnmuted(m) => ChatEvent::MemberUnmuted(*m.clone()),
             ChatEventInternal::Empty => ChatEvent::Empty,
         };
 
@@ -568,10 +570,13 @@ impl<'a, I: Iterator<Item = &'a EventWrapperInternal<ChatEventInternal>>> Iterat
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{ChatEvents, MessageContentInternal, PushMessageArgs, TextContentInternal};
+    use crate::{
+        ChatEvents, DeleteUndeleteMessagesArgs, EditMessageArgs, EditMessageResult, MessageContentInternal, PushMessageArgs,
+        TextContentInternal,
+    };
     use candid::Principal;
     use std::mem::size_of;
-    use types::{EventsTimeToLiveUpdated, Milliseconds};
+    use types::{EventsTimeToLiveUpdated, LinkPreview, MessageContent, MessageContentInitial, Milliseconds, TextContent};
 
     #[test]
     fn enum_size() {
@@ -717,6 +722,121 @@ mod tests {
         assert_eq!(event_indexes, (46..=70).map(|i| i.into()).collect_vec());
     }
 
+    #[test]
+    fn edit_history() {
+        let mut events = setup_events(None);
+        let sender = Principal::from_slice(&[2]).into();
+        let message_id = MessageId::from(0u128);
+
+        for (i, text) in ["one", "two"].into_iter().enumerate() {
+            let result = events.edit_message(EditMessageArgs {
+                sender,
+                min_visible_event_index: EventIndex::default(),
+                thread_root_message_index: None,
+                message_id,
+                content: MessageContentInitial::Text(TextContent {
+                    text: text.to_string(),
+                    link_previews: None,
+                }),
+                now: 10 + i as TimestampMillis,
+            });
+            assert!(matches!(result, EditMessageResult::Success));
+        }
+
+        let revisions = events
+            .message_edit_history(EventIndex::default(), None, message_id, None, 20)
+            .unwrap();
+
+        let texts: Vec<_> = revisions
+            .iter()
+            .map(|r| match &r.content {
+                MessageContent::Text(t) => t.text.as_str(),
+                _ => panic!(),
+            })
+            .collect();
+        let timestamps: Vec<_> = revisions.iter().map(|r| r.timestamp).collect();
+
+        assert_eq!(texts, vec!["hello", "one", "two"]);
+        assert_eq!(timestamps, vec![0, 10, 11]);
+    }
+
+    #[test]
+    fn hard_deleting_message_removes_edit_history() {
+        let mut events = setup_events(None);
+        let sender = Principal::from_slice(&[2]).into();
+        let message_id = MessageId::from(0u128);
+
+        events.edit_message(EditMessageArgs {
+            sender,
+            min_visible_event_index: EventIndex::default(),
+            thread_root_message_index: None,
+            message_id,
+            content: MessageContentInitial::Text(TextContent {
+                text: "edited".to_string(),
+                link_previews: None,
+            }),
+            now: 10,
+        });
+        events.delete_messages(DeleteUndeleteMessagesArgs {
+            caller: sender,
+            is_admin: false,
+            min_visible_event_index: EventIndex::default(),
+            thread_root_message_index: None,
+            message_ids: vec![message_id],
+            now: 11,
+        });
+
+        assert!(events.remove_deleted_message_content(None, message_id).is_some());
+
+        let message = events.main_events_reader().message_internal(message_id.into()).unwrap();
+        assert!(matches!(message.content, MessageContentInternal::Deleted(_)));
+        assert!(message.edit_history.is_empty());
+    }
+
+    #[test]
+    fn set_link_previews() {
+        let mut events = setup_events(None);
+        let sender = Principal::from_slice(&[2]).into();
+        let message_id = MessageId::from(0u128);
+
+        events.edit_message(EditMessageArgs {
+            sender,
+            min_visible_event_index: EventIndex::default(),
+            thread_root_message_index: None,
+            message_id,
+            content: MessageContentInitial::Text(TextContent {
+                text: "See https://oc.app".to_string(),
+                link_previews: None,
+            }),
+            now: 10,
+        });
+
+        let preview = |url: &str| LinkPreview {
+            url: url.to_string(),
+            title: Some("Title".to_string()),
+            description: None,
+            image: None,
+        };
+        let link_previews = vec![preview("https://oc.app"), preview("https://example.com")];
+
+        assert!(!events.set_link_previews(
+            None,
+            message_id,
+            Some(Principal::from_slice(&[3]).into()),
+            link_previews.clone(),
+            20
+        ));
+        assert!(events.set_link_previews(None, message_id, Some(sender), link_previews, 20));
+
+        let message = events.main_events_reader().message(message_id.into(), None).unwrap();
+
+        match message.content {
+            MessageContent::Text(t) => assert_eq!(t.link_previews, Some(vec![preview("https://oc.app")])),
+            _ => panic!(),
+        }
+        assert_eq!(message.last_updated, Some(20));
+    }
+
     fn setup_events(events_ttl: Option<Milliseconds>) -> ChatEvents {
         let mut events = ChatEvents::new_direct_chat(events_ttl, 1);
 
@@ -736,11 +856,13 @@ mod tests {
                 message_id,
                 content: MessageContentInternal::Text(TextContentInternal {
                     text: "hello".to_string(),
+                    link_previews: Vec::new(),
                 }),
                 mentioned: Vec::new(),
                 replies_to: None,
                 now,
                 forwarded: false,
+                forwarded_from: None,
                 correlation_id: i,
             });
             events.push_main_event(
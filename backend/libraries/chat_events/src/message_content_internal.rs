@@ -6,11 +6,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use types::{
     is_default, is_empty_hashmap, is_empty_hashset, is_empty_slice, AudioContent, BlobReference, CanisterId,
-    CompletedCryptoTransaction, CryptoContent, CryptoTransaction, CustomContent, FileContent, GiphyContent, GiphyImageVariant,
-    ImageContent, MessageContent, MessageContentInitial, MessageIndex, MessageReminderContent, MessageReminderCreatedContent,
-    MessageReport, PendingCryptoTransaction, PollConfig, PollContent, PollVotes, PrizeContent, PrizeContentInitial,
-    PrizeWinnerContent, Proposal, ProposalContent, RegisterVoteResult, ReportedMessage, TextContent, ThumbnailData,
-    TimestampMillis, TimestampNanos, TotalVotes, UserId, VideoContent, VoteOperation,
+    CompletedCryptoTransaction, CryptoContent, CryptoTransaction, CustomContent, EmojiPackId, FileContent, GiphyContent,
+    GiphyImageVariant, ImageContent, LinkPreview, MessageContent, MessageContentInitial, MessageIndex, MessageReminderContent,
+    MessageReminderCreatedContent, MessageReport, PendingCryptoTransaction, PollConfig, PollContent, PollVotes, PrizeContent,
+    PrizeContentInitial, PrizeWinnerContent, Proposal, ProposalContent, RegisterVoteResult, ReportedMessage, StickerContent,
+    TextContent, ThumbnailData, TimestampMillis, TimestampNanos, TotalVotes, UserId, VideoContent, VoteOperation,
 };
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -47,6 +47,8 @@ pub enum MessageContentInternal {
     ReportedMessage(ReportedMessageInternal),
     #[serde(rename = "cu")]
     Custom(CustomContentInternal),
+    #[serde(rename = "st")]
+    Sticker(StickerContentInternal),
 }
 
 impl MessageContentInternal {
@@ -68,6 +70,7 @@ impl MessageContentInternal {
             MessageContentInternal::MessageReminder(r) => MessageContent::MessageReminder(r.hydrate(my_user_id)),
             MessageContentInternal::ReportedMessage(r) => MessageContent::ReportedMessage(r.hydrate(my_user_id)),
             MessageContentInternal::Custom(c) => MessageContent::Custom(c.hydrate(my_user_id)),
+            MessageContentInternal::Sticker(s) => MessageContent::Sticker(s.hydrate(my_user_id)),
         }
     }
 
@@ -88,7 +91,8 @@ impl MessageContentInternal {
             MessageContentInternal::PrizeWinner(_)
             | MessageContentInternal::Deleted(_)
             | MessageContentInternal::ReportedMessage(_)
-            | MessageContentInternal::Custom(_) => None,
+            | MessageContentInternal::Custom(_)
+            | MessageContentInternal::Sticker(_) => None,
         }
     }
 
@@ -119,8 +123,10 @@ impl MessageContentInternal {
                     references.push(br);
                 }
             }
-            MessageContentInternal::Text(_)
-            | MessageContentInternal::Poll(_)
+            MessageContentInternal::Text(t) => {
+                references.extend(t.link_previews.iter().filter_map(|p| p.image.clone()));
+            }
+            MessageContentInternal::Poll(_)
             | MessageContentInternal::Crypto(_)
             | MessageContentInternal::Deleted(_)
             | MessageContentInternal::Giphy(_)
@@ -130,7 +136,8 @@ impl MessageContentInternal {
             | MessageContentInternal::MessageReminderCreated(_)
             | MessageContentInternal::MessageReminder(_)
             | MessageContentInternal::ReportedMessage(_)
-            | MessageContentInternal::Custom(_) => {}
+            | MessageContentInternal::Custom(_)
+            | MessageContentInternal::Sticker(_) => {}
         }
 
         references
@@ -154,6 +161,7 @@ impl From<MessageContentInitial> for MessageContentInternal {
             MessageContentInitial::MessageReminderCreated(r) => MessageContentInternal::MessageReminderCreated(r.into()),
             MessageContentInitial::MessageReminder(r) => MessageContentInternal::MessageReminder(r.into()),
             MessageContentInitial::Custom(c) => MessageContentInternal::Custom(c.into()),
+            MessageContentInitial::Sticker(s) => MessageContentInternal::Sticker(s.into()),
         }
     }
 }
@@ -216,6 +224,9 @@ impl From<&MessageContentInternal> for Document {
             MessageContentInternal::Custom(c) => {
                 document.add_field(c.kind.clone(), 1.0, false);
             }
+            MessageContentInternal::Sticker(s) => {
+                document.add_field(s.shortcode.clone(), 1.0, false);
+            }
             MessageContentInternal::ReportedMessage(_) | MessageContentInternal::Deleted(_) => {}
         }
 
@@ -234,11 +245,17 @@ pub(crate) trait MessageContentInternalSubtype: From<Self::ContentTypeInitial> {
 pub struct TextContentInternal {
     #[serde(rename = "t", alias = "text")]
     pub text: String,
+    #[serde(rename = "l", default, skip_serializing_if = "is_empty_slice")]
+    pub link_previews: Vec<LinkPreview>,
 }
 
 impl From<TextContent> for TextContentInternal {
     fn from(value: TextContent) -> Self {
-        TextContentInternal { text: value.text }
+        // Link previews are only ever set by the server, via `ChatEvents::set_link_previews`
+        TextContentInternal {
+            text: value.text,
+            link_previews: Vec::new(),
+        }
     }
 }
 
@@ -247,7 +264,10 @@ impl MessageContentInternalSubtype for TextContentInternal {
     type ContentTypeInitial = TextContent;
 
     fn hydrate(&self, _my_user_id: Option<UserId>) -> Self::ContentType {
-        TextContent { text: self.text.clone() }
+        TextContent {
+            text: self.text.clone(),
+            link_previews: (!self.link_previews.is_empty()).then(|| self.link_previews.clone()),
+        }
     }
 }
 
@@ -896,3 +916,37 @@ impl MessageContentInternalSubtype for CustomContentInternal {
         }
     }
 }
+
+// Only references the pack asset, so the image isn't deleted along with the message
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StickerContentInternal {
+    #[serde(rename = "p")]
+    pub pack_id: EmojiPackId,
+    #[serde(rename = "s")]
+    pub shortcode: String,
+    #[serde(rename = "b", default, skip_serializing_if = "Option::is_none")]
+    pub blob_reference: Option<BlobReference>,
+}
+
+impl From<StickerContent> for StickerContentInternal {
+    fn from(value: StickerContent) -> Self {
+        StickerContentInternal {
+            pack_id: value.pack_id,
+            shortcode: value.shortcode,
+            blob_reference: value.blob_reference,
+        }
+    }
+}
+
+impl MessageContentInternalSubtype for StickerContentInternal {
+    type ContentType = StickerContent;
+    type ContentTypeInitial = StickerContent;
+
+    fn hydrate(&self, _my_user_id: Option<UserId>) -> Self::ContentType {
+        StickerContent {
+            pack_id: self.pack_id,
+            shortcode: self.shortcode.clone(),
+            blob_reference: self.blob_reference.clone(),
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::schema::{ChatExport, ExportedChat, ExportedMessage, ExportedReaction, ExportedUser, SCHEMA_VERSION};
+use chat_events::{ChatEvents, EventKey, MessageInternal, Reader};
+use std::collections::BTreeSet;
+use types::{EventIndex, MessageIndex, TimestampMillis, UserId};
+
+// Measured as the JSON size of the exported messages. This leaves room for the rendered page to grow
+// (eg. due to escaping in the HTML format) while staying within the message size limit.
+pub(crate) const MAX_BYTES_PER_PAGE: usize = 500_000;
+
+/// Builds a single page of an export from the chat's events.
+///
+/// Messages are taken from the main timeline, starting at `from_message_index` (or the earliest
+/// visible message), until `max_messages` have been included or the page reaches its size limit.
+/// Thread replies count towards both limits, so a large thread may be split across pages, in which
+/// case the next page starts with the thread's root message again, carrying the remaining replies
+/// from `from_thread_message_index`. Deleted and expired messages are skipped.
+///
+/// The returned export has its `users` populated with user ids only, call `set_user_details` once
+/// the usernames have been resolved.
+pub fn build_export(
+    events: &ChatEvents,
+    chat: ExportedChat,
+    min_visible_event_index: EventIndex,
+    from_message_index: Option<MessageIndex>,
+    from_thread_message_index: Option<MessageIndex>,
+    max_messages: usize,
+    now: TimestampMillis,
+) -> ChatExport {
+    let events_reader = events.visible_main_events_reader(min_visible_event_index);
+    let max_messages = max_messages.max(1);
+
+    let mut messages = Vec::new();
+    let mut message_count = 0;
+    let mut total_bytes = 0;
+    let mut next_message_index = None;
+    let mut next_thread_message_index = None;
+
+    'messages: for (message, timestamp) in events_reader
+        .iter_events(from_message_index.map(EventKey::MessageIndex), true)
+        .filter(|e| !e.is_expired(now))
+        .filter_map(|e| e.event.as_message().map(|m| (m, e.timestamp)))
+        .filter(|(m, _)| m.deleted_by.is_none())
+    {
+        if message_count >= max_messages {
+            next_message_index = Some(message.message_index);
+            break;
+        }
+
+        let mut exported = export_message(message, timestamp, &events_reader, now);
+        let mut message_bytes = serialized_size(&exported);
+
+        // Each page includes at least one message so that the export always makes progress
+        if !messages.is_empty() && total_bytes + message_bytes > MAX_BYTES_PER_PAGE {
+            next_message_index = Some(message.message_index);
+            break;
+        }
+
+        // Only the first message of the page can be a thread which was split across the previous page
+        let first_reply =
+            from_thread_message_index.filter(|_| messages.is_empty() && from_message_index == Some(message.message_index));
+        // Always include at least one reply so that each page makes progress through the thread
+        let max_replies = max_messages.saturating_sub(message_count + 1).max(1);
+
+        if let Some(thread_reader) = message
+            .thread_summary
+            .as_ref()
+            .and_then(|_| events.events_reader(min_visible_event_index, Some(message.message_index)))
+        {
+            for (reply, timestamp) in thread_reader
+                .iter_events(first_reply.map(EventKey::MessageIndex), true)
+                .filter(|e| !e.is_expired(now))
+                .filter_map(|e| e.event.as_message().map(|m| (m, e.timestamp)))
+                .filter(|(m, _)| m.deleted_by.is_none())
+            {
+                let exported_reply = export_message(reply, timestamp, &thread_reader, now);
+                let reply_bytes = serialized_size(&exported_reply);
+                let over_size_limit = total_bytes + message_bytes + reply_bytes > MAX_BYTES_PER_PAGE;
+
+                if exported.thread.is_empty() && over_size_limit && !messages.is_empty() {
+                    // Start the thread on the next page rather than splitting it after its root message
+                    next_message_index = Some(message.message_index);
+                    break 'messages;
+                }
+                if exported.thread.len() >= max_replies || (!exported.thread.is_empty() && over_size_limit) {
+                    next_thread_message_index = Some(reply.message_index);
+                    break;
+                }
+                message_bytes += reply_bytes;
+                exported.thread.push(exported_reply);
+            }
+        }
+
+        message_count += 1 + exported.thread.len();
+        total_bytes += message_bytes;
+        messages.push(exported);
+
+        if next_thread_message_index.is_some() {
+            next_message_index = Some(message.message_index);
+            break;
+        }
+    }
+
+    let mut export = ChatExport {
+        schema_version: SCHEMA_VERSION,
+        chat,
+        exported_at: now,
+        users: Vec::new(),
+        messages,
+        next_message_index,
+        next_thread_message_index,
+    };
+
+    export.users = export
+        .user_ids()
+        .into_iter()
+        .map(|user_id| ExportedUser {
+            user_id,
+            username: None,
+            display_name: None,
+        })
+        .collect();
+
+    export
+}
+
+impl ChatExport {
+    /// The ids of all users who sent a message included in this export
+    pub fn user_ids(&self) -> BTreeSet<UserId> {
+        fn collect(messages: &[ExportedMessage], user_ids: &mut BTreeSet<UserId>) {
+            for message in messages {
+                user_ids.insert(message.sender);
+                collect(&message.thread, user_ids);
+            }
+        }
+
+        let mut user_ids = BTreeSet::new();
+        collect(&self.messages, &mut user_ids);
+        user_ids
+    }
+
+    pub fn set_user_details(&mut self, user_id: UserId, username: String, display_name: Option<String>) {
+        if let Some(user) = self.users.iter_mut().find(|u| u.user_id == user_id) {
+            user.username = Some(username);
+            user.display_name = display_name;
+        }
+    }
+}
+
+// Includes a byte for the separator between messages
+fn serialized_size(message: &ExportedMessage) -> usize {
+    serde_json::to_vec(message).map_or(0, |bytes| bytes.len()) + 1
+}
+
+fn export_message<R: Reader>(
+    message: &MessageInternal,
+    timestamp: TimestampMillis,
+    events_reader: &R,
+    now: TimestampMillis,
+) -> ExportedMessage {
+    let content = message.content.hydrate(None);
+
+    // Replies to messages in other chats aren't resolvable from here so are omitted
+    let replies_to = message
+        .replies_to
+        .as_ref()
+        .filter(|r| r.chat_if_other.is_none())
+        .and_then(|r| events_reader.get_event(EventKey::EventIndex(r.event_index)))
+        .filter(|e| !e.is_expired(now))
+        .and_then(|e| e.event.as_message())
+        .map(|m| m.message_index);
+
+    ExportedMessage {
+        message_index: message.message_index,
+        message_id: message.message_id.to_string(),
+        sender: message.sender,
+        timestamp,
+        edited: message.last_edited,
+        content_type: content.message_type(),
+        text: content.text().map(|t| t.to_string()),
+        files: content.blob_references().iter().map(|b| b.url()).collect(),
+        replies_to,
+        forwarded: message.forwarded,
+        reactions: message
+            .reactions
+            .iter()
+            .map(|(reaction, users)| ExportedReaction {
+                reaction: reaction.clone(),
+                count: users.len() as u32,
+            })
+            .collect(),
+        thread: Vec::new(),
+    }
+}
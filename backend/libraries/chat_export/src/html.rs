@@ -0,0 +1,124 @@
+use crate::{continues_from, format_timestamp, user_names, ChatExport, ExportedMessage};
+use std::collections::HashMap;
+use std::fmt::Write;
+use types::UserId;
+
+// Everything is inlined so that the exported file can be opened without any network access
+// (other than for attached files, which are linked to rather than embedded)
+const STYLES: &str = "body{font-family:sans-serif;max-width:800px;margin:0 auto;padding:16px;color:#222}\
+.message{border-bottom:1px solid #ddd;padding:8px 0}\
+.header{font-size:0.85em;color:#666}\
+.sender{font-weight:bold;color:#222}\
+.text{white-space:pre-wrap;margin:4px 0}\
+.reactions{font-size:0.85em}\
+.thread{margin-left:24px;border-left:3px solid #ddd;padding-left:12px}";
+
+pub fn render(export: &ChatExport) -> String {
+    let user_names = user_names(export);
+    let title = escape(&export.chat.name);
+    let mut output = String::new();
+
+    writeln!(output, "<!DOCTYPE html>").unwrap();
+    writeln!(output, "<html>").unwrap();
+    writeln!(output, "<head>").unwrap();
+    writeln!(output, "<meta charset=\"utf-8\">").unwrap();
+    writeln!(output, "<title>{title}</title>").unwrap();
+    writeln!(output, "<style>{STYLES}</style>").unwrap();
+    writeln!(output, "</head>").unwrap();
+    writeln!(output, "<body>").unwrap();
+    writeln!(output, "<h1>{title}</h1>").unwrap();
+    writeln!(
+        output,
+        "<p class=\"header\">Exported {}</p>",
+        format_timestamp(export.exported_at)
+    )
+    .unwrap();
+
+    for message in export.messages.iter() {
+        render_message(message, &user_names, &mut output);
+    }
+
+    if let Some(next) = continues_from(export) {
+        writeln!(output, "<p class=\"header\">Continues from {next}</p>").unwrap();
+    }
+
+    writeln!(output, "</body>").unwrap();
+    writeln!(output, "</html>").unwrap();
+    output
+}
+
+fn render_message(message: &ExportedMessage, user_names: &HashMap<UserId, String>, output: &mut String) {
+    let sender = user_names
+        .get(&message.sender)
+        .cloned()
+        .unwrap_or_else(|| message.sender.to_string());
+
+    writeln!(output, "<div class=\"message\" id=\"m{}\">", message.message_index).unwrap();
+
+    write!(
+        output,
+        "<div class=\"header\"><span class=\"sender\">{}</span> · {}",
+        escape(&sender),
+        format_timestamp(message.timestamp)
+    )
+    .unwrap();
+    if message.edited.is_some() {
+        write!(output, " · edited").unwrap();
+    }
+    if message.forwarded {
+        write!(output, " · forwarded").unwrap();
+    }
+    if let Some(replies_to) = message.replies_to {
+        write!(output, " · in reply to #{replies_to}").unwrap();
+    }
+    writeln!(output, "</div>").unwrap();
+
+    match message.text.as_deref() {
+        Some(text) => writeln!(output, "<div class=\"text\">{}</div>", escape(text)).unwrap(),
+        None => writeln!(
+            output,
+            "<div class=\"text\"><em>[{}]</em></div>",
+            escape(&message.content_type)
+        )
+        .unwrap(),
+    }
+
+    for file in message.files.iter() {
+        let file = escape(file);
+        writeln!(output, "<div><a href=\"{file}\">{file}</a></div>").unwrap();
+    }
+
+    if !message.reactions.is_empty() {
+        let reactions: Vec<_> = message
+            .reactions
+            .iter()
+            .map(|r| format!("{} {}", escape(&r.reaction.to_string()), r.count))
+            .collect();
+        writeln!(output, "<div class=\"reactions\">{}</div>", reactions.join(" · ")).unwrap();
+    }
+
+    if !message.thread.is_empty() {
+        writeln!(output, "<div class=\"thread\">").unwrap();
+        for reply in message.thread.iter() {
+            render_message(reply, user_names, output);
+        }
+        writeln!(output, "</div>").unwrap();
+    }
+
+    writeln!(output, "</div>").unwrap();
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,242 @@
+mod builder;
+mod html;
+mod markdown;
+mod schema;
+
+pub use builder::build_export;
+pub use schema::*;
+
+use std::collections::HashMap;
+use time::OffsetDateTime;
+use types::{ExportFormat, TimestampMillis, UserId};
+
+pub fn render(export: &ChatExport, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Json => serde_json::to_string_pretty(export).unwrap(),
+        ExportFormat::Markdown => markdown::render(export),
+        ExportFormat::Html => html::render(export),
+    }
+}
+
+// Falls back to the user id for any users whose usernames couldn't be resolved
+fn user_names(export: &ChatExport) -> HashMap<UserId, String> {
+    export
+        .users
+        .iter()
+        .map(|u| {
+            let name = match (&u.display_name, &u.username) {
+                (Some(display_name), Some(username)) => format!("{display_name} (@{username})"),
+                (None, Some(username)) => format!("@{username}"),
+                _ => u.user_id.to_string(),
+            };
+            (u.user_id, name)
+        })
+        .collect()
+}
+
+// Describes where the next page of the export starts
+fn continues_from(export: &ChatExport) -> Option<String> {
+    let next = export.next_message_index?;
+
+    Some(match export.next_thread_message_index {
+        Some(reply) => format!("message #{next}, thread reply #{reply}"),
+        None => format!("message #{next}"),
+    })
+}
+
+fn format_timestamp(timestamp: TimestampMillis) -> String {
+    match OffsetDateTime::from_unix_timestamp_nanos(timestamp as i128 * 1_000_000) {
+        Ok(datetime) => format!(
+            "{:04}-{:02}-{:02} {:02}:{:02} UTC",
+            datetime.year(),
+            datetime.month() as u8,
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute()
+        ),
+        Err(_) => timestamp.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+    use chat_events::{ChatEvents, MessageContentInternal, PushMessageArgs, TextContentInternal};
+    use types::{EventIndex, MessageId, MessageIndex};
+
+    #[test]
+    fn threads_are_nested_beneath_their_root_messages() {
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+        let mut events = ChatEvents::new_group_chat("Group".to_string(), String::new(), user_id, None, 0);
+
+        for i in 0..3u64 {
+            push_message(&mut events, user_id, None, i, "<b>hello</b>");
+        }
+        push_message(&mut events, user_id, Some(0.into()), 10, "reply 1");
+        push_message(&mut events, user_id, Some(0.into()), 11, "reply 2");
+
+        let chat = ExportedChat {
+            kind: ExportedChatKind::Group,
+            id: "group".to_string(),
+            name: "Group".to_string(),
+        };
+        let mut export = build_export(&events, chat, EventIndex::default(), None, None, 3, 100);
+
+        assert_eq!(export.messages.len(), 1);
+        assert_eq!(export.messages[0].thread.len(), 2);
+        assert_eq!(export.next_message_index, Some(1.into()));
+        assert_eq!(export.next_thread_message_index, None);
+        assert_eq!(export.users.len(), 1);
+
+        export.set_user_details(user_id, "alice".to_string(), None);
+        assert!(render(&export, ExportFormat::Markdown).contains("@alice"));
+
+        let html = render(&export, ExportFormat::Html);
+        assert!(html.contains("&lt;b&gt;hello&lt;/b&gt;"));
+        assert!(!html.contains("<b>hello</b>"));
+    }
+
+    #[test]
+    fn large_threads_are_split_across_pages() {
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+        let mut events = ChatEvents::new_group_chat("Group".to_string(), String::new(), user_id, None, 0);
+
+        push_message(&mut events, user_id, None, 0, "root");
+        push_message(&mut events, user_id, None, 1, "next");
+        for i in 10..15u64 {
+            push_message(&mut events, user_id, Some(0.into()), i, "reply");
+        }
+
+        let chat = ExportedChat {
+            kind: ExportedChatKind::Group,
+            id: "group".to_string(),
+            name: "Group".to_string(),
+        };
+
+        let export = build_export(&events, chat.clone(), EventIndex::default(), None, None, 3, 100);
+        assert_eq!(export.messages.len(), 1);
+        assert_eq!(export.messages[0].thread.len(), 2);
+        assert_eq!(export.next_message_index, Some(0.into()));
+        assert_eq!(export.next_thread_message_index, Some(2.into()));
+
+        let export = build_export(
+            &events,
+            chat,
+            EventIndex::default(),
+            export.next_message_index,
+            export.next_thread_message_index,
+            5,
+            100,
+        );
+        assert_eq!(export.messages.len(), 2);
+        let reply_indexes: Vec<_> = export.messages[0].thread.iter().map(|m| m.message_index).collect();
+        assert_eq!(reply_indexes, vec![2.into(), 3.into(), 4.into()]);
+        assert_eq!(export.messages[1].message_index, 1.into());
+        assert_eq!(export.next_message_index, None);
+        assert_eq!(export.next_thread_message_index, None);
+    }
+
+    #[test]
+    fn pages_end_once_they_reach_the_size_limit() {
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+        let mut events = ChatEvents::new_group_chat("Group".to_string(), String::new(), user_id, None, 0);
+        let long_text = "x".repeat(9_000);
+
+        for i in 0..100u64 {
+            push_message(&mut events, user_id, None, i, &long_text);
+        }
+
+        let chat = ExportedChat {
+            kind: ExportedChatKind::Group,
+            id: "group".to_string(),
+            name: "Group".to_string(),
+        };
+
+        let export = build_export(&events, chat.clone(), EventIndex::default(), None, None, 500, 100);
+        let page_size = export.messages.len();
+        assert!(page_size > 1 && page_size < 100);
+        assert!(json_size(&export.messages) <= builder::MAX_BYTES_PER_PAGE);
+        assert_eq!(export.next_message_index, Some((page_size as u32).into()));
+        assert_eq!(export.next_thread_message_index, None);
+
+        let export = build_export(
+            &events,
+            chat,
+            EventIndex::default(),
+            export.next_message_index,
+            None,
+            500,
+            100,
+        );
+        assert_eq!(export.messages.len(), 100 - page_size);
+        assert_eq!(export.next_message_index, None);
+    }
+
+    #[test]
+    fn threads_with_long_replies_are_split_by_size() {
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+        let mut events = ChatEvents::new_group_chat("Group".to_string(), String::new(), user_id, None, 0);
+        let long_text = "x".repeat(9_000);
+
+        push_message(&mut events, user_id, None, 0, "root");
+        for i in 10..110u64 {
+            push_message(&mut events, user_id, Some(0.into()), i, &long_text);
+        }
+
+        let chat = ExportedChat {
+            kind: ExportedChatKind::Group,
+            id: "group".to_string(),
+            name: "Group".to_string(),
+        };
+
+        let export = build_export(&events, chat.clone(), EventIndex::default(), None, None, 500, 100);
+        assert_eq!(export.messages.len(), 1);
+        let replies = export.messages[0].thread.len();
+        assert!(replies > 1 && replies < 100);
+        assert!(json_size(&export.messages) <= builder::MAX_BYTES_PER_PAGE);
+        assert_eq!(export.next_message_index, Some(0.into()));
+        assert_eq!(export.next_thread_message_index, Some((replies as u32).into()));
+
+        let export = build_export(
+            &events,
+            chat,
+            EventIndex::default(),
+            export.next_message_index,
+            export.next_thread_message_index,
+            500,
+            100,
+        );
+        assert_eq!(export.messages[0].thread.len(), 100 - replies);
+        assert_eq!(export.next_message_index, None);
+        assert_eq!(export.next_thread_message_index, None);
+    }
+
+    fn json_size(messages: &[ExportedMessage]) -> usize {
+        messages.iter().map(|m| serde_json::to_vec(m).unwrap().len()).sum()
+    }
+
+    fn push_message(
+        events: &mut ChatEvents,
+        sender: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        id: u64,
+        text: &str,
+    ) {
+        events.push_message(PushMessageArgs {
+            sender,
+            thread_root_message_index,
+            message_id: MessageId::from(id as u128),
+            content: MessageContentInternal::Text(TextContentInternal {
+                text: text.to_string(),
+                link_previews: Vec::new(),
+            }),
+            mentioned: Vec::new(),
+            replies_to: None,
+            now: id,
+            forwarded: false,
+            forwarded_from: None,
+            correlation_id: 0,
+        });
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{continues_from, format_timestamp, user_names, ChatExport, ExportedMessage};
+use std::collections::HashMap;
+use std::fmt::Write;
+use types::UserId;
+
+pub fn render(export: &ChatExport) -> String {
+    let user_names = user_names(export);
+    let mut output = String::new();
+
+    writeln!(output, "# {}", export.chat.name).unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "_Exported {}_", format_timestamp(export.exported_at)).unwrap();
+
+    for message in export.messages.iter() {
+        writeln!(output).unwrap();
+        writeln!(output, "---").unwrap();
+        writeln!(output).unwrap();
+        render_message(message, &user_names, "", &mut output);
+
+        for reply in message.thread.iter() {
+            writeln!(output, ">").unwrap();
+            render_message(reply, &user_names, "> ", &mut output);
+        }
+    }
+
+    if let Some(next) = continues_from(export) {
+        writeln!(output).unwrap();
+        writeln!(output, "---").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "_Continues from {next}_").unwrap();
+    }
+
+    output
+}
+
+fn render_message(message: &ExportedMessage, user_names: &HashMap<UserId, String>, prefix: &str, output: &mut String) {
+    let sender = user_names
+        .get(&message.sender)
+        .cloned()
+        .unwrap_or_else(|| message.sender.to_string());
+
+    let mut header = format!("**{sender}** · {}", format_timestamp(message.timestamp));
+    if message.edited.is_some() {
+        header.push_str(" · _edited_");
+    }
+    if message.forwarded {
+        header.push_str(" · _forwarded_");
+    }
+    writeln!(output, "{prefix}{header} · #{}", message.message_index).unwrap();
+
+    if let Some(replies_to) = message.replies_to {
+        writeln!(output, "{prefix}_In reply to #{replies_to}_").unwrap();
+    }
+
+    writeln!(output, "{prefix}").unwrap();
+    match message.text.as_deref() {
+        Some(text) => {
+            for line in text.lines() {
+                writeln!(output, "{prefix}{line}").unwrap();
+            }
+        }
+        None => writeln!(output, "{prefix}_[{}]_", message.content_type).unwrap(),
+    }
+
+    for file in message.files.iter() {
+        writeln!(output, "{prefix}").unwrap();
+        writeln!(output, "{prefix}<{file}>").unwrap();
+    }
+
+    if !message.reactions.is_empty() {
+        let reactions: Vec<_> = message
+            .reactions
+            .iter()
+            .map(|r| format!("{} {}", r.reaction, r.count))
+            .collect();
+        writeln!(output, "{prefix}").unwrap();
+        writeln!(output, "{prefix}{}", reactions.join(" · ")).unwrap();
+    }
+}
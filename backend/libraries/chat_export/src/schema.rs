@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use types::{MessageIndex, Reaction, TimestampMillis, UserId};
+
+// Bump this whenever a breaking change is made to the structs below
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The root of an export. A single export covers a page of the chat's main timeline, each message
+/// carrying its thread replies nested beneath it. If `next_message_index` is set, further pages can
+/// be requested starting from that message index. If `next_thread_message_index` is also set then
+/// the thread of that message was split and the next page should start from that thread reply.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ChatExport {
+    pub schema_version: u32,
+    pub chat: ExportedChat,
+    pub exported_at: TimestampMillis,
+    /// Every user who sent a message included in this export
+    pub users: Vec<ExportedUser>,
+    pub messages: Vec<ExportedMessage>,
+    pub next_message_index: Option<MessageIndex>,
+    pub next_thread_message_index: Option<MessageIndex>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportedChat {
+    pub kind: ExportedChatKind,
+    /// The textual canister id of the group or community, or the user id of the other party in a
+    /// direct chat. For channels this is suffixed with "/{channel_id}".
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportedChatKind {
+    Direct,
+    Group,
+    Channel,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportedUser {
+    pub user_id: UserId,
+    /// Only absent if the username could not be resolved at the time of the export
+    pub username: Option<String>,
+    pub display_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportedMessage {
+    pub message_index: MessageIndex,
+    /// The message id as a decimal string, since it doesn't fit within a JSON number
+    pub message_id: String,
+    pub sender: UserId,
+    pub timestamp: TimestampMillis,
+    pub edited: Option<TimestampMillis>,
+    /// The message content type, eg. "Text", "Image", "Poll"
+    pub content_type: String,
+    pub text: Option<String>,
+    /// URLs of any files attached to the message
+    pub files: Vec<String>,
+    /// The index of the message being replied to, within the same timeline (main or thread)
+    pub replies_to: Option<MessageIndex>,
+    pub forwarded: bool,
+    pub reactions: Vec<ExportedReaction>,
+    /// Replies within the message's thread, in chronological order
+    pub thread: Vec<ExportedMessage>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportedReaction {
+    pub reaction: Reaction,
+    pub count: u32,
+}
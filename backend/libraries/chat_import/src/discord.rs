@@ -0,0 +1,117 @@
+use crate::{ParsedAttachment, ParsedMessage};
+use serde::Deserialize;
+use types::TimestampMillis;
+
+// Discord ids are "snowflakes" which embed the time of creation relative to this epoch
+const DISCORD_EPOCH: TimestampMillis = 1_420_070_400_000;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Message {
+    id: String,
+    #[serde(rename = "type")]
+    message_type: String,
+    #[serde(default)]
+    content: String,
+    author: Author,
+    #[serde(default)]
+    attachments: Vec<Attachment>,
+    reference: Option<Reference>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Author {
+    id: String,
+    name: String,
+    nickname: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Attachment {
+    url: String,
+    file_name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Reference {
+    message_id: Option<String>,
+}
+
+impl Message {
+    // Only regular messages and replies are imported, other types (pins, joins, etc) are skipped
+    pub(crate) fn into_parsed(self) -> Option<ParsedMessage> {
+        if self.message_type != "Default" && self.message_type != "Reply" {
+            return None;
+        }
+
+        Some(ParsedMessage {
+            timestamp: snowflake_timestamp(&self.id),
+            external_id: self.id,
+            author_id: self.author.id,
+            author_name: self.author.nickname.unwrap_or(self.author.name),
+            text: self.content,
+            attachments: self
+                .attachments
+                .into_iter()
+                .map(|a| ParsedAttachment {
+                    path: a.url,
+                    file_name: a.file_name,
+                })
+                .collect(),
+            replies_to: self.reference.and_then(|r| r.message_id),
+        })
+    }
+}
+
+fn snowflake_timestamp(id: &str) -> TimestampMillis {
+    id.parse::<u64>().map_or(0, |id| (id >> 22) + DISCORD_EPOCH)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::read_all;
+    use types::ChatImportSource;
+
+    #[test]
+    fn parse_export() {
+        let json = r#"{
+            "guild": {"id": "1", "name": "Test server"},
+            "channel": {"id": "2", "type": "GuildTextChat", "name": "general"},
+            "messages": [
+                {
+                    "id": "175928847299117063",
+                    "type": "Default",
+                    "timestamp": "2016-04-30T11:18:25.796+00:00",
+                    "content": "Hello",
+                    "author": {"id": "10", "name": "alice", "nickname": "Alice"},
+                    "attachments": [{"id": "3", "url": "https://cdn.discordapp.com/a.png", "fileName": "a.png", "fileSizeBytes": 10}]
+                },
+                {
+                    "id": "175928847299117064",
+                    "type": "ChannelPinnedMessage",
+                    "content": "",
+                    "author": {"id": "10", "name": "alice"}
+                },
+                {
+                    "id": "175928847299117065",
+                    "type": "Reply",
+                    "content": "Hi",
+                    "author": {"id": "11", "name": "bob"},
+                    "reference": {"messageId": "175928847299117063", "channelId": "2"}
+                }
+            ]
+        }"#;
+
+        let messages = read_all(ChatImportSource::Discord, json.as_bytes()).unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].timestamp, 1462015105796);
+        assert_eq!(messages[0].author_name, "Alice");
+        assert_eq!(messages[0].attachments[0].file_name, "a.png");
+        assert_eq!(messages[1].author_name, "bob");
+        assert_eq!(messages[1].replies_to.as_deref(), Some("175928847299117063"));
+    }
+}
@@ -0,0 +1,156 @@
+mod discord;
+mod telegram;
+
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Serialize};
+use types::{ChatImportSource, TimestampMillis};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ParsedMessage {
+    /// The message's id within the export, used to resolve replies
+    pub external_id: String,
+    pub author_id: String,
+    pub author_name: String,
+    pub timestamp: TimestampMillis,
+    pub text: String,
+    pub attachments: Vec<ParsedAttachment>,
+    pub replies_to: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ParsedAttachment {
+    /// The path (Telegram) or url (Discord) of the file as it appears within the export
+    pub path: String,
+    pub file_name: String,
+}
+
+/// Reads the messages out of a Telegram JSON export ("result.json") or a Discord channel export in
+/// the JSON format produced by DiscordChatExporter. Messages are read in batches so that large exports
+/// can be parsed across multiple calls. Service messages (joins, pins, etc) are skipped.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportReader {
+    source: ChatImportSource,
+    // The offset within the export of the next entry in the "messages" array
+    position: usize,
+    finished: bool,
+}
+
+impl ExportReader {
+    /// Locates the "messages" array within the export, skipping over any fields which precede it
+    pub fn new(source: ChatImportSource, bytes: &[u8]) -> Result<ExportReader, String> {
+        let mut position = expect(bytes, skip_whitespace(bytes, 0), b'{')?;
+
+        loop {
+            let (key, next) = read_value::<String>(bytes, skip_whitespace(bytes, position))?;
+            position = skip_whitespace(bytes, expect(bytes, skip_whitespace(bytes, next), b':')?);
+
+            if key == "messages" {
+                position = skip_whitespace(bytes, expect(bytes, position, b'[')?);
+                let finished = bytes.get(position) == Some(&b']');
+
+                return Ok(ExportReader {
+                    source,
+                    position,
+                    finished,
+                });
+            }
+
+            let (_, next) = read_value::<IgnoredAny>(bytes, position)?;
+            position =
+                expect(bytes, skip_whitespace(bytes, next), b',').map_err(|_| "export contains no messages".to_string())?;
+        }
+    }
+
+    /// Reads up to `max_entries` entries from the "messages" array, returning those which can be imported
+    pub fn next_batch(&mut self, bytes: &[u8], max_entries: usize) -> Result<Vec<ParsedMessage>, String> {
+        let mut messages = Vec::new();
+
+        for _ in 0..max_entries {
+            if self.finished {
+                break;
+            }
+
+            let (message, next) = match self.source {
+                ChatImportSource::Telegram => {
+                    read_value::<telegram::Message>(bytes, self.position).map(|(m, next)| (m.into_parsed(), next))?
+                }
+                ChatImportSource::Discord => {
+                    read_value::<discord::Message>(bytes, self.position).map(|(m, next)| (m.into_parsed(), next))?
+                }
+            };
+            messages.extend(message);
+
+            let next = skip_whitespace(bytes, next);
+            match bytes.get(next) {
+                Some(b',') => self.position = skip_whitespace(bytes, next + 1),
+                Some(b']') => self.finished = true,
+                _ => return Err(format!("expected ',' or ']' at offset {next}")),
+            }
+        }
+
+        Ok(messages)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+fn read_value<T: DeserializeOwned>(bytes: &[u8], position: usize) -> Result<(T, usize), String> {
+    let mut stream = serde_json::Deserializer::from_slice(bytes.get(position..).unwrap_or_default()).into_iter::<T>();
+
+    match stream.next() {
+        Some(Ok(value)) => Ok((value, position + stream.byte_offset())),
+        Some(Err(error)) => Err(format!("{error} (offset {position})")),
+        None => Err("unexpected end of export".to_string()),
+    }
+}
+
+fn expect(bytes: &[u8], position: usize, expected: u8) -> Result<usize, String> {
+    if bytes.get(position) == Some(&expected) {
+        Ok(position + 1)
+    } else {
+        Err(format!("expected '{}' at offset {position}", expected as char))
+    }
+}
+
+fn skip_whitespace(bytes: &[u8], position: usize) -> usize {
+    position + bytes.iter().skip(position).take_while(|b| b.is_ascii_whitespace()).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub fn read_all(source: ChatImportSource, bytes: &[u8]) -> Result<Vec<ParsedMessage>, String> {
+        let mut reader = ExportReader::new(source, bytes)?;
+        let mut messages = Vec::new();
+        while !reader.is_finished() {
+            messages.extend(reader.next_batch(bytes, 1)?);
+        }
+        Ok(messages)
+    }
+
+    #[test]
+    fn empty_export() {
+        let json = r#"{"name": "Test group", "messages": [ ]}"#;
+
+        assert!(read_all(ChatImportSource::Telegram, json.as_bytes()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn export_without_messages_fails() {
+        let json = r#"{"name": "Test group", "id": 123}"#;
+
+        assert!(ExportReader::new(ChatImportSource::Telegram, json.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn truncated_export_fails() {
+        let json = r#"{"messages": [{"id": 1, "type": "message", "text": "Hello"}, {"id": 2"#;
+        let mut reader = ExportReader::new(ChatImportSource::Telegram, json.as_bytes()).unwrap();
+
+        assert_eq!(reader.next_batch(json.as_bytes(), 1).unwrap().len(), 1);
+        assert!(reader.next_batch(json.as_bytes(), 1).is_err());
+    }
+}
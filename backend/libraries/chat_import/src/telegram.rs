@@ -0,0 +1,128 @@
+use crate::{ParsedAttachment, ParsedMessage};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub(crate) struct Message {
+    id: i64,
+    #[serde(rename = "type")]
+    message_type: String,
+    date_unixtime: Option<String>,
+    from: Option<String>,
+    from_id: Option<String>,
+    #[serde(default)]
+    text: Text,
+    reply_to_message_id: Option<i64>,
+    photo: Option<String>,
+    file: Option<String>,
+    file_name: Option<String>,
+}
+
+// Plain messages have their text as a string, whereas messages containing any formatting, links or
+// mentions are split into a list of plain strings and entity objects
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Text {
+    Plain(String),
+    Parts(Vec<TextPart>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TextPart {
+    Plain(String),
+    Entity { text: String },
+}
+
+impl Default for Text {
+    fn default() -> Self {
+        Text::Plain(String::new())
+    }
+}
+
+impl From<Text> for String {
+    fn from(value: Text) -> Self {
+        match value {
+            Text::Plain(text) => text,
+            Text::Parts(parts) => parts
+                .into_iter()
+                .map(|p| match p {
+                    TextPart::Plain(text) | TextPart::Entity { text } => text,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Message {
+    // Only regular messages are imported, service messages (joins, pins, etc) are skipped
+    pub(crate) fn into_parsed(self) -> Option<ParsedMessage> {
+        if self.message_type != "message" {
+            return None;
+        }
+
+        let mut attachments = Vec::new();
+        if let Some(photo) = self.photo {
+            let file_name = photo.rsplit('/').next().unwrap_or_default().to_string();
+            attachments.push(ParsedAttachment { path: photo, file_name });
+        }
+        if let Some(file) = self.file {
+            let file_name = self
+                .file_name
+                .unwrap_or_else(|| file.rsplit('/').next().unwrap_or_default().to_string());
+            attachments.push(ParsedAttachment { path: file, file_name });
+        }
+
+        Some(ParsedMessage {
+            external_id: self.id.to_string(),
+            author_id: self.from_id.unwrap_or_default(),
+            // "from" is null for deleted accounts
+            author_name: self.from.unwrap_or_else(|| "Deleted account".to_string()),
+            timestamp: self
+                .date_unixtime
+                .and_then(|d| d.parse::<u64>().ok())
+                .map(|seconds| seconds * 1000)
+                .unwrap_or_default(),
+            text: self.text.into(),
+            attachments,
+            replies_to: self.reply_to_message_id.map(|id| id.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::read_all;
+    use types::ChatImportSource;
+
+    #[test]
+    fn parse_export() {
+        let json = r#"{
+            "name": "Test group",
+            "type": "private_supergroup",
+            "id": 123,
+            "messages": [
+                {"id": 1, "type": "service", "date_unixtime": "1600000000", "actor": "Alice", "action": "create_group"},
+                {"id": 2, "type": "message", "date_unixtime": "1600000001", "from": "Alice", "from_id": "user1", "text": "Hello"},
+                {
+                    "id": 3,
+                    "type": "message",
+                    "date_unixtime": "1600000002",
+                    "from": null,
+                    "from_id": "user2",
+                    "reply_to_message_id": 2,
+                    "photo": "photos/photo_1.jpg",
+                    "text": ["See ", {"type": "link", "text": "https://oc.app"}]
+                }
+            ]
+        }"#;
+
+        let messages = read_all(ChatImportSource::Telegram, json.as_bytes()).unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].timestamp, 1600000001000);
+        assert_eq!(messages[1].text, "See https://oc.app");
+        assert_eq!(messages[1].author_name, "Deleted account");
+        assert_eq!(messages[1].replies_to.as_deref(), Some("2"));
+        assert_eq!(messages[1].attachments[0].file_name, "photo_1.jpg");
+    }
+}
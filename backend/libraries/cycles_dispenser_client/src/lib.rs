@@ -89,7 +89,10 @@ fn run_once() {
 async fn request_top_up(cycles_balance: Cycles, cycles_dispenser_canister_id: CanisterId) {
     info!(cycles_balance, "Requesting cycles top up");
 
-    let args = cycles_dispenser_canister::c2c_request_cycles::Args { amount: None };
+    let args = cycles_dispenser_canister::c2c_request_cycles::Args {
+        amount: None,
+        cycles_balance: Some(cycles_balance),
+    };
 
     let response: CallResult<cycles_dispenser_canister::c2c_request_cycles::Response> = make_c2c_call(
         cycles_dispenser_canister_id,
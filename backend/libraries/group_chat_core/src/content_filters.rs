@@ -0,0 +1,240 @@
+use lazy_static::lazy_static;
+use regex_lite::Regex;
+use serde::{Deserialize, Serialize};
+use types::{ContentFilterCondition, ContentFilterMatch, ContentFilters};
+
+const MAX_RULES: usize = 50;
+const MAX_TERMS_PER_RULE: usize = 500;
+const MAX_TERM_LENGTH: usize = 200;
+
+lazy_static! {
+    static ref LINK_HOST_REGEX: Regex = Regex::new(r"(?i)\bhttps?://([^/\s:?#@]+)").unwrap();
+}
+
+// Patterns are compiled once when the filters are set (or when the canister is upgraded) rather than each
+// time a message is checked
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(from = "ContentFilters", into = "ContentFilters")]
+pub struct CompiledContentFilters {
+    filters: ContentFilters,
+    // One entry per rule, only set for `Pattern` rules
+    patterns: Vec<Option<Regex>>,
+}
+
+impl CompiledContentFilters {
+    pub fn filters(&self) -> &ContentFilters {
+        &self.filters
+    }
+}
+
+impl From<ContentFilters> for CompiledContentFilters {
+    fn from(filters: ContentFilters) -> Self {
+        let patterns = filters
+            .rules
+            .iter()
+            .map(|rule| match &rule.condition {
+                ContentFilterCondition::Pattern(pattern) => Regex::new(pattern).ok(),
+                _ => None,
+            })
+            .collect();
+
+        CompiledContentFilters { filters, patterns }
+    }
+}
+
+impl From<CompiledContentFilters> for ContentFilters {
+    fn from(value: CompiledContentFilters) -> Self {
+        value.filters
+    }
+}
+
+pub fn validate_content_filters(filters: &ContentFilters) -> Result<(), String> {
+    if filters.rules.len() > MAX_RULES {
+        return Err(format!("Too many rules. Max: {MAX_RULES}"));
+    }
+
+    for rule in filters.rules.iter() {
+        let terms = match &rule.condition {
+            ContentFilterCondition::Keywords(terms)
+            | ContentFilterCondition::LinkAllowlist(terms)
+            | ContentFilterCondition::LinkBlocklist(terms) => terms.as_slice(),
+            ContentFilterCondition::Pattern(pattern) => {
+                if pattern.len() > MAX_TERM_LENGTH {
+                    return Err(format!("Pattern too long. Max: {MAX_TERM_LENGTH}"));
+                }
+                if let Err(error) = Regex::new(pattern) {
+                    return Err(format!("Invalid pattern: {error}"));
+                }
+                continue;
+            }
+            ContentFilterCondition::MaxMentions(_) => continue,
+        };
+
+        if terms.is_empty() {
+            return Err("Rule has no terms".to_string());
+        }
+        if terms.len() > MAX_TERMS_PER_RULE {
+            return Err(format!("Too many terms in rule. Max: {MAX_TERMS_PER_RULE}"));
+        }
+        if terms.iter().any(|t| t.trim().is_empty() || t.len() > MAX_TERM_LENGTH) {
+            return Err(format!("Terms must be non-empty and at most {MAX_TERM_LENGTH} characters"));
+        }
+    }
+
+    Ok(())
+}
+
+// Returns the most severe match across all of the filters supplied
+pub fn check_content_filters<'a>(
+    filters: impl IntoIterator<Item = &'a CompiledContentFilters>,
+    text: Option<&str>,
+    mention_count: usize,
+) -> Option<ContentFilterMatch> {
+    let text_lowercase = text.map(|t| t.to_lowercase());
+    let text_lowercase = text_lowercase.as_deref().unwrap_or_default();
+    let link_hosts = extract_link_hosts(text_lowercase);
+
+    filters
+        .into_iter()
+        .flat_map(|f| f.filters.rules.iter().zip(f.patterns.iter()))
+        .filter(|(rule, pattern)| match &rule.condition {
+            ContentFilterCondition::Keywords(keywords) => keywords
+                .iter()
+                .any(|k| contains_keyword(text_lowercase, &k.trim().to_lowercase())),
+            ContentFilterCondition::Pattern(_) => text.map_or(false, |t| pattern.as_ref().map_or(false, |r| r.is_match(t))),
+            ContentFilterCondition::LinkAllowlist(domains) => {
+                link_hosts.iter().any(|h| !domains.iter().any(|d| host_matches(h, d)))
+            }
+            ContentFilterCondition::LinkBlocklist(domains) => {
+                link_hosts.iter().any(|h| domains.iter().any(|d| host_matches(h, d)))
+            }
+            ContentFilterCondition::MaxMentions(max) => mention_count > *max as usize,
+        })
+        .map(|(rule, _)| ContentFilterMatch {
+            action: rule.action,
+            reason: rule.condition.reason(),
+        })
+        .max_by_key(|m| m.action)
+}
+
+// Single words must match on word boundaries, phrases match anywhere
+fn contains_keyword(text: &str, keyword: &str) -> bool {
+    if keyword.is_empty() {
+        return false;
+    }
+    if keyword.contains(char::is_whitespace) {
+        return text.contains(keyword);
+    }
+
+    text.match_indices(keyword).any(|(start, _)| {
+        let end = start + keyword.len();
+        let before = text[..start].chars().next_back();
+        let after = text[end..].chars().next();
+        !before.map_or(false, char::is_alphanumeric) && !after.map_or(false, char::is_alphanumeric)
+    })
+}
+
+fn extract_link_hosts(text: &str) -> Vec<String> {
+    LINK_HOST_REGEX
+        .captures_iter(text)
+        .filter_map(|c| c.get(1))
+        .map(|m| m.as_str().trim_end_matches('.').to_string())
+        .collect()
+}
+
+fn host_matches(host: &str, domain: &str) -> bool {
+    let domain = domain.trim().trim_start_matches("*.").to_lowercase();
+    host == domain || host.strip_suffix(&domain).map_or(false, |prefix| prefix.ends_with('.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::{ContentFilterAction, ContentFilterReason, ContentFilterRule};
+
+    #[test]
+    fn keywords_match_whole_words_only() {
+        let filters = filters(vec![rule(
+            ContentFilterCondition::Keywords(vec!["spam".to_string()]),
+            ContentFilterAction::Reject,
+        )]);
+
+        assert!(check_content_filters([&filters], Some("This is SPAM!"), 0).is_some());
+        assert!(check_content_filters([&filters], Some("spammer"), 0).is_none());
+    }
+
+    #[test]
+    fn link_allowlist_blocks_other_domains() {
+        let filters = filters(vec![rule(
+            ContentFilterCondition::LinkAllowlist(vec!["oc.app".to_string()]),
+            ContentFilterAction::HoldForReview,
+        )]);
+
+        assert!(check_content_filters([&filters], Some("see https://www.oc.app/abc"), 0).is_none());
+        assert!(check_content_filters([&filters], Some("see https://notoc.app"), 0).is_some());
+    }
+
+    #[test]
+    fn most_severe_action_wins() {
+        let filters = filters(vec![
+            rule(ContentFilterCondition::MaxMentions(2), ContentFilterAction::HoldForReview),
+            rule(
+                ContentFilterCondition::Pattern(r"\d{6}".to_string()),
+                ContentFilterAction::Reject,
+            ),
+        ]);
+
+        let result = check_content_filters([&filters], Some("code 123456"), 3).unwrap();
+
+        assert_eq!(result.action, ContentFilterAction::Reject);
+        assert_eq!(result.reason, ContentFilterReason::BlockedPattern);
+    }
+
+    #[test]
+    fn delete_and_warn_outranks_hold_for_review() {
+        let filters = filters(vec![
+            rule(
+                ContentFilterCondition::Keywords(vec!["spam".to_string()]),
+                ContentFilterAction::DeleteAndWarn,
+            ),
+            rule(ContentFilterCondition::MaxMentions(2), ContentFilterAction::HoldForReview),
+        ]);
+
+        let result = check_content_filters([&filters], Some("spam"), 3).unwrap();
+
+        assert_eq!(result.action, ContentFilterAction::DeleteAndWarn);
+        assert_eq!(result.reason, ContentFilterReason::BlockedKeyword);
+    }
+
+    #[test]
+    fn invalid_pattern_fails_validation() {
+        let filters = filters(vec![rule(
+            ContentFilterCondition::Pattern("(abc".to_string()),
+            ContentFilterAction::Reject,
+        )]);
+
+        assert!(validate_content_filters(filters.filters()).is_err());
+    }
+
+    #[test]
+    fn compiled_patterns_survive_serialization() {
+        let filters = filters(vec![rule(
+            ContentFilterCondition::Pattern(r"\d{6}".to_string()),
+            ContentFilterAction::Reject,
+        )]);
+
+        let bytes = msgpack::serialize_then_unwrap(&filters);
+        let deserialized: CompiledContentFilters = msgpack::deserialize_then_unwrap(&bytes);
+
+        assert!(check_content_filters([&deserialized], Some("code 123456"), 0).is_some());
+        assert!(check_content_filters([&deserialized], Some("code 123"), 0).is_none());
+    }
+
+    fn filters(rules: Vec<ContentFilterRule>) -> CompiledContentFilters {
+        ContentFilters { rules }.into()
+    }
+
+    fn rule(condition: ContentFilterCondition, action: ContentFilterAction) -> ContentFilterRule {
+        ContentFilterRule { condition, action }
+    }
+}
@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{ForumSubtype, ForumTopicSolved, MessageIndex, NewForumTopic, TimestampMillis};
+
+const MAX_TITLE_LENGTH: usize = 200;
+const MAX_TAGS_PER_TOPIC: usize = 5;
+const MAX_TAG_LENGTH: usize = 25;
+const MAX_AVAILABLE_TAGS: usize = 50;
+
+// The topics of a forum channel, keyed by the message index of each topic's root message
+#[derive(Serialize, Deserialize, Default)]
+pub struct ForumTopics {
+    topics: BTreeMap<MessageIndex, ForumTopicInternal>,
+    #[serde(default)]
+    last_updated: TimestampMillis,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ForumTopicInternal {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub solved: Option<ForumTopicSolved>,
+    pub last_updated: TimestampMillis,
+}
+
+impl ForumTopics {
+    pub fn add(&mut self, root_message_index: MessageIndex, topic: NewForumTopic, now: TimestampMillis) {
+        self.topics.insert(
+            root_message_index,
+            ForumTopicInternal {
+                title: topic.title,
+                tags: topic.tags,
+                solved: None,
+                last_updated: now,
+            },
+        );
+        self.last_updated = now;
+    }
+
+    pub fn get(&self, root_message_index: &MessageIndex) -> Option<&ForumTopicInternal> {
+        self.topics.get(root_message_index)
+    }
+
+    // Topics are only ever accessed mutably in order to update them, so this marks the topic as updated
+    pub fn get_mut(&mut self, root_message_index: &MessageIndex, now: TimestampMillis) -> Option<&mut ForumTopicInternal> {
+        let topic = self.topics.get_mut(root_message_index)?;
+        topic.last_updated = now;
+        self.last_updated = now;
+        Some(topic)
+    }
+
+    // Returns the topics which have all of the given tags and, if specified, the given solved status
+    pub fn filter<'a>(
+        &'a self,
+        tags: &'a [String],
+        solved: Option<bool>,
+    ) -> impl Iterator<Item = (MessageIndex, &'a ForumTopicInternal)> + 'a {
+        self.topics
+            .iter()
+            .filter(move |(_, t)| tags.iter().all(|tag| t.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))))
+            .filter(move |(_, t)| solved.map_or(true, |s| t.solved.is_some() == s))
+            .map(|(i, t)| (*i, t))
+    }
+
+    pub fn last_updated(&self) -> TimestampMillis {
+        self.last_updated
+    }
+}
+
+pub fn validate_topic_title(title: &str) -> Result<String, String> {
+    let title = title.trim();
+    if title.is_empty() {
+        Err("Forum topics must have a title".to_string())
+    } else if title.chars().count() > MAX_TITLE_LENGTH {
+        Err(format!("Topic titles must be at most {MAX_TITLE_LENGTH} characters"))
+    } else {
+        Ok(title.to_string())
+    }
+}
+
+// Trims and dedupes the tags. If the forum has a fixed set of tags then each tag must be one of
+// them, in which case the tag is returned using the forum's casing.
+pub fn validate_topic_tags(tags: Vec<String>, forum: &ForumSubtype) -> Result<Vec<String>, String> {
+    let mut validated: Vec<String> = Vec::new();
+
+    for tag in tags {
+        let tag = tag.trim();
+        if tag.is_empty() || validated.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+            continue;
+        }
+
+        if forum.available_tags.is_empty() {
+            if tag.chars().count() > MAX_TAG_LENGTH {
+                return Err(format!("Tags must be at most {MAX_TAG_LENGTH} characters"));
+            }
+            validated.push(tag.to_string());
+        } else if let Some(available) = forum.available_tags.iter().find(|t| t.eq_ignore_ascii_case(tag)) {
+            validated.push(available.clone());
+        } else {
+            return Err(format!("Tag not available: {tag}"));
+        }
+    }
+
+    if validated.len() > MAX_TAGS_PER_TOPIC {
+        Err(format!("Topics can have at most {MAX_TAGS_PER_TOPIC} tags"))
+    } else {
+        Ok(validated)
+    }
+}
+
+// Trims and dedupes the forum's available tags, dropping any which are empty or too long
+pub fn sanitise_forum_subtype(forum: ForumSubtype) -> ForumSubtype {
+    let mut available_tags: Vec<String> = Vec::new();
+
+    for tag in forum.available_tags {
+        let tag = tag.trim();
+        if !tag.is_empty()
+            && tag.chars().count() <= MAX_TAG_LENGTH
+            && !available_tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+        {
+            available_tags.push(tag.to_string());
+        }
+    }
+    available_tags.truncate(MAX_AVAILABLE_TAGS);
+
+    ForumSubtype { available_tags }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn filter_by_tags_and_solved() {
+        let mut topics = ForumTopics::default();
+        topics.add(1.into(), new_topic("Install fails", &["help", "windows"]), 1);
+        topics.add(2.into(), new_topic("Feature idea", &["ideas"]), 2);
+        topics.add(3.into(), new_topic("Crash on start", &["Help"]), 3);
+        topics.get_mut(&3.into(), 4).unwrap().solved = Some(ForumTopicSolved {
+            marked_by: Principal::anonymous().into(),
+            timestamp: 4,
+            answer: None,
+        });
+
+        let indexes = |tags: &[String], solved| topics.filter(tags, solved).map(|(i, _)| u32::from(i)).collect::<Vec<_>>();
+
+        assert_eq!(indexes(&["help".to_string()], None), vec![1, 3]);
+        assert_eq!(indexes(&["help".to_string(), "windows".to_string()], None), vec![1]);
+        assert_eq!(indexes(&["help".to_string()], Some(false)), vec![1]);
+        assert_eq!(indexes(&[], Some(true)), vec![3]);
+    }
+
+    #[test]
+    fn tags_must_be_available() {
+        let forum = ForumSubtype {
+            available_tags: vec!["Bug".to_string(), "Question".to_string()],
+        };
+
+        assert_eq!(
+            validate_topic_tags(vec![" bug ".to_string(), "BUG".to_string()], &forum),
+            Ok(vec!["Bug".to_string()])
+        );
+        assert!(validate_topic_tags(vec!["other".to_string()], &forum).is_err());
+        assert_eq!(
+            validate_topic_tags(vec!["anything".to_string()], &ForumSubtype::default()),
+            Ok(vec!["anything".to_string()])
+        );
+    }
+
+    fn new_topic(title: &str, tags: &[&str]) -> NewForumTopic {
+        NewForumTopic {
+            title: title.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+}
@@ -6,33 +6,47 @@ use lazy_static::lazy_static;
 use regex_lite::Regex;
 use search::Query;
 use serde::{Deserialize, Serialize};
-use std::cmp::{max, min};
+use std::cmp::{max, min, Reverse};
 use std::collections::{BTreeSet, HashSet};
+use std::iter::once;
 use types::{
-    AccessGate, AvatarChanged, ContentValidationError, CryptoTransaction, CustomPermission, Document, EventIndex,
-    EventOrExpiredRange, EventWrapper, EventsResponse, FieldTooLongResult, FieldTooShortResult, GroupDescriptionChanged,
-    GroupGateUpdated, GroupNameChanged, GroupPermissionRole, GroupPermissions, GroupReplyContext, GroupRole, GroupRulesChanged,
-    GroupSubtype, GroupVisibilityChanged, HydratedMention, InvalidPollReason, MemberLeft, MembersRemoved, Message,
-    MessageContent, MessageContentInitial, MessageId, MessageIndex, MessageMatch, MessagePermissions, MessagePinned,
-    MessageUnpinned, MessagesResponse, Milliseconds, OptionUpdate, OptionalGroupPermissions, OptionalMessagePermissions,
-    PermissionsChanged, PushEventResult, PushIfNotContains, Reaction, RoleChanged, Rules, SelectedGroupUpdates, ThreadPreview,
+    AccessGate, AvatarChanged, Chat, ContentFilterAction, ContentFilterMatch, ContentFilterReason, ContentFilters,
+    ContentValidationError, CryptoTransaction, CustomPermission, Document, EventIndex, EventOrExpiredRange, EventWrapper,
+    EventsResponse, FieldTooLongResult, FieldTooShortResult, ForumSubtype, ForumTopic, ForumTopicSolved, ForumTopicsSortOrder,
+    ForwardedFrom, GroupDescriptionChanged, GroupGateUpdated, GroupNameChanged, GroupPermissionRole, GroupPermissions,
+    GroupReplyContext, GroupRole, GroupRulesChanged, GroupSubtype, GroupVisibilityChanged, HydratedMention, InvalidPollReason,
+    MemberLeft, MemberMuted, MemberUnmuted, MembersRemoved, Message, MessageContent, MessageContentInitial,
+    MessageForForwarding, MessageId, MessageIndex, MessageMatch, MessagePermissions, MessagePinned, MessageRevision,
+    MessageUnpinned, MessagesResponse, Milliseconds, ModerationAction, ModerationDecision, ModerationReport,
+    ModerationReportId, NewForumTopic, OptionUpdate, OptionalGroupPermissions, OptionalMessagePermissions, PermissionsChanged,
+    PushEventResult, PushIfNotContains, Reaction, RoleChanged, Rules, SelectedGroupUpdates, SlowMode, ThreadPreview,
     TimestampMillis, Timestamped, UpdatedRules, UserId, UsersBlocked, UsersInvited, Version, Versioned, VersionedRules,
 };
+use utils::consts::OPENCHAT_BOT_USER_ID;
 use utils::document_validation::validate_avatar;
 use utils::text_validation::{
     validate_description, validate_group_name, validate_rules, NameValidationError, RulesValidationError,
 };
+use utils::time::DAY_IN_MS;
 
+mod content_filters;
+mod forum_topics;
 mod invited_users;
 mod members;
 mod mentions;
+mod moderation_queue;
 mod roles;
 
+pub use content_filters::*;
+pub use forum_topics::*;
 pub use invited_users::*;
 pub use members::*;
 pub use mentions::*;
+pub use moderation_queue::*;
 pub use roles::*;
 
+pub const MAX_MUTE_DURATION: Milliseconds = 365 * DAY_IN_MS;
+
 #[derive(Serialize, Deserialize)]
 pub struct GroupChatCore {
     #[serde(alias = "is_public_v2")]
@@ -60,6 +74,16 @@ pub struct GroupChatCore {
     pub gate: Timestamped<Option<AccessGate>>,
     pub invited_users: InvitedUsers,
     pub min_visible_indexes_for_new_members: Option<(EventIndex, MessageIndex)>,
+    #[serde(default)]
+    pub moderation_queue: ModerationQueue,
+    #[serde(default)]
+    pub content_filters: Timestamped<CompiledContentFilters>,
+    #[serde(default)]
+    pub slow_mode: Timestamped<Option<SlowMode>>,
+    #[serde(default)]
+    pub forum_topics: ForumTopics,
+    #[serde(default)]
+    pub hide_forwarded_from: bool,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -101,6 +125,11 @@ impl GroupChatCore {
             gate: Timestamped::new(gate, now),
             invited_users: InvitedUsers::default(),
             min_visible_indexes_for_new_members: None,
+            moderation_queue: ModerationQueue::default(),
+            content_filters: Timestamped::default(),
+            slow_mode: Timestamped::default(),
+            forum_topics: ForumTopics::default(),
+            hide_forwarded_from: false,
         }
     }
 
@@ -125,10 +154,15 @@ impl GroupChatCore {
     }
 
     pub fn details_last_updated(&self) -> TimestampMillis {
-        max(
+        [
             self.events.last_updated().unwrap_or_default(),
             self.invited_users.last_updated(),
-        )
+            self.forum_topics.last_updated(),
+            self.slow_mode.timestamp,
+        ]
+        .into_iter()
+        .max()
+        .unwrap()
     }
 
     pub fn last_updated(&self, user_id: Option<UserId>) -> TimestampMillis {
@@ -218,6 +252,11 @@ impl GroupChatCore {
                 .if_set_after(since)
                 .cloned()
                 .map_or(OptionUpdate::NoChange, OptionUpdate::from_update),
+            slow_mode: self
+                .slow_mode
+                .if_set_after(since)
+                .cloned()
+                .map_or(OptionUpdate::NoChange, OptionUpdate::from_update),
             rules_changed: self.rules.version_last_updated > since,
         }
     }
@@ -456,6 +495,31 @@ impl GroupChatCore {
         }
     }
 
+    pub fn message_edit_history(
+        &self,
+        user_id: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        now: TimestampMillis,
+    ) -> MessageEditHistoryResult {
+        use MessageEditHistoryResult::*;
+
+        if let Some(member) = self.members.get(&user_id) {
+            match self.events.message_edit_history(
+                member.min_visible_event_index(),
+                thread_root_message_index,
+                message_id,
+                Some(user_id),
+                now,
+            ) {
+                Some(revisions) => Success(revisions),
+                None => MessageNotFound,
+            }
+        } else {
+            UserNotInGroup
+        }
+    }
+
     pub fn thread_previews(&self, user_id: UserId, threads: Vec<MessageIndex>) -> ThreadPreviewsResult {
         use ThreadPreviewsResult::*;
 
@@ -526,12 +590,16 @@ impl GroupChatCore {
         replies_to: Option<GroupReplyContext>,
         mentioned: Vec<UserId>,
         forwarding: bool,
+        forwarded_from: Option<ForwardedFrom>,
         rules_accepted: Option<Version>,
         proposals_bot_user_id: UserId,
+        parent_content_filters: Option<&CompiledContentFilters>,
         now: TimestampMillis,
     ) -> SendMessageResult {
         use SendMessageResult::*;
 
+        let forwarding = forwarding || forwarded_from.is_some();
+
         match self.members.get_mut(&sender) {
             Some(m) => {
                 if m.suspended.value {
@@ -544,6 +612,10 @@ impl GroupChatCore {
             None => return UserNotInGroup,
         };
 
+        if let Some(muted_until) = self.members.muted_until(&sender, now) {
+            return UserMuted(muted_until);
+        }
+
         let member = self.members.get(&sender).unwrap();
 
         if !self.check_rules(member) {
@@ -571,6 +643,22 @@ impl GroupChatCore {
             };
         }
 
+        let content_filter_match = self.content_filter_match(
+            sender,
+            content.text(),
+            mentioned.len(),
+            parent_content_filters,
+            proposals_bot_user_id,
+        );
+
+        if let Some(ContentFilterMatch {
+            action: ContentFilterAction::Reject,
+            reason,
+        }) = content_filter_match
+        {
+            return ContentRejected(reason);
+        }
+
         if let Some(transfer) = match &content {
             MessageContentInitial::Crypto(c) => Some(&c.transfer),
             MessageContentInitial::Prize(c) => Some(&c.transfer),
@@ -590,6 +678,21 @@ impl GroupChatCore {
             return NotAuthorized;
         }
 
+        let slow_mode = self
+            .slow_mode
+            .value
+            .as_ref()
+            .filter(|s| sender != proposals_bot_user_id && !s.is_exempt(member.role.value.into()));
+
+        if let Some(slow_mode) = slow_mode {
+            let next_message_allowed = slow_mode.next_message_allowed(&member.recent_message_timestamps);
+            if now < next_message_allowed {
+                return RateLimited(next_message_allowed);
+            }
+        }
+
+        let slow_mode_timestamps_to_retain = slow_mode.map(|s| s.timestamps_to_retain());
+
         if let Some(root_message_index) = thread_root_message_index {
             if !self
                 .events
@@ -614,6 +717,7 @@ impl GroupChatCore {
             mentioned: mentioned.clone(),
             replies_to: replies_to.as_ref().map(|r| r.into()),
             forwarded: forwarding,
+            forwarded_from,
             correlation_id: 0,
             now,
         };
@@ -621,6 +725,37 @@ impl GroupChatCore {
         let message_event = self.events.push_message(push_message_args);
         let message_index = message_event.event.message_index;
 
+        if let Some(timestamps_to_retain) = slow_mode_timestamps_to_retain {
+            if let Some(member) = self.members.get_mut(&sender) {
+                member.record_message_sent(now, timestamps_to_retain);
+            }
+        }
+
+        if let Some(filter_match) = content_filter_match {
+            self.delete_filtered_message(thread_root_message_index, message_id, now);
+
+            if filter_match.action == ContentFilterAction::HoldForReview {
+                self.moderation_queue.add_report(AddModerationReportArgs {
+                    reporter: OPENCHAT_BOT_USER_ID,
+                    thread_root_message_index,
+                    message_id,
+                    message_index,
+                    event_index: message_event.index,
+                    sender,
+                    reason_code: 0,
+                    notes: Some(format!("Held by content filter: {:?}", filter_match.reason)),
+                    held_for_review: true,
+                    now,
+                });
+            }
+
+            return Success(SendMessageSuccess {
+                message_event,
+                users_to_notify: Vec::new(),
+                content_filter_match,
+            });
+        }
+
         let mut mentions: HashSet<_> = mentioned.into_iter().chain(user_being_replied_to).collect();
 
         let mut users_to_notify = HashSet::new();
@@ -672,9 +807,51 @@ impl GroupChatCore {
         Success(SendMessageSuccess {
             message_event,
             users_to_notify: users_to_notify.into_iter().collect(),
+            content_filter_match: None,
         })
     }
 
+    // Members who can delete messages are exempt from the content filters
+    pub fn content_filter_match(
+        &self,
+        sender: UserId,
+        text: Option<&str>,
+        mention_count: usize,
+        parent_content_filters: Option<&CompiledContentFilters>,
+        proposals_bot_user_id: UserId,
+    ) -> Option<ContentFilterMatch> {
+        let member = self.members.get(&sender)?;
+
+        if sender == proposals_bot_user_id || member.role.can_delete_messages(&self.permissions) {
+            None
+        } else {
+            check_content_filters(
+                once(&self.content_filters.value).chain(parent_content_filters),
+                text,
+                mention_count,
+            )
+        }
+    }
+
+    // Messages which match a content filter without being rejected are soft deleted by the OpenChat
+    // bot. Held messages stay hidden until a moderator reviews them, otherwise the canister warns the
+    // sender.
+    fn delete_filtered_message(
+        &mut self,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        now: TimestampMillis,
+    ) {
+        self.events.delete_messages(DeleteUndeleteMessagesArgs {
+            caller: OPENCHAT_BOT_USER_ID,
+            is_admin: true,
+            min_visible_event_index: EventIndex::default(),
+            thread_root_message_index,
+            message_ids: vec![message_id],
+            now,
+        });
+    }
+
     pub fn add_reaction(
         &mut self,
         user_id: UserId,
@@ -689,7 +866,7 @@ impl GroupChatCore {
             if member.suspended.value {
                 return UserSuspended;
             }
-            if !member.role.can_react_to_messages(&self.permissions) {
+            if !member.role.can_react_to_messages(&self.permissions) || self.members.muted_until(&user_id, now).is_some() {
                 return NotAuthorized;
             }
 
@@ -1225,6 +1402,291 @@ impl GroupChatCore {
         }
     }
 
+    pub fn report_message(
+        &mut self,
+        reporter: UserId,
+        thread_root_message_index: Option<MessageIndex>,
+        message_id: MessageId,
+        reason_code: u32,
+        notes: Option<String>,
+        now: TimestampMillis,
+    ) -> ReportMessageResult {
+        use ReportMessageResult::*;
+
+        let member = match self.members.get(&reporter) {
+            Some(m) => m,
+            None => return UserNotInGroup,
+        };
+
+        if member.suspended.value {
+            return UserSuspended;
+        }
+
+        let events_reader = match self
+            .events
+            .events_reader(member.min_visible_event_index(), thread_root_message_index)
+        {
+            Some(r) => r,
+            None => return MessageNotFound,
+        };
+
+        let (sender, message_index, event_index) = match events_reader.message_event_internal(message_id.into()) {
+            Some(e) => (e.event.sender, e.event.message_index, e.index),
+            None => return MessageNotFound,
+        };
+
+        let report_id = self.moderation_queue.add_report(AddModerationReportArgs {
+            reporter,
+            thread_root_message_index,
+            message_id,
+            message_index,
+            event_index,
+            sender,
+            reason_code,
+            notes,
+            held_for_review: false,
+            now,
+        });
+
+        Success(report_id)
+    }
+
+    pub fn moderation_queue(
+        &self,
+        user_id: UserId,
+        since: Option<TimestampMillis>,
+        include_resolved: bool,
+    ) -> ModerationQueueResult {
+        use ModerationQueueResult::*;
+
+        if let Some(member) = self.members.get(&user_id) {
+            if !member.role.can_delete_messages(&self.permissions) {
+                return NotAuthorized;
+            }
+
+            Success(self.moderation_queue_unchecked(since, include_resolved))
+        } else {
+            UserNotInGroup
+        }
+    }
+
+    // For callers who have already authorized the user by some other means, eg. community admins
+    pub fn moderation_queue_unchecked(&self, since: Option<TimestampMillis>, include_resolved: bool) -> ModerationQueueSuccess {
+        ModerationQueueSuccess {
+            reports: self.moderation_queue.reports(since, include_resolved),
+            decisions: self.moderation_queue.decisions(since),
+        }
+    }
+
+    pub fn can_moderate_report(&self, user_id: UserId, report_id: ModerationReportId) -> CanModerateReportResult {
+        use CanModerateReportResult::*;
+
+        if let Some(member) = self.members.get(&user_id) {
+            if member.suspended.value {
+                return UserSuspended;
+            }
+            if !member.role.can_delete_messages(&self.permissions) {
+                return NotAuthorized;
+            }
+
+            match self.moderation_queue.get(report_id) {
+                Some(report) if report.is_pending() => Yes(report.sender),
+                Some(_) => AlreadyResolved,
+                None => ReportNotFound,
+            }
+        } else {
+            UserNotInGroup
+        }
+    }
+
+    // If the action involves removing or blocking the sender, the caller is responsible for doing that
+    // before calling this, since removals may require async checks which can't be done here.
+    // Suspending the sender mutes them in this chat for the chosen duration.
+    pub fn moderate_report(
+        &mut self,
+        user_id: UserId,
+        report_id: ModerationReportId,
+        action: ModerationAction,
+        notes: Option<String>,
+        now: TimestampMillis,
+    ) -> CanModerateReportResult {
+        use CanModerateReportResult::*;
+
+        let result = self.can_moderate_report(user_id, report_id);
+        if !matches!(result, Yes(_)) {
+            return result;
+        }
+
+        let report = self.moderation_queue.get(report_id).unwrap();
+        let thread_root_message_index = report.thread_root_message_index;
+        let message_id = report.message_id;
+        let sender = report.sender;
+        let held_for_review = report.held_for_review;
+
+        if let ModerationAction::SuspendSender(duration) = action {
+            match self.mute_member(user_id, sender, duration, now) {
+                // The sender may have already left the chat
+                MuteMemberResult::Success(_) | MuteMemberResult::TargetUserNotInGroup => {}
+                MuteMemberResult::UserNotInGroup => return UserNotInGroup,
+                MuteMemberResult::UserSuspended => return UserSuspended,
+                MuteMemberResult::NotAuthorized | MuteMemberResult::CannotMuteSelf => return NotAuthorized,
+                MuteMemberResult::InvalidDuration => return InvalidDuration,
+            }
+        }
+
+        if held_for_review {
+            // Held messages are already hidden, so dismissing the report releases the message
+            if matches!(action, ModerationAction::Dismiss) {
+                self.undelete_messages(user_id, thread_root_message_index, vec![message_id], now);
+            }
+        } else if matches!(action, ModerationAction::DeleteMessage) {
+            self.delete_messages(user_id, thread_root_message_index, vec![message_id], false, now);
+        }
+
+        self.moderation_queue.resolve(report_id, action, user_id, notes, now);
+        result
+    }
+
+    pub fn mute_member(
+        &mut self,
+        user_id: UserId,
+        target_user_id: UserId,
+        duration: Milliseconds,
+        now: TimestampMillis,
+    ) -> MuteMemberResult {
+        use MuteMemberResult::*;
+
+        if user_id == target_user_id {
+            return CannotMuteSelf;
+        }
+
+        if duration == 0 || duration > MAX_MUTE_DURATION {
+            return InvalidDuration;
+        }
+
+        let member = match self.members.get(&user_id) {
+            Some(m) => m,
+            None => return UserNotInGroup,
+        };
+
+        if member.suspended.value {
+            return UserSuspended;
+        }
+
+        let target_role = match self.members.get(&target_user_id) {
+            Some(t) => t.role.value,
+            None => return TargetUserNotInGroup,
+        };
+
+        if !member.role.can_remove_members_with_role(target_role, &self.permissions) {
+            return NotAuthorized;
+        }
+
+        let muted_until = now.saturating_add(duration);
+        self.members.mute(target_user_id, muted_until);
+
+        self.events.push_main_event(
+            ChatEventInternal::MemberMuted(Box::new(MemberMuted {
+                user_id: target_user_id,
+                muted_by: user_id,
+                muted_until,
+            })),
+            0,
+            now,
+        );
+
+        Success(muted_until)
+    }
+
+    pub fn unmute_member(&mut self, user_id: UserId, target_user_id: UserId, now: TimestampMillis) -> UnmuteMemberResult {
+        use UnmuteMemberResult::*;
+
+        let member = match self.members.get(&user_id) {
+            Some(m) => m,
+            None => return UserNotInGroup,
+        };
+
+        if member.suspended.value {
+            return UserSuspended;
+        }
+
+        let target = match self.members.get(&target_user_id) {
+            Some(t) => t,
+            None => return TargetUserNotInGroup,
+        };
+
+        if !member.role.can_remove_members_with_role(target.role.value, &self.permissions) {
+            return NotAuthorized;
+        }
+
+        if self.members.muted_until(&target_user_id, now).is_none() {
+            return TargetUserNotMuted;
+        }
+
+        self.unmute(target_user_id, Some(user_id), now);
+        Success
+    }
+
+    // Called once a mute has expired. Returns true if the member was unmuted
+    pub fn unmute_member_if_expired(&mut self, user_id: UserId, now: TimestampMillis) -> bool {
+        let expired = self.members.mute_expired(&user_id, now);
+
+        if expired {
+            self.unmute(user_id, None, now);
+        }
+        expired
+    }
+
+    fn unmute(&mut self, user_id: UserId, unmuted_by: Option<UserId>, now: TimestampMillis) {
+        self.members.unmute(&user_id);
+
+        self.events.push_main_event(
+            ChatEventInternal::MemberUnmuted(Box::new(MemberUnmuted { user_id, unmuted_by })),
+            0,
+            now,
+        );
+    }
+
+    pub fn content_filters(&self, user_id: UserId) -> ContentFiltersResult {
+        use ContentFiltersResult::*;
+
+        if let Some(member) = self.members.get(&user_id) {
+            if !member.role.can_update_group(&self.permissions) {
+                return NotAuthorized;
+            }
+
+            Success(self.content_filters.value.filters().clone())
+        } else {
+            UserNotInGroup
+        }
+    }
+
+    pub fn set_content_filters(
+        &mut self,
+        user_id: UserId,
+        content_filters: ContentFilters,
+        now: TimestampMillis,
+    ) -> SetContentFiltersResult {
+        use SetContentFiltersResult::*;
+
+        if let Some(member) = self.members.get(&user_id) {
+            if member.suspended.value {
+                return UserSuspended;
+            }
+            if !member.role.can_update_group(&self.permissions) {
+                return NotAuthorized;
+            }
+            if let Err(error) = validate_content_filters(&content_filters) {
+                return InvalidContentFilters(error);
+            }
+
+            self.content_filters = Timestamped::new(content_filters.into(), now);
+            Success
+        } else {
+            UserNotInGroup
+        }
+    }
+
     pub fn update(
         &mut self,
         user_id: UserId,
@@ -1236,6 +1698,9 @@ impl GroupChatCore {
         gate: OptionUpdate<AccessGate>,
         public: Option<bool>,
         events_ttl: OptionUpdate<Milliseconds>,
+        slow_mode: OptionUpdate<SlowMode>,
+        edit_history_enabled: Option<bool>,
+        hide_forwarded_from: Option<bool>,
         now: TimestampMillis,
     ) -> UpdateResult {
         match self.can_update(&user_id, &name, &description, &rules, &avatar, permissions.as_ref(), &public) {
@@ -1249,6 +1714,9 @@ impl GroupChatCore {
                 gate,
                 public,
                 events_ttl,
+                slow_mode,
+                edit_history_enabled,
+                hide_forwarded_from,
                 now,
             )),
             Err(result) => result,
@@ -1328,6 +1796,9 @@ impl GroupChatCore {
         gate: OptionUpdate<AccessGate>,
         public: Option<bool>,
         events_ttl: OptionUpdate<Milliseconds>,
+        slow_mode: OptionUpdate<SlowMode>,
+        edit_history_enabled: Option<bool>,
+        hide_forwarded_from: Option<bool>,
         now: TimestampMillis,
     ) -> UpdateSuccessResult {
         let mut result = UpdateSuccessResult {
@@ -1468,6 +1939,20 @@ impl GroupChatCore {
             }
         }
 
+        if let Some(slow_mode) = slow_mode.expand().map(|s| s.map(SlowMode::capped)) {
+            if self.slow_mode.value != slow_mode {
+                self.slow_mode = Timestamped::new(slow_mode, now);
+            }
+        }
+
+        if let Some(enabled) = edit_history_enabled {
+            self.events.set_edit_history_enabled(enabled);
+        }
+
+        if let Some(hide) = hide_forwarded_from {
+            self.hide_forwarded_from = hide;
+        }
+
         result
     }
 
@@ -1532,6 +2017,227 @@ impl GroupChatCore {
         }
     }
 
+    pub fn forum(&self) -> Option<&ForumSubtype> {
+        if let Some(GroupSubtype::Forum(forum)) = &self.subtype.value {
+            Some(forum)
+        } else {
+            None
+        }
+    }
+
+    // Each top-level message in a forum must start a topic, whereas no other messages can. Returns the
+    // validated topic, which should be added via `add_forum_topic` once the message has been sent.
+    pub fn validate_forum_topic(
+        &self,
+        thread_root_message_index: Option<MessageIndex>,
+        topic: Option<NewForumTopic>,
+    ) -> Result<Option<NewForumTopic>, String> {
+        match (self.forum(), thread_root_message_index, topic) {
+            (Some(forum), None, Some(topic)) => Ok(Some(NewForumTopic {
+                title: validate_topic_title(&topic.title)?,
+                tags: validate_topic_tags(topic.tags, forum)?,
+            })),
+            (Some(_), None, None) => Err("Top-level messages in a forum must start a topic".to_string()),
+            (_, _, Some(_)) => Err("Topics can only be started by top-level messages in a forum".to_string()),
+            (_, _, None) => Ok(None),
+        }
+    }
+
+    pub fn add_forum_topic(&mut self, root_message_index: MessageIndex, topic: NewForumTopic, now: TimestampMillis) {
+        self.forum_topics.add(root_message_index, topic, now);
+    }
+
+    pub fn update_forum_topic(
+        &mut self,
+        user_id: UserId,
+        root_message_index: MessageIndex,
+        title: Option<String>,
+        tags: Option<Vec<String>>,
+        solved: Option<bool>,
+        answer: Option<MessageIndex>,
+        now: TimestampMillis,
+    ) -> UpdateForumTopicResult {
+        use UpdateForumTopicResult::*;
+
+        let member = match self.members.get(&user_id) {
+            Some(m) => m,
+            None => return UserNotInGroup,
+        };
+        if member.suspended.value {
+            return UserSuspended;
+        }
+
+        let forum = match self.forum() {
+            Some(f) => f,
+            None => return NotForum,
+        };
+
+        let min_visible_event_index = member.min_visible_event_index();
+        let root_message_sender = match self
+            .events
+            .visible_main_events_reader(min_visible_event_index)
+            .message_internal(root_message_index.into())
+            .filter(|m| m.deleted_by.is_none() && self.forum_topics.get(&root_message_index).is_some())
+        {
+            Some(m) => m.sender,
+            None => return TopicNotFound,
+        };
+
+        // Topics can be updated by their creators and by anyone who can delete messages
+        if root_message_sender != user_id && !member.role.can_delete_messages(&self.permissions) {
+            return NotAuthorized;
+        }
+
+        let title = match title.map(|t| validate_topic_title(&t)).transpose() {
+            Ok(t) => t,
+            Err(error) => return InvalidRequest(error),
+        };
+        let tags = match tags.map(|t| validate_topic_tags(t, forum)).transpose() {
+            Ok(t) => t,
+            Err(error) => return InvalidRequest(error),
+        };
+        if let Some(answer) = answer.filter(|_| solved == Some(true)) {
+            if !self
+                .events
+                .is_accessible(min_visible_event_index, Some(root_message_index), answer.into())
+            {
+                return InvalidRequest("Answer not found".to_string());
+            }
+        }
+
+        let topic = self.forum_topics.get_mut(&root_message_index, now).unwrap();
+        if let Some(title) = title {
+            topic.title = title;
+        }
+        if let Some(tags) = tags {
+            topic.tags = tags;
+        }
+        match solved {
+            Some(true) => {
+                topic.solved = Some(ForumTopicSolved {
+                    marked_by: user_id,
+                    timestamp: now,
+                    answer,
+                })
+            }
+            Some(false) => topic.solved = None,
+            None => {}
+        }
+        Success
+    }
+
+    pub fn forum_topics(
+        &self,
+        user_id: Option<UserId>,
+        tags: &[String],
+        solved: Option<bool>,
+        sort: ForumTopicsSortOrder,
+        page_index: u32,
+        page_size: u8,
+    ) -> ForumTopicsResult {
+        use ForumTopicsResult::*;
+
+        if self.forum().is_none() {
+            return NotForum;
+        }
+
+        let min_visible_event_index = match self.min_visible_event_index(user_id) {
+            Some(index) => index,
+            None => return UserNotInGroup,
+        };
+
+        let reader = self.events.visible_main_events_reader(min_visible_event_index);
+
+        // Sort using the internal messages so that only the requested page needs to be hydrated
+        let mut matches: Vec<_> = self
+            .forum_topics
+            .filter(tags, solved)
+            .filter_map(|(root_message_index, topic)| {
+                let root = reader.message_event_internal(root_message_index.into())?;
+                if root.event.deleted_by.is_some() {
+                    return None;
+                }
+                let (reply_count, latest_activity) = root
+                    .event
+                    .thread_summary
+                    .as_ref()
+                    .map_or((0, root.timestamp), |s| (s.reply_count, s.latest_event_timestamp));
+
+                Some((root_message_index, topic, reply_count, latest_activity))
+            })
+            .collect();
+
+        match sort {
+            ForumTopicsSortOrder::LatestActivity => matches.sort_unstable_by_key(|(i, _, _, a)| Reverse((*a, *i))),
+            ForumTopicsSortOrder::Newest => matches.sort_unstable_by_key(|(i, _, _, _)| Reverse(*i)),
+            ForumTopicsSortOrder::MostReplies => matches.sort_unstable_by_key(|(i, _, r, _)| Reverse((*r, *i))),
+        }
+
+        let total = matches.len() as u32;
+        let topics = matches
+            .into_iter()
+            .skip(page_index as usize * page_size as usize)
+            .take(page_size as usize)
+            .filter_map(|(root_message_index, topic, reply_count, latest_activity)| {
+                Some(ForumTopic {
+                    root_message: reader.message_event(root_message_index.into(), user_id)?,
+                    title: topic.title.clone(),
+                    tags: topic.tags.clone(),
+                    solved: topic.solved.clone(),
+                    reply_count,
+                    latest_activity,
+                })
+            })
+            .collect();
+
+        Success(ForumTopicsSuccess { topics, total })
+    }
+
+    // Returns the content of the message along with where it was originally sent so that it can be
+    // forwarded to other chats. If the message was itself forwarded then its original provenance is kept.
+    // Channels pass `in_private_community` since a public channel within a private community is private.
+    pub fn message_for_forwarding(
+        &self,
+        user_id: UserId,
+        chat: Chat,
+        in_private_community: bool,
+        thread_root_message_index: Option<MessageIndex>,
+        message_index: MessageIndex,
+    ) -> MessageForForwardingResult {
+        use MessageForForwardingResult::*;
+
+        let events_reader = match self.events_reader(Some(user_id), thread_root_message_index) {
+            EventsReaderResult::Success(r) => r,
+            EventsReaderResult::UserNotInGroup => return UserNotInGroup,
+            EventsReaderResult::ThreadNotFound => return MessageNotFound,
+        };
+
+        let (message, timestamp) = match events_reader
+            .message_event(message_index.into(), Some(user_id))
+            .filter(|m| !matches!(m.event.content, MessageContent::Deleted(_)))
+        {
+            Some(m) => (m.event, m.timestamp),
+            None => return MessageNotFound,
+        };
+
+        // Private chats can choose to hide where their messages are forwarded from
+        let is_private = !self.is_public.value || in_private_community;
+        let hide_chat = is_private && self.hide_forwarded_from;
+
+        let forwarded_from = message.forwarded_from.unwrap_or(ForwardedFrom {
+            chat: (!hide_chat).then_some(chat),
+            thread_root_message_index: thread_root_message_index.filter(|_| !hide_chat),
+            message_index: (!hide_chat).then_some(message.message_index),
+            sender: message.sender,
+            timestamp,
+        });
+
+        match message.content.into_forwardable() {
+            Some(content) => Success(MessageForForwarding { content, forwarded_from }),
+            None => NotForwardable,
+        }
+    }
+
     pub fn remove_expired_events(&mut self, now: TimestampMillis) {
         let result = self.events.remove_expired_events(now);
 
@@ -1694,11 +2400,15 @@ pub enum SendMessageResult {
     UserSuspended,
     RulesNotAccepted,
     InvalidRequest(String),
+    ContentRejected(ContentFilterReason),
+    RateLimited(TimestampMillis),
+    UserMuted(TimestampMillis),
 }
 
 pub struct SendMessageSuccess {
     pub message_event: EventWrapper<Message>,
     pub users_to_notify: Vec<UserId>,
+    pub content_filter_match: Option<ContentFilterMatch>,
 }
 
 pub enum AddRemoveReactionResult {
@@ -1788,6 +2498,67 @@ pub enum RemoveMemberResult {
     CannotRemoveSelf,
 }
 
+pub enum ReportMessageResult {
+    Success(ModerationReportId),
+    MessageNotFound,
+    UserNotInGroup,
+    UserSuspended,
+}
+
+pub enum ModerationQueueResult {
+    Success(ModerationQueueSuccess),
+    UserNotInGroup,
+    NotAuthorized,
+}
+
+pub struct ModerationQueueSuccess {
+    pub reports: Vec<ModerationReport>,
+    pub decisions: Vec<ModerationDecision>,
+}
+
+pub enum CanModerateReportResult {
+    Yes(UserId),
+    UserSuspended,
+    UserNotInGroup,
+    NotAuthorized,
+    ReportNotFound,
+    AlreadyResolved,
+    InvalidDuration,
+}
+
+pub enum MuteMemberResult {
+    Success(TimestampMillis),
+    UserNotInGroup,
+    TargetUserNotInGroup,
+    UserSuspended,
+    NotAuthorized,
+    CannotMuteSelf,
+    InvalidDuration,
+}
+
+pub enum UnmuteMemberResult {
+    Success,
+    UserNotInGroup,
+    TargetUserNotInGroup,
+    TargetUserNotMuted,
+    UserSuspended,
+    NotAuthorized,
+}
+
+pub enum ContentFiltersResult {
+    Success(ContentFilters),
+    UserNotInGroup,
+    NotAuthorized,
+}
+
+pub enum SetContentFiltersResult {
+    Success,
+    UserSuspended,
+    UserNotInGroup,
+    NotAuthorized,
+    InvalidContentFilters(String),
+}
+
 pub enum UpdateResult {
     Success(UpdateSuccessResult),
     UserSuspended,
@@ -1830,6 +2601,40 @@ pub enum DeletedMessageResult {
     MessageHardDeleted,
 }
 
+pub enum MessageEditHistoryResult {
+    Success(Vec<MessageRevision>),
+    UserNotInGroup,
+    MessageNotFound,
+}
+
+pub enum UpdateForumTopicResult {
+    Success,
+    UserNotInGroup,
+    UserSuspended,
+    NotForum,
+    TopicNotFound,
+    NotAuthorized,
+    InvalidRequest(String),
+}
+
+pub enum ForumTopicsResult {
+    Success(ForumTopicsSuccess),
+    UserNotInGroup,
+    NotForum,
+}
+
+pub struct ForumTopicsSuccess {
+    pub topics: Vec<ForumTopic>,
+    pub total: u32,
+}
+
+pub enum MessageForForwardingResult {
+    Success(MessageForForwarding),
+    UserNotInGroup,
+    MessageNotFound,
+    NotForwardable,
+}
+
 pub enum ThreadPreviewsResult {
     Success(Vec<ThreadPreview>),
     UserNotInGroup,
@@ -1900,6 +2705,7 @@ pub struct SummaryUpdates {
     pub events_ttl: OptionUpdate<Milliseconds>,
     pub events_ttl_last_updated: Option<TimestampMillis>,
     pub gate: OptionUpdate<AccessGate>,
+    pub slow_mode: OptionUpdate<SlowMode>,
     pub rules_changed: bool,
 }
 
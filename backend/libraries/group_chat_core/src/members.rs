@@ -21,6 +21,9 @@ pub struct GroupMembers {
     #[serde(serialize_with = "serialize_members", deserialize_with = "deserialize_members")]
     pub members: HashMap<UserId, GroupMemberInternal>,
     pub blocked: HashSet<UserId>,
+    // Mutes are kept here rather than on each member so that they persist if the member leaves and rejoins
+    #[serde(default)]
+    muted: HashMap<UserId, TimestampMillis>,
     pub moderator_count: u32,
     pub admin_count: u32,
     pub owner_count: u32,
@@ -54,11 +57,13 @@ impl GroupMembers {
             suspended: Timestamped::default(),
             rules_accepted: Some(Timestamped::new(Version::zero(), now)),
             is_bot,
+            recent_message_timestamps: Vec::new(),
         };
 
         GroupMembers {
             members: vec![(creator_user_id, member)].into_iter().collect(),
             blocked: HashSet::new(),
+            muted: HashMap::new(),
             moderator_count: 0,
             admin_count: 0,
             owner_count: 1,
@@ -96,6 +101,7 @@ impl GroupMembers {
                         suspended: Timestamped::default(),
                         rules_accepted: None,
                         is_bot,
+                        recent_message_timestamps: Vec::new(),
                     };
                     e.insert(member.clone());
                     self.updates.insert((now, user_id, MemberUpdate::Added));
@@ -140,6 +146,23 @@ impl GroupMembers {
         }
     }
 
+    pub fn mute(&mut self, user_id: UserId, muted_until: TimestampMillis) {
+        self.muted.insert(user_id, muted_until);
+    }
+
+    pub fn unmute(&mut self, user_id: &UserId) -> bool {
+        self.muted.remove(user_id).is_some()
+    }
+
+    // Returns the time the user's mute expires, if they are currently muted
+    pub fn muted_until(&self, user_id: &UserId, now: TimestampMillis) -> Option<TimestampMillis> {
+        self.muted.get(user_id).copied().filter(|ts| *ts > now)
+    }
+
+    pub fn mute_expired(&self, user_id: &UserId, now: TimestampMillis) -> bool {
+        self.muted.get(user_id).map_or(false, |ts| *ts <= now)
+    }
+
     pub fn blocked(&self) -> Vec<UserId> {
         self.blocked.iter().copied().collect()
     }
@@ -335,6 +358,8 @@ pub struct GroupMemberInternal {
     pub rules_accepted: Option<Timestamped<Version>>,
     #[serde(rename = "b", default, skip_serializing_if = "is_default")]
     pub is_bot: bool,
+    #[serde(rename = "sm", default, skip_serializing_if = "is_empty_slice")]
+    pub recent_message_timestamps: Vec<TimestampMillis>,
 
     #[serde(rename = "me", default, skip_serializing_if = "is_default")]
     min_visible_event_index: EventIndex,
@@ -391,6 +416,15 @@ impl GroupMemberInternal {
 
         self.rules_accepted = Some(Timestamped::new(max(version, current_version), now));
     }
+
+    pub fn record_message_sent(&mut self, now: TimestampMillis, timestamps_to_retain: usize) {
+        self.recent_message_timestamps.push(now);
+
+        let len = self.recent_message_timestamps.len();
+        if len > timestamps_to_retain {
+            self.recent_message_timestamps.drain(..len - timestamps_to_retain);
+        }
+    }
 }
 
 impl From<&GroupMemberInternal> for GroupMember {
@@ -464,6 +498,7 @@ mod tests {
             min_visible_message_index: 0.into(),
             rules_accepted: Some(Timestamped::new(Version::zero(), 1)),
             is_bot: false,
+            recent_message_timestamps: Vec::new(),
         };
 
         let member_bytes = msgpack::serialize_then_unwrap(&member);
@@ -493,12 +528,13 @@ mod tests {
             min_visible_message_index: 1.into(),
             rules_accepted: Some(Timestamped::new(Version::zero(), 1)),
             is_bot: true,
+            recent_message_timestamps: vec![1],
         };
 
         let member_bytes = msgpack::serialize_then_unwrap(&member);
         let member_bytes_len = member_bytes.len();
 
-        assert_eq!(member_bytes_len, 120);
+        assert_eq!(member_bytes_len, 125);
 
         let _deserialized: GroupMemberInternal = msgpack::deserialize_then_unwrap(&member_bytes);
     }
@@ -0,0 +1,261 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use types::{
+    EventIndex, MessageId, MessageIndex, MessageReport, ModerationAction, ModerationDecision, ModerationReport,
+    ModerationReportId, ModerationReportStatus, TimestampMillis, UserId,
+};
+
+const MAX_NOTES_LENGTH: usize = 500;
+const MAX_DECISIONS: usize = 1000;
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct ModerationQueue {
+    reports: BTreeMap<ModerationReportId, ModerationReportInternal>,
+    pending: BTreeSet<ModerationReportId>,
+    decisions: VecDeque<ModerationDecision>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ModerationReportInternal {
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub message_index: MessageIndex,
+    pub event_index: EventIndex,
+    pub sender: UserId,
+    pub reports: Vec<MessageReport>,
+    pub status: ModerationReportStatus,
+    pub created: TimestampMillis,
+    pub last_updated: TimestampMillis,
+    // Set if the message was hidden by a content filter pending review
+    #[serde(default)]
+    pub held_for_review: bool,
+}
+
+pub struct AddModerationReportArgs {
+    pub reporter: UserId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub message_index: MessageIndex,
+    pub event_index: EventIndex,
+    pub sender: UserId,
+    pub reason_code: u32,
+    pub notes: Option<String>,
+    pub held_for_review: bool,
+    pub now: TimestampMillis,
+}
+
+impl ModerationQueue {
+    pub fn add_report(&mut self, args: AddModerationReportArgs) -> ModerationReportId {
+        let report = MessageReport {
+            reported_by: args.reporter,
+            timestamp: args.now,
+            reason_code: args.reason_code,
+            notes: args.notes.map(truncate_notes),
+        };
+
+        // If there is already a pending report for this message, add to it rather than creating a new one
+        if let Some(existing) = self.pending.iter().copied().find(|id| {
+            self.reports.get(id).map_or(false, |r| {
+                r.message_id == args.message_id && r.thread_root_message_index == args.thread_root_message_index
+            })
+        }) {
+            let existing_report = self.reports.get_mut(&existing).unwrap();
+            existing_report.reports.retain(|r| r.reported_by != args.reporter);
+            existing_report.reports.push(report);
+            existing_report.last_updated = args.now;
+            return existing;
+        }
+
+        let report_id = self.reports.keys().next_back().map_or(1, |id| id + 1);
+
+        self.reports.insert(
+            report_id,
+            ModerationReportInternal {
+                thread_root_message_index: args.thread_root_message_index,
+                message_id: args.message_id,
+                message_index: args.message_index,
+                event_index: args.event_index,
+                sender: args.sender,
+                reports: vec![report],
+                status: ModerationReportStatus::Pending,
+                created: args.now,
+                last_updated: args.now,
+                held_for_review: args.held_for_review,
+            },
+        );
+        self.pending.insert(report_id);
+
+        report_id
+    }
+
+    pub fn get(&self, report_id: ModerationReportId) -> Option<&ModerationReportInternal> {
+        self.reports.get(&report_id)
+    }
+
+    pub fn resolve(
+        &mut self,
+        report_id: ModerationReportId,
+        action: ModerationAction,
+        decided_by: UserId,
+        notes: Option<String>,
+        now: TimestampMillis,
+    ) -> bool {
+        if !self.pending.remove(&report_id) {
+            return false;
+        }
+
+        let report = self.reports.get_mut(&report_id).unwrap();
+        report.status = ModerationReportStatus::Resolved(action);
+        report.last_updated = now;
+
+        self.decisions.push_back(ModerationDecision {
+            report_id,
+            action,
+            target: report.sender,
+            decided_by,
+            timestamp: now,
+            notes: notes.map(truncate_notes),
+        });
+        if self.decisions.len() > MAX_DECISIONS {
+            self.decisions.pop_front();
+        }
+
+        true
+    }
+
+    pub fn pending_count(&self) -> u32 {
+        self.pending.len() as u32
+    }
+
+    pub fn reports(&self, since: Option<TimestampMillis>, include_resolved: bool) -> Vec<ModerationReport> {
+        let since = since.unwrap_or_default();
+
+        self.reports
+            .iter()
+            .filter(|(id, r)| r.last_updated > since && (include_resolved || self.pending.contains(*id)))
+            .map(|(id, r)| r.hydrate(*id))
+            .collect()
+    }
+
+    pub fn decisions(&self, since: Option<TimestampMillis>) -> Vec<ModerationDecision> {
+        let since = since.unwrap_or_default();
+
+        self.decisions
+            .iter()
+            .rev()
+            .take_while(|d| d.timestamp > since)
+            .cloned()
+            .collect()
+    }
+}
+
+impl ModerationReportInternal {
+    pub fn is_pending(&self) -> bool {
+        matches!(self.status, ModerationReportStatus::Pending)
+    }
+
+    fn hydrate(&self, report_id: ModerationReportId) -> ModerationReport {
+        ModerationReport {
+            report_id,
+            thread_root_message_index: self.thread_root_message_index,
+            message_id: self.message_id,
+            message_index: self.message_index,
+            event_index: self.event_index,
+            sender: self.sender,
+            reports: self.reports.clone(),
+            status: self.status.clone(),
+            created: self.created,
+            last_updated: self.last_updated,
+            held_for_review: self.held_for_review,
+        }
+    }
+}
+
+fn truncate_notes(notes: String) -> String {
+    if notes.chars().count() > MAX_NOTES_LENGTH {
+        notes.chars().take(MAX_NOTES_LENGTH).collect()
+    } else {
+        notes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn reports_for_same_message_are_merged() {
+        let mut queue = ModerationQueue::default();
+
+        let first = queue.add_report(args(user(1), 1));
+        let second = queue.add_report(args(user(2), 2));
+        let repeat = queue.add_report(args(user(1), 3));
+
+        assert_eq!(first, second);
+        assert_eq!(first, repeat);
+
+        let report = queue.get(first).unwrap();
+        assert_eq!(report.reports.len(), 2);
+        assert_eq!(report.last_updated, 3);
+        assert_eq!(queue.pending_count(), 1);
+    }
+
+    #[test]
+    fn reporting_after_resolution_opens_new_report() {
+        let mut queue = ModerationQueue::default();
+
+        let first = queue.add_report(args(user(1), 1));
+        assert!(queue.resolve(first, ModerationAction::Dismiss, user(3), None, 2));
+        assert!(!queue.resolve(first, ModerationAction::DeleteMessage, user(3), None, 3));
+
+        let second = queue.add_report(args(user(2), 4));
+        assert_ne!(first, second);
+
+        assert_eq!(queue.reports(None, false).len(), 1);
+        assert_eq!(queue.reports(None, true).len(), 2);
+        assert_eq!(queue.decisions(None).len(), 1);
+        assert!(queue.decisions(Some(2)).is_empty());
+    }
+
+    #[test]
+    fn notes_and_decisions_are_bounded() {
+        let mut queue = ModerationQueue::default();
+
+        for i in 0..(MAX_DECISIONS as u64 + 5) {
+            let mut args = args(user(1), 2 * i + 1);
+            args.message_id = (i as u128).into();
+            args.notes = Some("a".repeat(MAX_NOTES_LENGTH + 1));
+            let report_id = queue.add_report(args);
+
+            assert_eq!(
+                queue.get(report_id).unwrap().reports[0].notes.as_ref().unwrap().len(),
+                MAX_NOTES_LENGTH
+            );
+            queue.resolve(report_id, ModerationAction::Dismiss, user(3), None, 2 * i + 2);
+        }
+
+        let decisions = queue.decisions(None);
+        assert_eq!(decisions.len(), MAX_DECISIONS);
+        assert_eq!(decisions.last().unwrap().report_id, 6);
+    }
+
+    fn args(reporter: UserId, now: TimestampMillis) -> AddModerationReportArgs {
+        AddModerationReportArgs {
+            reporter,
+            thread_root_message_index: None,
+            message_id: 1u128.into(),
+            message_index: 1.into(),
+            event_index: 2.into(),
+            sender: user(10),
+            reason_code: 0,
+            notes: None,
+            held_for_review: false,
+            now,
+        }
+    }
+
+    fn user(index: u64) -> UserId {
+        Principal::from_slice(&index.to_be_bytes()).into()
+    }
+}
@@ -120,6 +120,7 @@ impl GroupRoleInternal {
                 .find(|cp| cp.subtype == mc.kind)
                 .map(|cp| cp.role)
                 .unwrap_or(ps.default),
+            MessageContentInitial::Sticker(_) => ps.default,
         };
 
         self.is_permitted(sender_role)
@@ -0,0 +1,95 @@
+use crate::InviteLinks;
+use candid::Principal;
+use group_chat_core::GroupChatCore;
+use serde::{Deserialize, Serialize};
+use types::{GroupRole, UserId};
+
+/// Everything which moves along with a channel when it is moved into another community or detached
+/// into a standalone group. This is serialized by the source community then pulled in pages by the
+/// destination canister.
+#[derive(Deserialize)]
+pub struct ChannelExport {
+    pub chat: GroupChatCore,
+    pub invite_links: InviteLinks<GroupRole>,
+    pub user_groups: Vec<ExportedUserGroup>,
+    pub principals: Vec<(Principal, UserId)>,
+}
+
+/// A community user group, reduced to those of its members who are in the channel
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportedUserGroup {
+    pub name: String,
+    pub users: Vec<UserId>,
+}
+
+// Serialized identically to `ChannelExport` but avoids having to clone the chat
+#[derive(Serialize)]
+struct ChannelExportRef<'a> {
+    chat: &'a GroupChatCore,
+    invite_links: &'a InviteLinks<GroupRole>,
+    user_groups: Vec<ExportedUserGroup>,
+    principals: Vec<(Principal, UserId)>,
+}
+
+impl ChannelExport {
+    pub fn serialize(
+        chat: &GroupChatCore,
+        invite_links: &InviteLinks<GroupRole>,
+        user_groups: Vec<ExportedUserGroup>,
+        principals: Vec<(Principal, UserId)>,
+    ) -> Vec<u8> {
+        msgpack::serialize_then_unwrap(ChannelExportRef {
+            chat,
+            invite_links,
+            user_groups,
+            principals,
+        })
+    }
+
+    pub fn deserialize(bytes: &[u8]) -> ChannelExport {
+        msgpack::deserialize_then_unwrap(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::{GroupPermissions, Rules};
+
+    #[test]
+    fn round_trip() {
+        let user_id: UserId = Principal::from_slice(&[1]).into();
+        let chat = GroupChatCore::new(
+            user_id,
+            true,
+            "channel".to_string(),
+            String::new(),
+            Rules::default(),
+            None,
+            None,
+            true,
+            GroupPermissions::default(),
+            None,
+            None,
+            false,
+            0,
+        );
+        let user_groups = vec![ExportedUserGroup {
+            name: "team".to_string(),
+            users: vec![user_id],
+        }];
+
+        let bytes = ChannelExport::serialize(
+            &chat,
+            &InviteLinks::default(),
+            user_groups,
+            vec![(Principal::from_slice(&[2]), user_id)],
+        );
+        let export = ChannelExport::deserialize(&bytes);
+
+        assert_eq!(export.chat.name.value, "channel");
+        assert!(export.chat.members.contains(&user_id));
+        assert_eq!(export.user_groups[0].users, vec![user_id]);
+        assert_eq!(export.principals.len(), 1);
+    }
+}
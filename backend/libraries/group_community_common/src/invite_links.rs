@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{InviteLink, TimestampMillis, UserId};
+
+const MAX_LINKS: usize = 100;
+const MAX_NAME_LENGTH: u32 = 50;
+
+#[derive(Serialize, Deserialize)]
+pub struct InviteLinks<R> {
+    links: BTreeMap<u64, InviteLinkInternal<R>>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct InviteLinkInternal<R> {
+    pub name: String,
+    pub created_by: UserId,
+    pub created: TimestampMillis,
+    pub expires_at: Option<TimestampMillis>,
+    pub max_uses: Option<u32>,
+    pub uses: u32,
+    pub role: Option<R>,
+}
+
+pub struct NewInviteLink<R> {
+    pub code: u64,
+    pub name: String,
+    pub created_by: UserId,
+    pub expires_at: Option<TimestampMillis>,
+    pub max_uses: Option<u32>,
+    pub role: Option<R>,
+}
+
+#[derive(Debug)]
+pub enum CreateInviteLinkError {
+    NameTooLong(u32),
+    ExpiryInPast,
+    InvalidMaxUses,
+    TooManyLinks(u32),
+}
+
+impl<R: Copy> InviteLinks<R> {
+    pub fn create(&mut self, link: NewInviteLink<R>, now: TimestampMillis) -> Result<InviteLink<R>, CreateInviteLinkError> {
+        if link.name.chars().count() as u32 > MAX_NAME_LENGTH {
+            return Err(CreateInviteLinkError::NameTooLong(MAX_NAME_LENGTH));
+        }
+        if link.expires_at.map_or(false, |ts| ts <= now) {
+            return Err(CreateInviteLinkError::ExpiryInPast);
+        }
+        if link.max_uses == Some(0) {
+            return Err(CreateInviteLinkError::InvalidMaxUses);
+        }
+        // Links which can no longer be used make way for new ones
+        if self.links.len() >= MAX_LINKS {
+            self.links.retain(|_, l| l.is_valid(now));
+            if self.links.len() >= MAX_LINKS {
+                return Err(CreateInviteLinkError::TooManyLinks(MAX_LINKS as u32));
+            }
+        }
+
+        let internal = InviteLinkInternal {
+            name: link.name,
+            created_by: link.created_by,
+            created: now,
+            expires_at: link.expires_at,
+            max_uses: link.max_uses,
+            uses: 0,
+            role: link.role,
+        };
+        let result = internal.hydrate(link.code);
+        self.links.insert(link.code, internal);
+
+        Ok(result)
+    }
+
+    pub fn contains(&self, code: u64) -> bool {
+        self.links.contains_key(&code)
+    }
+
+    pub fn get_valid(&self, code: u64, now: TimestampMillis) -> Option<&InviteLinkInternal<R>> {
+        self.links.get(&code).filter(|l| l.is_valid(now))
+    }
+
+    pub fn record_use(&mut self, code: u64, now: TimestampMillis) -> Option<&InviteLinkInternal<R>> {
+        let link = self.links.get_mut(&code).filter(|l| l.is_valid(now))?;
+        link.uses += 1;
+        Some(link)
+    }
+
+    // Counts the use of the invite link (if the code belongs to a valid link) and, if the link grants a role, passes the
+    // link's creator and the role to `grant_role`, which must only grant the role if the creator is still permitted
+    // to assign it. Must be called in the same message as the checks which admitted the user, so that concurrent joins
+    // can't take a link beyond its max uses.
+    pub fn apply(&mut self, code: u64, now: TimestampMillis, grant_role: impl FnOnce(UserId, R)) {
+        if let Some((created_by, role)) = self.record_use(code, now).and_then(|l| l.role.map(|r| (l.created_by, r))) {
+            grant_role(created_by, role);
+        }
+    }
+
+    pub fn revoke(&mut self, code: u64) -> bool {
+        self.links.remove(&code).is_some()
+    }
+
+    pub fn list(&self) -> Vec<InviteLink<R>> {
+        self.links.iter().map(|(code, l)| l.hydrate(*code)).collect()
+    }
+}
+
+impl<R> Default for InviteLinks<R> {
+    fn default() -> Self {
+        InviteLinks { links: BTreeMap::new() }
+    }
+}
+
+impl<R: Copy> InviteLinkInternal<R> {
+    pub fn is_valid(&self, now: TimestampMillis) -> bool {
+        self.expires_at.map_or(true, |ts| now < ts) && self.max_uses.map_or(true, |max| self.uses < max)
+    }
+
+    fn hydrate(&self, code: u64) -> InviteLink<R> {
+        InviteLink {
+            code,
+            name: self.name.clone(),
+            created_by: self.created_by,
+            created: self.created,
+            expires_at: self.expires_at,
+            max_uses: self.max_uses,
+            uses: self.uses,
+            role: self.role,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+    use types::GroupRole;
+
+    #[test]
+    fn link_becomes_invalid_once_max_uses_reached() {
+        let mut links = InviteLinks::default();
+        links.create(new_link(1, None, Some(2)), 0).unwrap();
+
+        assert!(links.record_use(1, 1).is_some());
+        assert!(links.record_use(1, 2).is_some());
+        assert!(links.record_use(1, 3).is_none());
+        assert!(links.get_valid(1, 3).is_none());
+        assert_eq!(links.list()[0].uses, 2);
+    }
+
+    #[test]
+    fn applying_link_grants_role() {
+        let mut links = InviteLinks::default();
+        let mut link = new_link(1, None, Some(1));
+        link.role = Some(GroupRole::Moderator);
+        links.create(link, 0).unwrap();
+
+        let mut granted = None;
+        links.apply(1, 1, |created_by, role| granted = Some((created_by, role)));
+        assert_eq!(granted, Some((Principal::from_slice(&[1]).into(), GroupRole::Moderator)));
+
+        // The link has reached its max uses so no longer grants the role
+        let mut granted = None;
+        links.apply(1, 2, |created_by, role| granted = Some((created_by, role)));
+        assert!(granted.is_none());
+    }
+
+    #[test]
+    fn link_becomes_invalid_once_expired() {
+        let mut links = InviteLinks::default();
+        links.create(new_link(1, Some(10), None), 0).unwrap();
+
+        assert!(links.get_valid(1, 9).is_some());
+        assert!(links.get_valid(1, 10).is_none());
+        assert!(matches!(
+            links.create(new_link(2, Some(5), None), 5),
+            Err(CreateInviteLinkError::ExpiryInPast)
+        ));
+    }
+
+    fn new_link(code: u64, expires_at: Option<TimestampMillis>, max_uses: Option<u32>) -> NewInviteLink<GroupRole> {
+        NewInviteLink {
+            code,
+            name: "link".to_string(),
+            created_by: Principal::from_slice(&[1]).into(),
+            expires_at,
+            max_uses,
+            role: None,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use candid::Principal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::{CanisterId, JoinRequest, Milliseconds, TimestampMillis, UserId};
+
+const MAX_PENDING_REQUESTS: usize = 1000;
+const MAX_MESSAGE_LENGTH: u32 = 500;
+const MAX_REQUEST_AGE: Milliseconds = 30 * 24 * 60 * 60 * 1000; // 30 days
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct JoinRequests {
+    requests: BTreeMap<UserId, JoinRequestInternal>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct JoinRequestInternal {
+    pub principal: Principal,
+    // The local_user_index which forwarded the request, which is the one to notify of the outcome
+    pub local_user_index_canister_id: CanisterId,
+    pub message: Option<String>,
+    pub requested: TimestampMillis,
+}
+
+#[derive(Debug)]
+pub enum AddJoinRequestError {
+    AlreadyRequested,
+    MessageTooLong(u32),
+    TooManyRequests(u32),
+}
+
+impl JoinRequests {
+    pub fn add(
+        &mut self,
+        user_id: UserId,
+        principal: Principal,
+        local_user_index_canister_id: CanisterId,
+        message: Option<String>,
+        now: TimestampMillis,
+    ) -> Result<(), AddJoinRequestError> {
+        if self.requests.contains_key(&user_id) {
+            return Err(AddJoinRequestError::AlreadyRequested);
+        }
+        if message
+            .as_ref()
+            .map_or(false, |m| m.chars().count() as u32 > MAX_MESSAGE_LENGTH)
+        {
+            return Err(AddJoinRequestError::MessageTooLong(MAX_MESSAGE_LENGTH));
+        }
+        if self.requests.len() >= MAX_PENDING_REQUESTS && self.remove_expired(now).is_empty() {
+            return Err(AddJoinRequestError::TooManyRequests(MAX_PENDING_REQUESTS as u32));
+        }
+
+        self.requests.insert(
+            user_id,
+            JoinRequestInternal {
+                principal,
+                local_user_index_canister_id,
+                message,
+                requested: now,
+            },
+        );
+        Ok(())
+    }
+
+    pub fn get(&self, user_id: &UserId) -> Option<&JoinRequestInternal> {
+        self.requests.get(user_id)
+    }
+
+    pub fn remove(&mut self, user_id: &UserId) -> Option<JoinRequestInternal> {
+        self.requests.remove(user_id)
+    }
+
+    // Removes and returns the requests which have gone unanswered for too long
+    pub fn remove_expired(&mut self, now: TimestampMillis) -> Vec<(UserId, JoinRequestInternal)> {
+        let expired: Vec<_> = self
+            .requests
+            .iter()
+            .filter(|(_, r)| now.saturating_sub(r.requested) > MAX_REQUEST_AGE)
+            .map(|(user_id, _)| *user_id)
+            .collect();
+
+        expired
+            .into_iter()
+            .filter_map(|user_id| self.requests.remove(&user_id).map(|r| (user_id, r)))
+            .collect()
+    }
+
+    // Returns the pending requests, oldest first
+    pub fn list(&self) -> Vec<JoinRequest> {
+        let mut requests: Vec<_> = self
+            .requests
+            .iter()
+            .map(|(user_id, r)| JoinRequest {
+                user_id: *user_id,
+                message: r.message.clone(),
+                requested: r.requested,
+            })
+            .collect();
+
+        requests.sort_by_key(|r| r.requested);
+        requests
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_requests_are_rejected() {
+        let mut requests = JoinRequests::default();
+        let principal = Principal::from_slice(&[1]);
+        let user_id: UserId = Principal::from_slice(&[2]).into();
+        let local_user_index = Principal::from_slice(&[3]);
+
+        assert!(requests.add(user_id, principal, local_user_index, None, 1).is_ok());
+        assert!(matches!(
+            requests.add(user_id, principal, local_user_index, Some("hi".to_string()), 2),
+            Err(AddJoinRequestError::AlreadyRequested)
+        ));
+
+        assert!(requests.remove(&user_id).is_some());
+        assert!(requests.add(user_id, principal, local_user_index, None, 3).is_ok());
+        assert_eq!(requests.list()[0].requested, 3);
+    }
+
+    #[test]
+    fn stale_requests_expire() {
+        let mut requests = JoinRequests::default();
+        let principal = Principal::from_slice(&[1]);
+        let user_id1: UserId = Principal::from_slice(&[2]).into();
+        let user_id2: UserId = Principal::from_slice(&[3]).into();
+        let local_user_index = Principal::from_slice(&[4]);
+
+        requests.add(user_id1, principal, local_user_index, None, 1).unwrap();
+        requests.add(user_id2, principal, local_user_index, None, 2).unwrap();
+
+        let expired = requests.remove_expired(MAX_REQUEST_AGE + 2);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].0, user_id1);
+        assert_eq!(expired[0].1.local_user_index_canister_id, local_user_index);
+        assert!(requests.get(&user_id2).is_some());
+    }
+}
@@ -1,5 +1,11 @@
+mod channel_export;
+mod invite_links;
+mod join_requests;
 mod payment_receipts;
 mod pending_payments_queue;
 
+pub use channel_export::*;
+pub use invite_links::*;
+pub use join_requests::*;
 pub use payment_receipts::*;
 pub use pending_payments_queue::*;
@@ -1,5 +1,6 @@
 mod document_handler;
 mod logs_handler;
+mod metrics;
 mod router;
 
 use serde::Serialize;
@@ -8,6 +9,7 @@ use types::{HeaderField, HttpResponse};
 
 pub use document_handler::*;
 pub use logs_handler::*;
+pub use metrics::*;
 pub use router::*;
 
 pub fn build_json_response<T: Serialize>(body: &T) -> HttpResponse {
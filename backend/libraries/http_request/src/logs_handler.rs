@@ -1,14 +1,283 @@
 use crate::build_response;
 use canister_logger::LogEntry;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::io::Write;
+use std::str::FromStr;
 use types::{HttpResponse, TimestampMillis};
 
-pub fn encode_logs(entries: Vec<LogEntry>, since: TimestampMillis) -> HttpResponse {
+#[derive(Clone, Debug, Default)]
+pub struct LogsQuery {
+    pub since: Option<TimestampMillis>,
+    pub level: Option<LogLevel>,
+    pub target: Option<String>,
+    pub text: Option<String>,
+    pub limit: Option<usize>,
+    pub newest_first: bool,
+    pub format: LogsFormat,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum LogsFormat {
+    // Each entry exactly as it was written by the logger
+    #[default]
+    Raw,
+    // One human readable line per entry
+    Text,
+    // One JSON object per line, with the structured fields captured by the logger
+    Json,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl FromStr for LogLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "trace" => Ok(LogLevel::Trace),
+            "debug" => Ok(LogLevel::Debug),
+            "info" => Ok(LogLevel::Info),
+            "warn" | "warning" => Ok(LogLevel::Warn),
+            "error" => Ok(LogLevel::Error),
+            _ => Err(()),
+        }
+    }
+}
+
+impl LogsQuery {
+    // Supported parameters are `level` (the minimum level), `target` (a module path prefix), `text` (a
+    // case-insensitive substring), `limit`, `order` (`asc` or `desc`) and `format` (`text` or `json`)
+    pub fn parse(since: Option<TimestampMillis>, qs: &str) -> LogsQuery {
+        let mut query = LogsQuery {
+            since,
+            ..Default::default()
+        };
+
+        for (key, value) in qs.split('&').filter_map(|p| p.split_once('=')) {
+            let value = percent_decode(value);
+            match key {
+                "level" => query.level = LogLevel::from_str(&value).ok(),
+                "target" => query.target = Some(value).filter(|v| !v.is_empty()),
+                "text" => query.text = Some(value.to_lowercase()).filter(|v| !v.is_empty()),
+                "limit" => query.limit = usize::from_str(&value).ok(),
+                "order" => query.newest_first = value == "desc",
+                "format" => {
+                    query.format = match value.as_str() {
+                        "text" => LogsFormat::Text,
+                        "json" => LogsFormat::Json,
+                        _ => LogsFormat::Raw,
+                    }
+                }
+                _ => {}
+            }
+        }
+        query
+    }
+
+    fn is_filtered(&self) -> bool {
+        self.level.is_some() || self.target.is_some() || self.text.is_some() || self.format != LogsFormat::Raw
+    }
+}
+
+pub fn encode_logs(entries: Vec<LogEntry>, query: LogsQuery) -> HttpResponse {
+    let since = query.since.unwrap_or(0);
+    let parse_entries = query.is_filtered();
+
+    let mut matching: Vec<_> = entries
+        .into_iter()
+        .filter(|e| e.timestamp > since)
+        .map(|e| {
+            let structured = if parse_entries { StructuredLogEntry::parse(&e) } else { None };
+            (e, structured)
+        })
+        .filter(|(e, s)| matches_filters(&query, e, s.as_ref()))
+        .collect();
+
+    if query.newest_first {
+        matching.reverse();
+    }
+    if let Some(limit) = query.limit {
+        matching.truncate(limit);
+    }
+
     let mut body = Vec::new();
+    for (entry, structured) in matching {
+        match (query.format, structured) {
+            (LogsFormat::Text, Some(s)) => writeln!(&mut body, "{}", s.to_text()).unwrap(),
+            (LogsFormat::Json, Some(s)) => writeln!(&mut body, "{}", serde_json::to_string(&s).unwrap()).unwrap(),
+            _ => writeln!(&mut body, "{}", entry.message).unwrap(),
+        }
+    }
+
+    let content_type = if query.format == LogsFormat::Json { "application/x-ndjson" } else { "text/plain" };
+
+    build_response(body, content_type)
+}
+
+fn matches_filters(query: &LogsQuery, entry: &LogEntry, structured: Option<&StructuredLogEntry>) -> bool {
+    if let Some(min_level) = query.level {
+        let level = structured.and_then(|s| LogLevel::from_str(&s.level).ok());
+        if !matches!(level, Some(l) if l >= min_level) {
+            return false;
+        }
+    }
+    if let Some(target) = query.target.as_ref() {
+        if !structured.is_some_and(|s| s.target.to_lowercase().starts_with(target)) {
+            return false;
+        }
+    }
+    if let Some(text) = query.text.as_ref() {
+        if !entry.message.to_lowercase().contains(text) {
+            return false;
+        }
+    }
+    true
+}
+
+// The shape of the entries written by the `tracing` JSON layer
+#[derive(Serialize, Deserialize)]
+struct StructuredLogEntry {
+    #[serde(default)]
+    timestamp: TimestampMillis,
+    #[serde(default)]
+    level: String,
+    #[serde(default)]
+    target: String,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    fields: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    filename: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    line_number: Option<u32>,
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+impl StructuredLogEntry {
+    fn parse(entry: &LogEntry) -> Option<StructuredLogEntry> {
+        // The JSON layer writes the timestamp as a string so we use the one recorded by the logger instead
+        let mut value: Map<String, Value> = serde_json::from_str(entry.message.trim()).ok()?;
+        value.remove("timestamp");
+        let mut structured: StructuredLogEntry = serde_json::from_value(Value::Object(value)).ok()?;
+        structured.timestamp = entry.timestamp;
+        Some(structured)
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = format!("{} {:>5} {}:", self.timestamp, self.level, self.target);
+        if let Some(message) = self.fields.get("message") {
+            text.push(' ');
+            match message.as_str() {
+                Some(m) => text.push_str(m),
+                None => text.push_str(&message.to_string()),
+            }
+        }
+        for (key, value) in self.fields.iter().filter(|(k, _)| *k != "message") {
+            text.push_str(&format!(" {key}={value}"));
+        }
+        text
+    }
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => match bytes
+                .get(i + 1..i + 3)
+                .and_then(|h| u8::from_str_radix(std::str::from_utf8(h).ok()?, 16).ok())
+            {
+                Some(b) => {
+                    decoded.push(b);
+                    i += 2;
+                }
+                None => decoded.push(b'%'),
+            },
+            b => decoded.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    for entry in entries.into_iter().filter(|e| e.timestamp > since) {
-        writeln!(&mut body, "{}", entry.message).unwrap();
+    fn entry(timestamp: TimestampMillis, level: &str, target: &str, message: &str) -> LogEntry {
+        LogEntry {
+            timestamp,
+            message: format!(
+                r#"{{"timestamp":"{timestamp}","level":"{level}","fields":{{"message":"{message}","user_id":"abc"}},"target":"{target}","filename":"src/lib.rs","line_number":10}}"#
+            ),
+        }
     }
 
-    build_response(body, "text/plain")
+    fn encode(entries: Vec<LogEntry>, qs: &str) -> String {
+        String::from_utf8(encode_logs(entries, LogsQuery::parse(None, qs)).body.into_vec()).unwrap()
+    }
+
+    fn entries() -> Vec<LogEntry> {
+        vec![
+            entry(1, "INFO", "user_canister_impl::updates::send_message", "Message sent"),
+            entry(2, "ERROR", "user_canister_impl::jobs::sync", "Sync failed"),
+            entry(3, "WARN", "group_canister_impl::updates::send_message", "Message rejected"),
+        ]
+    }
+
+    #[test]
+    fn filter_by_level() {
+        let output = encode(entries(), "level=warn&format=text");
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "2 ERROR user_canister_impl::jobs::sync: Sync failed user_id=\"abc\"",
+                "3  WARN group_canister_impl::updates::send_message: Message rejected user_id=\"abc\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_by_target_and_text() {
+        let output = encode(entries(), "target=user_canister_impl&text=message%20SENT&format=text");
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.starts_with("1  INFO"));
+    }
+
+    #[test]
+    fn newest_first_with_limit() {
+        let output = encode(entries(), "order=desc&limit=2&format=text");
+        let timestamps: Vec<_> = output.lines().map(|l| l.split(' ').next().unwrap()).collect();
+        assert_eq!(timestamps, vec!["3", "2"]);
+    }
+
+    #[test]
+    fn json_lines_include_structured_fields() {
+        let output = encode(entries(), "format=json&limit=1");
+        let value: Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(value["timestamp"], 1);
+        assert_eq!(value["level"], "INFO");
+        assert_eq!(value["fields"]["user_id"], "abc");
+        assert_eq!(value["line_number"], 10);
+    }
+
+    #[test]
+    fn unfiltered_output_is_unchanged() {
+        let entries = entries();
+        let expected: String = entries.iter().skip(1).map(|e| format!("{}\n", e.message)).collect();
+        let output = String::from_utf8(encode_logs(entries, LogsQuery::parse(Some(1), "")).body.into_vec()).unwrap();
+        assert_eq!(output, expected);
+    }
 }
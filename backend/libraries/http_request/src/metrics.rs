@@ -0,0 +1,433 @@
+use crate::{build_json_response, build_response};
+use num_traits::ToPrimitive;
+use serde::Serialize;
+use std::fmt::Write;
+use types::{BuildVersion, ChatMetrics, Cycles, HttpResponse, TimestampMillis, UpgradeRolloutStatus, UpgradeRolloutSummary};
+
+const OPEN_METRICS_CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum MetricsFormat {
+    #[default]
+    Json,
+    Prometheus,
+}
+
+impl MetricsFormat {
+    pub fn from_query_string(qs: &str) -> MetricsFormat {
+        if qs.split('&').any(|p| p == "format=prometheus" || p == "format=openmetrics") {
+            MetricsFormat::Prometheus
+        } else {
+            MetricsFormat::Json
+        }
+    }
+}
+
+// Implemented by each canister's `Metrics` struct so that its fields can be exposed as typed metrics
+pub trait RegisterMetrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry);
+}
+
+pub fn build_metrics_response<M: Serialize + RegisterMetrics>(metrics: &M, format: MetricsFormat) -> HttpResponse {
+    match format {
+        MetricsFormat::Json => build_json_response(metrics),
+        MetricsFormat::Prometheus => {
+            let mut registry = MetricsRegistry::default();
+            metrics.register_metrics(&mut registry);
+            build_response(registry.encode().into_bytes(), OPEN_METRICS_CONTENT_TYPE)
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MetricsRegistry {
+    families: Vec<MetricFamily>,
+}
+
+struct MetricFamily {
+    name: String,
+    help: String,
+    kind: MetricKind,
+    samples: Vec<Sample>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum MetricKind {
+    Counter,
+    Gauge,
+    Histogram,
+}
+
+struct Sample {
+    suffix: &'static str,
+    labels: Vec<(String, String)>,
+    value: f64,
+}
+
+impl MetricsRegistry {
+    pub fn counter(&mut self, name: &str, help: &str, value: impl ToPrimitive) -> &mut Self {
+        self.counter_with_labels(name, help, &[], value)
+    }
+
+    pub fn counter_with_labels(
+        &mut self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        value: impl ToPrimitive,
+    ) -> &mut Self {
+        self.push_sample(name, help, MetricKind::Counter, "_total", labels, value);
+        self
+    }
+
+    pub fn gauge(&mut self, name: &str, help: &str, value: impl ToPrimitive) -> &mut Self {
+        self.gauge_with_labels(name, help, &[], value)
+    }
+
+    pub fn gauge_with_labels(&mut self, name: &str, help: &str, labels: &[(&str, &str)], value: impl ToPrimitive) -> &mut Self {
+        self.push_sample(name, help, MetricKind::Gauge, "", labels, value);
+        self
+    }
+
+    pub fn histogram(&mut self, name: &str, help: &str, histogram: &Histogram) -> &mut Self {
+        self.histogram_with_labels(name, help, &[], histogram)
+    }
+
+    pub fn histogram_with_labels(
+        &mut self,
+        name: &str,
+        help: &str,
+        labels: &[(&str, &str)],
+        histogram: &Histogram,
+    ) -> &mut Self {
+        let mut cumulative = 0;
+        for (upper_bound, count) in histogram.buckets.iter().zip(histogram.counts.iter()) {
+            cumulative += count;
+            let le = upper_bound.to_string();
+            let bucket_labels: Vec<_> = labels.iter().copied().chain([("le", le.as_str())]).collect();
+            self.push_sample(name, help, MetricKind::Histogram, "_bucket", &bucket_labels, cumulative);
+        }
+        let bucket_labels: Vec<_> = labels.iter().copied().chain([("le", "+Inf")]).collect();
+        self.push_sample(name, help, MetricKind::Histogram, "_bucket", &bucket_labels, histogram.count);
+        self.push_sample(name, help, MetricKind::Histogram, "_sum", labels, histogram.sum);
+        self.push_sample(name, help, MetricKind::Histogram, "_count", labels, histogram.count);
+        self
+    }
+
+    // The metrics which every canister exposes
+    pub fn canister(
+        &mut self,
+        now: TimestampMillis,
+        memory_used: u64,
+        cycles_balance: Cycles,
+        wasm_version: BuildVersion,
+        git_commit_id: &str,
+    ) -> &mut Self {
+        let wasm_version = wasm_version.to_string();
+        self.gauge("canister_time_ms", "The canister time", now)
+            .gauge("canister_memory_used_bytes", "Memory used by the canister", memory_used)
+            .gauge("canister_cycles_balance", "The canister's cycles balance", cycles_balance)
+            .gauge_with_labels(
+                "canister_build_info",
+                "The wasm version and git commit the canister is running",
+                &[("wasm_version", &wasm_version), ("git_commit_id", git_commit_id)],
+                1,
+            )
+    }
+
+    // Upgrades of the canisters which an index canister is responsible for, by status
+    pub fn canister_upgrades(
+        &mut self,
+        canister_type: &str,
+        completed: u64,
+        pending: u64,
+        in_progress: u64,
+        failed: usize,
+    ) -> &mut Self {
+        const UPGRADES: &str = "canister_upgrades";
+        const UPGRADES_HELP: &str = "Upgrades of child canisters, by status";
+
+        self.gauge_with_labels(
+            UPGRADES,
+            UPGRADES_HELP,
+            &[("canister_type", canister_type), ("status", "completed")],
+            completed,
+        )
+        .gauge_with_labels(
+            UPGRADES,
+            UPGRADES_HELP,
+            &[("canister_type", canister_type), ("status", "pending")],
+            pending,
+        )
+        .gauge_with_labels(
+            UPGRADES,
+            UPGRADES_HELP,
+            &[("canister_type", canister_type), ("status", "in_progress")],
+            in_progress,
+        )
+        .gauge_with_labels(
+            UPGRADES,
+            UPGRADES_HELP,
+            &[("canister_type", canister_type), ("status", "failed")],
+            failed,
+        )
+    }
+
+    // The progress of each stage of a staged upgrade rollout, if there is one
+    pub fn canister_upgrade_rollout(&mut self, canister_type: &str, rollout: Option<&UpgradeRolloutSummary>) -> &mut Self {
+        const STAGE_UPGRADES: &str = "canister_upgrade_rollout_stage_upgrades";
+        const STAGE_UPGRADES_HELP: &str = "Upgrades within each stage of the current rollout, by outcome";
+
+        let rollout = match rollout {
+            Some(r) => r,
+            None => return self,
+        };
+
+        let status = match rollout.status {
+            UpgradeRolloutStatus::InProgress => "in_progress",
+            UpgradeRolloutStatus::Paused(_) => "paused",
+            UpgradeRolloutStatus::Aborted(_) => "aborted",
+            UpgradeRolloutStatus::Completed(_) => "completed",
+        };
+        let wasm_version = rollout.wasm_version.to_string();
+
+        self.gauge_with_labels(
+            "canister_upgrade_rollout_info",
+            "The wasm version and status of the current rollout",
+            &[
+                ("canister_type", canister_type),
+                ("wasm_version", &wasm_version),
+                ("status", status),
+            ],
+            1,
+        )
+        .gauge_with_labels(
+            "canister_upgrade_rollout_current_stage",
+            "The index of the stage the current rollout is on",
+            &[("canister_type", canister_type)],
+            rollout.current_stage,
+        );
+
+        for (index, stage) in rollout.stages.iter().enumerate() {
+            let index = index.to_string();
+            let target_percent = stage.target_percent.to_string();
+            let labels = [
+                ("canister_type", canister_type),
+                ("stage", index.as_str()),
+                ("target_percent", target_percent.as_str()),
+            ];
+
+            self.gauge_with_labels(
+                "canister_upgrade_rollout_stage_canisters",
+                "Canisters to be upgraded within each stage of the current rollout",
+                &labels,
+                stage.canisters,
+            );
+            for (outcome, count) in [("succeeded", stage.succeeded), ("failed", stage.failed)] {
+                let outcome_labels: Vec<_> = labels.iter().copied().chain([("outcome", outcome)]).collect();
+                self.gauge_with_labels(STAGE_UPGRADES, STAGE_UPGRADES_HELP, &outcome_labels, count);
+            }
+        }
+        self
+    }
+
+    pub fn encode(&self) -> String {
+        let mut text = String::new();
+
+        for family in self.families.iter() {
+            let kind = match family.kind {
+                MetricKind::Counter => "counter",
+                MetricKind::Gauge => "gauge",
+                MetricKind::Histogram => "histogram",
+            };
+            writeln!(text, "# TYPE {} {kind}", family.name).unwrap();
+            writeln!(text, "# HELP {} {}", family.name, escape(&family.help)).unwrap();
+
+            for sample in family.samples.iter() {
+                text.push_str(&family.name);
+                text.push_str(sample.suffix);
+                if !sample.labels.is_empty() {
+                    let labels: Vec<_> = sample.labels.iter().map(|(k, v)| format!("{k}=\"{}\"", escape(v))).collect();
+                    write!(text, "{{{}}}", labels.join(",")).unwrap();
+                }
+                writeln!(text, " {}", sample.value).unwrap();
+            }
+        }
+        text.push_str("# EOF\n");
+        text
+    }
+
+    fn push_sample(
+        &mut self,
+        name: &str,
+        help: &str,
+        kind: MetricKind,
+        suffix: &'static str,
+        labels: &[(&str, &str)],
+        value: impl ToPrimitive,
+    ) {
+        let sample = Sample {
+            suffix,
+            labels: labels.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            value: value.to_f64().unwrap_or(f64::NAN),
+        };
+
+        // Samples for the same metric must be grouped together under a single family
+        if let Some(family) = self.families.iter_mut().find(|f| f.name == name) {
+            assert!(family.kind == kind, "metric '{name}' registered with multiple types");
+            family.samples.push(sample);
+        } else {
+            self.families.push(MetricFamily {
+                name: name.to_string(),
+                help: help.to_string(),
+                kind,
+                samples: vec![sample],
+            });
+        }
+    }
+}
+
+impl RegisterMetrics for ChatMetrics {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const MESSAGES: &str = "chat_messages";
+        const MESSAGES_HELP: &str = "Messages sent, by content type";
+
+        for (message_type, count) in [
+            ("text", self.text_messages),
+            ("image", self.image_messages),
+            ("video", self.video_messages),
+            ("audio", self.audio_messages),
+            ("file", self.file_messages),
+            ("poll", self.polls),
+            ("icp", self.icp_messages),
+            ("sns1", self.sns1_messages),
+            ("ckbtc", self.ckbtc_messages),
+            ("chat", self.chat_messages),
+            ("kinic", self.kinic_messages),
+            ("giphy", self.giphy_messages),
+            ("prize", self.prize_messages),
+            ("prize_winner", self.prize_winner_messages),
+            ("custom", self.custom_type_messages),
+        ] {
+            registry.counter_with_labels(MESSAGES, MESSAGES_HELP, &[("type", message_type)], count);
+        }
+
+        registry
+            .counter("chat_deleted_messages", "Messages deleted", self.deleted_messages)
+            .counter("chat_poll_votes", "Votes cast in polls", self.poll_votes)
+            .counter("chat_replies", "Messages sent as replies", self.replies)
+            .counter("chat_edits", "Messages edited", self.edits)
+            .counter("chat_reactions", "Reactions added to messages", self.reactions)
+            .counter("chat_proposals", "Proposal messages", self.proposals)
+            .counter("chat_reported_messages", "Messages reported", self.reported_messages)
+            .counter("chat_message_reminders", "Message reminders created", self.message_reminders)
+            .gauge("chat_last_active_ms", "When the chat was last active", self.last_active);
+    }
+}
+
+pub struct Histogram {
+    buckets: Vec<f64>,
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    pub fn new(mut buckets: Vec<f64>) -> Histogram {
+        buckets.sort_by(|a, b| a.total_cmp(b));
+        buckets.dedup();
+        let counts = vec![0; buckets.len()];
+
+        Histogram {
+            buckets,
+            counts,
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    pub fn exponential(start: f64, factor: f64, count: usize) -> Histogram {
+        Histogram::new((0..count).map(|i| start * factor.powi(i as i32)).collect())
+    }
+
+    pub fn observe(&mut self, value: impl ToPrimitive) {
+        let value = match value.to_f64() {
+            Some(v) if v.is_finite() => v,
+            _ => return,
+        };
+        if let Some(index) = self.buckets.iter().position(|b| value <= *b) {
+            self.counts[index] += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_open_metrics() {
+        let mut histogram = Histogram::new(vec![10.0, 100.0]);
+        histogram.observe(5);
+        histogram.observe(50);
+        histogram.observe(500);
+
+        let mut registry = MetricsRegistry::default();
+        registry
+            .gauge("memory_used_bytes", "Memory used", 1000u64)
+            .counter_with_labels("messages", "Messages sent", &[("type", "text")], 3u64)
+            .counter_with_labels("messages", "Messages sent", &[("type", "image")], 2u64)
+            .histogram("upgrade_instructions", "Instructions per upgrade", &histogram);
+
+        let expected = r#"# TYPE memory_used_bytes gauge
+# HELP memory_used_bytes Memory used
+memory_used_bytes 1000
+# TYPE messages counter
+# HELP messages Messages sent
+messages_total{type="text"} 3
+messages_total{type="image"} 2
+# TYPE upgrade_instructions histogram
+# HELP upgrade_instructions Instructions per upgrade
+upgrade_instructions_bucket{le="10"} 1
+upgrade_instructions_bucket{le="100"} 2
+upgrade_instructions_bucket{le="+Inf"} 3
+upgrade_instructions_sum 555
+upgrade_instructions_count 3
+# EOF
+"#;
+
+        assert_eq!(registry.encode(), expected);
+    }
+
+    #[test]
+    fn non_finite_values_are_ignored() {
+        let mut histogram = Histogram::new(vec![10.0]);
+        histogram.observe(5);
+        histogram.observe(f64::NAN);
+        histogram.observe(f64::INFINITY);
+
+        let mut registry = MetricsRegistry::default();
+        registry.histogram("values", "Values", &histogram);
+
+        assert!(registry.encode().contains("values_sum 5\nvalues_count 1\n"));
+    }
+
+    #[test]
+    fn format_from_query_string() {
+        assert_eq!(
+            MetricsFormat::from_query_string("format=prometheus"),
+            MetricsFormat::Prometheus
+        );
+        assert_eq!(
+            MetricsFormat::from_query_string("a=1&format=prometheus"),
+            MetricsFormat::Prometheus
+        );
+        assert_eq!(MetricsFormat::from_query_string(""), MetricsFormat::Json);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{LogsQuery, MetricsFormat};
 use std::str::FromStr;
 use types::{ChannelId, FileId, TimestampMillis};
 
@@ -6,9 +7,9 @@ pub enum Route {
     Banner(Option<u128>),
     ChannelAvatar((ChannelId, Option<u128>)),
     File(u128),
-    Logs(Option<TimestampMillis>),
-    Traces(Option<TimestampMillis>),
-    Metrics,
+    Logs(LogsQuery),
+    Traces(LogsQuery),
+    Metrics(MetricsFormat),
     Other(String, String),
 }
 
@@ -49,13 +50,13 @@ pub fn extract_route(path: &str) -> Route {
         }
         "logs" => {
             let since = parts.get(1).and_then(|p| u64::from_str(p).ok());
-            return Route::Logs(since);
+            return Route::Logs(LogsQuery::parse(since, qs));
         }
         "trace" => {
             let since = parts.get(1).and_then(|p| u64::from_str(p).ok());
-            return Route::Traces(since);
+            return Route::Traces(LogsQuery::parse(since, qs));
         }
-        "metrics" => return Route::Metrics,
+        "metrics" => return Route::Metrics(MetricsFormat::from_query_string(qs)),
         _ => (),
     }
 
@@ -65,6 +66,7 @@ pub fn extract_route(path: &str) -> Route {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::LogLevel;
 
     #[test]
     fn avatar() {
@@ -78,6 +80,24 @@ mod tests {
     #[test]
     fn logs() {
         assert!(matches!(extract_route("/logs/1633649663014109000"), Route::Logs(_)));
+
+        match extract_route("/logs/1633649663014109000?level=warn&limit=10") {
+            Route::Logs(query) => {
+                assert_eq!(query.since, Some(1633649663014109000));
+                assert_eq!(query.level, Some(LogLevel::Warn));
+                assert_eq!(query.limit, Some(10));
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn metrics() {
+        assert!(matches!(extract_route("/metrics"), Route::Metrics(MetricsFormat::Json)));
+        assert!(matches!(
+            extract_route("/metrics?format=prometheus"),
+            Route::Metrics(MetricsFormat::Prometheus)
+        ));
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use candid::Principal;
 use serde::{Serialize, Serializer};
 use sha256::sha256_string;
-use types::{BuildVersion, CanisterWasm, Empty, UpgradeCanisterWasmArgs, UpgradesFilter};
+use types::{BuildVersion, CanisterWasm, Empty, UpgradeCanisterWasmArgs, UpgradeRolloutConfig, UpgradesFilter};
 
 pub use human_readable_derive::HumanReadable;
 
@@ -65,6 +65,8 @@ pub struct HumanReadableUpgradeCanisterWasmArgs {
     wasm: CanisterWasmTrimmed,
     filter: Option<HumanReadableUpgradesFilter>,
     use_for_new_canisters: Option<bool>,
+    rollout: Option<UpgradeRolloutConfig>,
+    wasm_hash: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -82,6 +84,8 @@ impl ToHumanReadable for UpgradeCanisterWasmArgs {
             wasm: (&self.wasm).into(),
             filter: self.filter.as_ref().map(|f| f.into()),
             use_for_new_canisters: self.use_for_new_canisters,
+            rollout: self.rollout.clone(),
+            wasm_hash: self.wasm_hash.map(hex::encode),
         }
     }
 }
@@ -1,3 +1,5 @@
+use candid::CandidType;
+use http_request::Histogram;
 use ic_stable_structures::log::WriteError;
 use ic_stable_structures::memory_manager::VirtualMemory;
 use ic_stable_structures::storable::Bound;
@@ -6,8 +8,15 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use types::{BuildVersion, TimestampMillis};
 
+mod samples;
+mod summary;
+
+pub use samples::*;
+pub use summary::*;
+
 pub type Memory = VirtualMemory<DefaultMemoryImpl>;
 
+// The stable log can't be truncated, so it is only used for the upgrades, which are infrequent
 pub struct InstructionCountsLog {
     log: StableLog<InstructionCountEntry, Memory, Memory>,
 }
@@ -37,6 +46,11 @@ impl InstructionCountsLog {
     pub fn iter(&self) -> impl Iterator<Item = InstructionCountEntry> + '_ {
         self.log.iter()
     }
+
+    pub fn latest(&self, count: u64) -> impl Iterator<Item = InstructionCountEntry> + '_ {
+        let len = self.log.len();
+        (len.saturating_sub(count)..len).filter_map(|index| self.log.get(index))
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -47,12 +61,52 @@ pub struct InstructionCountEntry {
     instruction_count: u64,
 }
 
+/// Buckets the instruction counts of the logged pre/post upgrade calls.
+pub fn upgrade_instructions_histogram<'a>(entries: impl IntoIterator<Item = &'a InstructionCountEntry>) -> Histogram {
+    let mut histogram = Histogram::exponential(100_000_000.0, 2.0, 8);
+    for entry in entries {
+        if entry.function_id.is_upgrade() {
+            histogram.observe(entry.instruction_count);
+        }
+    }
+    histogram
+}
+
+impl InstructionCountEntry {
+    pub fn instruction_count(&self) -> u64 {
+        self.instruction_count
+    }
+
+    pub fn function_id(&self) -> InstructionCountFunctionId {
+        self.function_id
+    }
+
+    pub fn wasm_version(&self) -> BuildVersion {
+        self.wasm_version
+    }
+}
+
 #[repr(u8)]
-#[derive(Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum InstructionCountFunctionId {
     Unknown = 0,
     PreUpgrade = 1,
     PostUpgrade = 2,
+    SendMessage = 3,
+    EditMessage = 4,
+    DeleteMessages = 5,
+    AddReaction = 6,
+    RemoveReaction = 7,
+    RegisterPollVote = 8,
+}
+
+impl InstructionCountFunctionId {
+    pub fn is_upgrade(&self) -> bool {
+        matches!(
+            self,
+            InstructionCountFunctionId::PreUpgrade | InstructionCountFunctionId::PostUpgrade
+        )
+    }
 }
 
 fn init_log(index_memory: Memory, data_memory: Memory) -> StableLog<InstructionCountEntry, Memory, Memory> {
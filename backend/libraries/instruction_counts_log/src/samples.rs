@@ -0,0 +1,119 @@
+use crate::{InstructionCountEntry, InstructionCountFunctionId, InstructionCountsLog, InstructionCountsSummary};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use types::BuildVersion;
+
+// Only one in every `SAMPLE_EVERY` calls to each function is recorded
+const SAMPLE_EVERY: u64 = 10;
+const MAX_SAMPLES_PER_VERSION: usize = 1_000;
+// Regressions are found by comparing the latest version with the one before, so older versions are dropped
+const MAX_VERSIONS_PER_FUNCTION: usize = 3;
+const MAX_UPGRADE_ENTRIES: u64 = 200;
+
+// The instruction counts of a sample of the calls to the frequently called functions. These are held on the heap,
+// rather than in the stable log alongside the upgrades, so that the samples can be bounded.
+#[derive(Serialize, Deserialize, Default)]
+pub struct InstructionCountSamples {
+    functions: BTreeMap<InstructionCountFunctionId, FunctionSamples>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct FunctionSamples {
+    calls: u64,
+    versions: Vec<VersionSamples>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct VersionSamples {
+    wasm_version: BuildVersion,
+    // The most recent samples, oldest first
+    instruction_counts: VecDeque<u64>,
+}
+
+impl InstructionCountSamples {
+    pub fn record(&mut self, function_id: InstructionCountFunctionId, instruction_count: u64, wasm_version: BuildVersion) {
+        let function = self.functions.entry(function_id).or_default();
+        function.calls += 1;
+        if function.calls % SAMPLE_EVERY != 1 {
+            return;
+        }
+
+        let index = match function.versions.iter().position(|v| v.wasm_version == wasm_version) {
+            Some(index) => index,
+            None => {
+                if function.versions.len() >= MAX_VERSIONS_PER_FUNCTION {
+                    let oldest = (0..function.versions.len())
+                        .min_by_key(|i| function.versions[*i].wasm_version)
+                        .unwrap();
+                    function.versions.remove(oldest);
+                }
+                function.versions.push(VersionSamples {
+                    wasm_version,
+                    instruction_counts: VecDeque::new(),
+                });
+                function.versions.len() - 1
+            }
+        };
+
+        let samples = &mut function.versions[index].instruction_counts;
+        if samples.len() >= MAX_SAMPLES_PER_VERSION {
+            samples.pop_front();
+        }
+        samples.push_back(instruction_count);
+    }
+
+    // Summarizes the samples along with the most recent upgrades. Both are bounded, so this is cheap enough to run
+    // whenever the summary is requested.
+    pub fn summarize(&self, log: &InstructionCountsLog, regression_threshold_percent: u32) -> InstructionCountsSummary {
+        let samples = self.functions.iter().flat_map(|(function_id, function)| {
+            function.versions.iter().flat_map(move |version| {
+                version.instruction_counts.iter().map(move |count| InstructionCountEntry {
+                    timestamp: 0,
+                    wasm_version: version.wasm_version,
+                    function_id: *function_id,
+                    instruction_count: *count,
+                })
+            })
+        });
+
+        InstructionCountsSummary::build(log.latest(MAX_UPGRADE_ENTRIES).chain(samples), regression_threshold_percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calls_are_sampled() {
+        let mut samples = InstructionCountSamples::default();
+        for i in 0..100 {
+            samples.record(InstructionCountFunctionId::SendMessage, i, BuildVersion::new(2, 0, 1));
+        }
+
+        let function = &samples.functions[&InstructionCountFunctionId::SendMessage];
+        assert_eq!(function.calls, 100);
+        assert_eq!(
+            function.versions[0].instruction_counts.iter().copied().collect::<Vec<_>>(),
+            (0..100).step_by(SAMPLE_EVERY as usize).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn samples_and_versions_are_bounded() {
+        let mut samples = InstructionCountSamples::default();
+        for version in 1..=5 {
+            for i in 0..(SAMPLE_EVERY * (MAX_SAMPLES_PER_VERSION as u64 + 10)) {
+                samples.record(InstructionCountFunctionId::AddReaction, i, BuildVersion::new(2, 0, version));
+            }
+        }
+
+        let function = &samples.functions[&InstructionCountFunctionId::AddReaction];
+        let versions: Vec<_> = function.versions.iter().map(|v| v.wasm_version).collect();
+        assert_eq!(versions, (3..=5).map(|v| BuildVersion::new(2, 0, v)).collect::<Vec<_>>());
+        assert!(function
+            .versions
+            .iter()
+            .all(|v| v.instruction_counts.len() == MAX_SAMPLES_PER_VERSION));
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{InstructionCountEntry, InstructionCountFunctionId};
+use candid::CandidType;
+use http_request::{MetricsRegistry, RegisterMetrics};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use types::BuildVersion;
+
+pub const DEFAULT_REGRESSION_THRESHOLD_PERCENT: u32 = 10;
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct InstructionCountsSummary {
+    pub stats: Vec<InstructionCountStats>,
+    pub regressions: Vec<InstructionCountRegression>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct InstructionCountStats {
+    pub function_id: InstructionCountFunctionId,
+    pub wasm_version: BuildVersion,
+    pub count: u32,
+    pub min: u64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p95: u64,
+    pub p99: u64,
+    pub max: u64,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct InstructionCountRegression {
+    pub function_id: InstructionCountFunctionId,
+    pub percentile: InstructionCountPercentile,
+    pub previous_version: BuildVersion,
+    pub previous: u64,
+    pub current_version: BuildVersion,
+    pub current: u64,
+    pub increase_percent: u32,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum InstructionCountPercentile {
+    P50,
+    P95,
+}
+
+impl InstructionCountsSummary {
+    // Calculates the percentiles for each function and wasm version, then flags each function whose p50 or p95
+    // under its latest wasm version rose by more than `regression_threshold_percent` compared to the version before
+    pub fn build(
+        entries: impl Iterator<Item = InstructionCountEntry>,
+        regression_threshold_percent: u32,
+    ) -> InstructionCountsSummary {
+        let mut grouped: BTreeMap<(InstructionCountFunctionId, BuildVersion), Vec<u64>> = BTreeMap::new();
+        for entry in entries {
+            grouped
+                .entry((entry.function_id, entry.wasm_version))
+                .or_default()
+                .push(entry.instruction_count);
+        }
+
+        let stats: Vec<_> = grouped
+            .into_iter()
+            .map(|((function_id, wasm_version), mut counts)| {
+                counts.sort_unstable();
+                InstructionCountStats {
+                    function_id,
+                    wasm_version,
+                    count: counts.len() as u32,
+                    min: counts[0],
+                    p50: percentile(&counts, 50),
+                    p90: percentile(&counts, 90),
+                    p95: percentile(&counts, 95),
+                    p99: percentile(&counts, 99),
+                    max: counts[counts.len() - 1],
+                }
+            })
+            .collect();
+
+        let regressions = find_regressions(&stats, regression_threshold_percent);
+
+        InstructionCountsSummary { stats, regressions }
+    }
+}
+
+impl RegisterMetrics for InstructionCountsSummary {
+    fn register_metrics(&self, registry: &mut MetricsRegistry) {
+        const INSTRUCTIONS: &str = "canister_instructions";
+        const INSTRUCTIONS_HELP: &str = "Instructions used per call, by function, wasm version and quantile";
+        const REGRESSIONS: &str = "canister_instructions_regression_percent";
+        const REGRESSIONS_HELP: &str = "How much a function's instruction count rose under the latest wasm version";
+
+        for stats in self.stats.iter() {
+            let function = format!("{:?}", stats.function_id);
+            let wasm_version = stats.wasm_version.to_string();
+            for (quantile, value) in [
+                ("0.5", stats.p50),
+                ("0.9", stats.p90),
+                ("0.95", stats.p95),
+                ("0.99", stats.p99),
+            ] {
+                registry.gauge_with_labels(
+                    INSTRUCTIONS,
+                    INSTRUCTIONS_HELP,
+                    &[
+                        ("function", &function),
+                        ("wasm_version", &wasm_version),
+                        ("quantile", quantile),
+                    ],
+                    value,
+                );
+            }
+        }
+
+        for regression in self.regressions.iter() {
+            let function = format!("{:?}", regression.function_id);
+            let percentile = format!("{:?}", regression.percentile);
+            let wasm_version = regression.current_version.to_string();
+            registry.gauge_with_labels(
+                REGRESSIONS,
+                REGRESSIONS_HELP,
+                &[
+                    ("function", &function),
+                    ("percentile", &percentile),
+                    ("wasm_version", &wasm_version),
+                ],
+                regression.increase_percent,
+            );
+        }
+    }
+}
+
+// The stats are ordered by function then version, so for each function the last two entries are its latest version
+// and the version before it
+fn find_regressions(stats: &[InstructionCountStats], threshold_percent: u32) -> Vec<InstructionCountRegression> {
+    let mut regressions = Vec::new();
+
+    for (index, pair) in stats.windows(2).enumerate() {
+        let (previous, current) = (&pair[0], &pair[1]);
+        let is_latest_version = stats
+            .get(index + 2)
+            .map_or(true, |next| next.function_id != current.function_id);
+
+        if previous.function_id != current.function_id || !is_latest_version {
+            continue;
+        }
+
+        for (percentile, previous_value, current_value) in [
+            (InstructionCountPercentile::P50, previous.p50, current.p50),
+            (InstructionCountPercentile::P95, previous.p95, current.p95),
+        ] {
+            if let Some(increase_percent) = increase_percent(previous_value, current_value) {
+                if increase_percent > threshold_percent {
+                    regressions.push(InstructionCountRegression {
+                        function_id: current.function_id,
+                        percentile,
+                        previous_version: previous.wasm_version,
+                        previous: previous_value,
+                        current_version: current.wasm_version,
+                        current: current_value,
+                        increase_percent,
+                    });
+                }
+            }
+        }
+    }
+
+    regressions
+}
+
+// Nearest-rank percentile of a sorted, non-empty slice
+fn percentile(sorted: &[u64], percentile: usize) -> u64 {
+    let rank = (sorted.len() * percentile).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+fn increase_percent(previous: u64, current: u64) -> Option<u32> {
+    if previous == 0 || current <= previous {
+        None
+    } else {
+        Some(((current - previous) as u128 * 100 / previous as u128).min(u32::MAX as u128) as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(function_id: InstructionCountFunctionId, version: u32, instruction_count: u64) -> InstructionCountEntry {
+        InstructionCountEntry {
+            timestamp: 0,
+            wasm_version: BuildVersion::new(2, 0, version),
+            function_id,
+            instruction_count,
+        }
+    }
+
+    #[test]
+    fn percentiles_per_function_and_version() {
+        let entries = (1..=100).map(|i| entry(InstructionCountFunctionId::PostUpgrade, 1, i));
+
+        let summary = InstructionCountsSummary::build(entries, DEFAULT_REGRESSION_THRESHOLD_PERCENT);
+
+        assert_eq!(summary.stats.len(), 1);
+        let stats = &summary.stats[0];
+        assert_eq!(
+            (stats.min, stats.p50, stats.p90, stats.p95, stats.p99, stats.max),
+            (1, 50, 90, 95, 99, 100)
+        );
+        assert!(summary.regressions.is_empty());
+    }
+
+    #[test]
+    fn regressions_compare_latest_version_with_previous() {
+        let entries = vec![
+            entry(InstructionCountFunctionId::PreUpgrade, 1, 1000),
+            entry(InstructionCountFunctionId::PreUpgrade, 2, 2000),
+            entry(InstructionCountFunctionId::PreUpgrade, 3, 2100),
+            entry(InstructionCountFunctionId::PostUpgrade, 1, 1000),
+            entry(InstructionCountFunctionId::PostUpgrade, 2, 1500),
+        ];
+
+        let summary = InstructionCountsSummary::build(entries.into_iter(), 10);
+
+        // PreUpgrade only rose by 5% under its latest version so only PostUpgrade is flagged
+        assert_eq!(summary.regressions.len(), 2);
+        assert!(summary
+            .regressions
+            .iter()
+            .all(|r| r.function_id == InstructionCountFunctionId::PostUpgrade && r.increase_percent == 50));
+    }
+
+    #[test]
+    fn regressions_depend_on_the_threshold() {
+        let entries = || {
+            vec![
+                entry(InstructionCountFunctionId::SendMessage, 1, 1000),
+                entry(InstructionCountFunctionId::SendMessage, 2, 1100),
+                entry(InstructionCountFunctionId::SendMessage, 3, 1200),
+            ]
+            .into_iter()
+        };
+
+        let summary = InstructionCountsSummary::build(entries(), 10);
+        assert!(summary.regressions.is_empty());
+
+        let summary = InstructionCountsSummary::build(entries(), 5);
+        assert_eq!(summary.stats.len(), 3);
+        assert_eq!(summary.regressions.len(), 2);
+        assert!(summary
+            .regressions
+            .iter()
+            .all(|r| r.previous_version == BuildVersion::new(2, 0, 2) && r.current_version == BuildVersion::new(2, 0, 3)));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{resolve_url, MAX_DESCRIPTION_LENGTH, MAX_TITLE_LENGTH};
+
+/// The metadata used to build a link preview. This is taken from the page's OpenGraph tags, falling
+/// back to its `<title>` and description meta tag.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PageMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+}
+
+impl PageMetadata {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.description.is_none() && self.image_url.is_none()
+    }
+}
+
+pub fn parse_page_metadata(html: &str, page_url: &str) -> PageMetadata {
+    // Metadata only ever appears in the head so there's no need to scan the body
+    let lowercase = html.to_ascii_lowercase();
+    let head_end = lowercase.find("</head>").unwrap_or(html.len());
+    let (html, lowercase) = (&html[..head_end], &lowercase[..head_end]);
+
+    let mut og_title = None;
+    let mut og_description = None;
+    let mut og_image = None;
+    let mut description = None;
+
+    let mut position = 0;
+    while let Some(start) = lowercase[position..].find("<meta").map(|i| i + position) {
+        let end = lowercase[start..].find('>').map_or(html.len(), |i| i + start);
+        let attributes = parse_attributes(&html[start + 5..end]);
+        position = end;
+
+        let key = attributes
+            .iter()
+            .find(|(name, _)| name == "property" || name == "name")
+            .map(|(_, value)| value.to_ascii_lowercase());
+        let content = attributes
+            .into_iter()
+            .find(|(name, _)| name == "content")
+            .map(|(_, value)| value)
+            .filter(|value| !value.trim().is_empty());
+
+        if let (Some(key), Some(content)) = (key, content) {
+            match key.as_str() {
+                "og:title" => og_title = og_title.or(Some(content)),
+                "og:description" => og_description = og_description.or(Some(content)),
+                "og:image" | "og:image:url" | "og:image:secure_url" => og_image = og_image.or(Some(content)),
+                "description" => description = description.or(Some(content)),
+                _ => {}
+            }
+        }
+    }
+
+    let title = og_title.or_else(|| {
+        let start = lowercase
+            .find("<title")
+            .and_then(|i| lowercase[i..].find('>').map(|j| i + j + 1))?;
+        let end = lowercase[start..].find("</title>").map(|i| i + start)?;
+        Some(decode_entities(&html[start..end]))
+    });
+
+    PageMetadata {
+        title: title.map(|t| clean(&t, MAX_TITLE_LENGTH)).filter(|t| !t.is_empty()),
+        description: og_description
+            .or(description)
+            .map(|d| clean(&d, MAX_DESCRIPTION_LENGTH))
+            .filter(|d| !d.is_empty()),
+        image_url: og_image.and_then(|i| resolve_url(page_url, i.trim())),
+    }
+}
+
+// Parses the attributes of a tag, lowercasing their names and decoding their values
+fn parse_attributes(tag: &str) -> Vec<(String, String)> {
+    let tag = tag.trim_end_matches('/');
+    let bytes = tag.as_bytes();
+    let mut attributes = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i].is_ascii_whitespace() || bytes[i] == b'/' {
+            i += 1;
+            continue;
+        }
+
+        let name_start = i;
+        while i < bytes.len() && bytes[i] != b'=' && !bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let name = tag[name_start..i].to_ascii_lowercase();
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i == bytes.len() || bytes[i] != b'=' {
+            attributes.push((name, String::new()));
+            continue;
+        }
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        let value = if i < bytes.len() && (bytes[i] == b'"' || bytes[i] == b'\'') {
+            let quote = bytes[i];
+            let value_start = i + 1;
+            i = value_start;
+            while i < bytes.len() && bytes[i] != quote {
+                i += 1;
+            }
+            let value = &tag[value_start..i];
+            i += 1;
+            value
+        } else {
+            let value_start = i;
+            while i < bytes.len() && !bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            &tag[value_start..i]
+        };
+
+        attributes.push((name, decode_entities(value)));
+    }
+
+    attributes
+}
+
+// Collapses whitespace and truncates to the given number of characters
+fn clean(value: &str, max_length: usize) -> String {
+    let mut cleaned = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    if let Some((index, _)) = cleaned.char_indices().nth(max_length) {
+        cleaned.truncate(index);
+    }
+    cleaned
+}
+
+fn decode_entities(value: &str) -> String {
+    if !value.contains('&') {
+        return value.to_string();
+    }
+
+    let mut decoded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').filter(|end| *end <= 10).and_then(|end| {
+            let c = match &rest[1..end] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                code => code
+                    .strip_prefix("#x")
+                    .or_else(|| code.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16).ok())
+                    .unwrap_or_else(|| code.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+
+        if let Some((c, end)) = entity {
+            decoded.push(c);
+            rest = &rest[end + 1..];
+        } else {
+            decoded.push('&');
+            rest = &rest[1..];
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_open_graph_tags() {
+        let html = r#"<!DOCTYPE html>
+            <html>
+            <head>
+                <title>Fallback title</title>
+                <meta charset="utf-8">
+                <meta property="og:title" content="OpenChat &amp; friends" />
+                <meta name="description" content="Fallback description">
+                <META PROPERTY='og:description' CONTENT='A decentralized
+                    chat app'>
+                <meta property=og:image content=/images/banner.png>
+            </head>
+            <body><meta property="og:title" content="Ignored"></body>
+            </html>"#;
+
+        let metadata = parse_page_metadata(html, "https://oc.app/home");
+
+        assert_eq!(
+            metadata,
+            PageMetadata {
+                title: Some("OpenChat & friends".to_string()),
+                description: Some("A decentralized chat app".to_string()),
+                image_url: Some("https://oc.app/images/banner.png".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn fall_back_to_title_and_description() {
+        let html = r#"<html><head><title lang="en">  Example   Domain </title>
+            <meta name="description" content="Just an &#x27;example&#39;"></head></html>"#;
+
+        let metadata = parse_page_metadata(html, "https://example.com");
+
+        assert_eq!(metadata.title.as_deref(), Some("Example Domain"));
+        assert_eq!(metadata.description.as_deref(), Some("Just an 'example'"));
+        assert!(metadata.image_url.is_none());
+    }
+
+    #[test]
+    fn long_titles_are_truncated() {
+        let html = format!(r#"<meta property="og:title" content="{}">"#, "a".repeat(1000));
+
+        let metadata = parse_page_metadata(&html, "https://example.com");
+
+        assert_eq!(metadata.title.unwrap().len(), MAX_TITLE_LENGTH);
+    }
+
+    #[test]
+    fn empty_page() {
+        assert!(parse_page_metadata("<html><body>Hello</body></html>", "https://example.com").is_empty());
+    }
+}
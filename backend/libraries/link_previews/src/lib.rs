@@ -0,0 +1,10 @@
+mod html;
+mod urls;
+
+pub use html::{parse_page_metadata, PageMetadata};
+pub use urls::{extract_urls, resolve_url};
+
+/// The maximum number of links in a message which previews are generated for
+pub const MAX_LINK_PREVIEWS: usize = 3;
+pub const MAX_TITLE_LENGTH: usize = 200;
+pub const MAX_DESCRIPTION_LENGTH: usize = 500;
@@ -0,0 +1,114 @@
+use crate::MAX_LINK_PREVIEWS;
+
+const SCHEMES: [&str; 2] = ["https://", "http://"];
+
+/// Extracts the distinct http(s) urls from a message's text in the order in which they appear,
+/// up to `MAX_LINK_PREVIEWS` of them
+pub fn extract_urls(text: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+
+    for word in text.split_whitespace() {
+        let start = match SCHEMES.iter().filter_map(|s| word.find(s)).min() {
+            Some(start) => start,
+            None => continue,
+        };
+
+        // Strip any punctuation which is more likely to belong to the surrounding text
+        let url = word[start..].trim_end_matches(|c| {
+            matches!(
+                c,
+                '.' | ',' | ';' | ':' | '!' | '?' | ')' | ']' | '}' | '\'' | '"' | '>' | '*' | '_'
+            )
+        });
+
+        if has_host(url) && !urls.iter().any(|u| u == url) {
+            urls.push(url.to_string());
+            if urls.len() == MAX_LINK_PREVIEWS {
+                break;
+            }
+        }
+    }
+
+    urls
+}
+
+/// Resolves a url found within a page (eg. an `og:image`) against the url of the page itself
+pub fn resolve_url(page_url: &str, url: &str) -> Option<String> {
+    if SCHEMES.iter().any(|s| url.starts_with(s)) {
+        return has_host(url).then(|| url.to_string());
+    }
+
+    let scheme_end = page_url.find("://")? + 3;
+    let scheme = &page_url[..scheme_end];
+
+    if let Some(protocol_relative) = url.strip_prefix("//") {
+        return Some(format!("{scheme}{protocol_relative}"));
+    }
+
+    let path_start = page_url[scheme_end..]
+        .find(['/', '?', '#'])
+        .map_or(page_url.len(), |i| i + scheme_end);
+    let origin = &page_url[..path_start];
+
+    if url.starts_with('/') {
+        Some(format!("{origin}{url}"))
+    } else {
+        let path = &page_url[path_start..];
+        let path = &path[..path.find(['?', '#']).unwrap_or(path.len())];
+        let directory = &path[..path.rfind('/').map_or(0, |i| i + 1)];
+        if directory.is_empty() {
+            Some(format!("{origin}/{url}"))
+        } else {
+            Some(format!("{origin}{directory}{url}"))
+        }
+    }
+}
+
+fn has_host(url: &str) -> bool {
+    SCHEMES
+        .iter()
+        .find_map(|s| url.strip_prefix(s))
+        .and_then(|rest| rest.split(['/', '?', '#']).next())
+        .map_or(false, |host| !host.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_urls_from_text() {
+        let text = "Check out https://oc.app/community, (see http://example.com/a?b=c) and https://oc.app/community again";
+
+        assert_eq!(
+            extract_urls(text),
+            vec!["https://oc.app/community".to_string(), "http://example.com/a?b=c".to_string()]
+        );
+    }
+
+    #[test]
+    fn extract_urls_ignores_schemes_without_hosts() {
+        assert!(extract_urls("https:// is a scheme").is_empty());
+    }
+
+    #[test]
+    fn extract_urls_is_capped() {
+        let text = "https://a.com https://b.com https://c.com https://d.com";
+
+        assert_eq!(extract_urls(text).len(), MAX_LINK_PREVIEWS);
+    }
+
+    #[test]
+    fn resolve_urls() {
+        let page = "https://oc.app/blog/post?id=1";
+
+        assert_eq!(
+            resolve_url(page, "https://cdn.oc.app/a.png").unwrap(),
+            "https://cdn.oc.app/a.png"
+        );
+        assert_eq!(resolve_url(page, "//cdn.oc.app/a.png").unwrap(), "https://cdn.oc.app/a.png");
+        assert_eq!(resolve_url(page, "/a.png").unwrap(), "https://oc.app/a.png");
+        assert_eq!(resolve_url(page, "a.png").unwrap(), "https://oc.app/blog/a.png");
+        assert_eq!(resolve_url("https://oc.app", "a.png").unwrap(), "https://oc.app/a.png");
+    }
+}
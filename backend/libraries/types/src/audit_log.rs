@@ -0,0 +1,67 @@
+use crate::{ChannelId, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+pub type AuditLogEntryIndex = u32;
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct AuditLogEntry {
+    pub index: AuditLogEntryIndex,
+    pub timestamp: TimestampMillis,
+    pub actor: UserId,
+    pub action: AuditLogActionType,
+    pub channel_id: Option<ChannelId>,
+    pub targets: Vec<UserId>,
+    // JSON encoded values describing the state before and after the action
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuditLogActionType {
+    CommunityDetailsChanged,
+    CommunityPermissionsChanged,
+    RoleChanged,
+    MembersRemoved,
+    UsersBlocked,
+    UsersUnblocked,
+    InvitesCancelled,
+    InviteCodeChanged,
+    ChannelDetailsChanged,
+    ChannelPermissionsChanged,
+    ChannelDeleted,
+    ChannelMoved,
+    ChannelRoleChanged,
+    ChannelMemberRemoved,
+    MessagesDeleted,
+    UserGroupCreated,
+    UserGroupUpdated,
+    UserGroupsDeleted,
+    ReportModerated,
+    ContentFiltersChanged,
+    InviteLinkCreated,
+    InviteLinkRevoked,
+    EmojiPackCreated,
+    EmojiPackUpdated,
+    EmojiPackDeleted,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct AuditLogFilter {
+    pub actor: Option<UserId>,
+    // If empty, entries of all action types are returned
+    pub action_types: Vec<AuditLogActionType>,
+    pub channel_id: Option<ChannelId>,
+    pub from: Option<TimestampMillis>,
+    pub to: Option<TimestampMillis>,
+}
+
+impl AuditLogFilter {
+    pub fn matches(&self, entry: &AuditLogEntry) -> bool {
+        self.actor.map_or(true, |a| entry.actor == a)
+            && (self.action_types.is_empty() || self.action_types.contains(&entry.action))
+            && self.channel_id.map_or(true, |c| entry.channel_id == Some(c))
+            && self.from.map_or(true, |f| entry.timestamp >= f)
+            && self.to.map_or(true, |t| entry.timestamp <= t)
+    }
+}
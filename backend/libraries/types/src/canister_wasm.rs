@@ -1,13 +1,61 @@
-use crate::{BuildVersion, CanisterId};
+use crate::{BuildVersion, CanisterId, Hash, TimestampMillis};
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
 
+// Wasms larger than this are uploaded in chunks of this size, since sending them in a single
+// message risks exceeding the maximum message size
+pub const WASM_CHUNK_SIZE: usize = 1024 * 1024;
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct UpgradeCanisterWasmArgs {
     pub wasm: CanisterWasm,
     pub filter: Option<UpgradesFilter>,
     pub use_for_new_canisters: Option<bool>,
+    #[serde(default)]
+    pub rollout: Option<UpgradeRolloutConfig>,
+    // If set, `wasm.module` is left empty and the module is instead taken from the chunks
+    // previously uploaded under this hash
+    #[serde(default)]
+    pub wasm_hash: Option<Hash>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone)]
+pub struct UploadWasmChunkArgs {
+    pub wasm_hash: Hash,
+    pub index: u32,
+    #[serde(with = "serde_bytes")]
+    pub chunk: Vec<u8>,
+}
+
+impl Debug for UploadWasmChunkArgs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadWasmChunkArgs")
+            .field("wasm_hash", &self.wasm_hash)
+            .field("index", &self.index)
+            .field("byte_length", &self.chunk.len())
+            .finish()
+    }
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum UploadWasmChunkResponse {
+    Success(UploadWasmChunkSuccess),
+    UnexpectedIndex(u32),
+    WasmTooLarge,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UploadWasmChunkSuccess {
+    pub chunks_received: u32,
+    pub total_bytes: u64,
+}
+
+// The reasons a wasm uploaded in chunks could not be taken from the staging area
+#[derive(Debug, Eq, PartialEq)]
+pub enum ChunkedWasmError {
+    NotFound,
+    HashMismatch(Hash),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone)]
@@ -40,3 +88,61 @@ pub struct UpgradesFilter {
     pub include: Vec<CanisterId>,
     pub exclude: Vec<CanisterId>,
 }
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeRolloutConfig {
+    // The cumulative percentage of canisters to have been upgraded by the end of each stage, eg. [1, 10, 100]
+    pub stages: Vec<u8>,
+    // The rollout is paused if the percentage of failed upgrades within a stage exceeds this value
+    pub max_failure_rate_percent: u8,
+    // The failure rate of a stage is only evaluated once this many of its upgrades have finished
+    pub min_upgrades_before_evaluating: u32,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpgradeRolloutStatus {
+    InProgress,
+    Paused(UpgradeRolloutPause),
+    Aborted(TimestampMillis),
+    Completed(TimestampMillis),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UpgradeRolloutPause {
+    pub timestamp: TimestampMillis,
+    pub reason: UpgradeRolloutPauseReason,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpgradeRolloutPauseReason {
+    FailureRateExceeded(u8),
+    Operator,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UpgradeRolloutAction {
+    Pause,
+    Resume,
+    Abort,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct UpgradeRolloutSummary {
+    pub wasm_version: BuildVersion,
+    pub config: UpgradeRolloutConfig,
+    pub status: UpgradeRolloutStatus,
+    pub started: TimestampMillis,
+    pub total_canisters: u32,
+    pub current_stage: u32,
+    pub stages: Vec<UpgradeRolloutStageSummary>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct UpgradeRolloutStageSummary {
+    pub target_percent: u8,
+    pub canisters: u32,
+    pub started: Option<TimestampMillis>,
+    pub completed: Option<TimestampMillis>,
+    pub succeeded: u32,
+    pub failed: u32,
+}
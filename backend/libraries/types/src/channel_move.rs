@@ -0,0 +1,19 @@
+use crate::{ChannelId, ChatId, CommunityId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+// Where a channel which has been moved out of a community now lives
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChannelMovedTo {
+    Community(CommunityId, ChannelId),
+    Group(ChatId),
+}
+
+// Passed to a newly created group canister so that it can pull in the history and members of the
+// channel it is being detached from
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct DetachedChannel {
+    pub community_id: CommunityId,
+    pub channel_id: ChannelId,
+    pub total_bytes: u64,
+}
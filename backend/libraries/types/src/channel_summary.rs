@@ -1,6 +1,6 @@
 use crate::{
     AccessGate, ChannelId, ChatMetrics, EventIndex, EventWrapper, GroupMembership, GroupMembershipUpdates, GroupPermissions,
-    GroupSubtype, Message, MessageIndex, Milliseconds, OptionUpdate, TimestampMillis,
+    GroupSubtype, Message, MessageIndex, Milliseconds, OptionUpdate, SlowMode, TimestampMillis,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -28,6 +28,8 @@ pub struct CommunityCanisterChannelSummary {
     pub events_ttl: Option<Milliseconds>,
     pub events_ttl_last_updated: TimestampMillis,
     pub gate: Option<AccessGate>,
+    #[serde(default)]
+    pub slow_mode: Option<SlowMode>,
     pub membership: Option<GroupMembership>,
 }
 
@@ -52,6 +54,8 @@ pub struct CommunityCanisterChannelSummaryUpdates {
     pub events_ttl: OptionUpdate<Milliseconds>,
     pub events_ttl_last_updated: Option<TimestampMillis>,
     pub gate: OptionUpdate<AccessGate>,
+    #[serde(default)]
+    pub slow_mode: OptionUpdate<SlowMode>,
     pub membership: Option<GroupMembershipUpdates>,
 }
 
@@ -0,0 +1,9 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExportFormat {
+    Json,
+    Markdown,
+    Html,
+}
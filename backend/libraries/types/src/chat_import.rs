@@ -0,0 +1,38 @@
+use crate::{BlobReference, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChatImportSource {
+    Telegram,
+    Discord,
+}
+
+// Attached to messages which were imported from another platform, so that they can't be mistaken
+// for messages sent natively
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ImportedMessageDetails {
+    pub source: ChatImportSource,
+    // The author's name on the original platform
+    pub sender_name: String,
+    // The time the message was originally sent
+    pub timestamp: TimestampMillis,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ChatImportAuthorMapping {
+    // The author's id within the export (Telegram's "from_id" or the Discord author id)
+    pub author_id: String,
+    pub user_id: UserId,
+}
+
+// A file referenced by the export which has already been uploaded to a storage bucket. The
+// community must be given access to the file when it is uploaded.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ChatImportMediaFile {
+    // The path (Telegram) or url (Discord) of the file as it appears within the export
+    pub path: String,
+    pub blob_reference: BlobReference,
+    pub mime_type: String,
+    pub file_size: u32,
+}
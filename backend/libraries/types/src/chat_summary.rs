@@ -1,7 +1,7 @@
 use crate::{
-    AccessGate, BuildVersion, CanisterId, ChatId, EventIndex, EventWrapper, FrozenGroupInfo, GroupMember, GroupPermissions,
-    GroupRole, HydratedMention, Message, MessageIndex, Milliseconds, OptionUpdate, TimestampMillis, UserId, Version,
-    MAX_RETURNED_MENTIONS,
+    AccessGate, BuildVersion, CanisterId, ChatId, EventIndex, EventWrapper, ForumSubtype, FrozenGroupInfo, GroupMember,
+    GroupPermissions, GroupRole, HydratedMention, Message, MessageIndex, Milliseconds, OptionUpdate, SlowMode, TimestampMillis,
+    UserId, Version, MAX_RETURNED_MENTIONS,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -141,6 +141,8 @@ pub struct GroupCanisterGroupChatSummary {
     pub events_ttl: Option<Milliseconds>,
     pub events_ttl_last_updated: TimestampMillis,
     pub gate: Option<AccessGate>,
+    #[serde(default)]
+    pub slow_mode: Option<SlowMode>,
     pub rules_accepted: bool,
     pub membership: Option<GroupMembership>,
 }
@@ -213,6 +215,7 @@ impl GroupCanisterGroupChatSummary {
             events_ttl: updates.events_ttl.apply_to(self.events_ttl),
             events_ttl_last_updated: updates.events_ttl_last_updated.unwrap_or(self.events_ttl_last_updated),
             gate: updates.gate.apply_to(self.gate),
+            slow_mode: updates.slow_mode.apply_to(self.slow_mode),
             rules_accepted: membership.rules_accepted,
             membership: Some(membership),
         }
@@ -247,6 +250,8 @@ pub struct GroupCanisterGroupChatSummaryUpdates {
     pub events_ttl: OptionUpdate<Milliseconds>,
     pub events_ttl_last_updated: Option<TimestampMillis>,
     pub gate: OptionUpdate<AccessGate>,
+    #[serde(default)]
+    pub slow_mode: OptionUpdate<SlowMode>,
     pub rules_accepted: Option<bool>,
     pub membership: Option<GroupMembershipUpdates>,
 }
@@ -361,6 +366,7 @@ impl From<&GroupCanisterThreadDetails> for ThreadSyncDetails {
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub enum GroupSubtype {
     GovernanceProposals(GovernanceProposalsSubtype),
+    Forum(ForumSubtype),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
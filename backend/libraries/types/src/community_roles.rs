@@ -17,6 +17,8 @@ pub struct CommunityPermissions {
     pub create_public_channel: CommunityPermissionRole,
     pub create_private_channel: CommunityPermissionRole,
     pub manage_user_groups: CommunityPermissionRole,
+    #[serde(default = "admins")]
+    pub manage_emoji_packs: CommunityPermissionRole,
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -28,6 +30,7 @@ pub struct OptionalCommunityPermissions {
     pub create_public_channel: Option<CommunityPermissionRole>,
     pub create_private_channel: Option<CommunityPermissionRole>,
     pub manage_user_groups: Option<CommunityPermissionRole>,
+    pub manage_emoji_packs: Option<CommunityPermissionRole>,
 }
 
 impl Default for CommunityPermissions {
@@ -40,10 +43,15 @@ impl Default for CommunityPermissions {
             create_public_channel: CommunityPermissionRole::Admins,
             create_private_channel: CommunityPermissionRole::Admins,
             manage_user_groups: CommunityPermissionRole::Admins,
+            manage_emoji_packs: CommunityPermissionRole::Admins,
         }
     }
 }
 
+fn admins() -> CommunityPermissionRole {
+    CommunityPermissionRole::Admins
+}
+
 #[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug)]
 pub enum CommunityPermissionRole {
     Owners,
@@ -104,6 +112,10 @@ impl CommunityRole {
         self.is_permitted(permissions.manage_user_groups)
     }
 
+    pub fn can_manage_emoji_packs(&self, permissions: &CommunityPermissions) -> bool {
+        self.is_permitted(permissions.manage_emoji_packs)
+    }
+
     pub fn can_delete_community(&self) -> bool {
         self.has_owner_rights()
     }
@@ -0,0 +1,57 @@
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContentFilters {
+    pub rules: Vec<ContentFilterRule>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ContentFilterRule {
+    pub condition: ContentFilterCondition,
+    pub action: ContentFilterAction,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ContentFilterCondition {
+    Keywords(Vec<String>),
+    Pattern(String),
+    LinkAllowlist(Vec<String>),
+    LinkBlocklist(Vec<String>),
+    MaxMentions(u32),
+}
+
+// Ordered from least to most severe
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ContentFilterAction {
+    HoldForReview,
+    DeleteAndWarn,
+    Reject,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContentFilterReason {
+    BlockedKeyword,
+    BlockedPattern,
+    BlockedLink,
+    TooManyMentions,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ContentFilterMatch {
+    pub action: ContentFilterAction,
+    pub reason: ContentFilterReason,
+}
+
+impl ContentFilterCondition {
+    pub fn reason(&self) -> ContentFilterReason {
+        match self {
+            ContentFilterCondition::Keywords(_) => ContentFilterReason::BlockedKeyword,
+            ContentFilterCondition::Pattern(_) => ContentFilterReason::BlockedPattern,
+            ContentFilterCondition::LinkAllowlist(_) | ContentFilterCondition::LinkBlocklist(_) => {
+                ContentFilterReason::BlockedLink
+            }
+            ContentFilterCondition::MaxMentions(_) => ContentFilterReason::TooManyMentions,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{BlobReference, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+pub type EmojiPackId = u32;
+
+pub const MAX_EMOJI_SHORTCODE_LENGTH: usize = 32;
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct EmojiPack {
+    pub pack_id: EmojiPackId,
+    pub name: String,
+    pub kind: EmojiPackKind,
+    pub assets: Vec<EmojiPackAsset>,
+    pub created_by: UserId,
+    pub last_updated: TimestampMillis,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EmojiPackKind {
+    Emoji,
+    Sticker,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct EmojiPackAsset {
+    pub shortcode: String,
+    // The image should be uploaded to a storage_bucket with the community canister as an accessor so
+    // that the community can delete it once it is removed from the pack
+    pub blob_reference: BlobReference,
+}
@@ -33,6 +33,8 @@ pub enum ChatEvent {
     GroupGateUpdated(GroupGateUpdated),
     UsersInvited(UsersInvited),
     MembersAddedToDefaultChannel(MembersAddedToDefaultChannel),
+    MemberMuted(MemberMuted),
+    MemberUnmuted(MemberUnmuted),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Debug)]
@@ -152,6 +154,20 @@ pub struct UsersUnblocked {
     pub unblocked_by: UserId,
 }
 
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MemberMuted {
+    pub user_id: UserId,
+    pub muted_by: UserId,
+    pub muted_until: TimestampMillis,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MemberUnmuted {
+    pub user_id: UserId,
+    // None if the mute expired
+    pub unmuted_by: Option<UserId>,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct MemberJoined {
     pub user_id: UserId,
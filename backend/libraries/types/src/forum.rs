@@ -0,0 +1,43 @@
+use crate::{EventWrapper, Message, MessageIndex, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+/// A forum channel is one where each top-level message starts a titled topic, with all discussion of
+/// that topic happening within the topic's thread
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ForumSubtype {
+    // If non-empty, topics can only be tagged with these tags
+    pub available_tags: Vec<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct NewForumTopic {
+    pub title: String,
+    pub tags: Vec<String>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ForumTopic {
+    pub root_message: EventWrapper<Message>,
+    pub title: String,
+    pub tags: Vec<String>,
+    pub solved: Option<ForumTopicSolved>,
+    pub reply_count: u32,
+    pub latest_activity: TimestampMillis,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ForumTopicSolved {
+    pub marked_by: UserId,
+    pub timestamp: TimestampMillis,
+    // The reply which solved the topic, if one was specified
+    pub answer: Option<MessageIndex>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ForumTopicsSortOrder {
+    #[default]
+    LatestActivity,
+    Newest,
+    MostReplies,
+}
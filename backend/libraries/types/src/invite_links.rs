@@ -0,0 +1,19 @@
+use crate::{CommunityRole, GroupRole, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+pub type GroupInviteLink = InviteLink<GroupRole>;
+pub type CommunityInviteLink = InviteLink<CommunityRole>;
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct InviteLink<R> {
+    pub code: u64,
+    pub name: String,
+    pub created_by: UserId,
+    pub created: TimestampMillis,
+    pub expires_at: Option<TimestampMillis>,
+    pub max_uses: Option<u32>,
+    pub uses: u32,
+    // The role granted to users who join using this link
+    pub role: Option<R>,
+}
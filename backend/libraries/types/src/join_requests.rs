@@ -0,0 +1,10 @@
+use crate::{TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct JoinRequest {
+    pub user_id: UserId,
+    pub message: Option<String>,
+    pub requested: TimestampMillis,
+}
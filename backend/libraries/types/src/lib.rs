@@ -3,23 +3,29 @@ use ic_ledger_types::Tokens;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap, HashSet};
 
+mod audit_log;
 mod avatar;
 mod bots;
 mod build_version;
 mod canister_upgrade_status;
 mod canister_wasm;
+mod channel_move;
 mod channel_summary;
 mod chat;
+mod chat_export;
 mod chat_id;
+mod chat_import;
 mod chat_summary;
 mod community_id;
 mod community_member;
 mod community_roles;
 mod community_summary;
+mod content_filters;
 mod cryptocurrency;
 mod cycles;
 mod deleted_group_info;
 mod diamond_membership;
+mod emoji_packs;
 mod error;
 mod event_index;
 mod event_result;
@@ -30,6 +36,7 @@ mod exchanges;
 mod field_invalid;
 mod file;
 mod file_status;
+mod forum;
 mod frozen_group_info;
 mod gated_groups;
 mod group_activity;
@@ -38,12 +45,16 @@ mod group_member;
 mod group_roles;
 mod http;
 mod indexed_event;
+mod invite_links;
+mod join_requests;
+mod link_previews;
 mod mention;
 mod message;
 mod message_content;
 mod message_id;
 mod message_index;
 mod message_match;
+mod moderation;
 mod notifications;
 mod option;
 mod phone_number;
@@ -53,6 +64,7 @@ mod range_set;
 mod reactions;
 mod referral_codes;
 mod registration_fee;
+mod slow_mode;
 mod source_group;
 mod subscription;
 mod suspension_duration;
@@ -66,23 +78,29 @@ mod version;
 mod versioned;
 
 pub use crate::range_set::*;
+pub use audit_log::*;
 pub use avatar::*;
 pub use bots::*;
 pub use build_version::*;
 pub use canister_upgrade_status::*;
 pub use canister_wasm::*;
+pub use channel_move::*;
 pub use channel_summary::*;
 pub use chat::*;
+pub use chat_export::*;
 pub use chat_id::*;
+pub use chat_import::*;
 pub use chat_summary::*;
 pub use community_id::*;
 pub use community_member::*;
 pub use community_roles::*;
 pub use community_summary::*;
+pub use content_filters::*;
 pub use cryptocurrency::*;
 pub use cycles::*;
 pub use deleted_group_info::*;
 pub use diamond_membership::*;
+pub use emoji_packs::*;
 pub use error::*;
 pub use event_index::*;
 pub use event_result::*;
@@ -93,6 +111,7 @@ pub use exchanges::*;
 pub use field_invalid::*;
 pub use file::*;
 pub use file_status::*;
+pub use forum::*;
 pub use frozen_group_info::*;
 pub use gated_groups::*;
 pub use group_activity::*;
@@ -101,12 +120,16 @@ pub use group_member::*;
 pub use group_roles::*;
 pub use http::*;
 pub use indexed_event::*;
+pub use invite_links::*;
+pub use join_requests::*;
+pub use link_previews::*;
 pub use mention::*;
 pub use message::*;
 pub use message_content::*;
 pub use message_id::*;
 pub use message_index::*;
 pub use message_match::*;
+pub use moderation::*;
 pub use notifications::*;
 pub use option::*;
 pub use phone_number::*;
@@ -115,6 +138,7 @@ pub use proposals::*;
 pub use reactions::*;
 pub use referral_codes::*;
 pub use registration_fee::*;
+pub use slow_mode::*;
 pub use source_group::*;
 pub use subscription::*;
 pub use suspension_duration::*;
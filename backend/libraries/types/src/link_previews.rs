@@ -0,0 +1,39 @@
+use crate::{BlobReference, CanisterId, Chat, MessageId, MessageIndex, TimestampMillis};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+/// A preview of a link within a message, generated server-side so that clients never need to fetch
+/// the linked page (and reveal their IP addresses to it) themselves
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LinkPreview {
+    pub url: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<BlobReference>,
+}
+
+/// A request, queued in a notifications canister, for the link previews of a message to be fetched
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct LinkPreviewRequest {
+    // The canister holding the message, which the previews will be sent to once fetched
+    pub canister_id: CanisterId,
+    pub chat: Chat,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub urls: Vec<String>,
+    pub timestamp: TimestampMillis,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct SetLinkPreviewsArgs {
+    pub chat: Chat,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub link_previews: Vec<LinkPreview>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Debug)]
+pub enum SetLinkPreviewsResponse {
+    Success,
+    MessageNotFound,
+}
@@ -1,5 +1,6 @@
 use crate::{
-    CanisterId, Chat, EventIndex, MessageContent, MessageId, MessageIndex, Reaction, ThreadSummary, TimestampMillis, UserId,
+    CanisterId, Chat, EventIndex, ImportedMessageDetails, MessageContent, MessageContentInitial, MessageId, MessageIndex,
+    Reaction, ThreadSummary, TimestampMillis, UserId,
 };
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
@@ -17,9 +18,36 @@ pub struct Message {
     pub thread_summary: Option<ThreadSummary>,
     pub edited: bool,
     pub forwarded: bool,
+    pub forwarded_from: Option<ForwardedFrom>,
+    pub imported: Option<ImportedMessageDetails>,
     pub last_updated: Option<TimestampMillis>,
 }
 
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MessageRevision {
+    pub content: MessageContent,
+    // The time at which this revision became the message's content
+    pub timestamp: TimestampMillis,
+}
+
+// Records where a forwarded message was originally sent. The chat and message indexes are omitted if
+// the original chat is private and has chosen to hide them.
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ForwardedFrom {
+    pub chat: Option<Chat>,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_index: Option<MessageIndex>,
+    pub sender: UserId,
+    pub timestamp: TimestampMillis,
+}
+
+// The content of a message to be forwarded, along with where it was originally sent
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct MessageForForwarding {
+    pub content: MessageContentInitial,
+    pub forwarded_from: ForwardedFrom,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct ReplyContext {
     pub chat_if_other: Option<(Chat, Option<MessageIndex>)>,
@@ -1,7 +1,7 @@
 use crate::polls::{InvalidPollReason, PollConfig, PollVotes};
 use crate::{
-    CanisterId, CompletedCryptoTransaction, CryptoTransaction, CryptoTransferDetails, Cryptocurrency, MessageIndex,
-    ProposalContent, TimestampMillis, TotalVotes, User, UserId,
+    CanisterId, CompletedCryptoTransaction, CryptoTransaction, CryptoTransferDetails, Cryptocurrency, EmojiPackId, LinkPreview,
+    MessageIndex, ProposalContent, TimestampMillis, TotalVotes, User, UserId,
 };
 use candid::{CandidType, Principal};
 use ic_ledger_types::Tokens;
@@ -29,6 +29,7 @@ pub enum MessageContentInitial {
     MessageReminderCreated(MessageReminderCreatedContent),
     MessageReminder(MessageReminderContent),
     Custom(CustomContent),
+    Sticker(StickerContent),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -49,6 +50,7 @@ pub enum MessageContent {
     MessageReminder(MessageReminderContent),
     ReportedMessage(ReportedMessage),
     Custom(CustomContent),
+    Sticker(StickerContent),
 }
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
@@ -64,6 +66,21 @@ pub enum ContentValidationError {
 }
 
 impl MessageContent {
+    // Returns the content to send when forwarding this message, or None if this type of message
+    // can't be forwarded
+    pub fn into_forwardable(self) -> Option<MessageContentInitial> {
+        match self {
+            MessageContent::Text(_)
+            | MessageContent::Image(_)
+            | MessageContent::Video(_)
+            | MessageContent::Audio(_)
+            | MessageContent::File(_)
+            | MessageContent::Giphy(_)
+            | MessageContent::Custom(_) => Some(self.into()),
+            _ => None,
+        }
+    }
+
     pub fn blob_references(&self) -> Vec<BlobReference> {
         let mut references = Vec::new();
 
@@ -102,7 +119,8 @@ impl MessageContent {
             | MessageContent::MessageReminderCreated(_)
             | MessageContent::MessageReminder(_)
             | MessageContent::ReportedMessage(_)
-            | MessageContent::Custom(_) => {}
+            | MessageContent::Custom(_)
+            | MessageContent::Sticker(_) => {}
         }
 
         references
@@ -126,6 +144,7 @@ impl MessageContent {
             MessageContent::MessageReminder(_) => "MessageReminder",
             MessageContent::ReportedMessage(_) => "ReportedMessage",
             MessageContent::Custom(c) => &c.kind,
+            MessageContent::Sticker(_) => "Sticker",
         };
 
         message_type.to_string()
@@ -148,7 +167,8 @@ impl MessageContent {
             | MessageContent::MessageReminderCreated(_)
             | MessageContent::MessageReminder(_)
             | MessageContent::ReportedMessage(_)
-            | MessageContent::Custom(_) => None,
+            | MessageContent::Custom(_)
+            | MessageContent::Sticker(_) => None,
         }
     }
 
@@ -186,7 +206,8 @@ impl MessageContent {
             | MessageContent::MessageReminderCreated(_)
             | MessageContent::MessageReminder(_)
             | MessageContent::ReportedMessage(_)
-            | MessageContent::Custom(_) => None,
+            | MessageContent::Custom(_)
+            | MessageContent::Sticker(_) => None,
         }
     }
 
@@ -240,7 +261,10 @@ impl MessageContentInitial {
     ) -> Result<(), ContentValidationError> {
         if forwarding {
             match self {
-                MessageContentInitial::Poll(_) | MessageContentInitial::Crypto(_) | MessageContentInitial::Deleted(_) => {
+                MessageContentInitial::Poll(_)
+                | MessageContentInitial::Crypto(_)
+                | MessageContentInitial::Deleted(_)
+                | MessageContentInitial::Sticker(_) => {
                     return Err(ContentValidationError::InvalidTypeForForwarding);
                 }
                 _ => {}
@@ -278,6 +302,12 @@ impl MessageContentInitial {
                     return Err(ContentValidationError::Unauthorized);
                 }
             }
+            MessageContentInitial::Sticker(_) => {
+                // Stickers belong to a community's packs so can't be sent in direct chats
+                if is_direct_chat {
+                    return Err(ContentValidationError::Unauthorized);
+                }
+            }
             _ => {}
         };
 
@@ -290,6 +320,7 @@ impl MessageContentInitial {
             MessageContentInitial::Poll(p) => p.config.options.is_empty(),
             MessageContentInitial::Prize(p) => p.prizes.is_empty(),
             MessageContentInitial::Deleted(_) => true,
+            MessageContentInitial::Sticker(s) => s.shortcode.is_empty(),
             MessageContentInitial::Crypto(_)
             | MessageContentInitial::Giphy(_)
             | MessageContentInitial::GovernanceProposal(_)
@@ -326,7 +357,7 @@ impl MessageContentInitial {
             MessageContentInitial::Prize(p) => p.caption.as_deref(),
             MessageContentInitial::MessageReminderCreated(r) => r.notes.as_deref(),
             MessageContentInitial::MessageReminder(r) => r.notes.as_deref(),
-            MessageContentInitial::Deleted(_) | MessageContentInitial::Custom(_) => None,
+            MessageContentInitial::Deleted(_) | MessageContentInitial::Custom(_) | MessageContentInitial::Sticker(_) => None,
         }
     }
 }
@@ -350,6 +381,7 @@ impl From<MessageContent> for MessageContentInitial {
             MessageContent::MessageReminder(r) => MessageContentInitial::MessageReminder(r),
             MessageContent::ReportedMessage(_) => panic!("Cannot send a 'reported message' message"),
             MessageContent::Custom(c) => MessageContentInitial::Custom(c),
+            MessageContent::Sticker(c) => MessageContentInitial::Sticker(c),
         }
     }
 }
@@ -379,6 +411,7 @@ impl From<MessageContentInitial> for MessageContent {
             MessageContentInitial::MessageReminderCreated(r) => MessageContent::MessageReminderCreated(r),
             MessageContentInitial::MessageReminder(r) => MessageContent::MessageReminder(r),
             MessageContentInitial::Custom(c) => MessageContent::Custom(c),
+            MessageContentInitial::Sticker(c) => MessageContent::Sticker(c),
         }
     }
 }
@@ -386,11 +419,16 @@ impl From<MessageContentInitial> for MessageContent {
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct TextContent {
     pub text: String,
+    // Populated by the server once the previews have been fetched, any value set by clients is ignored
+    pub link_previews: Option<Vec<LinkPreview>>,
 }
 
 impl From<String> for TextContent {
     fn from(value: String) -> Self {
-        TextContent { text: value }
+        TextContent {
+            text: value,
+            link_previews: None,
+        }
     }
 }
 
@@ -549,6 +587,16 @@ pub struct CustomContent {
     pub data: Vec<u8>,
 }
 
+// References an asset in one of the community's sticker packs
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct StickerContent {
+    pub pack_id: EmojiPackId,
+    pub shortcode: String,
+    // Set by the community when the sticker is sent, any value provided by the sender is ignored
+    #[serde(default)]
+    pub blob_reference: Option<BlobReference>,
+}
+
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
 pub struct DeletedBy {
     pub deleted_by: UserId,
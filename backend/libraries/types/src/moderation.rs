@@ -0,0 +1,46 @@
+use crate::{EventIndex, MessageId, MessageIndex, MessageReport, Milliseconds, TimestampMillis, UserId};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+
+pub type ModerationReportId = u32;
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ModerationReport {
+    pub report_id: ModerationReportId,
+    pub thread_root_message_index: Option<MessageIndex>,
+    pub message_id: MessageId,
+    pub message_index: MessageIndex,
+    pub event_index: EventIndex,
+    pub sender: UserId,
+    pub reports: Vec<MessageReport>,
+    pub status: ModerationReportStatus,
+    pub created: TimestampMillis,
+    pub last_updated: TimestampMillis,
+    pub held_for_review: bool,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum ModerationReportStatus {
+    Pending,
+    Resolved(ModerationAction),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModerationAction {
+    Dismiss,
+    DeleteMessage,
+    RemoveSender,
+    BlockSender,
+    // Mutes the sender in the chat for the given duration
+    SuspendSender(Milliseconds),
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug)]
+pub struct ModerationDecision {
+    pub report_id: ModerationReportId,
+    pub action: ModerationAction,
+    pub target: UserId,
+    pub decided_by: UserId,
+    pub timestamp: TimestampMillis,
+    pub notes: Option<String>,
+}
@@ -1,7 +1,10 @@
+use crate::{EmojiPackId, MAX_EMOJI_SHORTCODE_LENGTH};
 use candid::CandidType;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 const MAX_REACTION_LENGTH_BYTES: usize = 40;
+const CUSTOM_EMOJI_PREFIX: &str = "custom:";
 
 #[derive(CandidType, Serialize, Deserialize, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct Reaction(String);
@@ -12,8 +15,30 @@ impl Reaction {
     }
 
     pub fn is_valid(&self) -> bool {
-        let len = self.0.len();
+        if self.is_custom_emoji() {
+            // Custom emoji reactions can be longer than regular reactions, their length is bounded by the shortcode
+            self.custom_emoji().map_or(false, |(_, shortcode)| {
+                (1..=MAX_EMOJI_SHORTCODE_LENGTH).contains(&shortcode.len())
+            })
+        } else {
+            (1..=MAX_REACTION_LENGTH_BYTES).contains(&self.0.len())
+        }
+    }
+
+    pub fn is_custom_emoji(&self) -> bool {
+        self.0.starts_with(CUSTOM_EMOJI_PREFIX)
+    }
+
+    // Custom emoji reactions take the form "custom:{pack_id}:{shortcode}"
+    pub fn custom_emoji(&self) -> Option<(EmojiPackId, &str)> {
+        let (pack_id, shortcode) = self.0.strip_prefix(CUSTOM_EMOJI_PREFIX)?.split_once(':')?;
+
+        Some((pack_id.parse().ok()?, shortcode))
+    }
+}
 
-        (1..=MAX_REACTION_LENGTH_BYTES).contains(&len)
+impl Display for Reaction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
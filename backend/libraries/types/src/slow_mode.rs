@@ -0,0 +1,110 @@
+use crate::{GroupRole, Milliseconds, TimestampMillis};
+use candid::CandidType;
+use serde::{Deserialize, Serialize};
+use std::cmp::{max, min};
+
+pub const MAX_BURST_MESSAGES: u32 = 100;
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SlowMode {
+    // The minimum interval between consecutive messages from each member
+    pub min_interval: Milliseconds,
+    pub burst_limit: Option<BurstLimit>,
+    pub exempt_roles: Vec<GroupRole>,
+}
+
+#[derive(CandidType, Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BurstLimit {
+    pub max_messages: u32,
+    pub window: Milliseconds,
+}
+
+impl SlowMode {
+    pub fn is_exempt(&self, role: GroupRole) -> bool {
+        self.exempt_roles.contains(&role)
+    }
+
+    // `recent_messages` must be in ascending order
+    pub fn next_message_allowed(&self, recent_messages: &[TimestampMillis]) -> TimestampMillis {
+        let mut next = recent_messages.last().map_or(0, |ts| ts + self.min_interval);
+
+        if let Some(burst_limit) = self.burst_limit.filter(|b| b.max_messages > 0) {
+            let max_messages = burst_limit.max_messages as usize;
+            if recent_messages.len() >= max_messages {
+                let oldest_in_burst = recent_messages[recent_messages.len() - max_messages];
+                next = max(next, oldest_in_burst + burst_limit.window);
+            }
+        }
+
+        next
+    }
+
+    // The number of recent message timestamps which need to be retained per member
+    pub fn timestamps_to_retain(&self) -> usize {
+        max(
+            1,
+            self.burst_limit
+                .map_or(0, |b| min(b.max_messages, MAX_BURST_MESSAGES) as usize),
+        )
+    }
+
+    // Caps the burst limit so that the number of timestamps retained per member stays small
+    pub fn capped(mut self) -> SlowMode {
+        if let Some(burst_limit) = self.burst_limit.as_mut() {
+            burst_limit.max_messages = min(burst_limit.max_messages, MAX_BURST_MESSAGES);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_recent_messages_allows_sending_immediately() {
+        let slow_mode = slow_mode(1000, Some((3, 60_000)));
+
+        assert_eq!(slow_mode.next_message_allowed(&[]), 0);
+    }
+
+    #[test]
+    fn min_interval_applies_from_latest_message() {
+        let slow_mode = slow_mode(1000, None);
+
+        assert_eq!(slow_mode.next_message_allowed(&[500, 2000]), 3000);
+    }
+
+    #[test]
+    fn burst_limit_applies_once_reached() {
+        let slow_mode = slow_mode(1000, Some((3, 60_000)));
+
+        assert_eq!(slow_mode.next_message_allowed(&[1000, 2000]), 3000);
+        assert_eq!(slow_mode.next_message_allowed(&[1000, 2000, 3000]), 61_000);
+        assert_eq!(slow_mode.next_message_allowed(&[0, 1000, 70_000, 71_000]), 72_000);
+    }
+
+    #[test]
+    fn zero_burst_limit_is_ignored() {
+        let slow_mode = slow_mode(1000, Some((0, 60_000)));
+
+        assert_eq!(slow_mode.next_message_allowed(&[1000]), 2000);
+        assert_eq!(slow_mode.timestamps_to_retain(), 1);
+    }
+
+    #[test]
+    fn burst_limit_is_capped() {
+        let slow_mode = slow_mode(1000, Some((u32::MAX, 60_000))).capped();
+
+        assert_eq!(slow_mode.burst_limit.unwrap().max_messages, MAX_BURST_MESSAGES);
+        assert_eq!(slow_mode.timestamps_to_retain(), MAX_BURST_MESSAGES as usize);
+    }
+
+    fn slow_mode(min_interval: Milliseconds, burst_limit: Option<(u32, Milliseconds)>) -> SlowMode {
+        SlowMode {
+            min_interval,
+            burst_limit: burst_limit.map(|(max_messages, window)| BurstLimit { max_messages, window }),
+            exempt_roles: Vec::new(),
+        }
+    }
+}
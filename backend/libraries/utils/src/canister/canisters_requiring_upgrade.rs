@@ -1,9 +1,10 @@
+use crate::canister::UpgradeRollout;
 use candid::CandidType;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashSet, VecDeque};
 use tracing::info;
-use types::{BuildVersion, CanisterId};
+use types::{BuildVersion, CanisterId, TimestampMillis, UpgradeRolloutAction, UpgradeRolloutConfig, UpgradeRolloutSummary};
 
 #[derive(CandidType, Serialize, Deserialize)]
 pub struct FailedUpgrade {
@@ -18,6 +19,8 @@ pub struct CanistersRequiringUpgrade {
     in_progress: HashSet<CanisterId>,
     failed: VecDeque<FailedUpgrade>,
     completed: u64,
+    #[serde(default)]
+    rollout: Option<UpgradeRollout>,
 }
 
 impl CanistersRequiringUpgrade {
@@ -25,28 +28,74 @@ impl CanistersRequiringUpgrade {
         self.pending.push_back((canister_id, force));
     }
 
+    // Stages the upgrades of the canisters currently pending. Forced upgrades are not part of the
+    // rollout and so are never held back by it.
+    pub fn start_rollout(&mut self, wasm_version: BuildVersion, config: UpgradeRolloutConfig, now: TimestampMillis) {
+        let total_canisters = self.pending.iter().filter(|(_, force)| !force).count();
+        self.rollout = Some(UpgradeRollout::new(wasm_version, config, total_canisters, now));
+    }
+
+    // Returns false if there is no rollout or the action is not valid for its current status
+    pub fn update_rollout(&mut self, action: UpgradeRolloutAction, now: TimestampMillis) -> bool {
+        let applied = self.rollout.as_mut().is_some_and(|r| r.apply(action, now));
+        if applied && matches!(action, UpgradeRolloutAction::Abort) {
+            self.pending.retain(|(_, force)| *force);
+        }
+        applied
+    }
+
+    pub fn rollout(&self) -> Option<UpgradeRolloutSummary> {
+        self.rollout.as_ref().map(|r| r.summary())
+    }
+
     pub fn try_take_next(&mut self) -> Option<(CanisterId, bool)> {
-        let (canister_id, force) = self.pending.pop_front()?;
+        let index = if self.is_held_back_by_rollout() { self.pending.iter().position(|(_, force)| *force)? } else { 0 };
+
+        let (canister_id, force) = self.pending.remove(index)?;
         self.in_progress.insert(canister_id);
+        if !force {
+            if let Some(rollout) = self.rollout.as_mut() {
+                rollout.on_upgrade_started(canister_id);
+            }
+        }
         Some((canister_id, force))
     }
 
+    // Whether there are any pending upgrades which can be started now
+    pub fn has_upgrades_ready(&self) -> bool {
+        if self.is_held_back_by_rollout() {
+            self.pending.iter().any(|(_, force)| *force)
+        } else {
+            !self.pending.is_empty()
+        }
+    }
+
     pub fn clear(&mut self) {
         self.pending.clear();
+        self.rollout = None;
     }
 
-    pub fn mark_success(&mut self, canister_id: &CanisterId) {
+    pub fn mark_success(&mut self, canister_id: &CanisterId, now: TimestampMillis) {
         self.mark_upgrade_no_longer_in_progress(canister_id);
         self.completed += 1;
+        if let Some(rollout) = self.rollout.as_mut() {
+            rollout.on_upgrade_succeeded(canister_id, now);
+        }
     }
 
-    pub fn mark_failure(&mut self, failed_upgrade: FailedUpgrade) {
+    pub fn mark_failure(&mut self, failed_upgrade: FailedUpgrade, now: TimestampMillis) {
         self.mark_upgrade_no_longer_in_progress(&failed_upgrade.canister_id);
+        if let Some(rollout) = self.rollout.as_mut() {
+            rollout.on_upgrade_failed(&failed_upgrade.canister_id, now);
+        }
         self.failed.push_back(failed_upgrade);
     }
 
-    pub fn mark_skipped(&mut self, canister_id: &CanisterId) {
+    pub fn mark_skipped(&mut self, canister_id: &CanisterId, now: TimestampMillis) {
         self.mark_upgrade_no_longer_in_progress(canister_id);
+        if let Some(rollout) = self.rollout.as_mut() {
+            rollout.on_upgrade_skipped(canister_id, now);
+        }
     }
 
     pub fn is_in_progress(&self, canister_id: &CanisterId) -> bool {
@@ -77,9 +126,16 @@ impl CanistersRequiringUpgrade {
             in_progress: self.in_progress.len(),
             failed,
             completed: self.completed,
+            rollout: self.rollout(),
         }
     }
 
+    fn is_held_back_by_rollout(&self) -> bool {
+        self.rollout
+            .as_ref()
+            .is_some_and(|r| !r.is_finished() && !r.can_start_upgrade())
+    }
+
     fn mark_upgrade_no_longer_in_progress(&mut self, canister_id: &CanisterId) {
         if self.in_progress.remove(canister_id) && self.pending.is_empty() && self.in_progress.is_empty() {
             info!("Canister upgrade queue is now empty");
@@ -92,6 +148,7 @@ pub struct Metrics {
     pub failed: Vec<FailedUpgradeCount>,
     pub pending: usize,
     pub in_progress: usize,
+    pub rollout: Option<UpgradeRolloutSummary>,
 }
 
 #[derive(CandidType, Serialize, Debug)]
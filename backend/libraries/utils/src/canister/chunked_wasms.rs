@@ -0,0 +1,199 @@
+use crate::time::DAY_IN_MS;
+use ic_cdk::api::call::CallResult;
+use serde::{Deserialize, Serialize};
+use sha256::sha256;
+use std::collections::HashMap;
+use std::future::Future;
+use types::{
+    CanisterId, ChunkedWasmError, Hash, TimestampMillis, UpgradeCanisterWasmArgs, UploadWasmChunkArgs, UploadWasmChunkResponse,
+    UploadWasmChunkSuccess, WASM_CHUNK_SIZE,
+};
+
+// Wasms are installed by passing the whole module to `install_code` in a single call to the
+// management canister, so they can be no larger than the maximum payload of a call between
+// canisters on the same subnet
+const MAX_WASM_SIZE: usize = 10 * 1024 * 1024;
+
+// Wasms which are too large to be sent in a single message are uploaded chunk by chunk into this
+// staging area, keyed by the hash of the complete wasm. A wasm can only be taken out once all of
+// its chunks have been received and its hash verified.
+#[derive(Serialize, Deserialize, Default)]
+pub struct ChunkedWasms {
+    wasms: HashMap<Hash, PartialWasm>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PartialWasm {
+    #[serde(with = "serde_bytes")]
+    bytes: Vec<u8>,
+    chunks: u32,
+    last_updated: TimestampMillis,
+}
+
+impl ChunkedWasms {
+    pub fn push_chunk(&mut self, args: UploadWasmChunkArgs, now: TimestampMillis) -> UploadWasmChunkResponse {
+        // Discard any uploads which were abandoned part way through
+        self.wasms.retain(|_, w| now.saturating_sub(w.last_updated) < DAY_IN_MS);
+
+        let wasm = self.wasms.entry(args.wasm_hash).or_insert_with(|| PartialWasm {
+            bytes: Vec::new(),
+            chunks: 0,
+            last_updated: now,
+        });
+
+        if args.index > wasm.chunks {
+            return UploadWasmChunkResponse::UnexpectedIndex(wasm.chunks);
+        }
+
+        // Chunks which have already been received are ignored so that uploads can safely be retried
+        if args.index == wasm.chunks {
+            if wasm.bytes.len() + args.chunk.len() > MAX_WASM_SIZE {
+                self.wasms.remove(&args.wasm_hash);
+                return UploadWasmChunkResponse::WasmTooLarge;
+            }
+            wasm.bytes.extend_from_slice(&args.chunk);
+            wasm.chunks += 1;
+        }
+        wasm.last_updated = now;
+
+        UploadWasmChunkResponse::Success(UploadWasmChunkSuccess {
+            chunks_received: wasm.chunks,
+            total_bytes: wasm.bytes.len() as u64,
+        })
+    }
+
+    // Removes the wasm from the staging area, returning it only if its hash matches
+    pub fn take(&mut self, wasm_hash: Hash) -> Result<Vec<u8>, ChunkedWasmError> {
+        let wasm = self.wasms.remove(&wasm_hash).ok_or(ChunkedWasmError::NotFound)?;
+
+        let actual_hash = sha256(&wasm.bytes);
+        if actual_hash == wasm_hash {
+            Ok(wasm.bytes)
+        } else {
+            Err(ChunkedWasmError::HashMismatch(actual_hash))
+        }
+    }
+
+    // If the args reference a wasm uploaded in chunks, fills in `args.wasm.module` from the staging area
+    pub fn resolve(&mut self, args: &mut UpgradeCanisterWasmArgs) -> Result<(), ChunkedWasmError> {
+        if let Some(wasm_hash) = args.wasm_hash.take() {
+            args.wasm.module = self.take(wasm_hash)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn split_wasm_into_chunks(module: &[u8]) -> (Hash, Vec<UploadWasmChunkArgs>) {
+    let wasm_hash = sha256(module);
+    let chunks = module
+        .chunks(WASM_CHUNK_SIZE)
+        .enumerate()
+        .map(|(index, chunk)| UploadWasmChunkArgs {
+            wasm_hash,
+            index: index as u32,
+            chunk: chunk.to_vec(),
+        })
+        .collect();
+
+    (wasm_hash, chunks)
+}
+
+// Uploads the wasm to the target canister one chunk at a time, returning the hash under which it
+// has been staged
+pub async fn upload_wasm_in_chunks<F, R>(canister_id: CanisterId, module: &[u8], upload_chunk: F) -> Result<Hash, String>
+where
+    F: Fn(CanisterId, UploadWasmChunkArgs) -> R,
+    R: Future<Output = CallResult<UploadWasmChunkResponse>>,
+{
+    let (wasm_hash, chunks) = split_wasm_into_chunks(module);
+
+    for chunk in chunks {
+        let index = chunk.index;
+        match upload_chunk(canister_id, chunk).await {
+            Ok(UploadWasmChunkResponse::Success(_)) => {}
+            Ok(response) => return Err(format!("Failed to upload wasm chunk {index}: {response:?}")),
+            Err(error) => return Err(format!("Failed to upload wasm chunk {index}: {error:?}")),
+        }
+    }
+
+    Ok(wasm_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wasm_reassembled_from_chunks() {
+        let module: Vec<u8> = (0..(2 * WASM_CHUNK_SIZE + 10)).map(|i| i as u8).collect();
+        let (wasm_hash, chunks) = split_wasm_into_chunks(&module);
+        assert_eq!(chunks.len(), 3);
+
+        let mut chunked_wasms = ChunkedWasms::default();
+        let mut chunks = chunks.into_iter();
+        let first = chunks.next().unwrap();
+        let second = chunks.next().unwrap();
+        let third = chunks.next().unwrap();
+
+        assert_eq!(
+            chunked_wasms.push_chunk(third.clone(), 0),
+            UploadWasmChunkResponse::UnexpectedIndex(0)
+        );
+        chunked_wasms.push_chunk(first.clone(), 0);
+        chunked_wasms.push_chunk(second, 0);
+        // Retrying a chunk which has already been received has no effect
+        chunked_wasms.push_chunk(first, 0);
+        assert_eq!(
+            chunked_wasms.push_chunk(third, 0),
+            UploadWasmChunkResponse::Success(UploadWasmChunkSuccess {
+                chunks_received: 3,
+                total_bytes: module.len() as u64,
+            })
+        );
+
+        assert_eq!(chunked_wasms.take(wasm_hash), Ok(module));
+        assert_eq!(chunked_wasms.take(wasm_hash), Err(ChunkedWasmError::NotFound));
+    }
+
+    #[test]
+    fn incomplete_wasm_fails_hash_check() {
+        let module = vec![1; WASM_CHUNK_SIZE + 1];
+        let (wasm_hash, chunks) = split_wasm_into_chunks(&module);
+
+        let mut chunked_wasms = ChunkedWasms::default();
+        chunked_wasms.push_chunk(chunks.into_iter().next().unwrap(), 0);
+
+        assert!(matches!(
+            chunked_wasms.take(wasm_hash),
+            Err(ChunkedWasmError::HashMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn wasm_larger_than_install_limit_rejected() {
+        let module = vec![1; MAX_WASM_SIZE + 1];
+        let (_, chunks) = split_wasm_into_chunks(&module);
+
+        let mut chunked_wasms = ChunkedWasms::default();
+        let responses: Vec<_> = chunks.into_iter().map(|c| chunked_wasms.push_chunk(c, 0)).collect();
+
+        assert_eq!(responses.last(), Some(&UploadWasmChunkResponse::WasmTooLarge));
+        assert!(chunked_wasms.wasms.is_empty());
+    }
+
+    #[test]
+    fn abandoned_uploads_are_discarded() {
+        let (wasm_hash, chunks) = split_wasm_into_chunks(&[1, 2, 3]);
+        let other = UploadWasmChunkArgs {
+            wasm_hash: [0; 32],
+            index: 0,
+            chunk: vec![4],
+        };
+
+        let mut chunked_wasms = ChunkedWasms::default();
+        chunked_wasms.push_chunk(chunks.into_iter().next().unwrap(), 0);
+        chunked_wasms.push_chunk(other, DAY_IN_MS);
+
+        assert_eq!(chunked_wasms.take(wasm_hash), Err(ChunkedWasmError::NotFound));
+    }
+}
@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 use types::BuildVersion;
 
 mod canisters_requiring_upgrade;
+mod chunked_wasms;
 mod create;
 mod delete;
 mod deposit_cycles;
@@ -12,8 +13,10 @@ mod raw_rand;
 mod start;
 mod stop;
 mod update_settings;
+mod upgrade_rollout;
 
 pub use canisters_requiring_upgrade::*;
+pub use chunked_wasms::*;
 pub use create::*;
 pub use delete::*;
 pub use deposit_cycles::*;
@@ -24,6 +27,7 @@ pub use raw_rand::*;
 pub use start::*;
 pub use stop::*;
 pub use update_settings::*;
+pub use upgrade_rollout::*;
 
 pub fn should_perform_upgrade(current: BuildVersion, next: BuildVersion, test_mode: bool) -> bool {
     match current.cmp(&next) {
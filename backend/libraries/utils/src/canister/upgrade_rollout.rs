@@ -0,0 +1,305 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tracing::info;
+use types::{
+    BuildVersion, CanisterId, TimestampMillis, UpgradeRolloutAction, UpgradeRolloutConfig, UpgradeRolloutPause,
+    UpgradeRolloutPauseReason, UpgradeRolloutStageSummary, UpgradeRolloutStatus, UpgradeRolloutSummary,
+};
+
+// Upgrades a percentage of the canisters at a time, pausing if too many of the upgrades within a stage fail
+#[derive(Serialize, Deserialize)]
+pub struct UpgradeRollout {
+    wasm_version: BuildVersion,
+    config: UpgradeRolloutConfig,
+    status: UpgradeRolloutStatus,
+    started: TimestampMillis,
+    total_canisters: usize,
+    current_stage: usize,
+    stages: Vec<Stage>,
+    in_progress: HashSet<CanisterId>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct Stage {
+    target_percent: u8,
+    canisters: usize,
+    started: Option<TimestampMillis>,
+    completed: Option<TimestampMillis>,
+    taken: usize,
+    succeeded: usize,
+    failed: usize,
+    skipped: usize,
+    // The outcomes already accepted by an operator when they resumed the rollout
+    succeeded_before_resume: usize,
+    failed_before_resume: usize,
+}
+
+impl Stage {
+    fn finished(&self) -> usize {
+        self.succeeded + self.failed + self.skipped
+    }
+
+    fn is_complete(&self) -> bool {
+        self.finished() >= self.canisters
+    }
+}
+
+impl UpgradeRollout {
+    pub fn new(
+        wasm_version: BuildVersion,
+        config: UpgradeRolloutConfig,
+        total_canisters: usize,
+        now: TimestampMillis,
+    ) -> UpgradeRollout {
+        let mut percentages: Vec<_> = config.stages.iter().copied().filter(|p| (1..=100).contains(p)).collect();
+        percentages.sort_unstable();
+        percentages.dedup();
+        if percentages.last() != Some(&100) {
+            percentages.push(100);
+        }
+
+        let mut stages = Vec::with_capacity(percentages.len());
+        let mut upgraded_by_previous_stages = 0;
+        for target_percent in percentages {
+            let cumulative = (total_canisters * target_percent as usize).div_ceil(100);
+            stages.push(Stage {
+                target_percent,
+                canisters: cumulative - upgraded_by_previous_stages,
+                ..Default::default()
+            });
+            upgraded_by_previous_stages = cumulative;
+        }
+
+        let mut rollout = UpgradeRollout {
+            wasm_version,
+            config,
+            status: UpgradeRolloutStatus::InProgress,
+            started: now,
+            total_canisters,
+            current_stage: 0,
+            stages,
+            in_progress: HashSet::new(),
+        };
+        rollout.stages[0].started = Some(now);
+        rollout.advance_if_stage_complete(now);
+        rollout
+    }
+
+    pub fn can_start_upgrade(&self) -> bool {
+        matches!(self.status, UpgradeRolloutStatus::InProgress)
+            && self.stages.get(self.current_stage).is_some_and(|s| s.taken < s.canisters)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            UpgradeRolloutStatus::Completed(_) | UpgradeRolloutStatus::Aborted(_)
+        )
+    }
+
+    pub fn on_upgrade_started(&mut self, canister_id: CanisterId) {
+        if let Some(stage) = self.stages.get_mut(self.current_stage) {
+            stage.taken += 1;
+            self.in_progress.insert(canister_id);
+        }
+    }
+
+    pub fn on_upgrade_succeeded(&mut self, canister_id: &CanisterId, now: TimestampMillis) {
+        if self.in_progress.remove(canister_id) {
+            self.stages[self.current_stage].succeeded += 1;
+            self.evaluate(now);
+        }
+    }
+
+    pub fn on_upgrade_failed(&mut self, canister_id: &CanisterId, now: TimestampMillis) {
+        if self.in_progress.remove(canister_id) {
+            self.stages[self.current_stage].failed += 1;
+            self.evaluate(now);
+        }
+    }
+
+    pub fn on_upgrade_skipped(&mut self, canister_id: &CanisterId, now: TimestampMillis) {
+        if self.in_progress.remove(canister_id) {
+            self.stages[self.current_stage].skipped += 1;
+            self.evaluate(now);
+        }
+    }
+
+    // Returns true if the action was applied
+    pub fn apply(&mut self, action: UpgradeRolloutAction, now: TimestampMillis) -> bool {
+        match (action, self.status) {
+            (UpgradeRolloutAction::Pause, UpgradeRolloutStatus::InProgress) => {
+                self.pause(UpgradeRolloutPauseReason::Operator, now);
+                true
+            }
+            (UpgradeRolloutAction::Resume, UpgradeRolloutStatus::Paused(_)) => {
+                let stage = &mut self.stages[self.current_stage];
+                stage.succeeded_before_resume = stage.succeeded;
+                stage.failed_before_resume = stage.failed;
+                self.status = UpgradeRolloutStatus::InProgress;
+                info!(wasm_version = %self.wasm_version, "Upgrade rollout resumed");
+                self.advance_if_stage_complete(now);
+                true
+            }
+            (UpgradeRolloutAction::Abort, UpgradeRolloutStatus::InProgress | UpgradeRolloutStatus::Paused(_)) => {
+                self.status = UpgradeRolloutStatus::Aborted(now);
+                info!(wasm_version = %self.wasm_version, "Upgrade rollout aborted");
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn summary(&self) -> UpgradeRolloutSummary {
+        UpgradeRolloutSummary {
+            wasm_version: self.wasm_version,
+            config: self.config.clone(),
+            status: self.status,
+            started: self.started,
+            total_canisters: self.total_canisters as u32,
+            current_stage: self.current_stage as u32,
+            stages: self
+                .stages
+                .iter()
+                .map(|s| UpgradeRolloutStageSummary {
+                    target_percent: s.target_percent,
+                    canisters: s.canisters as u32,
+                    started: s.started,
+                    completed: s.completed,
+                    succeeded: s.succeeded as u32,
+                    failed: s.failed as u32,
+                })
+                .collect(),
+        }
+    }
+
+    fn evaluate(&mut self, now: TimestampMillis) {
+        if !matches!(self.status, UpgradeRolloutStatus::InProgress) {
+            return;
+        }
+
+        let stage = &self.stages[self.current_stage];
+        let succeeded = stage.succeeded - stage.succeeded_before_resume;
+        let failed = stage.failed - stage.failed_before_resume;
+        let evaluated = succeeded + failed;
+
+        // Once the stage is complete it is evaluated regardless of how small it was
+        if evaluated > 0 && (evaluated >= self.config.min_upgrades_before_evaluating as usize || stage.is_complete()) {
+            let failure_rate_percent = (failed * 100 / evaluated) as u8;
+            if failure_rate_percent > self.config.max_failure_rate_percent {
+                self.pause(UpgradeRolloutPauseReason::FailureRateExceeded(failure_rate_percent), now);
+                return;
+            }
+        }
+
+        self.advance_if_stage_complete(now);
+    }
+
+    fn advance_if_stage_complete(&mut self, now: TimestampMillis) {
+        while matches!(self.status, UpgradeRolloutStatus::InProgress) && self.stages[self.current_stage].is_complete() {
+            self.stages[self.current_stage].completed = Some(now);
+
+            if self.current_stage + 1 < self.stages.len() {
+                self.current_stage += 1;
+                self.stages[self.current_stage].started = Some(now);
+                info!(
+                    wasm_version = %self.wasm_version,
+                    stage = self.current_stage,
+                    "Upgrade rollout advanced to next stage"
+                );
+            } else {
+                self.status = UpgradeRolloutStatus::Completed(now);
+                info!(wasm_version = %self.wasm_version, "Upgrade rollout completed");
+            }
+        }
+    }
+
+    fn pause(&mut self, reason: UpgradeRolloutPauseReason, now: TimestampMillis) {
+        self.status = UpgradeRolloutStatus::Paused(UpgradeRolloutPause { timestamp: now, reason });
+        info!(wasm_version = %self.wasm_version, ?reason, "Upgrade rollout paused");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    fn canister(i: u8) -> CanisterId {
+        Principal::from_slice(&[i])
+    }
+
+    fn config() -> UpgradeRolloutConfig {
+        UpgradeRolloutConfig {
+            stages: vec![10, 50],
+            max_failure_rate_percent: 20,
+            min_upgrades_before_evaluating: 5,
+        }
+    }
+
+    #[test]
+    fn stages_cover_all_canisters() {
+        let rollout = UpgradeRollout::new(BuildVersion::new(1, 0, 0), config(), 95, 0);
+        let canisters: Vec<_> = rollout.stages.iter().map(|s| s.canisters).collect();
+
+        assert_eq!(canisters, vec![10, 38, 47]);
+    }
+
+    #[test]
+    fn advances_through_stages_then_completes() {
+        let mut rollout = UpgradeRollout::new(BuildVersion::new(1, 0, 0), config(), 10, 0);
+
+        for i in 0..10 {
+            assert!(rollout.can_start_upgrade());
+            rollout.on_upgrade_started(canister(i));
+            rollout.on_upgrade_succeeded(&canister(i), i as u64);
+        }
+
+        assert!(!rollout.can_start_upgrade());
+        assert_eq!(rollout.status, UpgradeRolloutStatus::Completed(9));
+    }
+
+    #[test]
+    fn pauses_when_failure_rate_exceeded_then_resumes() {
+        let mut rollout = UpgradeRollout::new(BuildVersion::new(1, 0, 0), config(), 100, 0);
+
+        for i in 0..10 {
+            rollout.on_upgrade_started(canister(i));
+        }
+        assert!(!rollout.can_start_upgrade());
+
+        for i in 0..3 {
+            rollout.on_upgrade_succeeded(&canister(i), 1);
+        }
+        for i in 3..5 {
+            rollout.on_upgrade_failed(&canister(i), 2);
+        }
+
+        assert!(matches!(
+            rollout.status,
+            UpgradeRolloutStatus::Paused(UpgradeRolloutPause {
+                reason: UpgradeRolloutPauseReason::FailureRateExceeded(40),
+                ..
+            })
+        ));
+
+        // Upgrades already in flight still count towards the stage
+        for i in 5..10 {
+            rollout.on_upgrade_succeeded(&canister(i), 3);
+        }
+        assert_eq!(rollout.current_stage, 0);
+
+        assert!(rollout.apply(UpgradeRolloutAction::Resume, 4));
+        assert_eq!(rollout.current_stage, 1);
+        assert!(rollout.can_start_upgrade());
+    }
+
+    #[test]
+    fn abort_stops_further_upgrades() {
+        let mut rollout = UpgradeRollout::new(BuildVersion::new(1, 0, 0), config(), 100, 0);
+
+        assert!(rollout.apply(UpgradeRolloutAction::Abort, 1));
+        assert!(!rollout.can_start_upgrade());
+        assert!(!rollout.apply(UpgradeRolloutAction::Resume, 2));
+    }
+}
@@ -16,6 +16,7 @@ async fn main() -> Result<(), Error> {
     let vapid_private_pem = dotenv::var("VAPID_PRIVATE_PEM")?;
     let index_canister_id = Principal::from_text(dotenv::var("NOTIFICATIONS_INDEX_CANISTER_ID")?).unwrap();
     let notifications_canister_ids_string = dotenv::var("NOTIFICATIONS_CANISTER_IDS")?;
+    let storage_index_canister_id = Principal::from_text(dotenv::var("STORAGE_INDEX_CANISTER_ID")?).unwrap();
     let ic_url = dotenv::var("IC_URL")?;
     let ic_identity_pem = dotenv::var("IC_IDENTITY_PEM")?;
     let is_production = bool::from_str(&dotenv::var("IS_PRODUCTION")?).unwrap();
@@ -38,6 +39,7 @@ async fn main() -> Result<(), Error> {
         ic_agent,
         index_canister_id,
         notifications_canister_ids,
+        storage_index_canister_id,
         dynamodb_index_store,
         vapid_private_pem,
         5,
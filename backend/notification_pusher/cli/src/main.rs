@@ -19,6 +19,7 @@ async fn main() -> Result<(), Error> {
     let vapid_private_pem = dotenv::var("VAPID_PRIVATE_PEM")?;
     let index_canister_id = Principal::from_text(dotenv::var("NOTIFICATIONS_INDEX_CANISTER_ID")?)?;
     let notifications_canister_id = Principal::from_text(dotenv::var("NOTIFICATIONS_CANISTER_ID")?)?;
+    let storage_index_canister_id = Principal::from_text(dotenv::var("STORAGE_INDEX_CANISTER_ID")?)?;
     let index_store = DummyStore::new(HashMap::from([(notifications_canister_id, index)]));
     let ic_url = dotenv::var("IC_URL")?;
     let ic_identity_pem = dotenv::var("IC_IDENTITY_PEM")?;
@@ -32,6 +33,7 @@ async fn main() -> Result<(), Error> {
         ic_agent,
         index_canister_id,
         vec![notifications_canister_id],
+        storage_index_canister_id,
         index_store,
         vapid_private_pem,
         1,
@@ -1,11 +1,17 @@
 use ic_agent::agent::http_transport::ReqwestHttpReplicaV2Transport;
 use ic_agent::identity::BasicIdentity;
 use ic_agent::{Agent, Identity};
-use notifications_canister::{latest_notification_index, notifications, remove_notifications};
+use notifications_canister::{
+    latest_notification_index, link_preview_requests, notifications, remove_link_preview_requests, remove_notifications,
+    set_link_previews,
+};
 use notifications_index_canister::remove_subscriptions;
+use serde_bytes::ByteBuf;
 use std::collections::HashMap;
+use storage_bucket_canister::upload_chunk_v2;
+use storage_index_canister::allocated_bucket_v2;
 use tracing::trace;
-use types::{CanisterId, Error, UserId};
+use types::{BlobReference, CanisterId, Error, IndexedEvent, LinkPreview, LinkPreviewRequest, UserId};
 
 #[derive(Clone)]
 pub struct IcAgent {
@@ -95,6 +101,94 @@ impl IcAgent {
         Ok(())
     }
 
+    pub async fn link_preview_requests(
+        &self,
+        notifications_canister_id: &CanisterId,
+        from_request_index: u64,
+    ) -> Result<Vec<IndexedEvent<LinkPreviewRequest>>, Error> {
+        let args = link_preview_requests::Args { from_request_index };
+
+        let link_preview_requests::Response::Success(result) =
+            notifications_canister_client::link_preview_requests(&self.agent, notifications_canister_id, &args).await?;
+
+        Ok(result.requests)
+    }
+
+    pub async fn set_link_previews(
+        &self,
+        notifications_canister_id: &CanisterId,
+        request_index: u64,
+        link_previews: Vec<LinkPreview>,
+    ) -> Result<set_link_previews::Response, Error> {
+        let args = set_link_previews::Args {
+            request_index,
+            link_previews,
+        };
+
+        trace!(?args, "set_link_previews::args");
+
+        Ok(notifications_canister_client::set_link_previews(&self.agent, notifications_canister_id, &args).await?)
+    }
+
+    pub async fn remove_link_preview_requests(
+        &self,
+        notifications_canister_id: &CanisterId,
+        up_to_request_index: u64,
+    ) -> Result<(), Error> {
+        let args = remove_link_preview_requests::Args { up_to_request_index };
+
+        notifications_canister_client::remove_link_preview_requests(&self.agent, notifications_canister_id, &args).await?;
+
+        Ok(())
+    }
+
+    // Uploads the file to a storage bucket, granting access to `accessor` so that it can delete the file once it
+    // is no longer needed
+    pub async fn upload_file(
+        &self,
+        storage_index_canister_id: &CanisterId,
+        bytes: Vec<u8>,
+        mime_type: String,
+        accessor: CanisterId,
+    ) -> Result<BlobReference, Error> {
+        let hash = sha256::sha256(&bytes);
+        let args = allocated_bucket_v2::Args {
+            file_hash: hash,
+            file_size: bytes.len() as u64,
+            file_id_seed: None,
+        };
+
+        let allocation =
+            match storage_index_canister_client::allocated_bucket_v2(&self.agent, storage_index_canister_id, &args).await? {
+                allocated_bucket_v2::Response::Success(result) => result,
+                response => return Err(format!("{response:?}").into()),
+            };
+
+        for (chunk_index, chunk) in bytes.chunks(allocation.chunk_size as usize).enumerate() {
+            let args = upload_chunk_v2::Args {
+                file_id: allocation.file_id,
+                hash,
+                mime_type: mime_type.clone(),
+                accessors: vec![accessor],
+                chunk_index: chunk_index as u32,
+                chunk_size: allocation.chunk_size,
+                total_size: bytes.len() as u64,
+                bytes: ByteBuf::from(chunk.to_vec()),
+                expiry: None,
+            };
+
+            match storage_bucket_canister_client::upload_chunk_v2(&self.agent, &allocation.canister_id, &args).await? {
+                upload_chunk_v2::Response::Success => {}
+                response => return Err(format!("{response:?}").into()),
+            }
+        }
+
+        Ok(BlobReference {
+            canister_id: allocation.canister_id,
+            blob_id: allocation.file_id,
+        })
+    }
+
     /// Returns an identity derived from the private key.
     fn get_identity(pem: &str) -> Box<dyn Identity + Sync + Send> {
         match BasicIdentity::from_pem(pem.as_bytes()) {
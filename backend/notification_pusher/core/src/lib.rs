@@ -1,4 +1,5 @@
 use crate::ic_agent::IcAgent;
+use crate::link_previewer::{HttpPageFetcher, LinkPreviewer};
 use crate::pusher::Pusher;
 use crate::reader::Reader;
 use crate::subscription_remover::SubscriptionRemover;
@@ -9,6 +10,7 @@ use types::{CanisterId, UserId};
 use web_push::SubscriptionInfo;
 
 pub mod ic_agent;
+pub mod link_previewer;
 mod pusher;
 mod reader;
 mod subscription_remover;
@@ -17,6 +19,7 @@ pub async fn run_notifications_pusher<I: IndexStore + 'static>(
     ic_agent: IcAgent,
     index_canister_id: CanisterId,
     notifications_canister_ids: Vec<CanisterId>,
+    storage_index_canister_id: CanisterId,
     index_store: I,
     vapid_private_pem: String,
     pusher_count: usize,
@@ -34,6 +37,14 @@ pub async fn run_notifications_pusher<I: IndexStore + 'static>(
             sender.clone(),
         );
         tokio::spawn(reader.run());
+
+        let link_previewer = LinkPreviewer::new(
+            ic_agent.clone(),
+            notification_canister_id,
+            storage_index_canister_id,
+            HttpPageFetcher::new(),
+        );
+        tokio::spawn(link_previewer.run());
     }
 
     for _ in 0..pusher_count {
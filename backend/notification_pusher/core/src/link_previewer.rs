@@ -0,0 +1,360 @@
+use crate::ic_agent::IcAgent;
+use async_trait::async_trait;
+use link_previews::{parse_page_metadata, PageMetadata};
+use notifications_canister::set_link_previews;
+use reqwest::redirect::Policy;
+use reqwest::Url;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+use tokio::net::lookup_host;
+use tokio::time;
+use tracing::{error, info};
+use types::{BlobReference, CanisterId, Error, LinkPreview, LinkPreviewRequest};
+
+const MAX_PAGE_SIZE_BYTES: usize = 2 * 1024 * 1024; // 2MB
+const MAX_IMAGE_SIZE_BYTES: usize = 1024 * 1024; // 1MB
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_REDIRECTS: usize = 5;
+
+#[async_trait]
+pub trait PageFetcher: Send + Sync {
+    async fn fetch_page(&self, url: &str) -> Result<String, Error>;
+
+    // Returns the image bytes along with their mime type
+    async fn fetch_image(&self, url: &str) -> Result<(Vec<u8>, String), Error>;
+}
+
+// Fetches pages over HTTP, refusing to connect to any address which isn't publicly routable so that
+// links can't be used to probe the network which the previewer runs within
+pub struct HttpPageFetcher;
+
+impl HttpPageFetcher {
+    pub fn new() -> HttpPageFetcher {
+        HttpPageFetcher
+    }
+
+    async fn fetch(&self, url: &str, max_size: usize) -> Result<(Vec<u8>, Option<String>), Error> {
+        let mut url = Url::parse(url)?;
+
+        // Redirects are followed manually so that the address of each hop can be checked
+        for _ in 0..=MAX_REDIRECTS {
+            let client = build_client(&url).await?;
+            let mut response = client.get(url.clone()).send().await?;
+
+            if response.status().is_redirection() {
+                let location = response
+                    .headers()
+                    .get(reqwest::header::LOCATION)
+                    .and_then(|h| h.to_str().ok())
+                    .ok_or_else(|| format!("Redirect without location. Url: {url}"))?;
+
+                url = url.join(location)?;
+                continue;
+            }
+
+            response = response.error_for_status()?;
+
+            if response.content_length().map_or(false, |length| length as usize > max_size) {
+                return Err(format!("Response too large. Url: {url}").into());
+            }
+
+            let content_type = response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|h| h.to_str().ok())
+                .map(|h| h.to_string());
+
+            // The body is read in chunks so that we stop as soon as the limit is exceeded, even if
+            // the server didn't specify the content length
+            let mut bytes = Vec::new();
+            while let Some(chunk) = response.chunk().await? {
+                if bytes.len() + chunk.len() > max_size {
+                    return Err(format!("Response too large. Url: {url}").into());
+                }
+                bytes.extend_from_slice(&chunk);
+            }
+
+            return Ok((bytes, content_type));
+        }
+
+        Err(format!("Too many redirects. Url: {url}").into())
+    }
+}
+
+// Resolves the url's host and builds a client which can only connect to the resolved address, so the
+// host can't be re-resolved to a different address between the check and the request
+async fn build_client(url: &Url) -> Result<reqwest::Client, Error> {
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(format!("Unsupported scheme. Url: {url}").into());
+    }
+
+    let host = url.host_str().ok_or_else(|| format!("Url has no host. Url: {url}"))?;
+    let port = url.port_or_known_default().unwrap_or(443);
+
+    let mut builder = reqwest::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .user_agent("OpenChatLinkPreviewer/1.0")
+        .redirect(Policy::none());
+
+    // IPv6 hosts are enclosed in square brackets within urls
+    let address = match host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>() {
+        Ok(ip) => SocketAddr::new(ip, port),
+        Err(_) => {
+            let address = lookup_host((host, port))
+                .await?
+                .next()
+                .ok_or_else(|| format!("Host not found. Url: {url}"))?;
+
+            builder = builder.resolve(host, address);
+            address
+        }
+    };
+
+    if !is_public_ip(address.ip()) {
+        return Err(format!("Address not allowed. Url: {url}").into());
+    }
+
+    Ok(builder.build()?)
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => {
+            if let Some(ipv4) = ip.to_ipv4_mapped() {
+                return is_public_ipv4(ipv4);
+            }
+            let first_segment = ip.segments()[0];
+
+            !(ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                // Unique local (fc00::/7)
+                || (first_segment & 0xfe00) == 0xfc00
+                // Link local (fe80::/10)
+                || (first_segment & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        // Shared address space (100.64.0.0/10)
+        || (first == 100 && (second & 0xc0) == 64)
+        // "This network" (0.0.0.0/8)
+        || first == 0)
+}
+
+#[async_trait]
+impl PageFetcher for HttpPageFetcher {
+    async fn fetch_page(&self, url: &str) -> Result<String, Error> {
+        let (bytes, content_type) = self.fetch(url, MAX_PAGE_SIZE_BYTES).await?;
+
+        if !content_type.map_or(false, |c| c.starts_with("text/html")) {
+            return Err(format!("Not an html page. Url: {url}").into());
+        }
+
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    async fn fetch_image(&self, url: &str) -> Result<(Vec<u8>, String), Error> {
+        let (bytes, content_type) = self.fetch(url, MAX_IMAGE_SIZE_BYTES).await?;
+
+        match content_type {
+            Some(mime_type) if mime_type.starts_with("image/") => Ok((bytes, mime_type)),
+            _ => Err(format!("Not an image. Url: {url}").into()),
+        }
+    }
+}
+
+/// Reads the link preview requests queued in a notifications canister, fetches the metadata of each
+/// linked page, uploads the preview images to a storage bucket, then passes the previews back to the
+/// notifications canister to be attached to their messages.
+pub struct LinkPreviewer<F: PageFetcher> {
+    ic_agent: IcAgent,
+    notifications_canister_id: CanisterId,
+    storage_index_canister_id: CanisterId,
+    fetcher: F,
+}
+
+impl<F: PageFetcher> LinkPreviewer<F> {
+    pub fn new(
+        ic_agent: IcAgent,
+        notifications_canister_id: CanisterId,
+        storage_index_canister_id: CanisterId,
+        fetcher: F,
+    ) -> Self {
+        Self {
+            ic_agent,
+            notifications_canister_id,
+            storage_index_canister_id,
+            fetcher,
+        }
+    }
+
+    pub async fn run(self) {
+        info!(%self.notifications_canister_id, "Link previewer started");
+
+        let mut interval = time::interval(time::Duration::from_secs(2));
+        loop {
+            if let Err(error) = self.process_requests().await {
+                error!(?error, "Process link preview requests failed");
+            }
+
+            interval.tick().await;
+        }
+    }
+
+    async fn process_requests(&self) -> Result<(), Error> {
+        // Requests are removed once processed so we can always read from the start of the queue
+        let requests = self
+            .ic_agent
+            .link_preview_requests(&self.notifications_canister_id, 0)
+            .await?;
+
+        if let Some(latest_request_index) = requests.last().map(|r| r.index) {
+            for request in requests {
+                let link_previews = self.build_link_previews(&request.value).await;
+
+                if !link_previews.is_empty() {
+                    match self
+                        .ic_agent
+                        .set_link_previews(&self.notifications_canister_id, request.index, link_previews)
+                        .await
+                    {
+                        Ok(set_link_previews::Response::Success | set_link_previews::Response::MessageNotFound) => {}
+                        Ok(response) => error!(?response, request.index, "Failed to set link previews"),
+                        Err(error) => error!(?error, request.index, "Failed to set link previews"),
+                    }
+                }
+            }
+
+            self.ic_agent
+                .remove_link_preview_requests(&self.notifications_canister_id, latest_request_index)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn build_link_previews(&self, request: &LinkPreviewRequest) -> Vec<LinkPreview> {
+        let mut link_previews = Vec::new();
+
+        for url in request.urls.iter() {
+            if let Some(metadata) = fetch_page_metadata(&self.fetcher, url).await {
+                let image = match metadata.image_url {
+                    Some(image_url) => self.upload_image(&image_url, request.canister_id).await,
+                    None => None,
+                };
+
+                link_previews.push(LinkPreview {
+                    url: url.clone(),
+                    title: metadata.title,
+                    description: metadata.description,
+                    image,
+                });
+            }
+        }
+
+        link_previews
+    }
+
+    // The image is stored in a storage bucket so that clients never need to fetch it from the linked site.
+    // The canister holding the message is made an accessor so that it can delete the image along with the
+    // message.
+    async fn upload_image(&self, image_url: &str, accessor: CanisterId) -> Option<BlobReference> {
+        let (bytes, mime_type) = self.fetcher.fetch_image(image_url).await.ok()?;
+
+        match self
+            .ic_agent
+            .upload_file(&self.storage_index_canister_id, bytes, mime_type, accessor)
+            .await
+        {
+            Ok(blob_reference) => Some(blob_reference),
+            Err(error) => {
+                error!(?error, image_url, "Failed to upload link preview image");
+                None
+            }
+        }
+    }
+}
+
+async fn fetch_page_metadata<F: PageFetcher>(fetcher: &F, url: &str) -> Option<PageMetadata> {
+    let html = fetcher.fetch_page(url).await.ok()?;
+    let metadata = parse_page_metadata(&html, url);
+
+    (!metadata.is_empty()).then_some(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    // Serves pages from memory rather than over the network
+    struct TestPageFetcher {
+        pages: HashMap<String, String>,
+    }
+
+    #[async_trait]
+    impl PageFetcher for TestPageFetcher {
+        async fn fetch_page(&self, url: &str) -> Result<String, Error> {
+            self.pages.get(url).cloned().ok_or_else(|| "Not found".into())
+        }
+
+        async fn fetch_image(&self, _url: &str) -> Result<(Vec<u8>, String), Error> {
+            Ok((vec![1, 2, 3], "image/png".to_string()))
+        }
+    }
+
+    #[test]
+    fn only_public_ips_are_allowed() {
+        for ip in ["1.1.1.1", "8.8.8.8", "2606:4700:4700::1111"] {
+            assert!(is_public_ip(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_public_ip(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_page_metadata_from_test_pages() {
+        let fetcher = TestPageFetcher {
+            pages: HashMap::from([
+                (
+                    "https://oc.app/".to_string(),
+                    r#"<head><meta property="og:title" content="OpenChat"><meta property="og:image" content="/logo.png"></head>"#
+                        .to_string(),
+                ),
+                ("https://example.com/".to_string(), "<body>No metadata</body>".to_string()),
+            ]),
+        };
+
+        let metadata = fetch_page_metadata(&fetcher, "https://oc.app/").await.unwrap();
+        assert_eq!(metadata.title.as_deref(), Some("OpenChat"));
+        assert_eq!(metadata.image_url.as_deref(), Some("https://oc.app/logo.png"));
+
+        assert!(fetch_page_metadata(&fetcher, "https://example.com/").await.is_none());
+        assert!(fetch_page_metadata(&fetcher, "https://missing.com/").await.is_none());
+    }
+}
@@ -30,11 +30,40 @@ echo "URL: $URL"
 # Download the canister WASM at the given commit
 ./scripts/download-canister-wasm.sh $CANISTER_NAME $COMMIT_ID || exit 1
 
+# Setup variables
+WASM_FILE=$CANISTER_NAME.wasm.gz
+WASM_PATH=$WASM_FOLDER/$WASM_FILE
+PROPOSAL_BUILDER_FOLDER=$SCRIPT_DIR/../backend/canister_upgrade_proposal_builder
+
+# If DRY_RUN=true, print the decoded proposal and check it against the deployed canister rather than submitting it
+if [ "$DRY_RUN" = "true" ] ; then
+    # Custom functions are compared against the wasm version held by the index canister which owns them
+    if [ "$FUNCTION_ID" -lt "1000" ] ; then
+        COMPARE_TO=$CANISTER_NAME
+    else
+        case $((FUNCTION_ID / 1000)) in
+            1) COMPARE_TO=user_index ;;
+            2) COMPARE_TO=group_index ;;
+            3) COMPARE_TO=notifications_index ;;
+            5) COMPARE_TO=storage_index ;;
+        esac
+    fi
+
+    if [ -n "$COMPARE_TO" ] ; then
+        CANISTER_ID_ARG="--canister-id $(dfx -qq canister --network $NETWORK id $COMPARE_TO)"
+    fi
+
+    cd $PROPOSAL_BUILDER_FOLDER
+    cargo run --quiet -- --dry-run --title "$TITLE" --summary "$SUMMARY" --url "$URL" --function-id $FUNCTION_ID --wasm-path "$WASM_PATH" --version $VERSION $CANISTER_ID_ARG
+    EXIT_CODE=$?
+
+    cd $SCRIPT_DIR/..
+    ./sns/scripts/utils/cleanup_env.sh
+    exit $EXIT_CODE
+fi
+
 if [ "$FUNCTION_ID" -ge "1000" ] ; then
     # Setup variables
-    WASM_FILE=$CANISTER_NAME.wasm.gz
-    WASM_PATH=$WASM_FOLDER/$WASM_FILE
-    PROPOSAL_BUILDER_FOLDER=$SCRIPT_DIR/../backend/canister_upgrade_proposal_builder    
     PROPOSAL_FILE=proposal.candid
     PROPOSAL_BUILDER_PATH=$PROPOSAL_BUILDER_FOLDER/$PROPOSAL_FILE
 